@@ -2,7 +2,10 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{ErrorKind, MergedInterfaces, NmstateError};
+use crate::{
+    mptcp::get_mptcp_flags_overrides, ErrorKind, MergedInterface,
+    MergedInterfaces, NmstateError,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize, Serialize)]
 #[serde(rename_all = "kebab-case")]
@@ -28,17 +31,14 @@ impl MergedInterfaces {
     pub(crate) fn validate_dispatch_script_has_no_checkpoint(
         &self,
     ) -> Result<(), NmstateError> {
-        if self.kernel_ifaces.values().any(|i| {
-            i.is_desired()
-                && i.for_apply
-                    .as_ref()
-                    .map(|f| f.base_iface().dispatch.is_some())
-                    .unwrap_or_default()
-        }) {
+        if self.kernel_ifaces.values().any(use_dispatch_script) {
             if self.gen_conf_mode {
                 return Err(NmstateError::new(
                     ErrorKind::InvalidArgument,
-                    "Dispatch script is not supported in gc(gen_conf) mode"
+                    "Dispatch script, sysctl, static neighbors, IPv6 \
+                    accept-ra-mtu, accept-ra, forwarding and IP address \
+                    specific MPTCP flags are not supported in gc(gen_conf) \
+                    mode"
                         .to_string(),
                 ));
             } else {
@@ -51,3 +51,35 @@ impl MergedInterfaces {
         Ok(())
     }
 }
+
+// Whether the desired interface requires dispatch script of backend.
+fn use_dispatch_script(merged_iface: &MergedInterface) -> bool {
+    if !merged_iface.is_desired() {
+        return false;
+    }
+    let base_iface = match merged_iface.for_apply.as_ref() {
+        Some(i) => i.base_iface(),
+        None => return false,
+    };
+    let iface_mptcp_flags = merged_iface
+        .merged
+        .base_iface()
+        .mptcp
+        .as_ref()
+        .and_then(|m| m.address_flags.as_deref())
+        .unwrap_or_default();
+    base_iface.dispatch.is_some()
+        || base_iface.sysctl.is_some()
+        || base_iface.static_neighbors.is_some()
+        || base_iface
+            .ipv6
+            .as_ref()
+            .map(|i| {
+                i.enabled
+                    && (i.accept_ra_mtu.is_some()
+                        || i.accept_ra.is_some()
+                        || i.forwarding.is_some())
+            })
+            .unwrap_or_default()
+        || !get_mptcp_flags_overrides(base_iface, iface_mptcp_flags).is_empty()
+}
@@ -107,6 +107,8 @@ pub struct DnsClientState {
     pub server: Option<Vec<String>>,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Search list for host-name lookup.
+    /// The domain prefixed with `~` is routing-only domain which is only
+    /// used for choosing name servers instead of host-name lookup.
     /// To remove all existing search, please use `Some(Vec::new())`.
     /// If undefined(set to `None`), will preserve current config.
     pub search: Option<Vec<String>>,
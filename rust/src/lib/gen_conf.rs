@@ -2,7 +2,77 @@
 
 use std::collections::HashMap;
 
-use crate::{nm::nm_gen_conf, MergedNetworkState, NetworkState, NmstateError};
+use crate::{
+    nm::{nm_gen_conf, nm_reconcile, NmConnection},
+    MergedNetworkState, NetworkState, NmstateError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+#[non_exhaustive]
+/// The NetworkManager keyfile operations required to converge current
+/// state to desired state, generated by [NetworkState::reconcile()].
+pub struct NmConnectionOperations {
+    /// New keyfiles as `(config_file_name, config_content)`.
+    pub create: Vec<(String, String)>,
+    /// Changed keyfiles as `(config_file_name, config_content)`.
+    pub modify: Vec<(String, String)>,
+    /// File names of keyfiles to remove.
+    pub delete: Vec<String>,
+}
+
+/// The NetworkManager connection generated by
+/// [NetworkState::gen_conf_with_hook()], handed to the hook for
+/// site-specific tweaks.
+#[derive(Debug)]
+pub struct GenConfNmConnection<'a> {
+    nm_conn: &'a mut NmConnection,
+}
+
+impl<'a> GenConfNmConnection<'a> {
+    pub(crate) fn new(nm_conn: &'a mut NmConnection) -> Self {
+        Self { nm_conn }
+    }
+
+    /// The `connection.id` of this connection.
+    pub fn id(&self) -> Option<&str> {
+        self.nm_conn.id()
+    }
+
+    /// The `connection.uuid` of this connection.
+    pub fn uuid(&self) -> Option<&str> {
+        self.nm_conn.uuid()
+    }
+
+    /// The `connection.interface-name` of this connection.
+    pub fn iface_name(&self) -> Option<&str> {
+        self.nm_conn.iface_name()
+    }
+
+    /// The `connection.type` of this connection.
+    pub fn iface_type(&self) -> Option<&str> {
+        self.nm_conn.iface_type()
+    }
+
+    /// Get the value of specified key from `user` setting.
+    pub fn user_data(&self, key: &str) -> Option<&str> {
+        self.nm_conn
+            .user
+            .as_ref()
+            .and_then(|u| u.data.as_ref())
+            .and_then(|d| d.get(key))
+            .map(String::as_str)
+    }
+
+    /// Store specified key and value into `user` setting.
+    pub fn set_user_data(&mut self, key: &str, value: &str) {
+        self.nm_conn
+            .user
+            .get_or_insert_with(Default::default)
+            .data
+            .get_or_insert_with(Default::default)
+            .insert(key.to_string(), value.to_string());
+    }
+}
 
 impl NetworkState {
     /// Generate offline network configurations.
@@ -16,6 +86,23 @@ impl NetworkState {
     pub fn gen_conf(
         &self,
     ) -> Result<HashMap<String, Vec<(String, String)>>, NmstateError> {
+        self.gen_conf_with_hook(|_| {})
+    }
+
+    /// Identical to [NetworkState::gen_conf()] with `hook` invoked on every
+    /// generated NetworkManager connection for site-specific tweaks.
+    ///
+    /// The hook runs after the connections have been topologically ordered
+    /// and their controller/parent references resolved, hence changes made
+    /// by hook are not taken into account by ordering. The connections are
+    /// then sorted by ID and serialized into keyfiles.
+    pub fn gen_conf_with_hook<F>(
+        &self,
+        hook: F,
+    ) -> Result<HashMap<String, Vec<(String, String)>>, NmstateError>
+    where
+        F: Fn(&mut GenConfNmConnection),
+    {
         let mut ret = HashMap::new();
         let merged_state = MergedNetworkState::new(
             self.clone(),
@@ -23,9 +110,24 @@ impl NetworkState {
             true,  // gen_conf mode
             false, // memory only
         )?;
-        ret.insert("NetworkManager".to_string(), nm_gen_conf(&merged_state)?);
+        ret.insert(
+            "NetworkManager".to_string(),
+            nm_gen_conf(&merged_state, &hook)?,
+        );
         Ok(ret)
     }
+
+    /// Generate the NetworkManager keyfiles to create, modify and delete
+    /// for converging specified current state to this desired state.
+    ///
+    /// Interfaces only mentioned in current state are left untouched unless
+    /// [NetworkState::set_purge_unmanaged()] is enabled on desired state.
+    pub fn reconcile(
+        &self,
+        current: &NetworkState,
+    ) -> Result<NmConnectionOperations, NmstateError> {
+        nm_reconcile(self, current)
+    }
 }
 
 #[cfg(test)]
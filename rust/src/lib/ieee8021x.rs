@@ -1,6 +1,6 @@
 use serde::{Deserialize, Serialize};
 
-use crate::NetworkState;
+use crate::{ErrorKind, NetworkState, NmstateError};
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case")]
@@ -39,8 +39,32 @@ pub struct Ieee8021XConfig {
     pub ca_cert: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Deserialize and serialize from/to `private-key-password`.
+    /// Existing password is preserved if not defined, use empty string to
+    /// remove it.
     /// Replaced to `<_password_hid_by_nmstate>` when querying.
     pub private_key_password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// How the `private-key-password` is stored.
+    /// Deserialize and serialize from/to `private-key-password-storage`.
+    pub private_key_password_storage: Option<SecretStorageMode>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Timeout in seconds for the authentication.
+    /// If not defined, backend default will be used.
+    /// Deserialize and serialize from/to `auth-timeout`.
+    pub auth_timeout: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Number of authentication retries, 0 means retry forever.
+    /// If not defined, existing setting or backend default will be used.
+    /// Deserialize and serialize from/to `auth-retries`.
+    pub auth_retries: Option<u32>,
 }
 
 impl Ieee8021XConfig {
@@ -50,4 +74,87 @@ impl Ieee8021XConfig {
                 Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
         }
     }
+
+    pub(crate) fn sanitize(
+        &self,
+        iface_name: &str,
+    ) -> Result<(), NmstateError> {
+        if let Some(auth_timeout) = self.auth_timeout {
+            if auth_timeout == 0 || auth_timeout > i32::MAX as u32 {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid 802.1x auth-timeout {auth_timeout} of \
+                        interface {iface_name}, should be in the range of \
+                        1 to {}",
+                        i32::MAX
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        if let Some(auth_retries) = self.auth_retries {
+            if auth_retries > i32::MAX as u32 {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid 802.1x auth-retries {auth_retries} of \
+                        interface {iface_name}, should be in the range of \
+                        0 to {}",
+                        i32::MAX
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn sanitize_desired_for_verify(&mut self) {
+        // Secret stored by system is the default
+        if self.private_key_password_storage == Some(SecretStorageMode::None) {
+            self.private_key_password_storage = None;
+        }
+        // Empty password means removal
+        if self.private_key_password.as_deref() == Some("") {
+            self.private_key_password = None;
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Storage mode of secret.
+pub enum SecretStorageMode {
+    /// Secret is stored by the system.
+    /// Deserialize and serialize from/to `none`.
+    None,
+    /// Secret is owned by user session agent which will provide the secret
+    /// when required.
+    /// Deserialize and serialize from/to `agent-owned`.
+    AgentOwned,
+    /// Secret is not saved, but asked each time when required.
+    /// Deserialize and serialize from/to `not-saved`.
+    NotSaved,
+    /// Secret is not required.
+    /// Deserialize and serialize from/to `not-required`.
+    NotRequired,
+}
+
+impl std::fmt::Display for SecretStorageMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::None => "none",
+                Self::AgentOwned => "agent-owned",
+                Self::NotSaved => "not-saved",
+                Self::NotRequired => "not-required",
+            }
+        )
+    }
 }
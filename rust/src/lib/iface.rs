@@ -6,10 +6,11 @@ use serde::{Deserialize, Deserializer, Serialize};
 
 use crate::{
     BaseInterface, BondInterface, DummyInterface, ErrorKind, EthernetInterface,
-    HsrInterface, InfiniBandInterface, IpsecInterface, LinuxBridgeInterface,
-    LoopbackInterface, MacSecInterface, MacVlanInterface, MacVtapInterface,
-    NmstateError, OvsBridgeInterface, OvsInterface, VlanInterface,
-    VrfInterface, VxlanInterface, XfrmInterface,
+    GenericInterface, HsrInterface, InfiniBandInterface, IpsecInterface,
+    LinuxBridgeInterface, LoopbackInterface, MacSecInterface, MacVlanInterface,
+    MacVtapInterface, NmstateError, OvsBridgeInterface, OvsInterface,
+    TeamInterface, VlanInterface, VrfInterface, VxlanInterface,
+    WireGuardInterface, XfrmInterface,
 };
 
 use crate::state::merge_json_value;
@@ -51,6 +52,9 @@ pub enum InterfaceType {
     /// OpenvSwitch system interface.
     /// Deserialize and serialize from/to 'ovs-interface'.
     OvsInterface,
+    /// Team interface provided by libteam(teamd).
+    /// Deserialize and serialize from/to 'team'.
+    Team,
     /// Virtual ethernet provide by Linux kernel.
     /// Deserialize and serialize from/to 'veth'.
     Veth,
@@ -78,6 +82,14 @@ pub enum InterfaceType {
     Ipsec,
     /// Linux Xfrm kernel interface
     Xfrm,
+    /// WireGuard interface.
+    /// Deserialize and serialize from/to 'wireguard'.
+    #[serde(rename = "wireguard")]
+    WireGuard,
+    /// Helper device managed by NetworkManager as `generic` connection.
+    /// Only IP and ethernet settings will be applied.
+    /// Deserialize and serialize from/to 'generic'.
+    Generic,
     /// Unknown interface.
     Unknown,
     /// Reserved for future use.
@@ -108,6 +120,7 @@ impl std::fmt::Display for InterfaceType {
                 InterfaceType::MacVtap => "mac-vtap",
                 InterfaceType::OvsBridge => "ovs-bridge",
                 InterfaceType::OvsInterface => "ovs-interface",
+                InterfaceType::Team => "team",
                 InterfaceType::Veth => "veth",
                 InterfaceType::Vlan => "vlan",
                 InterfaceType::Vrf => "vrf",
@@ -118,6 +131,8 @@ impl std::fmt::Display for InterfaceType {
                 InterfaceType::MacSec => "macsec",
                 InterfaceType::Ipsec => "ipsec",
                 InterfaceType::Xfrm => "xfrm",
+                InterfaceType::WireGuard => "wireguard",
+                InterfaceType::Generic => "generic",
                 InterfaceType::Other(ref s) => s,
             }
         )
@@ -126,8 +141,13 @@ impl std::fmt::Display for InterfaceType {
 
 impl InterfaceType {
     const USERSPACE_IFACE_TYPES: [Self; 2] = [Self::OvsBridge, Self::Ipsec];
-    const CONTROLLER_IFACES_TYPES: [Self; 4] =
-        [Self::Bond, Self::LinuxBridge, Self::OvsBridge, Self::Vrf];
+    const CONTROLLER_IFACES_TYPES: [Self; 5] = [
+        Self::Bond,
+        Self::LinuxBridge,
+        Self::OvsBridge,
+        Self::Team,
+        Self::Vrf,
+    ];
 
     // other interfaces are also considered as userspace
     pub(crate) fn is_userspace(&self) -> bool {
@@ -210,6 +230,15 @@ impl UnknownInterface {
     pub fn new() -> Self {
         Self::default()
     }
+
+    // The interface type not supported by nmstate, `None` if type is
+    // `unknown` or not defined.
+    pub(crate) fn unsupported_type(&self) -> Option<&str> {
+        self.other
+            .get("type")
+            .and_then(|t| t.as_str())
+            .filter(|t| *t != InterfaceType::Unknown.to_string())
+    }
 }
 
 impl<'de> Deserialize<'de> for UnknownInterface {
@@ -268,6 +297,8 @@ pub enum Interface {
     MacVtap(MacVtapInterface),
     /// [Virtual Routing and Forwarding interface](https://www.kernel.org/doc/html/latest/networking/vrf.html)
     Vrf(VrfInterface),
+    /// Team interface provided by libteam(teamd).
+    Team(TeamInterface),
     /// [IP over InfiniBand interface](https://docs.kernel.org/infiniband/ipoib.html)
     InfiniBand(InfiniBandInterface),
     /// Linux loopback interface
@@ -278,6 +309,10 @@ pub enum Interface {
     Ipsec(IpsecInterface),
     /// Linux xfrm interface
     Xfrm(XfrmInterface),
+    /// WireGuard interface
+    WireGuard(WireGuardInterface),
+    /// NetworkManager generic interface
+    Generic(GenericInterface),
 }
 
 impl<'de> Deserialize<'de> for Interface {
@@ -369,6 +404,11 @@ impl<'de> Deserialize<'de> for Interface {
                     .map_err(serde::de::Error::custom)?;
                 Ok(Interface::Vrf(inner))
             }
+            Some(InterfaceType::Team) => {
+                let inner = TeamInterface::deserialize(v)
+                    .map_err(serde::de::Error::custom)?;
+                Ok(Interface::Team(inner))
+            }
             Some(InterfaceType::Hsr) => {
                 let inner = HsrInterface::deserialize(v)
                     .map_err(serde::de::Error::custom)?;
@@ -399,6 +439,16 @@ impl<'de> Deserialize<'de> for Interface {
                     .map_err(serde::de::Error::custom)?;
                 Ok(Interface::Xfrm(inner))
             }
+            Some(InterfaceType::WireGuard) => {
+                let inner = WireGuardInterface::deserialize(v)
+                    .map_err(serde::de::Error::custom)?;
+                Ok(Interface::WireGuard(inner))
+            }
+            Some(InterfaceType::Generic) => {
+                let inner = GenericInterface::deserialize(v)
+                    .map_err(serde::de::Error::custom)?;
+                Ok(Interface::Generic(inner))
+            }
             Some(iface_type) => {
                 log::warn!("Unsupported interface type {}", iface_type);
                 let inner = UnknownInterface::deserialize(v)
@@ -433,6 +483,11 @@ impl Interface {
         self.base_iface().iface_type.clone()
     }
 
+    // Interface desired as up without any other property
+    pub(crate) fn is_empty(&self) -> bool {
+        self.is_up() && self == &self.clone_name_type_only()
+    }
+
     pub(crate) fn clone_name_type_only(&self) -> Self {
         match self {
             Self::LinuxBridge(iface) => {
@@ -492,6 +547,11 @@ impl Interface {
                 new_iface.base = iface.base.clone_name_type_only();
                 Self::Vrf(new_iface)
             }
+            Self::Team(iface) => {
+                let mut new_iface = TeamInterface::new();
+                new_iface.base = iface.base.clone_name_type_only();
+                Self::Team(new_iface)
+            }
             Self::Hsr(iface) => {
                 let mut new_iface = HsrInterface::new();
                 new_iface.base = iface.base.clone_name_type_only();
@@ -524,6 +584,16 @@ impl Interface {
                 new_iface.base = iface.base.clone_name_type_only();
                 Self::Xfrm(new_iface)
             }
+            Self::WireGuard(iface) => {
+                let mut new_iface = WireGuardInterface::new();
+                new_iface.base = iface.base.clone_name_type_only();
+                Self::WireGuard(new_iface)
+            }
+            Self::Generic(iface) => {
+                let mut new_iface = GenericInterface::new();
+                new_iface.base = iface.base.clone_name_type_only();
+                Self::Generic(new_iface)
+            }
             Self::Unknown(iface) => {
                 let mut new_iface = UnknownInterface::new();
                 new_iface.base = iface.base.clone_name_type_only();
@@ -617,11 +687,14 @@ impl Interface {
             Self::MacVlan(iface) => &iface.base,
             Self::MacVtap(iface) => &iface.base,
             Self::Vrf(iface) => &iface.base,
+            Self::Team(iface) => &iface.base,
             Self::InfiniBand(iface) => &iface.base,
             Self::Loopback(iface) => &iface.base,
             Self::MacSec(iface) => &iface.base,
             Self::Ipsec(iface) => &iface.base,
             Self::Xfrm(iface) => &iface.base,
+            Self::WireGuard(iface) => &iface.base,
+            Self::Generic(iface) => &iface.base,
             Self::Unknown(iface) => &iface.base,
         }
     }
@@ -640,11 +713,14 @@ impl Interface {
             Self::MacVlan(iface) => &mut iface.base,
             Self::MacVtap(iface) => &mut iface.base,
             Self::Vrf(iface) => &mut iface.base,
+            Self::Team(iface) => &mut iface.base,
             Self::InfiniBand(iface) => &mut iface.base,
             Self::Loopback(iface) => &mut iface.base,
             Self::MacSec(iface) => &mut iface.base,
             Self::Ipsec(iface) => &mut iface.base,
             Self::Xfrm(iface) => &mut iface.base,
+            Self::WireGuard(iface) => &mut iface.base,
+            Self::Generic(iface) => &mut iface.base,
             Self::Unknown(iface) => &mut iface.base,
         }
     }
@@ -658,6 +734,7 @@ impl Interface {
                 Self::OvsBridge(_) => Some(Vec::new()),
                 Self::Bond(_) => Some(Vec::new()),
                 Self::Vrf(_) => Some(Vec::new()),
+                Self::Team(_) => Some(Vec::new()),
                 _ => None,
             }
         } else {
@@ -666,6 +743,7 @@ impl Interface {
                 Self::OvsBridge(iface) => iface.ports(),
                 Self::Bond(iface) => iface.ports(),
                 Self::Vrf(iface) => iface.ports(),
+                Self::Team(iface) => iface.ports(),
                 _ => None,
             }
         }
@@ -683,6 +761,23 @@ impl Interface {
         is_desired: bool,
     ) -> Result<(), NmstateError> {
         self.base_iface_mut().sanitize(is_desired)?;
+        if is_desired
+            && self.base_iface().autoconnect_ports.is_some()
+            && self.iface_type() != InterfaceType::Unknown
+            && !self.is_controller()
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "The autoconnect-ports is only valid for controller \
+                    interface, but interface {}/{} is not",
+                    self.name(),
+                    self.iface_type()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
         match self {
             Interface::Ethernet(iface) => iface.sanitize()?,
             Interface::Hsr(iface) => iface.sanitize(is_desired)?,
@@ -690,12 +785,16 @@ impl Interface {
             Interface::OvsInterface(iface) => iface.sanitize(is_desired)?,
             Interface::OvsBridge(iface) => iface.sanitize(is_desired)?,
             Interface::Vrf(iface) => iface.sanitize(is_desired)?,
+            Interface::Team(iface) => iface.sanitize(is_desired)?,
             Interface::Bond(iface) => iface.sanitize()?,
             Interface::MacVlan(iface) => iface.sanitize(is_desired)?,
             Interface::MacVtap(iface) => iface.sanitize(is_desired)?,
             Interface::Loopback(iface) => iface.sanitize(is_desired)?,
             Interface::MacSec(iface) => iface.sanitize(is_desired)?,
             Interface::Ipsec(iface) => iface.sanitize(is_desired),
+            Interface::WireGuard(iface) => iface.sanitize(is_desired)?,
+            Interface::Vxlan(iface) => iface.sanitize(is_desired)?,
+            Interface::InfiniBand(iface) => iface.sanitize(is_desired)?,
             _ => (),
         }
         Ok(())
@@ -721,6 +820,8 @@ impl Interface {
             br_iface.remove_port(port_name);
         } else if let Interface::Bond(iface) = self {
             iface.remove_port(port_name);
+        } else if let Interface::Team(iface) = self {
+            iface.remove_port(port_name);
         }
     }
 
@@ -735,6 +836,8 @@ impl Interface {
             iface.change_port_name(org_port_name, new_port_name);
         } else if let Interface::Bond(iface) = self {
             iface.change_port_name(org_port_name, new_port_name);
+        } else if let Interface::Team(iface) = self {
+            iface.change_port_name(org_port_name, new_port_name);
         }
     }
 }
@@ -814,6 +917,7 @@ impl MergedInterface {
         self.post_inter_ifaces_process_sriov()?;
         self.post_inter_ifaces_process_vrf()?;
         self.post_inter_ifaces_process_bond()?;
+        self.post_inter_ifaces_process_team()?;
 
         if let Some(apply_iface) = self.for_apply.as_mut() {
             apply_iface.sanitize(true)?;
@@ -1140,3 +1244,131 @@ impl InterfaceIdentifier {
         self == &InterfaceIdentifier::default()
     }
 }
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    Default,
+)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+/// The method for network backend on binding the connection to network
+/// interface when interface name is transient.
+pub enum InterfaceBindBy {
+    /// Bind to network interface holding exact the interface name, default
+    /// value.
+    /// Deserialize and serialize from/to 'exact-name'.
+    #[default]
+    ExactName,
+    /// Bind to network interface whose name matches any of the glob patterns
+    /// defined in `bind-pattern`, for example `en*`.
+    /// Deserialize and serialize from/to 'match-glob'.
+    MatchGlob,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    Default,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Whether the connection profile generated for the interface can be
+/// activated on multiple network interfaces simultaneously.
+pub enum InterfaceMultiConnect {
+    /// Use the global default of network backend, which is `single` unless
+    /// changed by NetworkManager configuration.
+    /// Deserialize and serialize from/to 'default'.
+    #[default]
+    Default,
+    /// Profile can only be active on single network interface.
+    /// Deserialize and serialize from/to 'single'.
+    Single,
+    /// Profile can be activated on multiple network interfaces but only
+    /// when requested manually, autoconnect will activate it only once.
+    /// Deserialize and serialize from/to 'manual-multiple'.
+    ManualMultiple,
+    /// Profile can be activated on all matching network interfaces
+    /// including autoconnect.
+    /// Deserialize and serialize from/to 'multiple'.
+    Multiple,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+    Default,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Whether multicast DNS(mDNS) or Link-Local Multicast Name
+/// Resolution(LLMNR) is enabled on the interface.
+pub enum InterfaceResolveMode {
+    /// Use the global default of network backend.
+    /// Deserialize and serialize from/to 'default'.
+    #[default]
+    Default,
+    /// Disable hostname registration and resolution.
+    /// Deserialize and serialize from/to 'no'.
+    No,
+    /// Do not register hostname but allow resolving.
+    /// Deserialize and serialize from/to 'resolve'.
+    Resolve,
+    /// Enable hostname registration and resolution.
+    /// Deserialize and serialize from/to 'yes'.
+    Yes,
+}
+
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    Hash,
+    PartialOrd,
+    Ord,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+/// The flags of NetworkManager connection profile generated for the
+/// interface.
+pub enum InterfaceNmFlag {
+    /// Profile is only stored in memory and removed once deactivated.
+    /// Deserialize and serialize from/to 'volatile'.
+    Volatile,
+    /// Keep the interface in the externally managed profile generated by
+    /// NetworkManager, no persistent profile will be created. Only valid when
+    /// interface is currently managed externally.
+    /// Deserialize and serialize from/to 'external'.
+    External,
+    /// Profile is only stored in memory.
+    /// Deserialize and serialize from/to 'unsaved'.
+    Unsaved,
+}
@@ -1,15 +1,21 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+use std::net::IpAddr;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    DispatchConfig, ErrorKind, EthtoolConfig, Ieee8021XConfig,
-    InterfaceIdentifier, InterfaceIpv4, InterfaceIpv6, InterfaceState,
-    InterfaceType, LldpConfig, MergedInterface, MptcpConfig, NmstateError,
-    OvsDbIfaceConfig, RouteEntry, WaitIp,
+    DispatchConfig, ErrorKind, EthtoolConfig, Ieee8021XConfig, InterfaceBindBy,
+    InterfaceIdentifier, InterfaceIpv4, InterfaceIpv6, InterfaceMultiConnect,
+    InterfaceNmFlag, InterfaceResolveMode, InterfaceState, InterfaceType,
+    LldpConfig, MergedInterface, MptcpConfig, NmstateError, OvsDbIfaceConfig,
+    ProxyConfig, RouteEntry, StaticNeighborConfig, WaitIp,
 };
 
 const MINIMUM_IPV6_MTU: u64 = 1280;
+// NetworkManager limits `connection.gateway-ping-timeout` to 600 seconds.
+const GATEWAY_PING_TIMEOUT_MAX: u32 = 600;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
@@ -35,6 +41,37 @@ pub struct BaseInterface {
     /// Define network backend matching method on choosing network interface.
     /// Default to [InterfaceIdentifier::Name].
     pub identifier: Option<InterfaceIdentifier>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Define how network backend binds the connection to network interface.
+    /// Default to [InterfaceBindBy::ExactName].
+    /// Serialize and deserialize to/from `bind-by`.
+    pub bind_by: Option<InterfaceBindBy>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Interface name glob patterns used when `bind-by: match-glob`, for
+    /// example `en*`.
+    /// Serialize and deserialize to/from `bind-pattern`.
+    pub bind_pattern: Option<Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether the connection profile can be activated on multiple network
+    /// interfaces at the same time. Default to
+    /// [InterfaceMultiConnect::Default]. The
+    /// [InterfaceMultiConnect::Multiple] requires `bind-by: match-glob`.
+    /// Only supported by NetworkManager backend.
+    /// Serialize and deserialize to/from `multi-connect`.
+    pub multi_connect: Option<InterfaceMultiConnect>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether multicast DNS(mDNS) is enabled on this interface. When not
+    /// defined, the default set by [crate::NetworkState::set_default_mdns()]
+    /// or the global default of backend will be used.
+    /// Only supported by NetworkManager backend.
+    pub mdns: Option<InterfaceResolveMode>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    /// Whether Link-Local Multicast Name Resolution(LLMNR) is enabled on
+    /// this interface. When not defined, the default set by
+    /// [crate::NetworkState::set_default_llmnr()] or the global default of
+    /// backend will be used.
+    /// Only supported by NetworkManager backend.
+    pub llmnr: Option<InterfaceResolveMode>,
     /// When applying with `[InterfaceIdentifier::MacAddress]`,
     /// nmstate will store original desired interface name as `profile_name`
     /// here and store the real interface name as `name` property.
@@ -59,6 +96,9 @@ pub struct BaseInterface {
         deserialize_with = "crate::deserializer::option_u64_or_string"
     )]
     /// Maximum transmission unit.
+    /// For VLAN, VXLAN, MACVLAN, MACVTAP and MACsec interfaces, this MTU is
+    /// used as it is instead of inheriting from parent interface, it should
+    /// not exceed what the parent could carry.
     pub mtu: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Minimum MTU allowed. Ignored during apply.
@@ -74,6 +114,32 @@ pub struct BaseInterface {
     /// Serialize and deserialize to/from `wait-ip`.
     pub wait_ip: Option<WaitIp>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    /// IP addresses to ping before considering network interface activated.
+    /// Empty list means remove existing ones. Only supported by
+    /// NetworkManager 1.48+.
+    /// Serialize and deserialize to/from `ip-ping-addresses`.
+    pub ip_ping_addresses: Option<Vec<String>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Timeout in seconds of pinging `ip_ping_addresses`, 0 means backend
+    /// default. Only supported by NetworkManager 1.48+.
+    /// Serialize and deserialize to/from `ip-ping-timeout`.
+    pub ip_ping_timeout: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Timeout in seconds(0 to 600) of pinging the default gateway before
+    /// considering network interface activated, 0 means no ping.
+    /// Only valid when the interface has default gateway defined by static
+    /// default route or retrieved from DHCP/autoconf.
+    /// Serialize and deserialize to/from `gateway-ping-timeout`.
+    pub gateway_ping_timeout: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     /// IPv4 information.
     /// Hided if interface is not allowed to hold IP information(e.g. port of
     /// bond is not allowed to hold IP information).
@@ -88,6 +154,16 @@ pub struct BaseInterface {
     /// Nmstate will apply these flags to all valid IP addresses(both static
     /// and dynamic).
     pub mptcp: Option<MptcpConfig>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Whether activating controller interface should also activate all its
+    /// ports. Only valid for controller interfaces, `None` means the backend
+    /// default which is `true` for nmstate. Only shown when set to `false`.
+    /// Serialize and deserialize to/from `autoconnect-ports`.
+    pub autoconnect_ports: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Controller of the specified interface.
     /// Only valid for applying, `None` means no change, empty string means
@@ -125,6 +201,38 @@ pub struct BaseInterface {
     /// Dispatch script configurations
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dispatch: Option<DispatchConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Proxy configurations, only supported by NetworkManager backend.
+    pub proxy: Option<ProxyConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Custom key/value pairs stored in the `user` setting of
+    /// NetworkManager connection profile, the key should be in the format of
+    /// `domain.key`, for example `inventory.rack`. The `nmstate` domain is
+    /// reserved. Set to empty to remove all custom user data.
+    /// Only supported by NetworkManager backend.
+    /// Serialize and deserialize to/from `user-data`.
+    pub user_data: Option<BTreeMap<String, String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Per-interface kernel sysctl settings, the key should be in the format
+    /// of `ipv4.<name>` or `ipv6.<name>`, for example `ipv4.arp_ignore` will
+    /// be applied to `net.ipv4.conf.<iface_name>.arp_ignore`.
+    /// Set to empty to remove all sysctl settings managed by nmstate.
+    /// Only supported by NetworkManager backend via dispatch script.
+    pub sysctl: Option<BTreeMap<String, i64>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Static neighbor entries(ARP/NDP) of this interface.
+    /// Set to empty to remove all static neighbors managed by nmstate.
+    /// Only supported by NetworkManager backend via dispatch script.
+    /// Serialize and deserialize to/from `static-neighbors`.
+    pub static_neighbors: Option<Vec<StaticNeighborConfig>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Flags of the NetworkManager connection profile, `external` cannot be
+    /// combined with other flags.
+    /// Ignored during verification as NetworkManager might append extra
+    /// flags.
+    /// Only supported by NetworkManager backend.
+    /// Serialize and deserialize to/from `nm-flags`.
+    pub nm_flags: Option<Vec<InterfaceNmFlag>>,
     #[serde(skip)]
     pub controller_type: Option<InterfaceType>,
     // The interface lowest up_priority will be activated first.
@@ -224,7 +332,7 @@ impl BaseInterface {
         is_desired: bool,
     ) -> Result<(), NmstateError> {
         if let Some(mac) = self.mac_address.as_mut() {
-            mac.make_ascii_uppercase();
+            *mac = mac.replace('-', ":").to_ascii_uppercase();
         }
         // These are not for apply or verify
         self.permanent_mac_address = None;
@@ -256,6 +364,34 @@ impl BaseInterface {
         if let Some(lldp_conf) = self.lldp.as_mut() {
             lldp_conf.sanitize();
         }
+        if is_desired {
+            if let Some(proxy_conf) = self.proxy.as_ref() {
+                proxy_conf.sanitize(self.name.as_str())?;
+            }
+            if let Some(ieee8021x_conf) = self.ieee8021x.as_ref() {
+                ieee8021x_conf.sanitize(self.name.as_str())?;
+            }
+            if let Some(ethtool_conf) = self.ethtool.as_ref() {
+                ethtool_conf.sanitize(self.name.as_str())?;
+            }
+            self.sanitize_ip_ping_addresses()?;
+            self.validate_gateway_ping_timeout()?;
+            self.validate_sysctl()?;
+            self.validate_bind_by()?;
+            self.validate_multi_connect()?;
+            self.validate_nm_flags()?;
+        }
+        if let Some(flags) = self.nm_flags.as_mut() {
+            flags.sort_unstable();
+            flags.dedup();
+        }
+        if let Some(neighbors) = self.static_neighbors.as_mut() {
+            for neighbor in neighbors.iter_mut() {
+                neighbor.sanitize(self.name.as_str())?;
+            }
+            neighbors.sort_unstable();
+            neighbors.dedup();
+        }
 
         if !self.can_have_ip() {
             self.wait_ip = None;
@@ -263,13 +399,15 @@ impl BaseInterface {
 
         if is_desired
             && self.iface_type.is_userspace()
-            && self.dispatch.is_some()
+            && (self.dispatch.is_some()
+                || self.sysctl.is_some()
+                || self.static_neighbors.is_some())
         {
             return Err(NmstateError::new(
                 ErrorKind::InvalidArgument,
                 format!(
                     "User space interface {}/{} is not allow to hold \
-                    dispatch configurations",
+                    dispatch, sysctl or static neighbor configurations",
                     self.name.as_str(),
                     self.iface_type,
                 ),
@@ -280,6 +418,163 @@ impl BaseInterface {
     }
 }
 
+impl BaseInterface {
+    pub(crate) fn is_bind_by_glob(&self) -> bool {
+        self.bind_by == Some(InterfaceBindBy::MatchGlob)
+    }
+
+    fn validate_bind_by(&self) -> Result<(), NmstateError> {
+        let has_pattern = self
+            .bind_pattern
+            .as_ref()
+            .map(|p| !p.is_empty())
+            .unwrap_or_default();
+        let is_glob = self.bind_by == Some(InterfaceBindBy::MatchGlob);
+        if is_glob != has_pattern {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Interface {} should define non-empty `bind-pattern` \
+                    only when `bind-by: match-glob`",
+                    self.name.as_str()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        if let Some(pattern) = self
+            .bind_pattern
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .find(|p| p.is_empty())
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Interface {} got invalid empty `bind-pattern` \
+                    {pattern:?}",
+                    self.name.as_str()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn validate_multi_connect(&self) -> Result<(), NmstateError> {
+        if self.multi_connect == Some(InterfaceMultiConnect::Multiple)
+            && !self.is_bind_by_glob()
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Interface {} should define `bind-by: match-glob` when \
+                    `multi-connect: multiple`",
+                    self.name.as_str()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn validate_nm_flags(&self) -> Result<(), NmstateError> {
+        let flags = self.nm_flags.as_deref().unwrap_or_default();
+        if flags.contains(&InterfaceNmFlag::External)
+            && flags.iter().any(|f| f != &InterfaceNmFlag::External)
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Interface {} cannot have `nm-flags` combined `external` \
+                    with other flags",
+                    self.name.as_str()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn validate_sysctl(&self) -> Result<(), NmstateError> {
+        for key in self.sysctl.as_ref().map(|s| s.keys()).into_iter().flatten()
+        {
+            let is_valid = match key.split_once('.') {
+                Some(("ipv4", name)) | Some(("ipv6", name)) => {
+                    !name.is_empty()
+                        && name.chars().all(|c| {
+                            c.is_ascii_lowercase()
+                                || c.is_ascii_digit()
+                                || c == '_'
+                        })
+                }
+                _ => false,
+            };
+            if !is_valid {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid sysctl key {key} of interface {}, expecting \
+                        format `ipv4.<name>` or `ipv6.<name>`, for example: \
+                        ipv4.arp_ignore",
+                        self.name.as_str()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    fn sanitize_ip_ping_addresses(&mut self) -> Result<(), NmstateError> {
+        if let Some(addrs) = self.ip_ping_addresses.as_mut() {
+            for addr in addrs.iter_mut() {
+                match addr.parse::<IpAddr>() {
+                    Ok(ip) => *addr = ip.to_string(),
+                    Err(_) => {
+                        let e = NmstateError::new(
+                            ErrorKind::InvalidArgument,
+                            format!(
+                                "Invalid IP address {addr} in \
+                                ip-ping-addresses of interface {}",
+                                self.name.as_str()
+                            ),
+                        );
+                        log::error!("{}", e);
+                        return Err(e);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_gateway_ping_timeout(&self) -> Result<(), NmstateError> {
+        if let Some(timeout) = self
+            .gateway_ping_timeout
+            .filter(|t| *t > GATEWAY_PING_TIMEOUT_MAX)
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Invalid gateway-ping-timeout {timeout} of interface {}, \
+                    should be in the range of 0 to {}",
+                    self.name.as_str(),
+                    GATEWAY_PING_TIMEOUT_MAX
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+}
+
 fn default_state() -> InterfaceState {
     InterfaceState::Up
 }
@@ -1211,6 +1211,20 @@ pub struct BondOptions {
     /// The valid range is 0 - 65535; the default value is 1. This option has
     /// effect only in balance-rr mode.
     pub packets_per_slave: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Specify the delay, in milliseconds, between each peer notification
+    /// (gratuitous ARP and unsolicited IPv6 Neighbor Advertisement) when
+    /// they are issued after a failover event. This delay should be a
+    /// multiple of the miimon value; if not, it will be rounded down to the
+    /// nearest multiple.
+    ///
+    /// The valid range is 0 - 300000; the default value is 0, which means
+    /// to match the value of the miimon.
+    pub peer_notif_delay: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     /// A string (eth0, eth2, etc) specifying which slave is the primary
     /// device. The specified device will always be the active slave while
@@ -1324,11 +1338,31 @@ pub struct BondOptions {
     pub arp_missed_max: Option<u8>,
 }
 
+const BOND_PEER_NOTIF_DELAY_MAX: u32 = 300000;
+
 impl BondOptions {
     pub fn new() -> Self {
         Self::default()
     }
 
+    fn validate_peer_notif_delay(&self) -> Result<(), NmstateError> {
+        if let Some(delay) = self.peer_notif_delay {
+            if delay > BOND_PEER_NOTIF_DELAY_MAX {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The bond option peer_notif_delay should be in the \
+                        range of 0 - {BOND_PEER_NOTIF_DELAY_MAX}, but got \
+                        {delay}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
     fn validate_ad_actor_system_mac_address(&self) -> Result<(), NmstateError> {
         if let Some(ad_actor_system) = &self.ad_actor_system {
             if ad_actor_system.to_uppercase().starts_with("01:00:5E") {
@@ -1405,6 +1439,7 @@ impl MergedInterface {
             {
                 bond_opts.validate_ad_actor_system_mac_address()?;
                 bond_opts.validate_miimon_and_arp_interval()?;
+                bond_opts.validate_peer_notif_delay()?;
 
                 if let Interface::Bond(merged_iface) = &self.merged {
                     if let Some(mode) =
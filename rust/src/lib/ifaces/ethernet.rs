@@ -1,5 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
@@ -94,6 +96,11 @@ impl EthernetInterface {
         {
             sriov_conf.sanitize()?
         }
+        if let Some(s390_conf) =
+            self.ethernet.as_mut().and_then(|e| e.s390.as_mut())
+        {
+            s390_conf.sanitize(self.base.name.as_str())?
+        }
 
         Ok(())
     }
@@ -126,6 +133,58 @@ impl std::fmt::Display for EthernetDuplex {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum EthernetClonedMac {
+    /// Do not change the MAC address on activation.
+    /// Deserialize and serialize from/to `preserve`.
+    Preserve,
+    /// Use the permanent MAC address of the device.
+    /// Deserialize and serialize from/to `permanent`.
+    Permanent,
+    /// Use a randomly generated MAC address on each activation.
+    /// Deserialize and serialize from/to `random`.
+    Random,
+    /// Use a MAC address generated from stable connection ID.
+    /// Deserialize and serialize from/to `stable`.
+    Stable,
+}
+
+impl std::fmt::Display for EthernetClonedMac {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Preserve => "preserve",
+                Self::Permanent => "permanent",
+                Self::Random => "random",
+                Self::Stable => "stable",
+            }
+        )
+    }
+}
+
+impl std::str::FromStr for EthernetClonedMac {
+    type Err = NmstateError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "preserve" => Ok(Self::Preserve),
+            "permanent" => Ok(Self::Permanent),
+            "random" => Ok(Self::Random),
+            "stable" => Ok(Self::Stable),
+            _ => Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Invalid cloned-mac {s}, only support preserve, \
+                    permanent, random or stable"
+                ),
+            )),
+        }
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 #[non_exhaustive]
@@ -150,6 +209,21 @@ pub struct EthernetConfig {
     pub speed: Option<u32>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub duplex: Option<EthernetDuplex>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// How the MAC address is assigned when activating. Ignored if
+    /// [BaseInterface.mac_address] is defined.
+    /// Deserialize and serialize from/to `cloned-mac`.
+    pub cloned_mac: Option<EthernetClonedMac>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The mask and optional list of OUI used when generating MAC address
+    /// for [EthernetClonedMac::Random] and [EthernetClonedMac::Stable].
+    /// For example `FE:FF:FF:00:00:00 68:F7:28:00:00:00`.
+    /// Empty string means backend default.
+    /// Deserialize and serialize from/to `generate-mac-address-mask`.
+    pub generate_mac_address_mask: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// IBM mainframe(s390) specific configuration.
+    pub s390: Option<EthernetS390Config>,
 }
 
 impl EthernetConfig {
@@ -158,6 +232,126 @@ impl EthernetConfig {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+/// IBM mainframe(s390) network device configuration.
+/// Example yaml output of ethernet interface with s390 configuration:
+/// ```yml
+/// interfaces:
+/// - name: enc600
+///   type: ethernet
+///   state: up
+///   ethernet:
+///     s390:
+///       subchannels:
+///       - 0.0.0600
+///       - 0.0.0601
+///       - 0.0.0602
+///       nettype: qeth
+///       options:
+///         layer2: "1"
+///         portno: "0"
+/// ```
+pub struct EthernetS390Config {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Subchannels in the format of `x.y.zzzz` in hexadecimal, for
+    /// example `0.0.0600`. The `qeth` device requires three subchannels,
+    /// `lcs` and `ctc` devices require two.
+    pub subchannels: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub nettype: Option<EthernetS390NetType>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Driver options of s390 network device, for example `layer2: "1"`.
+    pub options: Option<BTreeMap<String, String>>,
+}
+
+impl EthernetS390Config {
+    pub(crate) fn sanitize(
+        &mut self,
+        iface_name: &str,
+    ) -> Result<(), NmstateError> {
+        if let Some(subchannels) = self.subchannels.as_mut() {
+            for subchannel in subchannels.iter_mut() {
+                if !is_valid_s390_subchannel(subchannel) {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Invalid s390 subchannel '{subchannel}' of \
+                            interface {iface_name}, should be in the \
+                            format of `x.y.zzzz` in hexadecimal, for \
+                            example `0.0.0600`"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+                subchannel.make_ascii_lowercase();
+            }
+            let expected_count = match self.nettype {
+                Some(EthernetS390NetType::Qeth) => 3,
+                Some(EthernetS390NetType::Lcs)
+                | Some(EthernetS390NetType::Ctc) => 2,
+                None => subchannels.len(),
+            };
+            if subchannels.len() != expected_count
+                || !(2..=3).contains(&subchannels.len())
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid s390 subchannel count {} of interface \
+                        {iface_name}: qeth device requires 3 subchannels, \
+                        lcs and ctc devices require 2",
+                        subchannels.len()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Format is `x.y.zzzz` in hexadecimal
+fn is_valid_s390_subchannel(subchannel: &str) -> bool {
+    let parts: Vec<&str> = subchannel.split('.').collect();
+    parts.len() == 3
+        && parts[0].len() == 1
+        && parts[1].len() == 1
+        && parts[2].len() == 4
+        && parts
+            .iter()
+            .all(|p| p.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum EthernetS390NetType {
+    /// Deserialize and serialize from/to `qeth`.
+    Qeth,
+    /// Deserialize and serialize from/to `lcs`.
+    Lcs,
+    /// Deserialize and serialize from/to `ctc`.
+    Ctc,
+}
+
+impl std::fmt::Display for EthernetS390NetType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Qeth => "qeth",
+                Self::Lcs => "lcs",
+                Self::Ctc => "ctc",
+            }
+        )
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[non_exhaustive]
 pub struct VethConfig {
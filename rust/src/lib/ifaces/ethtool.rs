@@ -9,7 +9,7 @@ use serde::{
     Serializer,
 };
 
-use crate::MergedInterface;
+use crate::{ErrorKind, MergedInterface, NmstateError};
 
 const ETHTOOL_FEATURE_CLI_ALIAS: [(&str, &str); 17] = [
     ("rx", "rx-checksum"),
@@ -134,6 +134,19 @@ pub struct EthtoolConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// The rx/tx ring parameters of the specified network device.
     pub ring: Option<EthtoolRingConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The number of rx/tx queues(channels) of the specified network device.
+    pub channels: Option<EthtoolChannelsConfig>,
+    #[serde(
+        rename = "gso-max-size",
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// The maximum size of Generic Segmentation Offload(GSO) packet the
+    /// specified network device should accept.
+    /// Deserialize and serialize from/to `gso-max-size`.
+    pub gso_max_size: Option<u32>,
 }
 
 impl EthtoolConfig {
@@ -141,6 +154,43 @@ impl EthtoolConfig {
         Self::default()
     }
 
+    pub(crate) fn sanitize(
+        &self,
+        iface_name: &str,
+    ) -> Result<(), NmstateError> {
+        if let Some(channels) = self.channels.as_ref() {
+            for (name, value) in [
+                ("rx", channels.rx),
+                ("tx", channels.tx),
+                ("combined", channels.combined),
+            ] {
+                if value == Some(0) {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Ethtool channels {name} of interface \
+                            {iface_name} should be bigger than 0"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        if self.gso_max_size == Some(0) {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Ethtool gso-max-size of interface {iface_name} should \
+                    be bigger than 0"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+
     // There are some alias on ethtool features.
     pub(crate) fn apply_feature_alias(&mut self) {
         if let Some(features) = self.feature.as_mut() {
@@ -419,6 +469,39 @@ impl EthtoolRingConfig {
     }
 }
 
+#[derive(Serialize, Deserialize, Debug, Eq, PartialEq, Clone, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct EthtoolChannelsConfig {
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Number of RX queues.
+    pub rx: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Number of TX queues.
+    pub tx: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Number of queues serving both RX and TX.
+    pub combined: Option<u32>,
+}
+
+impl EthtoolChannelsConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
 fn parse_ethtool_feature<'de, D>(
     deserializer: D,
 ) -> Result<Option<EthtoolFeatureConfig>, D::Error>
@@ -498,6 +581,9 @@ impl MergedInterface {
             .and_then(|b| b.ethtool.as_mut())
         {
             ethtool_conf.apply_feature_alias();
+            // Channels and GSO max size are not queried yet.
+            ethtool_conf.channels = None;
+            ethtool_conf.gso_max_size = None;
         }
     }
 }
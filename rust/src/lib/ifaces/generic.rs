@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{BaseInterface, InterfaceType};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Helper device represented as NetworkManager `generic` connection. Only
+/// contain information of [BaseInterface], only the IP and ethernet settings
+/// will be applied.
+/// Example yaml of `[crate::NetworkState]` with generic interface:
+/// ```yml
+/// interfaces:
+/// - name: helper0
+///   type: generic
+///   state: up
+///   ipv4:
+///     enabled: true
+///     dhcp: false
+///     address:
+///     - ip: 192.0.2.1
+///       prefix-length: 24
+/// ```
+pub struct GenericInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+}
+
+impl Default for GenericInterface {
+    fn default() -> Self {
+        let mut base = BaseInterface::new();
+        base.iface_type = InterfaceType::Generic;
+        Self { base }
+    }
+}
+
+impl GenericInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
@@ -2,7 +2,10 @@
 
 use serde::{Deserialize, Serialize, Serializer};
 
-use crate::{BaseInterface, InterfaceType};
+use crate::{
+    BaseInterface, ErrorKind, InterfaceType, NmstateError, SrIovConfig,
+    VlanProtocol,
+};
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
 #[serde(rename_all = "kebab-case")]
@@ -21,6 +24,19 @@ use crate::{BaseInterface, InterfaceType};
 ///       mode: "connected"
 ///       base-iface: "ib2"
 /// ```
+/// The physical InfiniBand interface could also enable SR-IOV VFs:
+/// ```yaml
+/// ---
+/// interfaces:
+///   - name: ib0
+///     type: infiniband
+///     state: up
+///     infiniband:
+///       pkey: "0xffff"
+///       mode: "datagram"
+///       sr-iov:
+///         total-vfs: 4
+/// ```
 pub struct InfiniBandInterface {
     #[serde(flatten)]
     pub base: BaseInterface,
@@ -40,6 +56,24 @@ impl InfiniBandInterface {
     pub(crate) fn parent(&self) -> Option<&str> {
         self.ib.as_ref().and_then(|cfg| cfg.base_iface.as_deref())
     }
+
+    pub(crate) fn sanitize(
+        &mut self,
+        is_desired: bool,
+    ) -> Result<(), NmstateError> {
+        if let Some(sriov_conf) =
+            self.ib.as_mut().and_then(|ib_conf| ib_conf.sr_iov.as_mut())
+        {
+            sriov_conf.sanitize()?;
+            sanitize_ib_vfs(sriov_conf);
+        }
+        if is_desired {
+            if let Some(ib_conf) = self.ib.as_ref() {
+                ib_conf.validate_sriov(self.base.name.as_str())?;
+            }
+        }
+        Ok(())
+    }
 }
 
 #[derive(Serialize, Deserialize, Debug, PartialEq, Eq, Clone, Copy)]
@@ -89,12 +123,84 @@ pub struct InfiniBandConfig {
     /// For base interface, it is set to None.
     /// The `0xffff` value also indicate this is a InfiniBand base interface.
     pub pkey: Option<u16>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Single Root I/O Virtualization(SRIOV) configuration.
+    /// For physical InfiniBand interface only.
+    /// Deserialize and serialize from/to `sr-iov`.
+    pub sr_iov: Option<SrIovConfig>,
 }
 
 impl InfiniBandConfig {
     pub fn new() -> Self {
         Self::default()
     }
+
+    // InfiniBand VF is identified by GUID instead of MAC address and has no
+    // support of VLAN or spoof checking. The pkey sub-interface cannot have
+    // VFs.
+    fn validate_sriov(&self, iface_name: &str) -> Result<(), NmstateError> {
+        let sriov_conf = match self.sr_iov.as_ref() {
+            Some(c) => c,
+            None => return Ok(()),
+        };
+        if self.base_iface.as_deref().map(|p| !p.is_empty()) == Some(true) {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "The sr-iov is only supported on physical InfiniBand \
+                    interface, but {iface_name} is an InfiniBand pkey \
+                    interface"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        for vf in sriov_conf.vfs.as_deref().unwrap_or_default() {
+            let unsupported = if vf.mac_address.is_some() {
+                Some("mac-address")
+            } else if vf.spoof_check == Some(true) {
+                Some("spoof-check")
+            } else if vf.vlan_id.unwrap_or_default() != 0
+                || vf.qos.unwrap_or_default() != 0
+                || vf.vlan_proto.is_some()
+            {
+                Some("VLAN")
+            } else {
+                None
+            };
+            if let Some(unsupported) = unsupported {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "VF ID {} of InfiniBand interface {iface_name}: \
+                        {unsupported} is not supported by InfiniBand VF",
+                        vf.id
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+// Kernel always reports MAC address and VLAN protocol of VF even they are
+// not applicable to InfiniBand VF, remove them when holding default value.
+fn sanitize_ib_vfs(sriov_conf: &mut SrIovConfig) {
+    for vf in sriov_conf.vfs.as_mut().into_iter().flatten() {
+        if vf
+            .mac_address
+            .as_deref()
+            .map(|m| m.chars().all(|c| c == '0' || c == ':'))
+            == Some(true)
+        {
+            vf.mac_address = None;
+        }
+        if vf.vlan_proto == Some(VlanProtocol::Ieee8021Q) {
+            vf.vlan_proto = None;
+        }
+    }
 }
 
 fn show_as_hex<S>(v: &Option<u16>, s: S) -> Result<S::Ok, S::Error>
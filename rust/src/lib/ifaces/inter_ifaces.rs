@@ -8,7 +8,8 @@ use serde::{
 
 use crate::{
     ErrorKind, EthernetInterface, Interface, InterfaceIdentifier,
-    InterfaceState, InterfaceType, MergedInterface, NmstateError,
+    InterfaceIpAddr, InterfaceState, InterfaceType, MergedInterface,
+    NmstateError,
 };
 
 // The max loop count for Interfaces.set_ifaces_up_priority()
@@ -169,6 +170,26 @@ impl Interfaces {
             if let Interface::Ipsec(ipsec_iface) = iface {
                 ipsec_iface.hide_secrets();
             }
+            if let Interface::WireGuard(wg_iface) = iface {
+                wg_iface.hide_secrets();
+            }
+        }
+    }
+
+    pub(crate) fn prune_empty(&mut self) {
+        let empty_ifaces: Vec<(String, InterfaceType)> = self
+            .iter()
+            .filter(|i| i.is_empty())
+            .map(|i| (i.name().to_string(), i.iface_type()))
+            .collect();
+        for (iface_name, iface_type) in empty_ifaces {
+            log::info!(
+                "Pruning interface {iface_name}({iface_type}) as it holds \
+                no configuration"
+            );
+            self.remove_iface(iface_name.as_str(), iface_type.clone());
+            self.insert_order
+                .retain(|(n, t)| n != &iface_name || t != &iface_type);
         }
     }
 
@@ -281,6 +302,31 @@ impl Interfaces {
         }
     }
 
+    // In strict mode, interface of type not supported by nmstate is treated
+    // as error instead of being ignored or set to ethernet.
+    pub(crate) fn validate_strict(&self) -> Result<(), NmstateError> {
+        for iface in self.kernel_ifaces.values() {
+            if let Interface::Unknown(unknown_iface) = iface {
+                if let Some(iface_type) = unknown_iface
+                    .unsupported_type()
+                    .filter(|_| !iface.is_absent())
+                {
+                    let e = NmstateError::new(
+                        ErrorKind::NotImplementedError,
+                        format!(
+                            "Interface {} of type {iface_type} is not \
+                            supported in strict mode",
+                            unknown_iface.base.name
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn resolve_unknown_ifaces(
         &mut self,
         cur_ifaces: &Self,
@@ -627,6 +673,8 @@ impl MergedInterfaces {
             MergedInterface,
         > = HashMap::new();
 
+        desired.validate_ovs_bridge_not_in_ovs_bridge(&current)?;
+
         if gen_conf_mode {
             desired.set_unknown_iface_to_eth()?;
             desired.set_missing_port_to_eth();
@@ -755,10 +803,16 @@ impl MergedInterfaces {
         self.validate_controller_and_port_list_confliction()?;
         self.handle_changed_ports()?;
         self.resolve_port_iface_controller_type()?;
+        self.validate_controller_loop()?;
+        self.validate_ovs_iface_has_ovs_bridge()?;
+        self.validate_no_vlan_iface_in_ovs_bridge()?;
         self._set_up_priority()?;
         self.check_overbook_ports()?;
         self.check_infiniband_as_ports()?;
         self.mark_orphan_interface_as_absent()?;
+        self.validate_parent_exist()?;
+        self.validate_mac_vlan_parent_not_ovs_port()?;
+        self.validate_anycast_ip_collision()?;
         self.process_veth_peer_changes()?;
         self.validate_dispatch_script_has_no_checkpoint()?;
         for iface in self
@@ -769,6 +823,7 @@ impl MergedInterfaces {
         {
             iface.post_inter_ifaces_process()?;
         }
+        self.validate_vrf_table_id_unique()?;
         Ok(())
     }
 
@@ -912,6 +967,102 @@ impl MergedInterfaces {
         }
         Ok(())
     }
+
+    // In gen_conf mode, the parent might be a kernel device only exists on
+    // the target system, hence no validation.
+    fn validate_parent_exist(&self) -> Result<(), NmstateError> {
+        if self.gen_conf_mode {
+            return Ok(());
+        }
+        // OvsInterface is using controller as parent.
+        for iface in self.kernel_ifaces.values().filter(|i| {
+            i.is_desired()
+                && i.merged.is_up()
+                && i.merged.iface_type() != InterfaceType::OvsInterface
+        }) {
+            if let Some(parent) = iface.merged.parent() {
+                if !self.kernel_ifaces.contains_key(parent)
+                    && !self
+                        .ignored_ifaces
+                        .iter()
+                        .any(|(name, _)| name == parent)
+                {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Parent interface {parent} of interface {} \
+                            does not exist in desired or current state",
+                            iface.merged.name(),
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // The anycast/VIP addresses are host addresses(/32 or /128) attached to
+    // dummy or loopback interface, they should not collide with address of
+    // other interfaces.
+    fn validate_anycast_ip_collision(&self) -> Result<(), NmstateError> {
+        for iface in self.kernel_ifaces.values().filter(|i| {
+            i.is_desired()
+                && i.merged.is_up()
+                && (i.merged.iface_type() == InterfaceType::Dummy
+                    || i.merged.iface_type() == InterfaceType::Loopback)
+        }) {
+            for addr in get_static_ip_addrs(&iface.merged).filter(|a| {
+                a.prefix_length == if a.ip.is_ipv6() { 128 } else { 32 }
+            }) {
+                if let Some(other_iface) =
+                    self.kernel_ifaces.values().find(|o| {
+                        o.merged.name() != iface.merged.name()
+                            && o.merged.is_up()
+                            && get_static_ip_addrs(&o.merged)
+                                .any(|a| a.ip == addr.ip)
+                    })
+                {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Anycast address {} of interface {} collides \
+                            with the address of interface {}",
+                            addr.ip,
+                            iface.merged.name(),
+                            other_iface.merged.name()
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn get_static_ip_addrs(
+    iface: &Interface,
+) -> impl Iterator<Item = &InterfaceIpAddr> {
+    let base_iface = iface.base_iface();
+    base_iface
+        .ipv4
+        .as_ref()
+        .filter(|i| i.enabled)
+        .and_then(|i| i.addresses.as_deref())
+        .unwrap_or_default()
+        .iter()
+        .chain(
+            base_iface
+                .ipv6
+                .as_ref()
+                .filter(|i| i.enabled)
+                .and_then(|i| i.addresses.as_deref())
+                .unwrap_or_default()
+                .iter(),
+        )
 }
 
 // Special cases:
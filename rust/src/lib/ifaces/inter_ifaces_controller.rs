@@ -153,6 +153,55 @@ impl MergedInterfaces {
         Ok(())
     }
 
+    // Check whether any interface is using itself as controller directly or
+    // indirectly, for example: eth1 -> eth1, bond0 -> bond1 -> bond0.
+    // The OVS bridge is not checked as it is allowed to hold OVS internal
+    // interface with the same name.
+    pub(crate) fn validate_controller_loop(&self) -> Result<(), NmstateError> {
+        let mut port_to_ctrl: HashMap<&str, &str> = HashMap::new();
+        for iface in self.kernel_ifaces.values().filter(|i| i.merged.is_up()) {
+            let base_iface = iface.merged.base_iface();
+            if base_iface.controller_type == Some(InterfaceType::OvsBridge) {
+                continue;
+            }
+            if let Some(ctrl) =
+                base_iface.controller.as_deref().filter(|c| !c.is_empty())
+            {
+                port_to_ctrl.insert(iface.merged.name(), ctrl);
+            }
+        }
+        let mut iface_names: Vec<&str> = port_to_ctrl.keys().copied().collect();
+        // Sort the interface names to produce consistent error message
+        iface_names.sort_unstable();
+        for iface_name in iface_names {
+            let mut chain = vec![iface_name];
+            let mut cur = iface_name;
+            while let Some(ctrl) = port_to_ctrl.get(cur) {
+                if *ctrl == iface_name {
+                    chain.push(ctrl);
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Interface {iface_name} is using itself as \
+                            controller: {}",
+                            chain.join(" -> ")
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+                // Loop not involving iface_name will be found by its own
+                // member
+                if chain.contains(ctrl) {
+                    break;
+                }
+                chain.push(ctrl);
+                cur = ctrl;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn handle_changed_ports(&mut self) -> Result<(), NmstateError> {
         let mut pending_changes: HashMap<
             String,
@@ -108,6 +108,7 @@ impl LinuxBridgeInterface {
         {
             if is_desired {
                 opts.validate_vlan_default_pvid(self)?;
+                opts.validate_vlan_stats_enabled(self)?;
             }
         }
 
@@ -119,6 +120,7 @@ impl LinuxBridgeInterface {
         self.sort_ports();
         if is_desired {
             self.sanitize_stp_opts()?;
+            self.validate_group_addr()?;
         }
         self.use_upper_case_of_mac_address();
         self.flatten_port_vlan_ranges();
@@ -149,6 +151,44 @@ impl LinuxBridgeInterface {
         }
     }
 
+    // Linux kernel only allows link local multicast address
+    // 01:80:C2:00:00:0X as group address except the reserved
+    // 01:80:C2:00:00:01(pause frame), 01:80:C2:00:00:02(Slow Protocols) and
+    // 01:80:C2:00:00:03(802.1X PAE).
+    fn validate_group_addr(&self) -> Result<(), NmstateError> {
+        if let Some(address) = self
+            .bridge
+            .as_ref()
+            .and_then(|br_conf| br_conf.options.as_ref())
+            .and_then(|br_opts| br_opts.group_addr.as_ref())
+        {
+            let address = address.to_ascii_uppercase();
+            let is_valid = address.len() == 17
+                && address.starts_with("01:80:C2:00:00:0")
+                && address
+                    .chars()
+                    .last()
+                    .map(|c| c.is_ascii_hexdigit() && !"123".contains(c))
+                    .unwrap_or_default();
+            if !is_valid {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid group-addr {address} of linux bridge {}, \
+                        only link local multicast address \
+                        01:80:C2:00:00:0X is allowed except \
+                        01:80:C2:00:00:01, 01:80:C2:00:00:02 and \
+                        01:80:C2:00:00:03",
+                        self.base.name.as_str()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
     fn flatten_port_vlan_ranges(&mut self) {
         if let Some(port_confs) = self
             .bridge
@@ -403,7 +443,7 @@ impl LinuxBridgePortConfig {
         Self::default()
     }
 
-    fn is_changed(&self, current: &Self) -> bool {
+    pub(crate) fn is_changed(&self, current: &Self) -> bool {
         (self.stp_hairpin_mode.is_some()
             && self.stp_hairpin_mode != current.stp_hairpin_mode)
             || (self.stp_path_cost.is_some()
@@ -427,7 +467,12 @@ impl LinuxBridgePortConfig {
 pub struct LinuxBridgeOptions {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub gc_timer: Option<u64>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(skip_serializing_if = "Option::is_none", alias = "group-address")]
+    /// The MAC address of STP BPDU, only link local multicast address
+    /// 01:80:C2:00:00:0X is allowed except 01:80:C2:00:00:01,
+    /// 01:80:C2:00:00:02 and 01:80:C2:00:00:03.
+    /// Serialize to `group-addr`, deserialize from `group-addr` or
+    /// `group-address`.
     pub group_addr: Option<String>,
     #[serde(
         skip_serializing_if = "Option::is_none",
@@ -535,6 +580,15 @@ pub struct LinuxBridgeOptions {
     pub vlan_protocol: Option<VlanProtocol>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub vlan_default_pvid: Option<u16>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Whether to collect per-VLAN statistics, only valid when VLAN
+    /// filtering is enabled.
+    /// Deserialize and serialize from/to `vlan-stats-enabled`.
+    pub vlan_stats_enabled: Option<bool>,
 }
 
 impl LinuxBridgeOptions {
@@ -596,6 +650,26 @@ impl LinuxBridgeOptions {
 
         Ok(())
     }
+
+    pub(crate) fn validate_vlan_stats_enabled(
+        &self,
+        linux_bridge: &LinuxBridgeInterface,
+    ) -> Result<(), NmstateError> {
+        if self.vlan_stats_enabled == Some(true)
+            && !linux_bridge.vlan_filtering_is_enabled()
+        {
+            return Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Linux bridge {} has vlan-stats-enabled set to true \
+                    but VLAN filtering is not enabled.",
+                    linux_bridge.base.name.as_str()
+                ),
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
@@ -2,7 +2,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{BaseInterface, ErrorKind, InterfaceType, NmstateError};
+use crate::{
+    BaseInterface, ErrorKind, InterfaceType, MergedInterfaces, NmstateError,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -125,3 +127,44 @@ impl Default for MacVlanMode {
         Self::Unknown
     }
 }
+
+impl MergedInterfaces {
+    // Kernel interface attached to OVS bridge cannot be the parent of
+    // MAC VLAN/VTAP as its traffic is consumed by OVS datapath.
+    pub(crate) fn validate_mac_vlan_parent_not_ovs_port(
+        &self,
+    ) -> Result<(), NmstateError> {
+        for iface in self.kernel_ifaces.values().filter(|i| {
+            i.is_changed()
+                && i.merged.is_up()
+                && matches!(
+                    i.merged.iface_type(),
+                    InterfaceType::MacVlan | InterfaceType::MacVtap
+                )
+        }) {
+            let parent = match iface.merged.parent() {
+                Some(p) => p,
+                None => continue,
+            };
+            if self
+                .kernel_ifaces
+                .get(parent)
+                .and_then(|p| p.merged.base_iface().controller_type.as_ref())
+                == Some(&InterfaceType::OvsBridge)
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The base-iface {parent} of {} interface {} is an \
+                        OVS bridge port, which cannot be used as base-iface",
+                        iface.merged.iface_type(),
+                        iface.merged.name()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
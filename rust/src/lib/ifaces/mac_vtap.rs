@@ -18,6 +18,8 @@ use crate::{BaseInterface, ErrorKind, InterfaceType, NmstateError};
 ///       base-iface: eth1
 ///       mode: passthru
 ///       promiscuous: true
+///       vnet-hdr: true
+///       multi-queue: true
 /// ```
 pub struct MacVtapInterface {
     #[serde(flatten)]
@@ -88,6 +90,28 @@ pub struct MacVtapConfig {
     /// Serialize to `promiscuous`.
     /// Deserialize from `promiscuous` or `accept-all-mac`.
     pub accept_all_mac: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Request the tap device to be opened with virtio network header
+    /// (`IFF_VNET_HDR`) by VM hypervisor.
+    /// Kernel does not expose this flag, hence it is not verified after
+    /// apply.
+    /// Deserialize and serialize from/to `vnet-hdr`.
+    pub vnet_hdr: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Request the tap device to be opened with multiple queues
+    /// (`IFF_MULTI_QUEUE`) by VM hypervisor.
+    /// Kernel does not expose this flag, hence it is not verified after
+    /// apply.
+    /// Deserialize and serialize from/to `multi-queue`.
+    pub multi_queue: Option<bool>,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
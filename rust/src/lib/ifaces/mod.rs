@@ -4,6 +4,7 @@ mod bridge_vlan;
 mod dummy;
 mod ethernet;
 mod ethtool;
+mod generic;
 mod hsr;
 pub(crate) mod inter_ifaces;
 mod ipsec;
@@ -20,8 +21,14 @@ mod mac_vtap;
 mod macsec;
 mod ovs;
 mod sriov;
+mod team;
 mod vlan;
+mod wireguard;
 
+pub use self::wireguard::{
+    WireGuardConfig, WireGuardEndpointResolver, WireGuardInterface,
+    WireGuardPeerConfig,
+};
 pub use self::xfrm::XfrmInterface;
 pub use base::*;
 pub use bond::{
@@ -35,12 +42,14 @@ pub use bridge_vlan::{
 };
 pub use dummy::DummyInterface;
 pub use ethernet::{
-    EthernetConfig, EthernetDuplex, EthernetInterface, VethConfig,
+    EthernetClonedMac, EthernetConfig, EthernetDuplex, EthernetInterface,
+    EthernetS390Config, EthernetS390NetType, VethConfig,
 };
 pub use ethtool::{
-    EthtoolCoalesceConfig, EthtoolConfig, EthtoolFeatureConfig,
-    EthtoolPauseConfig, EthtoolRingConfig,
+    EthtoolChannelsConfig, EthtoolCoalesceConfig, EthtoolConfig,
+    EthtoolFeatureConfig, EthtoolPauseConfig, EthtoolRingConfig,
 };
+pub use generic::GenericInterface;
 pub use hsr::{HsrConfig, HsrInterface, HsrProtocol};
 pub use infiniband::{InfiniBandConfig, InfiniBandInterface, InfiniBandMode};
 pub(crate) use inter_ifaces::MergedInterfaces;
@@ -64,7 +73,10 @@ pub use ovs::{
     OvsBridgeConfig, OvsBridgeInterface, OvsBridgeOptions, OvsBridgePortConfig,
     OvsBridgeStpOptions, OvsDpdkConfig, OvsInterface, OvsPatchConfig,
 };
-pub use sriov::{SrIovConfig, SrIovVfConfig};
+pub use sriov::{
+    SrIovConfig, SrIovEswitchEncapMode, SrIovEswitchMode, SrIovVfConfig,
+};
+pub use team::{TeamConfig, TeamInterface, TeamPortConfig, TeamRunner};
 pub use vlan::{
     VlanConfig, VlanInterface, VlanProtocol, VlanRegistrationProtocol,
 };
@@ -1,15 +1,27 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 
 use serde::{de, Deserialize, Deserializer, Serialize};
 
 use crate::{
     BaseInterface, BridgePortVlanConfig, ErrorKind, Interface, InterfaceState,
-    InterfaceType, LinuxBridgeStpOptions, MergedInterface, MergedInterfaces,
-    NmstateError, OvsDbIfaceConfig,
+    InterfaceType, Interfaces, LinuxBridgeStpOptions, MergedInterface,
+    MergedInterfaces, NmstateError, OvsDbIfaceConfig,
 };
 
+const SUPPORTED_OVS_CONTROLLER_TARGET_PREFIXES: [&str; 6] =
+    ["tcp:", "ssl:", "unix:", "ptcp:", "pssl:", "punix:"];
+const SUPPORTED_OVS_PROTOCOLS: [&str; 6] = [
+    "OpenFlow10",
+    "OpenFlow11",
+    "OpenFlow12",
+    "OpenFlow13",
+    "OpenFlow14",
+    "OpenFlow15",
+];
+const OVS_DATAPATH_ID_LEN: usize = 16;
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
 /// OpenvSwitch bridge interface. Example yaml output of [crate::NetworkState]
@@ -140,6 +152,97 @@ impl OvsBridgeInterface {
                 }
             }
         }
+        if is_desired {
+            self.validate_controller()?;
+            self.validate_datapath_id()?;
+            self.validate_protocols()?;
+        }
+        Ok(())
+    }
+
+    fn validate_datapath_id(&self) -> Result<(), NmstateError> {
+        if let Some(dp_id) = self
+            .bridge
+            .as_ref()
+            .and_then(|br_conf| br_conf.options.as_ref())
+            .and_then(|opts| opts.datapath_id.as_deref())
+            .filter(|d| !d.is_empty())
+        {
+            if dp_id.len() != OVS_DATAPATH_ID_LEN
+                || !dp_id.chars().all(|c| c.is_ascii_hexdigit())
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid datapath-id {dp_id} of OVS bridge {}, \
+                        expecting exactly {OVS_DATAPATH_ID_LEN} hex digits",
+                        self.base.name,
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_protocols(&self) -> Result<(), NmstateError> {
+        if let Some(protocols) = self
+            .bridge
+            .as_ref()
+            .and_then(|br_conf| br_conf.options.as_ref())
+            .and_then(|opts| opts.protocols.as_ref())
+        {
+            for protocol in protocols {
+                if !SUPPORTED_OVS_PROTOCOLS.contains(&protocol.as_str()) {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Invalid OpenFlow protocol {protocol} of OVS \
+                            bridge {}, supported protocols are: {}",
+                            self.base.name,
+                            SUPPORTED_OVS_PROTOCOLS.join(", ")
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_controller(&self) -> Result<(), NmstateError> {
+        if let Some(target) = self
+            .bridge
+            .as_ref()
+            .and_then(|br_conf| br_conf.options.as_ref())
+            .and_then(|opts| opts.controller.as_deref())
+            .filter(|t| !t.is_empty())
+        {
+            let is_valid =
+                SUPPORTED_OVS_CONTROLLER_TARGET_PREFIXES
+                    .iter()
+                    .any(|prefix| {
+                        target
+                            .strip_prefix(prefix)
+                            .map(|t| !t.is_empty())
+                            .unwrap_or_default()
+                    });
+            if !is_valid {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid OpenFlow controller target {target} of OVS \
+                        bridge {}, expecting target started with one of: {}",
+                        self.base.name,
+                        SUPPORTED_OVS_CONTROLLER_TARGET_PREFIXES.join(", ")
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
         Ok(())
     }
 
@@ -301,9 +404,31 @@ pub struct OvsBridgeOptions {
     /// Set to `netdev` for DPDK.
     /// Deserialize and serialize from/to `datapath`.
     pub datapath: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// OpenFlow controller connection target, for example
+    /// `tcp:192.0.2.1:6653`. Supported prefixes are `tcp:`, `ssl:`, `unix:`,
+    /// `ptcp:`, `pssl:` and `punix:`. Set to empty string to remove the
+    /// controller. NetworkManager has no support of this option, nmstate
+    /// stores it to OVS database directly.
+    /// Deserialize and serialize from/to `controller`.
+    pub controller: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// OpenFlow datapath ID of the bridge in exactly 16 hex digits, for
+    /// example `0000000000000001`. Set to empty string to let OVS choose the
+    /// datapath ID.
+    /// Deserialize and serialize from/to `datapath-id`.
+    pub datapath_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// OpenFlow protocols allowed on the bridge, for example `OpenFlow13`.
+    /// Supported values are `OpenFlow10` to `OpenFlow15`. Set to empty list
+    /// to use OVS default. NetworkManager has no support of this option,
+    /// nmstate stores it to OVS database directly.
+    pub protocols: Option<Vec<String>>,
 }
 
 impl OvsBridgeOptions {
+    pub(crate) const DATAPATH_ID_KEY: &'static str = "datapath-id";
+
     pub fn new() -> Self {
         Self::default()
     }
@@ -389,6 +514,17 @@ pub struct OvsInterface {
     pub patch: Option<OvsPatchConfig>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub dpdk: Option<OvsDpdkConfig>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "mtu-request",
+        default,
+        deserialize_with = "crate::deserializer::option_u64_or_string"
+    )]
+    /// The `mtu_request` of OVS interface, override the `mtu` of interface
+    /// when requesting MTU from OpenvSwitch. Not allowed for OVS patch
+    /// interface.
+    /// Serialize and deserialize to/from `mtu-request`.
+    pub mtu_request: Option<u64>,
 }
 
 impl Default for OvsInterface {
@@ -399,6 +535,7 @@ impl Default for OvsInterface {
             base,
             patch: None,
             dpdk: None,
+            mtu_request: None,
         }
     }
 }
@@ -429,12 +566,24 @@ impl OvsInterface {
     // OVS patch interface cannot have MTU or IP configuration
     // OVS DPDK `n_rxq_desc` and `n_txq_desc` should be power of 2 within
     // 1-4096.
+    // The `mtu_request` should be positive.
     pub(crate) fn sanitize(
         &self,
         is_desired: bool,
     ) -> Result<(), NmstateError> {
+        if is_desired && self.mtu_request == Some(0) {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "The mtu-request of OVS interface {} should be positive",
+                    self.base.name.as_str()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
         if is_desired && self.patch.is_some() {
-            if self.base.mtu.is_some() {
+            if self.base.mtu.is_some() || self.mtu_request.is_some() {
                 let e = NmstateError::new(
                     ErrorKind::InvalidArgument,
                     format!(
@@ -649,6 +798,17 @@ pub struct OvsDpdkConfig {
     /// Must be power of 2 in the range of 1 to 4096.
     /// Setting to 0 means remove this setting from OVS database.
     pub n_txq_desc: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "pmd-rxq-affinity"
+    )]
+    /// Pin rx queues to PMD threads on the specified CPU cores, the key is
+    /// rx queue ID and the value is CPU core ID.
+    /// NetworkManager has no property for this, nmstate stores it to
+    /// `other_config` of OVS interface.
+    /// Setting to empty means remove this setting from OVS database.
+    /// Deserialize and serialize from/to `pmd-rxq-affinity`.
+    pub pmd_rxq_affinity: Option<BTreeMap<u32, u32>>,
 }
 
 const POWER_2_BETWEEN_1_4096: [u32; 13] =
@@ -673,6 +833,8 @@ fn validate_dpdk_queue_desc(
 }
 
 impl OvsDpdkConfig {
+    pub(crate) const PMD_RXQ_AFFINITY_KEY: &'static str = "pmd-rxq-affinity";
+
     pub(crate) fn sanitize(
         &self,
         is_desired: bool,
@@ -684,6 +846,23 @@ impl OvsDpdkConfig {
             if let Some(n_txq_desc) = self.n_txq_desc {
                 validate_dpdk_queue_desc(n_txq_desc, "n_txq_desc")?;
             }
+            if let (Some(affinity), Some(rx_queue)) =
+                (self.pmd_rxq_affinity.as_ref(), self.rx_queue)
+            {
+                if let Some(queue_id) =
+                    affinity.keys().find(|queue_id| **queue_id >= rx_queue)
+                {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "OVS DPDK pmd-rxq-affinity rx queue {queue_id} \
+                            does not exist as rx-queue is set to {rx_queue}"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
         }
         Ok(())
     }
@@ -758,6 +937,46 @@ impl MergedInterface {
     }
 }
 
+impl Interfaces {
+    // OpenvSwitch does not allow OVS bridge to be port of another OVS bridge,
+    // they should be connected via OVS patch interfaces.
+    pub(crate) fn validate_ovs_bridge_not_in_ovs_bridge(
+        &self,
+        current: &Self,
+    ) -> Result<(), NmstateError> {
+        for br_iface in self.user_ifaces.values().filter_map(|i| {
+            if let Interface::OvsBridge(br_iface) = i {
+                if br_iface.base.state == InterfaceState::Up {
+                    return Some(br_iface);
+                }
+            }
+            None
+        }) {
+            for port_name in br_iface.ports().unwrap_or_default() {
+                let br_key = (port_name.to_string(), InterfaceType::OvsBridge);
+                if !self.kernel_ifaces.contains_key(port_name)
+                    && !current.kernel_ifaces.contains_key(port_name)
+                    && (self.user_ifaces.contains_key(&br_key)
+                        || current.user_ifaces.contains_key(&br_key))
+                {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "OVS bridge {port_name} cannot be port of OVS \
+                            bridge {}, please connect them using OVS patch \
+                            interfaces",
+                            br_iface.base.name.as_str()
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
 impl MergedInterfaces {
     // This function remove extra(undesired) ovs patch port from pre-apply
     // current, so it will not interfere with port change.
@@ -805,6 +1024,111 @@ impl MergedInterfaces {
             }
         }
     }
+
+    // OVS bridge tags traffic using the `vlan` setting of its port, kernel
+    // VLAN interface as OVS system port is considered as misconfiguration.
+    // Only VLAN interfaces newly attached to OVS bridge are checked, so
+    // existing setup is not blocking unrelated changes.
+    pub(crate) fn validate_no_vlan_iface_in_ovs_bridge(
+        &self,
+    ) -> Result<(), NmstateError> {
+        if let Some(iface) = self.kernel_ifaces.values().find(|i| {
+            i.is_changed()
+                && i.merged.is_up()
+                && i.merged.iface_type() == InterfaceType::Vlan
+                && i.merged.base_iface().controller_type
+                    == Some(InterfaceType::OvsBridge)
+                && !self.is_existing_ovs_bridge_port(i.merged.name())
+        }) {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "VLAN interface {} cannot be port of OVS bridge {}, \
+                    please use `vlan` setting of OVS bridge port with \
+                    `mode: access` and `tag` instead",
+                    iface.merged.name(),
+                    iface
+                        .merged
+                        .base_iface()
+                        .controller
+                        .as_deref()
+                        .unwrap_or_default()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        Ok(())
+    }
+
+    fn is_existing_ovs_bridge_port(&self, iface_name: &str) -> bool {
+        self.user_ifaces
+            .values()
+            .filter(|i| i.merged.iface_type() == InterfaceType::OvsBridge)
+            .any(|i| {
+                i.current
+                    .as_ref()
+                    .and_then(|c| c.ports())
+                    .map(|ports| ports.contains(&iface_name))
+                    .unwrap_or_default()
+                    && i.merged
+                        .ports()
+                        .map(|ports| ports.contains(&iface_name))
+                        .unwrap_or_default()
+            })
+    }
+
+    // OVS internal interface cannot be activated without its OVS bridge,
+    // the OVS bridge could be defined in desired or current state.
+    pub(crate) fn validate_ovs_iface_has_ovs_bridge(
+        &self,
+    ) -> Result<(), NmstateError> {
+        for iface in self.kernel_ifaces.values().filter(|i| {
+            i.is_desired()
+                && i.merged.is_up()
+                && i.merged.iface_type() == InterfaceType::OvsInterface
+        }) {
+            let iface_name = iface.merged.name();
+            let ctrl_name = match iface
+                .merged
+                .base_iface()
+                .controller
+                .as_deref()
+                .filter(|c| !c.is_empty())
+            {
+                Some(c) => c,
+                None => {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "OVS internal interface {iface_name} is not \
+                            port of any OVS bridge"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            };
+            if !self
+                .user_ifaces
+                .get(&(ctrl_name.to_string(), InterfaceType::OvsBridge))
+                .map(|i| i.merged.is_up())
+                .unwrap_or_default()
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "OVS bridge {ctrl_name} of OVS internal interface \
+                        {iface_name} does not exist in desired or current \
+                        state"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
 }
 
 pub type OvsBridgeStpOptions = LinuxBridgeStpOptions;
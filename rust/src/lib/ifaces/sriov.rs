@@ -57,6 +57,16 @@ pub struct SrIovConfig {
     ///   to defaults.
     /// * If not empty, missing [SrIovVfConfig] will use current configuration.
     pub vfs: Option<Vec<SrIovVfConfig>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The eswitch mode of PF, `switchdev` is required by hardware offload.
+    /// Not available for query yet, hence ignored during verification.
+    /// Deserialize and serialize from/to `eswitch-mode`.
+    pub eswitch_mode: Option<SrIovEswitchMode>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The eswitch encapsulation mode of PF.
+    /// Not available for query yet, hence ignored during verification.
+    /// Deserialize and serialize from/to `eswitch-encap-mode`.
+    pub eswitch_encap_mode: Option<SrIovEswitchEncapMode>,
 }
 
 impl SrIovConfig {
@@ -67,13 +77,13 @@ impl SrIovConfig {
         Self::default()
     }
 
-    // * Convert VF MAC address to upper case
+    // * Convert VF MAC address to upper case separated by `:`
     // * Sort by VF ID
     pub(crate) fn sanitize(&mut self) -> Result<(), NmstateError> {
         if let Some(vfs) = self.vfs.as_mut() {
             for vf in vfs.iter_mut() {
                 if let Some(address) = vf.mac_address.as_mut() {
-                    address.make_ascii_uppercase()
+                    *address = address.replace('-', ":").to_ascii_uppercase();
                 }
 
                 if let Some(VlanProtocol::Ieee8021Ad) = vf.vlan_proto {
@@ -88,6 +98,24 @@ impl SrIovConfig {
                         return Err(e);
                     }
                 }
+
+                // The max-tx-rate 0 means no limit
+                if let (Some(min_tx_rate), Some(max_tx_rate)) =
+                    (vf.min_tx_rate, vf.max_tx_rate)
+                {
+                    if max_tx_rate != 0 && min_tx_rate > max_tx_rate {
+                        let e = NmstateError::new(
+                            ErrorKind::InvalidArgument,
+                            format!(
+                                "VF ID {}: min-tx-rate {min_tx_rate} should \
+                                not be bigger than max-tx-rate {max_tx_rate}",
+                                vf.id
+                            ),
+                        );
+                        log::error!("{}", e);
+                        return Err(e);
+                    }
+                }
             }
             vfs.sort_unstable_by(|a, b| a.id.cmp(&b.id));
         }
@@ -167,7 +195,7 @@ pub struct SrIovVfConfig {
         default,
         deserialize_with = "crate::deserializer::option_u32_or_string"
     )]
-    /// Deserialize and serialize from/to `min_tx_rate`.
+    /// Deserialize and serialize from/to `min-tx-rate`.
     pub min_tx_rate: Option<u32>,
     #[serde(
         skip_serializing_if = "Option::is_none",
@@ -200,6 +228,52 @@ impl SrIovVfConfig {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum SrIovEswitchMode {
+    /// Deserialize and serialize from/to `legacy`.
+    Legacy,
+    /// Deserialize and serialize from/to `switchdev`.
+    Switchdev,
+}
+
+impl std::fmt::Display for SrIovEswitchMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Legacy => "legacy",
+                Self::Switchdev => "switchdev",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum SrIovEswitchEncapMode {
+    /// Deserialize and serialize from/to `none`.
+    None,
+    /// Deserialize and serialize from/to `basic`.
+    Basic,
+}
+
+impl std::fmt::Display for SrIovEswitchEncapMode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::None => "none",
+                Self::Basic => "basic",
+            }
+        )
+    }
+}
+
 impl Interfaces {
     pub(crate) fn resolve_sriov_reference(
         &mut self,
@@ -386,31 +460,43 @@ impl MergedInterface {
     pub(crate) fn post_inter_ifaces_process_sriov(
         &mut self,
     ) -> Result<(), NmstateError> {
-        if let (
-            Some(Interface::Ethernet(apply_iface)),
-            Some(Interface::Ethernet(verify_iface)),
-            Some(Interface::Ethernet(cur_iface)),
-        ) = (
-            self.for_apply.as_mut(),
-            self.for_verify.as_mut(),
-            self.current.as_ref(),
+        let cur_iface = match self.current.as_ref() {
+            Some(i) => i,
+            None => return Ok(()),
+        };
+        let cur_conf = get_sriov_conf(cur_iface);
+        if let (Some(apply_conf), Some(verify_conf)) = (
+            self.for_apply.as_mut().and_then(get_sriov_conf_mut),
+            self.for_verify.as_mut().and_then(get_sriov_conf_mut),
         ) {
-            let cur_conf =
-                cur_iface.ethernet.as_ref().and_then(|e| e.sr_iov.as_ref());
-            if let (Some(apply_conf), Some(verify_conf)) = (
-                apply_iface
-                    .ethernet
-                    .as_mut()
-                    .and_then(|e| e.sr_iov.as_mut()),
-                verify_iface
-                    .ethernet
-                    .as_mut()
-                    .and_then(|e| e.sr_iov.as_mut()),
-            ) {
-                apply_conf.auto_fill_unmentioned_vf_id(cur_conf);
-                verify_conf.auto_fill_unmentioned_vf_id(cur_conf);
-            }
+            apply_conf.auto_fill_unmentioned_vf_id(cur_conf);
+            verify_conf.auto_fill_unmentioned_vf_id(cur_conf);
         }
         Ok(())
     }
 }
+
+// Both Ethernet and InfiniBand interface could hold SR-IOV configuration.
+fn get_sriov_conf(iface: &Interface) -> Option<&SrIovConfig> {
+    match iface {
+        Interface::Ethernet(iface) => {
+            iface.ethernet.as_ref().and_then(|e| e.sr_iov.as_ref())
+        }
+        Interface::InfiniBand(iface) => {
+            iface.ib.as_ref().and_then(|i| i.sr_iov.as_ref())
+        }
+        _ => None,
+    }
+}
+
+fn get_sriov_conf_mut(iface: &mut Interface) -> Option<&mut SrIovConfig> {
+    match iface {
+        Interface::Ethernet(iface) => {
+            iface.ethernet.as_mut().and_then(|e| e.sr_iov.as_mut())
+        }
+        Interface::InfiniBand(iface) => {
+            iface.ib.as_mut().and_then(|i| i.sr_iov.as_mut())
+        }
+        _ => None,
+    }
+}
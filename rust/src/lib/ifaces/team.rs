@@ -0,0 +1,357 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    BaseInterface, ErrorKind, Interface, InterfaceType, MergedInterface,
+    NmstateError,
+};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[non_exhaustive]
+/// Team interface provided by libteam(teamd). The example yaml output of
+/// [crate::NetworkState] with a team interface would be:
+/// ```yml
+/// interfaces:
+/// - name: team0
+///   type: team
+///   state: up
+///   team:
+///     runner: activebackup
+///     port:
+///     - name: eth1
+///     - name: eth2
+/// ```
+pub struct TeamInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub team: Option<TeamConfig>,
+}
+
+impl Default for TeamInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::Team,
+                ..Default::default()
+            },
+            team: None,
+        }
+    }
+}
+
+impl TeamInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn ports(&self) -> Option<Vec<&str>> {
+        self.team
+            .as_ref()
+            .and_then(|team_conf| team_conf.port.as_ref())
+            .map(|ports| ports.iter().map(|p| p.name.as_str()).collect())
+    }
+
+    pub(crate) fn sanitize(
+        &mut self,
+        is_desired: bool,
+    ) -> Result<(), NmstateError> {
+        if let Some(team_conf) = self.team.as_mut() {
+            if is_desired {
+                team_conf.validate(self.base.name.as_str())?;
+            }
+            if let Some(ports) = team_conf.port.as_mut() {
+                ports.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn remove_port(&mut self, port_to_remove: &str) {
+        if let Some(ports) = self
+            .team
+            .as_mut()
+            .and_then(|team_conf| team_conf.port.as_mut())
+        {
+            ports.retain(|p| p.name != port_to_remove);
+        }
+    }
+
+    pub(crate) fn change_port_name(
+        &mut self,
+        origin_name: &str,
+        new_name: String,
+    ) {
+        if let Some(port_conf) = self
+            .team
+            .as_mut()
+            .and_then(|team_conf| team_conf.port.as_mut())
+            .and_then(|ports| ports.iter_mut().find(|p| p.name == origin_name))
+        {
+            port_conf.name = new_name;
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct TeamConfig {
+    #[serde(skip_serializing_if = "Option::is_none", alias = "ports")]
+    /// Port list.
+    /// Deserialize and serialize from/to `port`.
+    /// Also deserialize from `ports`.
+    pub port: Option<Vec<TeamPortConfig>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The runner of teamd. When both `runner` and `config` are defined,
+    /// the `runner.name` in `config` should be the same.
+    pub runner: Option<TeamRunner>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The teamd configuration in JSON format.
+    pub config: Option<String>,
+}
+
+impl TeamConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn validate(&self, iface_name: &str) -> Result<(), NmstateError> {
+        let json_runner = if let Some(config) =
+            self.config.as_deref().filter(|c| !c.is_empty())
+        {
+            let conf_value: serde_json::Value = serde_json::from_str(config)
+                .map_err(|e| {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Invalid team config of interface {iface_name}: \
+                            {e}"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    e
+                })?;
+            if !conf_value.is_object() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid team config of interface {iface_name}: \
+                        expecting JSON object, but got {config}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            conf_value
+                .get("runner")
+                .and_then(|r| r.get("name"))
+                .and_then(|n| n.as_str())
+                .map(|n| n.to_string())
+        } else {
+            None
+        };
+        if let (Some(runner), Some(json_runner)) =
+            (self.runner, json_runner.as_deref())
+        {
+            if runner.to_string() != json_runner {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The team runner {runner} of interface {iface_name} \
+                        is conflicting with runner {json_runner} defined \
+                        in team config"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+
+    // The teamd use `roundrobin` runner by default
+    fn get_runner(&self) -> TeamRunner {
+        self.runner
+            .or_else(|| {
+                self.config
+                    .as_deref()
+                    .and_then(|c| {
+                        serde_json::from_str::<serde_json::Value>(c).ok()
+                    })
+                    .and_then(|v| {
+                        v.get("runner")
+                            .and_then(|r| r.get("name"))
+                            .and_then(|n| n.as_str())
+                            .and_then(|n| n.parse::<TeamRunner>().ok())
+                    })
+            })
+            .unwrap_or(TeamRunner::Roundrobin)
+    }
+
+    fn validate_lacp_port_opts(
+        &self,
+        iface_name: &str,
+        runner: TeamRunner,
+    ) -> Result<(), NmstateError> {
+        if runner != TeamRunner::Lacp {
+            if let Some(port_conf) = self
+                .port
+                .as_deref()
+                .unwrap_or_default()
+                .iter()
+                .find(|p| p.lacp_prio.is_some() || p.lacp_key.is_some())
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The lacp-prio and lacp-key of team port {} are \
+                        only valid for lacp runner, but team interface \
+                        {iface_name} is using {runner} runner",
+                        port_conf.name
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+impl MergedInterface {
+    // The runner might be defined in current only, hence validate the LACP
+    // options of desired ports against the merged runner.
+    pub(crate) fn post_inter_ifaces_process_team(
+        &self,
+    ) -> Result<(), NmstateError> {
+        if let (
+            Some(Interface::Team(apply_iface)),
+            Interface::Team(merged_iface),
+        ) = (self.for_apply.as_ref(), &self.merged)
+        {
+            if let Some(apply_conf) = apply_iface.team.as_ref() {
+                let runner = merged_iface
+                    .team
+                    .as_ref()
+                    .map(|c| c.get_runner())
+                    .unwrap_or(TeamRunner::Roundrobin);
+                apply_conf.validate_lacp_port_opts(
+                    apply_iface.base.name.as_str(),
+                    runner,
+                )?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct TeamPortConfig {
+    /// The kernel interface name of this team port.
+    pub name: String,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_i32_or_string"
+    )]
+    /// The port priority, higher number means higher priority.
+    pub prio: Option<i32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Whether active port stays active even another port with higher
+    /// priority becomes available. Only for `activebackup` runner.
+    pub sticky: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_i32_or_string"
+    )]
+    /// The queue ID of this port.
+    /// Deserialize and serialize from/to `queue-id`.
+    pub queue_id: Option<i32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_i32_or_string"
+    )]
+    /// The LACP port priority. Only valid for `lacp` runner.
+    /// Deserialize and serialize from/to `lacp-prio`.
+    pub lacp_prio: Option<i32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_i32_or_string"
+    )]
+    /// The LACP port key. Only valid for `lacp` runner.
+    /// Deserialize and serialize from/to `lacp-key`.
+    pub lacp_key: Option<i32>,
+}
+
+impl TeamPortConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+#[non_exhaustive]
+pub enum TeamRunner {
+    /// Deserialize and serialize from/to `broadcast`.
+    Broadcast,
+    /// Deserialize and serialize from/to `roundrobin`.
+    Roundrobin,
+    /// Deserialize and serialize from/to `random`.
+    Random,
+    /// Deserialize and serialize from/to `activebackup`.
+    Activebackup,
+    /// Deserialize and serialize from/to `loadbalance`.
+    Loadbalance,
+    /// Deserialize and serialize from/to `lacp`.
+    Lacp,
+}
+
+impl std::fmt::Display for TeamRunner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Broadcast => "broadcast",
+                Self::Roundrobin => "roundrobin",
+                Self::Random => "random",
+                Self::Activebackup => "activebackup",
+                Self::Loadbalance => "loadbalance",
+                Self::Lacp => "lacp",
+            }
+        )
+    }
+}
+
+impl std::str::FromStr for TeamRunner {
+    type Err = NmstateError;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "broadcast" => Ok(Self::Broadcast),
+            "roundrobin" => Ok(Self::Roundrobin),
+            "random" => Ok(Self::Random),
+            "activebackup" => Ok(Self::Activebackup),
+            "loadbalance" => Ok(Self::Loadbalance),
+            "lacp" => Ok(Self::Lacp),
+            _ => Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Invalid team runner {s}, only support broadcast, \
+                    roundrobin, random, activebackup, loadbalance or lacp"
+                ),
+            )),
+        }
+    }
+}
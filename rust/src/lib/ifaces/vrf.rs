@@ -1,10 +1,12 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::HashMap;
+
 use serde::{Deserialize, Serialize};
 
 use crate::{
     BaseInterface, ErrorKind, Interface, InterfaceType, MergedInterface,
-    NmstateError,
+    MergedInterfaces, NmstateError,
 };
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -149,6 +151,9 @@ impl MergedInterface {
     pub(crate) fn post_inter_ifaces_process_vrf(
         &mut self,
     ) -> Result<(), NmstateError> {
+        if let Interface::Vrf(merged_iface) = &mut self.merged {
+            merged_iface.merge_table_id(self.current.as_ref())?;
+        }
         if let Some(Interface::Vrf(apply_iface)) = self.for_apply.as_mut() {
             apply_iface.merge_table_id(self.current.as_ref())?;
         }
@@ -158,3 +163,45 @@ impl MergedInterface {
         Ok(())
     }
 }
+
+impl MergedInterfaces {
+    // Kernel does not allow two VRF interfaces to use the same route table.
+    pub(crate) fn validate_vrf_table_id_unique(
+        &self,
+    ) -> Result<(), NmstateError> {
+        let mut table_ids: HashMap<u32, &MergedInterface> = HashMap::new();
+        for iface in self.kernel_ifaces.values().filter(|i| {
+            i.merged.iface_type() == InterfaceType::Vrf && i.merged.is_up()
+        }) {
+            let table_id = match &iface.merged {
+                Interface::Vrf(vrf_iface) => vrf_iface
+                    .vrf
+                    .as_ref()
+                    .map(|v| v.table_id)
+                    .unwrap_or_default(),
+                _ => continue,
+            };
+            if table_id == 0 {
+                continue;
+            }
+            if let Some(other_iface) = table_ids.insert(table_id, iface) {
+                if iface.is_desired() || other_iface.is_desired() {
+                    let mut names =
+                        [iface.merged.name(), other_iface.merged.name()];
+                    names.sort_unstable();
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "VRF interfaces {} and {} cannot share the same \
+                            route table ID {table_id}",
+                            names[0], names[1]
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{BaseInterface, InterfaceType};
+use crate::{BaseInterface, ErrorKind, InterfaceType, NmstateError};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[non_exhaustive]
@@ -47,6 +47,18 @@ impl VxlanInterface {
         Self::default()
     }
 
+    pub(crate) fn sanitize(
+        &self,
+        is_desired: bool,
+    ) -> Result<(), NmstateError> {
+        if is_desired {
+            if let Some(conf) = self.vxlan.as_ref() {
+                conf.validate(self.base.name.as_str())?;
+            }
+        }
+        Ok(())
+    }
+
     pub(crate) fn parent(&self) -> Option<&str> {
         self.vxlan.as_ref().and_then(|cfg| {
             if cfg.base_iface.is_empty() {
@@ -69,14 +81,77 @@ pub struct VxlanConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub learning: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    /// Source IP address used for VXLAN packets. Should be in the same IP
+    /// family as `remote`.
     pub local: Option<std::net::IpAddr>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    /// Unicast destination IP address or multicast group address.
     pub remote: Option<std::net::IpAddr>,
     #[serde(
         rename = "destination-port",
+        skip_serializing_if = "Option::is_none",
         default,
         deserialize_with = "crate::deserializer::option_u16_or_string"
     )]
     /// Deserialize and serialize from/to `destination-port`.
     pub dst_port: Option<u16>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Enable the Group Based Policy (GBP) extension carrying the group
+    /// policy ID in VXLAN header. Cannot be used with `gpe`.
+    /// Only supported by NetworkManager backend when not enabled.
+    pub gbp: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Enable the Generic Protocol Extension (GPE) allowing VXLAN to carry
+    /// non-Ethernet payload like NSH. Cannot be used with `gbp` or
+    /// `learning: true`.
+    /// Only supported by NetworkManager backend when not enabled.
+    pub gpe: Option<bool>,
+}
+
+impl VxlanConfig {
+    fn validate(&self, iface_name: &str) -> Result<(), NmstateError> {
+        if let (Some(local), Some(remote)) = (self.local, self.remote) {
+            if local.is_ipv4() != remote.is_ipv4() {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The VXLAN local address {local} and remote \
+                        address {remote} of interface {iface_name} should \
+                        be in the same IP family"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        if self.gpe == Some(true) {
+            let conflict = if self.gbp == Some(true) {
+                Some("gbp")
+            } else if self.learning == Some(true) {
+                Some("learning")
+            } else {
+                None
+            };
+            if let Some(conflict) = conflict {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The VXLAN gpe extension of interface {iface_name} \
+                        cannot be used with {conflict} enabled"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
 }
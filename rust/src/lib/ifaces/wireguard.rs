@@ -0,0 +1,256 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    ip::sanitize_ip_network, BaseInterface, ErrorKind, InterfaceType,
+    NetworkState, NmstateError, SecretStorageMode,
+};
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// WireGuard interface. The example YAML output of a
+/// [crate::NetworkState] with a WireGuard interface would be:
+/// ```yaml
+/// ---
+/// interfaces:
+///   - name: wg0
+///     type: wireguard
+///     state: up
+///     wireguard:
+///       private-key: <_password_hid_by_nmstate>
+///       listen-port: 51820
+///       peers:
+///         - public-key: 8bdQrVLqiw3ZoHCucNh1YfH0iCWuyStniRr8t7H24Fk=
+///           preshared-key: <_password_hid_by_nmstate>
+///           endpoint: vpn.example.org:51820
+///           allowed-ips:
+///             - 192.0.2.0/24
+///           persistent-keepalive: 25
+/// ```
+pub struct WireGuardInterface {
+    #[serde(flatten)]
+    pub base: BaseInterface,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Deserialize and serialize to `wireguard`.
+    pub wireguard: Option<WireGuardConfig>,
+}
+
+impl Default for WireGuardInterface {
+    fn default() -> Self {
+        Self {
+            base: BaseInterface {
+                iface_type: InterfaceType::WireGuard,
+                ..Default::default()
+            },
+            wireguard: None,
+        }
+    }
+}
+
+impl WireGuardInterface {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn sanitize(
+        &mut self,
+        is_desired: bool,
+    ) -> Result<(), NmstateError> {
+        if let Some(conf) = self.wireguard.as_mut() {
+            conf.sanitize(self.base.name.as_str(), is_desired)?;
+        }
+        Ok(())
+    }
+
+    pub(crate) fn hide_secrets(&mut self) {
+        if let Some(conf) = self.wireguard.as_mut() {
+            conf.hide_secrets();
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct WireGuardConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The base64 encoded private key.
+    /// Replaced to `<_password_hid_by_nmstate>` when querying.
+    pub private_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// How the `private-key` is stored.
+    /// Deserialize and serialize from/to `private-key-storage`.
+    pub private_key_storage: Option<SecretStorageMode>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u16_or_string"
+    )]
+    /// The UDP port to listen on, 0 means random port.
+    pub listen_port: Option<u16>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// The firewall mark of outgoing packets, 0 means disabled.
+    pub fwmark: Option<u32>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Who resolves the hostname in peer endpoint.
+    /// Default to [WireGuardEndpointResolver::Backend].
+    pub endpoint_resolver: Option<WireGuardEndpointResolver>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub peers: Option<Vec<WireGuardPeerConfig>>,
+}
+
+impl WireGuardConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn sanitize(
+        &mut self,
+        iface_name: &str,
+        is_desired: bool,
+    ) -> Result<(), NmstateError> {
+        if let Some(peers) = self.peers.as_mut() {
+            let mut public_keys: HashSet<&str> = HashSet::new();
+            for peer in peers.iter_mut() {
+                peer.sanitize(is_desired)?;
+            }
+            for peer in peers.iter() {
+                if !public_keys.insert(peer.public_key.as_str()) {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "WireGuard interface {iface_name} has duplicate \
+                            peer public key {}",
+                            peer.public_key
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn hide_secrets(&mut self) {
+        if self.private_key.is_some() {
+            self.private_key =
+                Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+        }
+        for peer in self.peers.as_deref_mut().unwrap_or_default() {
+            if peer.preshared_key.is_some() {
+                peer.preshared_key =
+                    Some(NetworkState::PASSWORD_HID_BY_NMSTATE.to_string());
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq, Default)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+pub struct WireGuardPeerConfig {
+    /// The base64 encoded public key of the peer.
+    pub public_key: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The base64 encoded preshared key.
+    /// Existing preshared key is preserved if not defined, use empty string
+    /// to remove it.
+    /// Replaced to `<_password_hid_by_nmstate>` when querying.
+    pub preshared_key: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The endpoint of peer in the format of `<host>:<port>`, the host could
+    /// be IPv4 address, IPv6 address in square brackets or hostname.
+    pub endpoint: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// The IP networks allowed to send and receive via this peer, for
+    /// example `192.0.2.0/24`.
+    pub allowed_ips: Option<Vec<String>>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    /// Interval in seconds of keepalive packets, 0 means disabled.
+    pub persistent_keepalive: Option<u32>,
+}
+
+impl WireGuardPeerConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn sanitize(&mut self, is_desired: bool) -> Result<(), NmstateError> {
+        if let Some(allowed_ips) = self.allowed_ips.as_mut() {
+            for allowed_ip in allowed_ips.iter_mut() {
+                let sanitized = sanitize_ip_network(allowed_ip)?;
+                if is_desired && &sanitized != allowed_ip {
+                    log::info!(
+                        "Sanitized WireGuard peer {} allowed IP {} to {}",
+                        self.public_key,
+                        allowed_ip,
+                        sanitized
+                    );
+                }
+                *allowed_ip = sanitized;
+            }
+        }
+        if is_desired {
+            if let Some(endpoint) = self.endpoint.as_deref() {
+                if parse_wireguard_endpoint(endpoint).is_none() {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Invalid WireGuard peer {} endpoint {endpoint}, \
+                            expecting format `<host>:<port>`, for example: \
+                            192.0.2.1:51820, [2001:db8::1]:51820 or \
+                            vpn.example.org:51820",
+                            self.public_key
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+// Split endpoint into host and port, the square brackets of IPv6 address are
+// preserved in host.
+fn parse_wireguard_endpoint(endpoint: &str) -> Option<(&str, u16)> {
+    let (host, port) = endpoint.rsplit_once(':')?;
+    if host.is_empty()
+        || (host.contains(':')
+            && !(host.starts_with('[') && host.ends_with(']')))
+    {
+        return None;
+    }
+    Some((host, port.parse::<u16>().ok()?))
+}
+
+#[derive(
+    Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum WireGuardEndpointResolver {
+    /// Leave the hostname in peer endpoint to backend(e.g. NetworkManager)
+    /// to resolve.
+    /// Deserialize and serialize from/to `backend`.
+    #[default]
+    Backend,
+    /// Nmstate resolves the hostname in peer endpoint to IP address before
+    /// passing to backend.
+    /// Deserialize and serialize from/to `nmstate`.
+    Nmstate,
+}
@@ -16,6 +16,9 @@ const AF_INET6: u8 = 10;
 const IPV4_ADDR_LEN: usize = 32;
 const IPV6_ADDR_LEN: usize = 128;
 const FOREVER: &str = "forever";
+const IPV6_ACCEPT_RA_MAX: u8 = 2;
+const NM_DAD_TIMEOUT_DEFAULT: i32 = -1;
+const MAX_DAD_TIMEOUT: i32 = 30000;
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[non_exhaustive]
@@ -102,6 +105,68 @@ struct InterfaceIp {
         rename = "dhcp-custom-hostname"
     )]
     pub dhcp_custom_hostname: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dhcp-vendor-class-identifier"
+    )]
+    pub dhcp_vendor_class_identifier: Option<String>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dad-transmits",
+        default,
+        deserialize_with = "crate::deserializer::option_i32_or_string"
+    )]
+    pub dad_transmits: Option<i32>,
+    #[serde(skip_serializing_if = "Option::is_none", rename = "ip6-privacy")]
+    pub ip6_privacy: Option<Ipv6Privacy>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dad-timeout",
+        default,
+        deserialize_with = "crate::deserializer::option_i32_or_string"
+    )]
+    pub dad_timeout: Option<i32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "ra-timeout",
+        default,
+        deserialize_with = "crate::deserializer::option_u32_or_string"
+    )]
+    pub ra_timeout: Option<u32>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "accept-ra-mtu",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub accept_ra_mtu: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "accept-ra",
+        default,
+        deserialize_with = "crate::deserializer::option_u8_or_string"
+    )]
+    pub accept_ra: Option<u8>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub forwarding: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "replace-local-rule",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub replace_local_rule: Option<bool>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        rename = "dns-priority",
+        default,
+        deserialize_with = "crate::deserializer::option_i32_or_string"
+    )]
+    pub dns_priority: Option<i32>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
@@ -190,6 +255,36 @@ pub struct InterfaceIpv4 {
     /// If not defined, current non-dynamic hostname will be used.
     /// Deserialize from `dhcp-custom-hostname`
     pub dhcp_custom_hostname: Option<String>,
+    /// The Vendor Class Identifier option(60) defined in RFC 2132 sent in
+    /// DHCP request, commonly used for PXE provisioning.
+    /// Set to empty string to remove this option.
+    /// Only available for DHCPv4 enabled interface.
+    /// Deserialize from `dhcp-vendor-class-identifier`
+    pub dhcp_vendor_class_identifier: Option<String>,
+    /// Whether to replace the default local routing rule(priority 0 with
+    /// lookup local table) with the route rules defined for this interface.
+    /// When set to true, the route rules pointing to local table should be
+    /// included to keep local traffic working.
+    /// If not defined, the default local rule will be preserved.
+    /// Only supported by NetworkManager 1.44+.
+    /// Serialize and deserialize to/from `replace-local-rule`.
+    pub replace_local_rule: Option<bool>,
+    /// Priority of the DNS configuration of this IP stack, the full signed
+    /// 32 bits range is supported. Lower value means higher priority.
+    /// Negative value excludes the DNS configurations holding greater
+    /// numerical value, hence in presence of negative priority, only DNS
+    /// servers of interfaces with the lowest priority value will be used,
+    /// which is useful for VPN split DNS.
+    /// If not defined or set to 0, NetworkManager default (50 for VPN, 100
+    /// for others) or the priority chosen by nmstate for interface holding
+    /// global DNS servers will be used.
+    /// Serialize and deserialize to/from `dns-priority`.
+    pub dns_priority: Option<i32>,
+    /// Timeout in milliseconds of IPv4 Duplicate Address Detection, the
+    /// maximum is 30000. Setting to 0 disables DAD, which is useful for
+    /// faster VIP failover. Setting to -1 uses NetworkManager default.
+    /// Serialize and deserialize to/from `dad-timeout`.
+    pub dad_timeout: Option<i32>,
     pub(crate) dns: Option<DnsClientState>,
     pub(crate) rules: Option<Vec<RouteRuleEntry>>,
 }
@@ -267,7 +362,7 @@ impl InterfaceIpv4 {
     // * Disable DHCP and remove address if enabled: false
     // * Remove auto IP address.
     // * Set DHCP options to None if DHCP is false
-    // * Remove mptcp_flags is they are for query only
+    // * Sort and dedup mptcp_flags
     pub(crate) fn sanitize(
         &mut self,
         is_desired: bool,
@@ -340,6 +435,22 @@ impl InterfaceIpv4 {
             });
         }
 
+        if let Some(dad_timeout) = self.dad_timeout {
+            if is_desired
+                && !(NM_DAD_TIMEOUT_DEFAULT..=MAX_DAD_TIMEOUT)
+                    .contains(&dad_timeout)
+            {
+                return Err(NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid IPv4 dad-timeout {dad_timeout}, should be \
+                        in the range of {NM_DAD_TIMEOUT_DEFAULT} to \
+                        {MAX_DAD_TIMEOUT}"
+                    ),
+                ));
+            }
+        }
+
         if !self.enabled {
             self.dhcp = None;
             self.addresses = None;
@@ -360,6 +471,13 @@ impl InterfaceIpv4 {
             self.dhcp_client_id = None;
             self.dhcp_send_hostname = None;
             self.dhcp_custom_hostname = None;
+            if is_desired && self.dhcp_vendor_class_identifier.is_some() {
+                log::warn!(
+                    "Ignoring `dhcp-vendor-class-identifier` setting when \
+                    DHCPv4 is disabled"
+                );
+            }
+            self.dhcp_vendor_class_identifier = None;
         }
         if self.dhcp_send_hostname == Some(false) {
             if is_desired {
@@ -379,7 +497,7 @@ impl InterfaceIpv4 {
         }
         if let Some(addrs) = self.addresses.as_mut() {
             for addr in addrs.iter_mut() {
-                addr.mptcp_flags = None;
+                addr.sanitize_mptcp_flags();
             }
         }
         Ok(())
@@ -404,6 +522,26 @@ impl<'de> Deserialize<'de> for InterfaceIpv4 {
                     "dhcp-duid is not allowed for IPv4",
                 ));
             }
+            if v_map.contains_key("dad-transmits") {
+                return Err(serde::de::Error::custom(
+                    "dad-transmits is not allowed for IPv4",
+                ));
+            }
+            if v_map.contains_key("ra-timeout") {
+                return Err(serde::de::Error::custom(
+                    "ra-timeout is not allowed for IPv4",
+                ));
+            }
+            if v_map.contains_key("accept-ra-mtu") {
+                return Err(serde::de::Error::custom(
+                    "accept-ra-mtu is not allowed for IPv4",
+                ));
+            }
+            if v_map.contains_key("ip6-privacy") {
+                return Err(serde::de::Error::custom(
+                    "ip6-privacy is not allowed for IPv4",
+                ));
+            }
         }
 
         let ip: InterfaceIp = match serde_json::from_value(v) {
@@ -433,6 +571,10 @@ impl From<InterfaceIp> for InterfaceIpv4 {
             auto_route_metric: ip.auto_route_metric,
             dhcp_send_hostname: ip.dhcp_send_hostname,
             dhcp_custom_hostname: ip.dhcp_custom_hostname,
+            dhcp_vendor_class_identifier: ip.dhcp_vendor_class_identifier,
+            replace_local_rule: ip.replace_local_rule,
+            dns_priority: ip.dns_priority,
+            dad_timeout: ip.dad_timeout,
             ..Default::default()
         }
     }
@@ -458,6 +600,10 @@ impl From<InterfaceIpv4> for InterfaceIp {
             auto_route_metric: ip.auto_route_metric,
             dhcp_send_hostname: ip.dhcp_send_hostname,
             dhcp_custom_hostname: ip.dhcp_custom_hostname,
+            dhcp_vendor_class_identifier: ip.dhcp_vendor_class_identifier,
+            replace_local_rule: ip.replace_local_rule,
+            dns_priority: ip.dns_priority,
+            dad_timeout: ip.dad_timeout,
             ..Default::default()
         }
     }
@@ -552,6 +698,57 @@ pub struct InterfaceIpv6 {
     /// If not defined, current non-dynamic hostname will be used.
     /// Deserialize from `dhcp-custom-hostname`
     pub dhcp_custom_hostname: Option<String>,
+    /// Number of IPv6 Duplicate Address Detection probes to send. Setting to
+    /// 0 disables DAD for faster address availability.
+    /// If not defined, kernel default will be used.
+    /// Serialize and deserialize to/from `dad-transmits`.
+    pub dad_transmits: Option<i32>,
+    /// Usage of IPv6 privacy extensions(temporary addresses) defined in
+    /// RFC 4941.
+    /// If not defined, backend default will be used.
+    /// Serialize and deserialize to/from `ip6-privacy`.
+    pub ip6_privacy: Option<Ipv6Privacy>,
+    /// Seconds to wait for IPv6 router advertisement, 0 means backend
+    /// default. When not defined, nmstate waits infinitely for autoconf.
+    /// Serialize and deserialize to/from `ra-timeout`.
+    pub ra_timeout: Option<u32>,
+    /// Whether to accept MTU provided by IPv6 router advertisement.
+    /// Setting to false is helpful when link advertises bad MTU.
+    /// If not defined, kernel default(true) will be used.
+    /// Serialize and deserialize to/from `accept-ra-mtu`.
+    pub accept_ra_mtu: Option<bool>,
+    /// Whether to accept IPv6 router advertisement:
+    ///  * 0: Do not accept.
+    ///  * 1: Accept when forwarding is disabled.
+    ///  * 2: Accept even when forwarding is enabled, useful for router.
+    ///
+    /// If not defined, kernel default will be used.
+    /// Only supported by NetworkManager backend via dispatch script.
+    /// Serialize and deserialize to/from `accept-ra`.
+    pub accept_ra: Option<u8>,
+    /// Whether to forward IPv6 packets of this interface.
+    /// If not defined, kernel default will be used.
+    /// Only supported by NetworkManager backend via dispatch script.
+    pub forwarding: Option<bool>,
+    /// Whether to replace the default local routing rule(priority 0 with
+    /// lookup local table) with the route rules defined for this interface.
+    /// When set to true, the route rules pointing to local table should be
+    /// included to keep local traffic working.
+    /// If not defined, the default local rule will be preserved.
+    /// Only supported by NetworkManager 1.44+.
+    /// Serialize and deserialize to/from `replace-local-rule`.
+    pub replace_local_rule: Option<bool>,
+    /// Priority of the DNS configuration of this IP stack, the full signed
+    /// 32 bits range is supported. Lower value means higher priority.
+    /// Negative value excludes the DNS configurations holding greater
+    /// numerical value, hence in presence of negative priority, only DNS
+    /// servers of interfaces with the lowest priority value will be used,
+    /// which is useful for VPN split DNS.
+    /// If not defined or set to 0, NetworkManager default (50 for VPN, 100
+    /// for others) or the priority chosen by nmstate for interface holding
+    /// global DNS servers will be used.
+    /// Serialize and deserialize to/from `dns-priority`.
+    pub dns_priority: Option<i32>,
 
     pub(crate) dns: Option<DnsClientState>,
     pub(crate) rules: Option<Vec<RouteRuleEntry>>,
@@ -577,7 +774,7 @@ impl InterfaceIpv6 {
     //   those options is None
     // * Disable DHCP and remove address if enabled: false
     // * Set DHCP options to None if DHCP is false
-    // * Remove `mptcp_flags` as they are for query only
+    // * Sort and dedup `mptcp_flags`
     pub(crate) fn sanitize(
         &mut self,
         is_desired: bool,
@@ -675,10 +872,45 @@ impl InterfaceIpv6 {
             self.auto_route_metric = None;
             self.dhcp_send_hostname = None;
             self.dhcp_custom_hostname = None;
+            self.ra_timeout = None;
         }
         if let Some(addrs) = self.addresses.as_mut() {
             for addr in addrs.iter_mut() {
-                addr.mptcp_flags = None;
+                addr.sanitize_mptcp_flags();
+            }
+        }
+        if let Some(accept_ra) = self.accept_ra {
+            if is_desired && accept_ra > IPV6_ACCEPT_RA_MAX {
+                return Err(NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid IPv6 accept-ra {accept_ra}, should be 0, 1 \
+                        or 2"
+                    ),
+                ));
+            }
+        }
+        if let Some(dad_transmits) = self.dad_transmits {
+            if is_desired && dad_transmits < 0 {
+                return Err(NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid IPv6 dad-transmits {dad_transmits}, \
+                        should be equal or bigger than 0"
+                    ),
+                ));
+            }
+            if let Some(ra_timeout) = self.ra_timeout {
+                if is_desired && ra_timeout > i32::MAX as u32 {
+                    return Err(NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Invalid IPv6 ra-timeout {ra_timeout}, \
+                        should be equal or smaller than {}",
+                            i32::MAX
+                        ),
+                    ));
+                }
             }
         }
         if let Some(token) = self.token.as_mut() {
@@ -787,6 +1019,16 @@ impl<'de> Deserialize<'de> for InterfaceIpv6 {
                     "dhcp-client-id is not allowed for IPv6",
                 ));
             }
+            if v_map.contains_key("dhcp-vendor-class-identifier") {
+                return Err(serde::de::Error::custom(
+                    "dhcp-vendor-class-identifier is not allowed for IPv6",
+                ));
+            }
+            if v_map.contains_key("dad-timeout") {
+                return Err(serde::de::Error::custom(
+                    "dad-timeout is not allowed for IPv6",
+                ));
+            }
         }
         let ip: InterfaceIp = match serde_json::from_value(v) {
             Ok(i) => i,
@@ -818,6 +1060,14 @@ impl From<InterfaceIp> for InterfaceIpv6 {
             token: ip.token,
             dhcp_send_hostname: ip.dhcp_send_hostname,
             dhcp_custom_hostname: ip.dhcp_custom_hostname,
+            dad_transmits: ip.dad_transmits,
+            ip6_privacy: ip.ip6_privacy,
+            ra_timeout: ip.ra_timeout,
+            accept_ra_mtu: ip.accept_ra_mtu,
+            accept_ra: ip.accept_ra,
+            forwarding: ip.forwarding,
+            replace_local_rule: ip.replace_local_rule,
+            dns_priority: ip.dns_priority,
             ..Default::default()
         }
     }
@@ -846,6 +1096,14 @@ impl From<InterfaceIpv6> for InterfaceIp {
             token: ip.token,
             dhcp_send_hostname: ip.dhcp_send_hostname,
             dhcp_custom_hostname: ip.dhcp_custom_hostname,
+            dad_transmits: ip.dad_transmits,
+            ip6_privacy: ip.ip6_privacy,
+            ra_timeout: ip.ra_timeout,
+            accept_ra_mtu: ip.accept_ra_mtu,
+            accept_ra: ip.accept_ra,
+            forwarding: ip.forwarding,
+            replace_local_rule: ip.replace_local_rule,
+            dns_priority: ip.dns_priority,
             ..Default::default()
         }
     }
@@ -864,10 +1122,9 @@ pub struct InterfaceIpAddr {
     /// Serialize and deserialize to/from `prefix-length`.
     pub prefix_length: u8,
     #[serde(skip_serializing_if = "is_none_or_empty_mptcp_flags", default)]
-    /// MPTCP flag on this IP address.
-    /// Ignored when applying as nmstate does not support support IP address
-    /// specific MPTCP flags. You should apply MPTCP flags at interface level
-    /// via [BaseInterface.mptcp].
+    /// MPTCP flag on this IP address, overriding the interface level MPTCP
+    /// flags defined via [BaseInterface.mptcp].
+    /// Only supported by NetworkManager backend via dispatch script.
     pub mptcp_flags: Option<Vec<MptcpAddressFlag>>,
     /// Remaining time for IP address been valid. The output format is
     /// "32sec" or "forever".
@@ -928,6 +1185,13 @@ impl InterfaceIpAddr {
         self.valid_life_time.is_some()
             && self.valid_life_time.as_deref() != Some(FOREVER)
     }
+
+    fn sanitize_mptcp_flags(&mut self) {
+        if let Some(flags) = self.mptcp_flags.as_mut() {
+            flags.sort_unstable();
+            flags.dedup();
+        }
+    }
 }
 
 pub(crate) fn is_ipv6_addr(addr: &str) -> bool {
@@ -1064,6 +1328,15 @@ pub enum Ipv6AddrGenMode {
     /// Serialize and deserialize to/from `stable-privacy`.
     #[serde(rename = "stable-privacy", alias = "STABLE-PRIVACY")]
     StablePrivacy,
+    /// Use global default of backend, fallback to EUI-64 if not configured.
+    /// Serialize and deserialize to/from `default-or-eui64`.
+    #[serde(rename = "default-or-eui64")]
+    DefaultOrEui64,
+    /// Use global default of backend, fallback to stable privacy if not
+    /// configured.
+    /// Serialize and deserialize to/from `default`.
+    #[serde(rename = "default")]
+    Default,
     /// Backend specific
     #[serde(untagged)]
     Other(String),
@@ -1077,12 +1350,50 @@ impl std::fmt::Display for Ipv6AddrGenMode {
             match self {
                 Ipv6AddrGenMode::Eui64 => "eui64",
                 Ipv6AddrGenMode::StablePrivacy => "stable-privacy",
+                Ipv6AddrGenMode::DefaultOrEui64 => "default-or-eui64",
+                Ipv6AddrGenMode::Default => "default",
                 Ipv6AddrGenMode::Other(s) => s,
             }
         )
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Usage of IPv6 privacy extensions(temporary addresses) defined in RFC 4941
+pub enum Ipv6Privacy {
+    /// Use global default of backend.
+    /// Serialize and deserialize to/from `default`.
+    Default,
+    /// Do not generate temporary addresses.
+    /// Serialize and deserialize to/from `disabled`.
+    Disabled,
+    /// Generate temporary addresses, but prefer public address as source
+    /// address.
+    /// Serialize and deserialize to/from `prefer-public-addr`.
+    PreferPublicAddr,
+    /// Generate temporary addresses and prefer them over public address as
+    /// source address.
+    /// Serialize and deserialize to/from `prefer-temp-addr`.
+    PreferTempAddr,
+}
+
+impl std::fmt::Display for Ipv6Privacy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Default => "default",
+                Self::Disabled => "disabled",
+                Self::PreferPublicAddr => "prefer-public-addr",
+                Self::PreferTempAddr => "prefer-temp-addr",
+            }
+        )
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
 #[non_exhaustive]
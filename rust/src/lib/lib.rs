@@ -96,6 +96,7 @@ mod ifaces;
 mod ip;
 mod lldp;
 mod mptcp;
+mod neighbor;
 mod net_state;
 #[cfg(feature = "query_apply")]
 mod nispor;
@@ -107,6 +108,7 @@ mod ovs;
 mod ovsdb;
 #[cfg(feature = "query_apply")]
 mod policy;
+mod proxy;
 #[cfg(feature = "query_apply")]
 mod query_apply;
 #[cfg(feature = "gen_revert")]
@@ -117,18 +119,23 @@ mod serializer;
 mod state;
 #[cfg(feature = "query_apply")]
 mod statistic;
+mod topology;
 mod unit_tests;
+mod versioned;
 
 pub use crate::dispatch::DispatchConfig;
 pub(crate) use crate::dns::MergedDnsState;
 pub use crate::dns::{DnsClientState, DnsState};
 pub use crate::error::{ErrorKind, NmstateError};
+#[cfg(feature = "gen_conf")]
+pub use crate::gen_conf::{GenConfNmConnection, NmConnectionOperations};
 pub use crate::hostname::HostNameState;
 pub(crate) use crate::hostname::MergedHostNameState;
-pub use crate::ieee8021x::Ieee8021XConfig;
+pub use crate::ieee8021x::{Ieee8021XConfig, SecretStorageMode};
 pub(crate) use crate::iface::MergedInterface;
 pub use crate::iface::{
-    Interface, InterfaceIdentifier, InterfaceState, InterfaceType,
+    Interface, InterfaceBindBy, InterfaceIdentifier, InterfaceMultiConnect,
+    InterfaceNmFlag, InterfaceResolveMode, InterfaceState, InterfaceType,
     UnknownInterface,
 };
 pub(crate) use crate::ifaces::MergedInterfaces;
@@ -137,37 +144,44 @@ pub use crate::ifaces::{
     BondArpValidate, BondConfig, BondFailOverMac, BondInterface, BondLacpRate,
     BondMode, BondOptions, BondPortConfig, BondPrimaryReselect,
     BondXmitHashPolicy, BridgePortTrunkTag, BridgePortVlanConfig,
-    BridgePortVlanMode, BridgePortVlanRange, DummyInterface, EthernetConfig,
-    EthernetDuplex, EthernetInterface, EthtoolCoalesceConfig, EthtoolConfig,
-    EthtoolFeatureConfig, EthtoolPauseConfig, EthtoolRingConfig, HsrConfig,
-    HsrInterface, HsrProtocol, InfiniBandConfig, InfiniBandInterface,
-    InfiniBandMode, Interfaces, IpsecInterface, LibreswanAddressFamily,
-    LibreswanConfig, LibreswanConnectionType, LinuxBridgeConfig,
-    LinuxBridgeInterface, LinuxBridgeMulticastRouterType, LinuxBridgeOptions,
-    LinuxBridgePortConfig, LinuxBridgeStpOptions, LoopbackInterface,
-    MacSecConfig, MacSecInterface, MacSecOffload, MacSecValidate,
-    MacVlanConfig, MacVlanInterface, MacVlanMode, MacVtapConfig,
-    MacVtapInterface, MacVtapMode, OvsBridgeBondConfig, OvsBridgeBondMode,
-    OvsBridgeBondPortConfig, OvsBridgeConfig, OvsBridgeInterface,
-    OvsBridgeOptions, OvsBridgePortConfig, OvsBridgeStpOptions, OvsDpdkConfig,
-    OvsInterface, OvsPatchConfig, SrIovConfig, SrIovVfConfig, VethConfig,
+    BridgePortVlanMode, BridgePortVlanRange, DummyInterface, EthernetClonedMac,
+    EthernetConfig, EthernetDuplex, EthernetInterface, EthernetS390Config,
+    EthernetS390NetType, EthtoolChannelsConfig, EthtoolCoalesceConfig,
+    EthtoolConfig, EthtoolFeatureConfig, EthtoolPauseConfig, EthtoolRingConfig,
+    GenericInterface, HsrConfig, HsrInterface, HsrProtocol, InfiniBandConfig,
+    InfiniBandInterface, InfiniBandMode, Interfaces, IpsecInterface,
+    LibreswanAddressFamily, LibreswanConfig, LibreswanConnectionType,
+    LinuxBridgeConfig, LinuxBridgeInterface, LinuxBridgeMulticastRouterType,
+    LinuxBridgeOptions, LinuxBridgePortConfig, LinuxBridgeStpOptions,
+    LoopbackInterface, MacSecConfig, MacSecInterface, MacSecOffload,
+    MacSecValidate, MacVlanConfig, MacVlanInterface, MacVlanMode,
+    MacVtapConfig, MacVtapInterface, MacVtapMode, OvsBridgeBondConfig,
+    OvsBridgeBondMode, OvsBridgeBondPortConfig, OvsBridgeConfig,
+    OvsBridgeInterface, OvsBridgeOptions, OvsBridgePortConfig,
+    OvsBridgeStpOptions, OvsDpdkConfig, OvsInterface, OvsPatchConfig,
+    SrIovConfig, SrIovEswitchEncapMode, SrIovEswitchMode, SrIovVfConfig,
+    TeamConfig, TeamInterface, TeamPortConfig, TeamRunner, VethConfig,
     VlanConfig, VlanInterface, VlanProtocol, VlanRegistrationProtocol,
-    VrfConfig, VrfInterface, VxlanConfig, VxlanInterface, XfrmInterface,
+    VrfConfig, VrfInterface, VxlanConfig, VxlanInterface, WireGuardConfig,
+    WireGuardEndpointResolver, WireGuardInterface, WireGuardPeerConfig,
+    XfrmInterface,
 };
 pub use crate::ip::{
     AddressFamily, Dhcpv4ClientId, Dhcpv6Duid, InterfaceIpAddr, InterfaceIpv4,
-    InterfaceIpv6, Ipv6AddrGenMode, WaitIp,
+    InterfaceIpv6, Ipv6AddrGenMode, Ipv6Privacy, WaitIp,
 };
 pub use crate::lldp::{
     LldpAddressFamily, LldpChassisId, LldpChassisIdType, LldpConfig,
-    LldpMacPhy, LldpMaxFrameSize, LldpMgmtAddr, LldpMgmtAddrs, LldpNeighborTlv,
-    LldpPortId, LldpPortIdType, LldpPpvids, LldpSystemCapabilities,
-    LldpSystemCapability, LldpSystemDescription, LldpSystemName, LldpVlan,
-    LldpVlans,
+    LldpMacPhy, LldpMaxFrameSize, LldpMgmtAddr, LldpMgmtAddrs, LldpNeighbor,
+    LldpNeighborTlv, LldpPortId, LldpPortIdType, LldpPpvids,
+    LldpSystemCapabilities, LldpSystemCapability, LldpSystemDescription,
+    LldpSystemName, LldpVlan, LldpVlans,
 };
 pub use crate::mptcp::{MptcpAddressFlag, MptcpConfig};
+pub use crate::neighbor::StaticNeighborConfig;
 pub(crate) use crate::net_state::MergedNetworkState;
 pub use crate::net_state::NetworkState;
+pub use crate::nm::{Incompatibility, NmVersion};
 pub(crate) use crate::ovn::MergedOvnConfiguration;
 pub use crate::ovn::{
     OvnBridgeMapping, OvnBridgeMappingState, OvnConfiguration,
@@ -178,11 +192,13 @@ pub use crate::ovs::{OvsDbGlobalConfig, OvsDbIfaceConfig};
 pub use crate::policy::{
     NetworkCaptureRules, NetworkPolicy, NetworkStateTemplate,
 };
+pub use crate::proxy::{ProxyConfig, ProxyMethod};
 pub(crate) use crate::route::MergedRoutes;
-pub use crate::route::{RouteEntry, RouteState, RouteType, Routes};
+pub use crate::route::{RouteEntry, RouteScope, RouteState, RouteType, Routes};
 pub(crate) use crate::route_rule::MergedRouteRules;
 pub use crate::route_rule::{
     RouteRuleAction, RouteRuleEntry, RouteRuleState, RouteRules,
 };
 #[cfg(feature = "query_apply")]
 pub use crate::statistic::{NmstateFeature, NmstateStatistic};
+pub use crate::topology::{ControllerTopology, NetworkTopology};
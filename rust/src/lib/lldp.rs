@@ -2,6 +2,8 @@
 
 use serde::{de::IgnoredAny, Deserialize, Deserializer, Serialize};
 
+use crate::{ErrorKind, InterfaceType, MergedNetworkState, NmstateError};
+
 const LLDP_SYS_CAP_OTHER: u16 = 1;
 const LLDP_SYS_CAP_REPEATER: u16 = 2;
 const LLDP_SYS_CAP_MAC_BRIDGE: u16 = 3;
@@ -92,6 +94,97 @@ impl LldpConfig {
         // Remove since it is for query only
         self.neighbors = Vec::new();
     }
+
+    /// Typed representation of gathered LLDP neighbors.
+    pub fn get_neighbors(&self) -> Vec<LldpNeighbor> {
+        self.neighbors
+            .iter()
+            .map(|tlvs| LldpNeighbor::from(tlvs.as_slice()))
+            .collect()
+    }
+}
+
+impl MergedNetworkState {
+    // Gathering LLDP neighbors requires LLDP enabled on all desired ethernet
+    // interfaces.
+    pub(crate) fn validate_lldp_for_neighbor_gathering(
+        &self,
+    ) -> Result<(), NmstateError> {
+        if !self.gather_lldp_neighbors {
+            return Ok(());
+        }
+        for iface in self
+            .interfaces
+            .kernel_ifaces
+            .values()
+            .filter_map(|i| i.desired.as_ref())
+            .filter(|i| i.iface_type() == InterfaceType::Ethernet)
+        {
+            if iface.base_iface().lldp.as_ref().map(|l| l.enabled)
+                == Some(false)
+            {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Gathering LLDP neighbors requires LLDP enabled, \
+                        but interface {} has `lldp.enabled: false` desired",
+                        iface.name()
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+#[non_exhaustive]
+#[serde(rename_all = "kebab-case")]
+/// Summary of LLDP neighbor extracted from its TLVs.
+pub struct LldpNeighbor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub chassis_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub port_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub system_description: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub management_addresses: Vec<String>,
+}
+
+impl From<&[LldpNeighborTlv]> for LldpNeighbor {
+    fn from(tlvs: &[LldpNeighborTlv]) -> Self {
+        let mut ret = Self::default();
+        for tlv in tlvs {
+            match tlv {
+                LldpNeighborTlv::ChassisId(v) => {
+                    ret.chassis_id = Some(v.chassis_id.clone());
+                }
+                LldpNeighborTlv::PortId(v) => {
+                    ret.port_id = Some(v.port_id.clone());
+                }
+                LldpNeighborTlv::SystemName(v) => {
+                    ret.system_name = Some(v.system_name.clone());
+                }
+                LldpNeighborTlv::SystemDescription(v) => {
+                    ret.system_description = Some(v.system_description.clone());
+                }
+                LldpNeighborTlv::ManagementAddresses(v) => {
+                    ret.management_addresses.extend(
+                        v.management_addresses
+                            .iter()
+                            .map(|a| a.address.clone()),
+                    );
+                }
+                _ => (),
+            }
+        }
+        ret
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize)]
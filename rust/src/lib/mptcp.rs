@@ -5,7 +5,9 @@
 
 use serde::{Deserialize, Serialize};
 
-use crate::{BaseInterface, ErrorKind, MergedInterface, NmstateError};
+use crate::{
+    BaseInterface, ErrorKind, InterfaceIpAddr, MergedInterface, NmstateError,
+};
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Default)]
 #[serde(rename_all = "kebab-case", deny_unknown_fields)]
@@ -14,6 +16,7 @@ pub struct MptcpConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     /// Automatically assign MPTCP flags to all valid IP addresses of this
     /// interface including both static and dynamic ones.
+    /// The `mptcp-flags` of individual IP address overrides this.
     pub address_flags: Option<Vec<MptcpAddressFlag>>,
 }
 
@@ -60,64 +63,98 @@ impl MergedInterface {
             if let Some(iface_flags) =
                 iface.mptcp.as_ref().and_then(|m| m.address_flags.as_ref())
             {
-                if iface_flags.contains(&MptcpAddressFlag::Signal)
-                    && iface_flags.contains(&MptcpAddressFlag::Fullmesh)
-                {
-                    let e = NmstateError::new(
-                        ErrorKind::InvalidArgument,
-                        "MPTCP flags mustn't have both signal and fullmesh"
-                            .to_string(),
-                    );
-                    log::error!("{}", e);
-                    return Err(e);
+                validate_mptcp_flags(iface_flags, None)?;
+            }
+            for ip_addr in iface_ip_addrs(iface) {
+                if let Some(addr_flags) = ip_addr.mptcp_flags.as_ref() {
+                    validate_mptcp_flags(addr_flags, Some(ip_addr))?;
                 }
             }
-            validate_iface_mptcp_and_addr_mptcp_flags(iface);
         }
 
         Ok(())
     }
 }
 
-fn validate_iface_mptcp_and_addr_mptcp_flags(iface: &BaseInterface) {
-    let mut iface_flags =
-        match iface.mptcp.as_ref().and_then(|m| m.address_flags.as_ref()) {
-            Some(f) => f.clone(),
-            None => Vec::new(),
-        };
-    iface_flags.sort_unstable();
-
-    let empty_ip_addrs = Vec::new();
+fn validate_mptcp_flags(
+    flags: &[MptcpAddressFlag],
+    ip_addr: Option<&InterfaceIpAddr>,
+) -> Result<(), NmstateError> {
+    if flags.contains(&MptcpAddressFlag::Signal)
+        && flags.contains(&MptcpAddressFlag::Fullmesh)
+    {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            if let Some(ip_addr) = ip_addr {
+                format!(
+                    "MPTCP flags of IP address {}/{} mustn't have both \
+                    signal and fullmesh",
+                    ip_addr.ip, ip_addr.prefix_length
+                )
+            } else {
+                "MPTCP flags mustn't have both signal and fullmesh".to_string()
+            },
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
+}
 
-    for ip_addr in iface
+fn iface_ip_addrs(
+    iface: &BaseInterface,
+) -> impl Iterator<Item = &InterfaceIpAddr> {
+    iface
         .ipv4
         .as_ref()
-        .and_then(|i| i.addresses.as_ref())
-        .unwrap_or(&empty_ip_addrs)
+        .and_then(|i| i.addresses.as_deref())
+        .unwrap_or_default()
         .iter()
         .chain(
             iface
                 .ipv6
                 .as_ref()
-                .and_then(|i| i.addresses.as_ref())
-                .unwrap_or(&empty_ip_addrs)
+                .and_then(|i| i.addresses.as_deref())
+                .unwrap_or_default()
                 .iter(),
         )
-    {
-        if let Some(mut addr_flags) = ip_addr.mptcp_flags.as_ref().cloned() {
-            addr_flags.sort_unstable();
-            if iface_flags != addr_flags {
-                log::warn!(
-                    "Nmstate does not support setting different \
-                    MPTCP flags within the interface. Ignoring MPTCP \
-                    flags {:?} of IP address {}/{} as it is different \
-                    from interface level MPTCP flags {:?}",
-                    addr_flags,
-                    ip_addr.ip,
-                    ip_addr.prefix_length,
-                    iface_flags
-                );
+}
+
+// The IP addresses of specified interface holding MPTCP flags different from
+// interface level MPTCP flags `iface_flags`.
+pub(crate) fn get_mptcp_flags_overrides<'a>(
+    iface: &'a BaseInterface,
+    iface_flags: &[MptcpAddressFlag],
+) -> Vec<&'a InterfaceIpAddr> {
+    let mut iface_flags = iface_flags.to_vec();
+    iface_flags.sort_unstable();
+    iface_flags.dedup();
+
+    iface_ip_addrs(iface)
+        .filter(|ip_addr| {
+            if let Some(addr_flags) = ip_addr.mptcp_flags.as_ref() {
+                let mut addr_flags = addr_flags.clone();
+                addr_flags.sort_unstable();
+                addr_flags.dedup();
+                addr_flags != iface_flags
+            } else {
+                false
             }
-        }
+        })
+        .collect()
+}
+
+impl std::fmt::Display for MptcpAddressFlag {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Signal => "signal",
+                Self::Subflow => "subflow",
+                Self::Backup => "backup",
+                Self::Fullmesh => "fullmesh",
+            }
+        )
     }
 }
@@ -0,0 +1,93 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::IpAddr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ErrorKind, NmstateError};
+
+#[derive(
+    Debug,
+    Clone,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    Serialize,
+    Deserialize,
+)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+/// Static neighbor(ARP for IPv4, NDP for IPv6) entry of interface, only
+/// supported by NetworkManager backend via dispatch script.
+/// The example yaml output of [crate::NetworkState] with static neighbors:
+/// ```yml
+/// ---
+/// interfaces:
+///   - name: eth1
+///     type: ethernet
+///     state: up
+///     static-neighbors:
+///       - ip: 192.0.2.1
+///         mac-address: 00:23:45:67:89:1A
+///       - ip: 2001:db8::1
+///         mac-address: 00:23:45:67:89:1B
+/// ```
+pub struct StaticNeighborConfig {
+    /// IPv4 or IPv6 address of neighbor.
+    pub ip: String,
+    /// Link layer address of neighbor.
+    /// Serialize and deserialize to/from `mac-address`.
+    pub mac_address: String,
+}
+
+impl StaticNeighborConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // * Canonicalize IP address
+    // * Convert MAC address to upper case with colon as separator
+    pub(crate) fn sanitize(
+        &mut self,
+        iface_name: &str,
+    ) -> Result<(), NmstateError> {
+        match self.ip.parse::<IpAddr>() {
+            Ok(ip) => self.ip = ip.to_string(),
+            Err(e) => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid IP address {} of static neighbor on \
+                        interface {iface_name}: {e}",
+                        self.ip
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+        let mac = self.mac_address.replace('-', ":").to_ascii_uppercase();
+        let octets: Vec<&str> = mac.split(':').collect();
+        if octets.len() != 6
+            || !octets.iter().all(|o| {
+                o.len() == 2 && o.chars().all(|c| c.is_ascii_hexdigit())
+            })
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Invalid MAC address {} of static neighbor {} on \
+                    interface {iface_name}, expecting format like \
+                    00:23:45:67:89:1A",
+                    self.mac_address, self.ip
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        self.mac_address = mac;
+        Ok(())
+    }
+}
@@ -6,10 +6,11 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 
 use crate::{
-    DnsState, ErrorKind, HostNameState, Interface, Interfaces, MergedDnsState,
-    MergedHostNameState, MergedInterfaces, MergedOvnConfiguration,
-    MergedOvsDbGlobalConfig, MergedRouteRules, MergedRoutes, NmstateError,
-    OvnConfiguration, OvsDbGlobalConfig, RouteRules, Routes,
+    nm::NmVersion, DnsState, ErrorKind, HostNameState, Interface,
+    InterfaceResolveMode, Interfaces, MergedDnsState, MergedHostNameState,
+    MergedInterfaces, MergedOvnConfiguration, MergedOvsDbGlobalConfig,
+    MergedRouteRules, MergedRoutes, NmstateError, OvnConfiguration,
+    OvsDbGlobalConfig, RouteRules, Routes,
 };
 
 /// The [NetworkState] represents the whole network state including both
@@ -119,6 +120,28 @@ pub struct NetworkState {
     pub(crate) running_config_only: bool,
     #[serde(skip)]
     pub(crate) memory_only: bool,
+    #[serde(skip)]
+    pub(crate) controller_by_uuid: Option<bool>,
+    #[serde(skip)]
+    pub(crate) volatile_external: bool,
+    #[serde(skip)]
+    pub(crate) activation_order: Vec<String>,
+    #[serde(skip)]
+    pub(crate) purge_unmanaged: bool,
+    #[serde(skip)]
+    pub(crate) parent_by_uuid: bool,
+    #[serde(skip)]
+    pub(crate) maintenance: bool,
+    #[serde(skip)]
+    pub(crate) strict: bool,
+    #[serde(skip)]
+    pub(crate) keep_unmanaged_routes: bool,
+    #[serde(skip)]
+    pub(crate) gather_lldp_neighbors: bool,
+    #[serde(skip)]
+    pub(crate) default_mdns: Option<InterfaceResolveMode>,
+    #[serde(skip)]
+    pub(crate) default_llmnr: Option<InterfaceResolveMode>,
 }
 
 impl NetworkState {
@@ -203,6 +226,136 @@ impl NetworkState {
         self
     }
 
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// When set to false, NetworkManager profiles of kernel ports will
+    /// reference their controller by interface name instead of connection
+    /// UUID. Referencing by UUID keeps the port profile valid when
+    /// controller is renamed.
+    /// Default to true.
+    pub fn set_controller_by_uuid(&mut self, value: bool) -> &mut Self {
+        self.controller_by_uuid = Some(value);
+        self
+    }
+
+    /// Only available in gen_conf mode.
+    /// When set to true, NetworkManager profiles of VLAN and other child
+    /// interfaces will reference their parent by the stable UUID generated
+    /// from parent interface name and type, even when parent is not
+    /// mentioned in desired state. Referencing by UUID keeps the child
+    /// profile valid when parent is renamed.
+    /// Default to false.
+    pub fn set_parent_by_uuid(&mut self, value: bool) -> &mut Self {
+        self.parent_by_uuid = value;
+        self
+    }
+
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// When set to false, the NetworkManager profiles converted from external
+    /// managed interfaces will be marked as volatile which will be removed
+    /// once deactivated or after reboot.
+    /// Default to true for storing converted profiles persistently.
+    pub fn set_external_persistent(&mut self, value: bool) -> &mut Self {
+        self.volatile_external = !value;
+        self
+    }
+
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// Ordered list of interface names hinting the sequence of activating
+    /// NetworkManager profiles. The controller and parent dependencies are
+    /// still honored, so listed interface will only be activated before
+    /// others which are in the same dependency wave.
+    /// Interfaces not listed are activated after listed ones in default
+    /// order.
+    /// Default to empty.
+    pub fn set_activation_order(&mut self, iface_names: &[&str]) -> &mut Self {
+        self.activation_order =
+            iface_names.iter().map(|n| n.to_string()).collect();
+        self
+    }
+
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// When set to true, NetworkManager profiles stored by nmstate are marked
+    /// as owned by nmstate, and previously owned profiles of interfaces not
+    /// mentioned in desired state will be deleted. Profiles not created in
+    /// this mode are never touched.
+    /// Default to false.
+    pub fn set_purge_unmanaged(&mut self, value: bool) -> &mut Self {
+        self.purge_unmanaged = value;
+        self
+    }
+
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// When set to true, NetworkManager profiles are stored with autoconnect
+    /// disabled and not activated, so devices stay untouched till user
+    /// activate them after maintenance. Verification is skipped as desired
+    /// state is not applied to devices.
+    /// Default to false.
+    pub fn set_maintenance(&mut self, value: bool) -> &mut Self {
+        self.maintenance = value;
+        self
+    }
+
+    /// When set to true, interface of unsupported type or desired property
+    /// ignored by nmstate is treated as error instead of being silently
+    /// ignored when generating NetworkManager profiles. Useful for
+    /// validating network state in CI.
+    /// Default to false.
+    pub fn set_strict(&mut self, value: bool) -> &mut Self {
+        self.strict = value;
+        self
+    }
+
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// When set to true, static routes stored in existing NetworkManager
+    /// profile but not found in desired or current state are preserved
+    /// instead of being replaced. For route sharing the same destination and
+    /// route table with route defined by nmstate, nmstate one wins.
+    /// Routes marked as absent in desired state are still removed.
+    /// Default to false.
+    pub fn set_keep_unmanaged_routes(&mut self, value: bool) -> &mut Self {
+        self.keep_unmanaged_routes = value;
+        self
+    }
+
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// When set to true, LLDP receiving is enabled in NetworkManager
+    /// profiles of all desired ethernet interfaces, so their neighbors could
+    /// be queried via [crate::LldpConfig::get_neighbors()] afterwards.
+    /// Desired ethernet interface with `lldp.enabled: false` is treated as
+    /// invalid argument.
+    /// Default to false.
+    pub fn set_gather_lldp_neighbors(&mut self, value: bool) -> &mut Self {
+        self.gather_lldp_neighbors = value;
+        self
+    }
+
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// The multicast DNS(mDNS) mode used for NetworkManager profiles of
+    /// desired interfaces which have no `mdns` property defined.
+    /// Default to not set, which means using the global default of
+    /// NetworkManager.
+    pub fn set_default_mdns(
+        &mut self,
+        value: InterfaceResolveMode,
+    ) -> &mut Self {
+        self.default_mdns = Some(value);
+        self
+    }
+
+    /// Only available when [NetworkState::set_kernel_only()] set to false.
+    /// The Link-Local Multicast Name Resolution(LLMNR) mode used for
+    /// NetworkManager profiles of desired interfaces which have no `llmnr`
+    /// property defined.
+    /// Default to not set, which means using the global default of
+    /// NetworkManager.
+    pub fn set_default_llmnr(
+        &mut self,
+        value: InterfaceResolveMode,
+    ) -> &mut Self {
+        self.default_llmnr = Some(value);
+        self
+    }
+
     /// Create empty [NetworkState]
     pub fn new() -> Self {
         Default::default()
@@ -251,6 +404,16 @@ impl NetworkState {
         self.interfaces.hide_secrets();
     }
 
+    /// Remove interfaces holding nothing but name, type and `state: up`,
+    /// for example those assembled programmatically without any
+    /// configuration, so no empty profile is generated for them.
+    /// Interfaces desired as down, absent or ignore are kept.
+    /// Please be aware pruned virtual interface will not be created.
+    pub fn prune_empty(&mut self) -> &mut Self {
+        self.interfaces.prune_empty();
+        self
+    }
+
     #[cfg(not(feature = "query_apply"))]
     pub fn apply(&mut self) -> Result<(), NmstateError> {
         Err(NmstateError::new(
@@ -300,6 +463,20 @@ pub(crate) struct MergedNetworkState {
     pub(crate) routes: MergedRoutes,
     pub(crate) rules: MergedRouteRules,
     pub(crate) memory_only: bool,
+    pub(crate) controller_by_uuid: bool,
+    pub(crate) volatile_external: bool,
+    pub(crate) activation_order: Vec<String>,
+    pub(crate) purge_unmanaged: bool,
+    pub(crate) parent_by_uuid: bool,
+    pub(crate) maintenance: bool,
+    pub(crate) strict: bool,
+    pub(crate) keep_unmanaged_routes: bool,
+    pub(crate) gather_lldp_neighbors: bool,
+    pub(crate) default_mdns: Option<InterfaceResolveMode>,
+    pub(crate) default_llmnr: Option<InterfaceResolveMode>,
+    // Version of NetworkManager daemon the state is applied to, `None` for
+    // kernel mode and gen_conf mode.
+    pub(crate) nm_version: Option<NmVersion>,
 }
 
 impl MergedNetworkState {
@@ -309,6 +486,20 @@ impl MergedNetworkState {
         gen_conf_mode: bool,
         memory_only: bool,
     ) -> Result<Self, NmstateError> {
+        let controller_by_uuid = desired.controller_by_uuid.unwrap_or(true);
+        let volatile_external = desired.volatile_external;
+        let activation_order = desired.activation_order.clone();
+        let purge_unmanaged = desired.purge_unmanaged;
+        let parent_by_uuid = desired.parent_by_uuid;
+        let maintenance = desired.maintenance;
+        let strict = desired.strict;
+        let keep_unmanaged_routes = desired.keep_unmanaged_routes;
+        let gather_lldp_neighbors = desired.gather_lldp_neighbors;
+        let default_mdns = desired.default_mdns;
+        let default_llmnr = desired.default_llmnr;
+        if strict {
+            desired.interfaces.validate_strict()?;
+        }
         let interfaces = MergedInterfaces::new(
             desired.interfaces,
             current.interfaces,
@@ -347,8 +538,21 @@ impl MergedNetworkState {
             ovsdb,
             hostname,
             memory_only,
+            controller_by_uuid,
+            volatile_external,
+            activation_order,
+            purge_unmanaged,
+            parent_by_uuid,
+            maintenance,
+            strict,
+            keep_unmanaged_routes,
+            gather_lldp_neighbors,
+            default_mdns,
+            default_llmnr,
+            nm_version: None,
         };
         ret.validate_ipv6_link_local_address_dns_srv()?;
+        ret.validate_lldp_for_neighbor_gathering()?;
 
         Ok(ret)
     }
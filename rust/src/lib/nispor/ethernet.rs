@@ -39,7 +39,7 @@ fn gen_eth_conf(np_iface: &nispor::Iface) -> EthernetConfig {
     eth_conf
 }
 
-fn gen_sriov_conf(sriov_info: &nispor::SriovInfo) -> SrIovConfig {
+pub(super) fn gen_sriov_conf(sriov_info: &nispor::SriovInfo) -> SrIovConfig {
     let mut ret = SrIovConfig::new();
     let mut vfs: Vec<SrIovVfConfig> = Vec::new();
     for vf_info in &sriov_info.vfs {
@@ -1,3 +1,4 @@
+use super::ethernet::gen_sriov_conf;
 use crate::{
     BaseInterface, InfiniBandConfig, InfiniBandInterface, InfiniBandMode,
 };
@@ -23,6 +24,7 @@ pub(crate) fn np_ib_to_nmstate(
         mode: np_ib_info.mode.into(),
         base_iface: np_ib_info.base_iface.clone(),
         pkey: Some(np_ib_info.pkey),
+        sr_iov: np_iface.sriov.as_ref().map(gen_sriov_conf),
     });
 
     InfiniBandInterface {
@@ -140,6 +140,7 @@ fn np_bridge_options_to_nmstate(
                 }
             });
         options.vlan_default_pvid = np_bridge.default_pvid;
+        options.vlan_stats_enabled = np_bridge.vlan_stats_enabled;
     }
     Ok(options)
 }
@@ -69,6 +69,8 @@ pub(crate) fn np_mac_vtap_to_nmstate(
                     np_vtap_info.flags & MACVTAP_FLAG_NOPROMISC == 0,
                 ),
                 base_iface: np_vtap_info.base_iface.clone(),
+                vnet_hdr: None,
+                multi_queue: None,
             });
 
     MacVtapInterface {
@@ -1,9 +1,13 @@
 use log::warn;
 
-use crate::{RouteEntry, RouteType, Routes};
+use crate::{RouteEntry, RouteScope, RouteType, Routes};
 
-const SUPPORTED_ROUTE_SCOPE: [nispor::RouteScope; 2] =
-    [nispor::RouteScope::Universe, nispor::RouteScope::Link];
+const SUPPORTED_ROUTE_SCOPE: [nispor::RouteScope; 4] = [
+    nispor::RouteScope::Universe,
+    nispor::RouteScope::Site,
+    nispor::RouteScope::Link,
+    nispor::RouteScope::Host,
+];
 
 const SUPPORTED_ROUTE_PROTOCOL: [nispor::RouteProtocol; 7] = [
     nispor::RouteProtocol::Boot,
@@ -186,8 +190,32 @@ fn np_route_to_nmstate(np_route: &nispor::Route) -> RouteEntry {
     route_entry.destination = destination;
     route_entry.next_hop_iface = np_route.oif.as_ref().cloned();
     route_entry.next_hop_addr = next_hop_addr;
+    route_entry.source = np_route.prefered_src.as_ref().cloned();
     route_entry.metric = np_route.metric.map(i64::from);
     route_entry.table_id = Some(np_route.table);
+    route_entry.route_type = match np_route.route_type {
+        nispor::RouteType::Local => Some(RouteType::Local),
+        nispor::RouteType::Broadcast => Some(RouteType::Broadcast),
+        nispor::RouteType::Anycast => Some(RouteType::Anycast),
+        nispor::RouteType::Multicast => Some(RouteType::Multicast),
+        _ => None,
+    };
+    if route_entry.route_type.is_some() {
+        route_entry.next_hop_addr = None;
+    }
+    let scope = match np_route.scope {
+        nispor::RouteScope::Universe => Some(RouteScope::Universe),
+        nispor::RouteScope::Site => Some(RouteScope::Site),
+        nispor::RouteScope::Link => Some(RouteScope::Link),
+        nispor::RouteScope::Host => Some(RouteScope::Host),
+        _ => None,
+    };
+    // Only show scope when it is not the default one
+    if np_route.address_family == nispor::AddressFamily::IPv4
+        && scope != Some(route_entry.default_scope())
+    {
+        route_entry.scope = scope;
+    }
 
     route_entry
 }
@@ -22,7 +22,8 @@ use crate::{
         vxlan::np_vxlan_to_nmstate,
     },
     DummyInterface, Interface, InterfaceType, Interfaces, LoopbackInterface,
-    NetworkState, NmstateError, OvsInterface, UnknownInterface, XfrmInterface,
+    NetworkState, NmstateError, OvsInterface, TeamConfig, TeamInterface,
+    TeamPortConfig, UnknownInterface, WireGuardInterface, XfrmInterface,
 };
 
 pub(crate) fn nispor_retrieve(
@@ -138,6 +139,16 @@ pub(crate) fn nispor_retrieve(
                 iface.base = base_iface;
                 Interface::Xfrm(iface)
             }
+            InterfaceType::Team => {
+                let mut iface = TeamInterface::new();
+                iface.base = base_iface;
+                Interface::Team(iface)
+            }
+            InterfaceType::WireGuard => {
+                let mut iface = WireGuardInterface::new();
+                iface.base = base_iface;
+                Interface::WireGuard(iface)
+            }
             _ => {
                 log::info!(
                     "Got unsupported interface {} type {:?}",
@@ -154,6 +165,7 @@ pub(crate) fn nispor_retrieve(
         net_state.append_interface_data(iface);
     }
     set_controller_type(&mut net_state.interfaces);
+    set_team_ports(&mut net_state.interfaces);
     net_state.routes = get_routes(running_config_only);
     net_state.rules = get_route_rules(&np_state.rules, running_config_only);
 
@@ -177,3 +189,32 @@ fn set_controller_type(ifaces: &mut Interfaces) {
         }
     }
 }
+
+// Nispor does not provide team information, hence we collect team ports from
+// the controller property of kernel interfaces.
+fn set_team_ports(ifaces: &mut Interfaces) {
+    let mut team_to_ports: HashMap<String, Vec<TeamPortConfig>> =
+        HashMap::new();
+    for iface in ifaces.kernel_ifaces.values() {
+        if iface.base_iface().controller_type == Some(InterfaceType::Team) {
+            if let Some(ctrl) = iface.base_iface().controller.as_ref() {
+                team_to_ports.entry(ctrl.to_string()).or_default().push(
+                    TeamPortConfig {
+                        name: iface.name().to_string(),
+                        ..Default::default()
+                    },
+                );
+            }
+        }
+    }
+    for iface in ifaces.kernel_ifaces.values_mut() {
+        if let Interface::Team(team_iface) = iface {
+            let mut ports = team_to_ports
+                .remove(team_iface.base.name.as_str())
+                .unwrap_or_default();
+            ports.sort_unstable_by(|a, b| a.name.cmp(&b.name));
+            team_iface.team.get_or_insert_with(TeamConfig::new).port =
+                Some(ports);
+        }
+    }
+}
@@ -15,6 +15,8 @@ pub(crate) fn np_vxlan_to_nmstate(
         local: std::net::IpAddr::from_str(np_vxlan_info.local.as_str()).ok(),
         remote: std::net::IpAddr::from_str(np_vxlan_info.remote.as_str()).ok(),
         dst_port: Some(np_vxlan_info.dst_port),
+        gbp: Some(np_vxlan_info.gbp),
+        gpe: Some(np_vxlan_info.gpe),
     });
 
     VxlanInterface {
@@ -0,0 +1,107 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::NmVersion;
+use crate::{Interface, InterfaceType, NetworkState};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+/// Property or interface type of [NetworkState] not supported by specified
+/// NetworkManager version.
+pub struct Incompatibility {
+    /// Name of the interface holding the incompatible configuration.
+    pub iface_name: String,
+    /// The incompatible property, e.g. `ipv4.replace-local-rule` or
+    /// `type: wireguard`.
+    pub property: String,
+    /// Minimum NetworkManager version supporting this property.
+    pub required_version: NmVersion,
+}
+
+impl std::fmt::Display for Incompatibility {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} of interface {} requires NetworkManager {} or newer",
+            self.property, self.iface_name, self.required_version
+        )
+    }
+}
+
+impl NetworkState {
+    /// Check whether this network state could be applied to specified
+    /// NetworkManager version. Return the list of interface types and
+    /// properties not supported by that version, empty if compatible.
+    /// Absent interfaces are not checked.
+    pub fn check_nm_compat(&self, version: NmVersion) -> Vec<Incompatibility> {
+        let mut ret = Vec::new();
+        for iface in self.interfaces.to_vec().iter().filter(|i| !i.is_absent())
+        {
+            for (property, required_version) in iface_nm_requirements(iface) {
+                if version < required_version {
+                    ret.push(Incompatibility {
+                        iface_name: iface.name().to_string(),
+                        property,
+                        required_version,
+                    });
+                }
+            }
+        }
+        ret
+    }
+}
+
+// The minimum NetworkManager version required by each property used by
+// specified interface. Properties like `ipv6.addr-gen-mode: default`,
+// `replace-local-rule` and `autoconnect-ports` are not included as nmstate
+// falls back or ignores them with warning on older NetworkManager.
+fn iface_nm_requirements(iface: &Interface) -> Vec<(String, NmVersion)> {
+    let mut ret = Vec::new();
+    let base_iface = iface.base_iface();
+    match iface.iface_type() {
+        InterfaceType::WireGuard => {
+            ret.push(("type: wireguard".to_string(), NmVersion::WIREGUARD))
+        }
+        InterfaceType::Loopback => {
+            ret.push(("type: loopback".to_string(), NmVersion::LOOPBACK))
+        }
+        _ => (),
+    }
+    if base_iface.ip_ping_addresses.is_some() {
+        ret.push(("ip-ping-addresses".to_string(), NmVersion::IP_PING));
+    }
+    if base_iface.ip_ping_timeout.is_some() {
+        ret.push(("ip-ping-timeout".to_string(), NmVersion::IP_PING));
+    }
+    if base_iface
+        .ethtool
+        .as_ref()
+        .and_then(|e| e.gso_max_size)
+        .is_some()
+    {
+        ret.push(("ethtool.gso-max-size".to_string(), NmVersion::LINK));
+    }
+    let sriov_conf = match iface {
+        Interface::Ethernet(iface) => {
+            iface.ethernet.as_ref().and_then(|c| c.sr_iov.as_ref())
+        }
+        Interface::InfiniBand(iface) => {
+            iface.ib.as_ref().and_then(|c| c.sr_iov.as_ref())
+        }
+        _ => None,
+    };
+    if let Some(sriov_conf) = sriov_conf {
+        if sriov_conf.eswitch_mode.is_some() {
+            ret.push((
+                "sr-iov.eswitch-mode".to_string(),
+                NmVersion::SRIOV_ESWITCH,
+            ));
+        }
+        if sriov_conf.eswitch_encap_mode.is_some() {
+            ret.push((
+                "sr-iov.eswitch-encap-mode".to_string(),
+                NmVersion::SRIOV_ESWITCH,
+            ));
+        }
+    }
+    ret
+}
@@ -0,0 +1,215 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use super::nm_dbus::{NmConnection, NmError, ToDbusValue};
+
+use crate::NetworkState;
+
+// Setting sections NetworkManager can apply to activated device via
+// `Reapply()` without deactivating it first.
+// Changes to any other setting section require reactivation.
+pub(crate) const NM_SETTINGS_REAPPLY_SUPPORTED: [&str; 10] = [
+    "connection",
+    "ipv4",
+    "ipv6",
+    "ethtool",
+    "sriov",
+    "user",
+    "bridge",
+    "bridge-port",
+    "ovs-external-ids",
+    "ovs-other-config",
+];
+
+// Secret keys are compared by presence only, their values are hidden from
+// the generated field changes.
+const NM_SETTINGS_SECRET_KEYS: [(&str, &str); 3] = [
+    ("802-1x", "password"),
+    ("802-1x", "private-key-password"),
+    ("802-1x", "phase2-private-key-password"),
+];
+
+macro_rules! _patch_nm_settings {
+    ($delta: ident, $cur: ident, $new: ident,
+     $($member: ident => $name: expr),+ $(,)?) => {
+        $(
+            if $cur.$member != $new.$member {
+                if $new.$member.is_some() {
+                    $delta.patch.$member = $new.$member.clone();
+                    $delta.changed.push($name);
+                } else {
+                    $delta.removed.push($name);
+                }
+            }
+        )+
+    };
+}
+
+#[derive(Debug, Clone, PartialEq, Default)]
+pub(crate) struct NmConnectionDelta {
+    // Only changed setting sections are included, with the exception of
+    // `connection` section which is always included for identifying the
+    // profile.
+    pub(crate) patch: NmConnection,
+    // Setting names which been added or modified
+    pub(crate) changed: Vec<&'static str>,
+    // Setting names which been removed
+    pub(crate) removed: Vec<&'static str>,
+}
+
+impl NmConnectionDelta {
+    pub(crate) fn new(cur: &NmConnection, new: &NmConnection) -> Self {
+        let mut delta = Self::default();
+        delta.patch.connection = new.connection.clone();
+        delta.patch.obj_path = cur.obj_path.clone();
+
+        _patch_nm_settings!(delta, cur, new,
+            connection => "connection",
+            bond => "bond",
+            bond_port => "bond-port",
+            bridge => "bridge",
+            bridge_port => "bridge-port",
+            ipv4 => "ipv4",
+            ipv6 => "ipv6",
+            ovs_bridge => "ovs-bridge",
+            ovs_port => "ovs-port",
+            ovs_iface => "ovs-interface",
+            ovs_ext_ids => "ovs-external-ids",
+            ovs_other_config => "ovs-other-config",
+            ovs_patch => "ovs-patch",
+            ovs_dpdk => "ovs-dpdk",
+            wired => "802-3-ethernet",
+            vlan => "vlan",
+            vxlan => "vxlan",
+            mac_vlan => "macvlan",
+            sriov => "sriov",
+            vrf => "vrf",
+            veth => "veth",
+            ieee8021x => "802-1x",
+            user => "user",
+            ethtool => "ethtool",
+            link => "link",
+            infiniband => "infiniband",
+            loopback => "loopback",
+            macsec => "macsec",
+            hsr => "hsr",
+            team => "team",
+            team_port => "team-port",
+            vpn => "vpn",
+            proxy => "proxy",
+            wireguard => "wireguard",
+            matching => "match",
+        );
+        delta
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.changed.is_empty() && self.removed.is_empty()
+    }
+
+    // Whether the delta could be applied via `Reapply()` or not.
+    // NetworkManager cannot reapply changes to MPTCP flags.
+    pub(crate) fn need_reactivation(&self, cur: &NmConnection) -> bool {
+        self.removed
+            .iter()
+            .chain(self.changed.iter())
+            .any(|name| !NM_SETTINGS_REAPPLY_SUPPORTED.contains(name))
+            || self.patch.connection.as_ref().and_then(|c| c.mptcp_flags)
+                != cur.connection.as_ref().and_then(|c| c.mptcp_flags)
+    }
+}
+
+macro_rules! _diff_nm_settings {
+    ($changes: ident, $cur: ident, $new: ident,
+     $($member: ident => $name: expr),+ $(,)?) => {
+        $(
+            if $cur.$member != $new.$member {
+                diff_nm_setting(
+                    &mut $changes,
+                    $name,
+                    $cur.$member.as_ref().map(|s| s.to_value()).transpose()?,
+                    $new.$member.as_ref().map(|s| s.to_value()).transpose()?,
+                );
+            }
+        )+
+    };
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) struct SettingFieldChange {
+    // Setting name, for example `802-3-ethernet`
+    pub(crate) setting: &'static str,
+    // Property key, for example `mtu`
+    pub(crate) key: String,
+    // None means property been added
+    pub(crate) old_value: Option<zvariant::OwnedValue>,
+    // None means property been removed
+    pub(crate) new_value: Option<zvariant::OwnedValue>,
+}
+
+impl NmConnection {
+    // Field by field changes required to turn self into `other`, sorted by
+    // setting name and property key.
+    pub(crate) fn diff(
+        &self,
+        other: &NmConnection,
+    ) -> Result<Vec<SettingFieldChange>, NmError> {
+        let mut changes = Vec::new();
+        _diff_nm_settings!(changes, self, other,
+            connection => "connection",
+            ipv4 => "ipv4",
+            ipv6 => "ipv6",
+            wired => "802-3-ethernet",
+            vlan => "vlan",
+            bond => "bond",
+            bond_port => "bond-port",
+            bridge => "bridge",
+            bridge_port => "bridge-port",
+            ieee8021x => "802-1x",
+        );
+        changes.sort_unstable_by(|a, b| {
+            (a.setting, a.key.as_str()).cmp(&(b.setting, b.key.as_str()))
+        });
+        Ok(changes)
+    }
+}
+
+fn diff_nm_setting(
+    changes: &mut Vec<SettingFieldChange>,
+    setting: &'static str,
+    cur: Option<HashMap<&str, zvariant::Value>>,
+    new: Option<HashMap<&str, zvariant::Value>>,
+) {
+    let mut cur = cur.unwrap_or_default();
+    let mut new = new.unwrap_or_default();
+    let mut keys: Vec<&str> = cur.keys().chain(new.keys()).copied().collect();
+    keys.sort_unstable();
+    keys.dedup();
+    for key in keys {
+        let old_value = cur.remove(key);
+        let new_value = new.remove(key);
+        if NM_SETTINGS_SECRET_KEYS.contains(&(setting, key)) {
+            if old_value.is_some() != new_value.is_some() {
+                let hide = |_| {
+                    zvariant::OwnedValue::from(zvariant::Value::new(
+                        NetworkState::PASSWORD_HID_BY_NMSTATE,
+                    ))
+                };
+                changes.push(SettingFieldChange {
+                    setting,
+                    key: key.to_string(),
+                    old_value: old_value.map(hide),
+                    new_value: new_value.map(hide),
+                });
+            }
+        } else if old_value != new_value {
+            changes.push(SettingFieldChange {
+                setting,
+                key: key.to_string(),
+                old_value: old_value.map(zvariant::OwnedValue::from),
+                new_value: new_value.map(zvariant::OwnedValue::from),
+            });
+        }
+    }
+}
@@ -15,6 +15,38 @@ use super::nm_dbus::{
 };
 
 const DEFAULT_DNS_PRIORITY: i32 = 40;
+const DNS_PRIORITY_STEP: i32 = 10;
+
+// Consecutive name servers of the same IP family in desired order. Each group
+// is stored into a single interface with its own DNS priority, so that the
+// overall order of name servers is preserved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct NmDnsSrvGroup {
+    is_ipv6: bool,
+    servers: Vec<String>,
+    priority: i32,
+}
+
+impl NmDnsSrvGroup {
+    fn from_servers(srvs: &[String]) -> Vec<Self> {
+        let mut ret: Vec<Self> = Vec::new();
+        for srv in srvs {
+            let is_ipv6 = is_ipv6_addr(srv);
+            match ret.last_mut() {
+                Some(group) if group.is_ipv6 == is_ipv6 => {
+                    group.servers.push(srv.to_string());
+                }
+                _ => ret.push(Self {
+                    is_ipv6,
+                    servers: vec![srv.to_string()],
+                    priority: DEFAULT_DNS_PRIORITY
+                        + DNS_PRIORITY_STEP * ret.len() as i32,
+                }),
+            }
+        }
+        ret
+    }
+}
 
 pub(crate) fn store_dns_config_to_iface(
     merged_state: &mut MergedNetworkState,
@@ -24,17 +56,6 @@ pub(crate) fn store_dns_config_to_iface(
     if merged_state.dns.is_changed()
         || !cur_dns_ifaces_still_valid_for_dns(&merged_state.interfaces)
     {
-        let srvs = merged_state.dns.servers.as_slice();
-
-        if srvs.len() > 2 && is_mixed_dns_servers(srvs) {
-            return Err(NmstateError::new(
-                ErrorKind::NotImplementedError,
-                "Placing IPv4/IPv6 nameserver in the middle of IPv6/IPv4 \
-                nameservers is not supported yet"
-                    .to_string(),
-            ));
-        }
-
         let (cur_v4_ifaces, cur_v6_ifaces) =
             get_cur_dns_ifaces(&merged_state.interfaces);
         log::debug!(
@@ -55,7 +76,15 @@ pub(crate) fn store_dns_config_to_iface(
 
         purge_dns_config(false, cur_v4_ifaces.as_slice(), merged_state)?;
         purge_dns_config(true, cur_v6_ifaces.as_slice(), merged_state)?;
-        save_dns_to_iface(&v4_iface_name, &v6_iface_name, merged_state)?;
+        save_dns_to_iface(
+            &v4_iface_name,
+            &v6_iface_name,
+            cur_v4_ifaces.as_slice(),
+            cur_v6_ifaces.as_slice(),
+            merged_state,
+            nm_acs,
+            nm_devs,
+        )?;
     }
     Ok(())
 }
@@ -80,6 +109,7 @@ pub(crate) fn reselect_dns_ifaces(
         false,
         &merged_state.interfaces,
         cur_v4_ifaces,
+        &[],
         nm_acs,
         nm_devs,
     )
@@ -93,6 +123,7 @@ pub(crate) fn reselect_dns_ifaces(
             true,
             &merged_state.interfaces,
             cur_v6_ifaces,
+            &[],
             nm_acs,
             nm_devs,
         )
@@ -108,15 +139,17 @@ pub(crate) fn reselect_dns_ifaces(
 //  * Use desire interface if it is valid for DNS interface.
 //  * Use current interface if it is valid for DNS interface. Skip if NM marked
 //    it as unmanaged or external managed.
+// Interfaces listed in `excludes` are never chosen.
 fn find_dns_iface(
     is_ipv6: bool,
     merged_ifaces: &MergedInterfaces,
     cur_dns_ifaces: &[String],
+    excludes: &[String],
     nm_acs: &[NmActiveConnection],
     nm_devs: &[NmDevice],
 ) -> Option<String> {
     // Try using current DNS interface if in desired list
-    for iface_name in cur_dns_ifaces {
+    for iface_name in cur_dns_ifaces.iter().filter(|n| !excludes.contains(n)) {
         if let Some(iface) = merged_ifaces.kernel_ifaces.get(iface_name) {
             if iface.is_changed() && iface.is_iface_valid_for_dns(is_ipv6) {
                 return Some(iface_name.to_string());
@@ -132,7 +165,10 @@ fn find_dns_iface(
             .as_slice()
             .iter()
             .filter_map(|(n, t)| {
-                if !t.is_userspace() && t != &InterfaceType::Loopback {
+                if !t.is_userspace()
+                    && t != &InterfaceType::Loopback
+                    && !excludes.contains(n)
+                {
                     Some(n)
                 } else {
                     None
@@ -157,7 +193,10 @@ fn find_dns_iface(
             .as_slice()
             .iter()
             .filter_map(|(n, t)| {
-                if !t.is_userspace() && t != &InterfaceType::Loopback {
+                if !t.is_userspace()
+                    && t != &InterfaceType::Loopback
+                    && !excludes.contains(n)
+                {
                     Some(n)
                 } else {
                     None
@@ -180,6 +219,7 @@ fn find_dns_iface(
         .filter_map(|i| {
             if !i.is_changed()
                 && i.merged.iface_type() != InterfaceType::Loopback
+                && !excludes.iter().any(|n| n == i.merged.name())
             {
                 Some(i.merged.name())
             } else {
@@ -264,48 +304,80 @@ pub(crate) fn purge_dns_config(
     Ok(())
 }
 
+// The first group of name servers is stored into `v4_iface_name` or
+// `v6_iface_name` along with the searches and options. When IPv4 and IPv6 name
+// servers are interleaved, the following groups of the same IP family are
+// stored into other interfaces valid for DNS with increasing DNS priority.
 fn save_dns_to_iface(
     v4_iface_name: &str,
     v6_iface_name: &str,
+    cur_v4_ifaces: &[String],
+    cur_v6_ifaces: &[String],
     merged_state: &mut MergedNetworkState,
+    nm_acs: &[NmActiveConnection],
+    nm_devs: &[NmDevice],
 ) -> Result<(), NmstateError> {
-    let mut v4_servers = Vec::new();
-    let mut v6_servers = Vec::new();
-    let prefer_ipv6_srv = merged_state
-        .dns
-        .servers
-        .first()
-        .map(|s| is_ipv6_addr(s.as_str()))
-        .unwrap_or_default();
-    for srv in merged_state.dns.servers.as_slice() {
-        if is_ipv6_addr(srv) {
-            v6_servers.push(srv.to_string())
+    let mut used_v4_ifaces: Vec<String> = Vec::new();
+    let mut used_v6_ifaces: Vec<String> = Vec::new();
+    let groups =
+        NmDnsSrvGroup::from_servers(merged_state.dns.servers.as_slice());
+    for (index, group) in groups.into_iter().enumerate() {
+        let (iface_name, cur_dns_ifaces, used_ifaces) = if group.is_ipv6 {
+            (v6_iface_name, cur_v6_ifaces, &mut used_v6_ifaces)
         } else {
-            v4_servers.push(srv.to_string())
-        }
-    }
-    if !v6_servers.is_empty() {
-        _save_dns_to_iface(
-            true,
-            v6_iface_name,
-            v6_servers,
-            merged_state,
-            prefer_ipv6_srv,
-        )?;
-    }
-    if !v4_servers.is_empty() {
+            (v4_iface_name, cur_v4_ifaces, &mut used_v4_ifaces)
+        };
+        let iface_name = if used_ifaces.is_empty() {
+            iface_name.to_string()
+        } else {
+            if let Some(srv) = group.servers.iter().find(|s| s.contains('%')) {
+                return Err(NmstateError::new(
+                    ErrorKind::NotImplementedError,
+                    format!(
+                        "IPv6 link local DNS name server {srv} should be \
+                        placed in the first group of IPv6 name servers \
+                        when IPv4 and IPv6 name servers are interleaved"
+                    ),
+                ));
+            }
+            match find_dns_iface(
+                group.is_ipv6,
+                &merged_state.interfaces,
+                cur_dns_ifaces,
+                used_ifaces.as_slice(),
+                nm_acs,
+                nm_devs,
+            ) {
+                Some(n) => n,
+                None => {
+                    return Err(NmstateError::new(
+                        ErrorKind::NotImplementedError,
+                        format!(
+                            "Placing IPv4/IPv6 name server in the middle of \
+                            IPv6/IPv4 name servers requires another \
+                            interface valid for DNS to hold name servers \
+                            {:?}, but none found",
+                            group.servers
+                        ),
+                    ));
+                }
+            }
+        };
         _save_dns_to_iface(
-            false,
-            v4_iface_name,
-            v4_servers,
+            group.is_ipv6,
+            iface_name.as_str(),
+            group.servers,
             merged_state,
-            !prefer_ipv6_srv,
+            index == 0,
+            group.priority,
         )?;
+        used_ifaces.push(iface_name);
     }
     Ok(())
 }
 
-// Argument `preferred`: true will save the searches
+// Argument `preferred`: true will save the searches, false will only save the
+// routing-only domains.
 // Assuming all IPv6 link local address is pointing to specified argument
 // `iface_name` iface.
 fn _save_dns_to_iface(
@@ -314,6 +386,7 @@ fn _save_dns_to_iface(
     mut servers: Vec<String>,
     merged_state: &mut MergedNetworkState,
     preferred: bool,
+    priority: i32,
 ) -> Result<(), NmstateError> {
     for srv in servers.as_mut_slice() {
         if let Some((ip, _)) = parse_dns_ipv6_link_local_srv(srv)? {
@@ -356,16 +429,25 @@ fn _save_dns_to_iface(
                     servers,
                     merged_state.dns.searches.clone(),
                     merged_state.dns.options.clone(),
-                    Some(DEFAULT_DNS_PRIORITY),
+                    Some(priority),
                 )?;
             } else {
+                // The routing-only domains(`~` prefixed) instruct DNS plugin
+                // to send queries of matching domain to the name servers of
+                // this interface, hence we store them into both families.
                 set_iface_dns_conf(
                     is_ipv6,
                     apply_iface,
                     servers,
+                    merged_state
+                        .dns
+                        .searches
+                        .iter()
+                        .filter(|s| is_dns_routing_domain(s))
+                        .cloned()
+                        .collect(),
                     Vec::new(),
-                    Vec::new(),
-                    Some(DEFAULT_DNS_PRIORITY + 10),
+                    Some(priority),
                 )?;
             }
         }
@@ -479,17 +561,6 @@ pub(crate) fn cur_dns_ifaces_still_valid_for_dns(
     true
 }
 
-fn is_mixed_dns_servers(srvs: &[String]) -> bool {
-    let mut pattern = String::new();
-    for srv in srvs {
-        let cur_char = if is_ipv6_addr(srv) { '6' } else { '4' };
-        if !pattern.ends_with(cur_char) {
-            pattern.push(cur_char);
-        }
-    }
-    pattern.contains("464") || pattern.contains("646")
-}
-
 impl MergedInterface {
     // These are considered preferred DNS interface:
     //  * Desire state has specified IP stack with static IP or auto with
@@ -908,3 +979,9 @@ fn store_dns_search_or_options_to_ip_enabled_iface(
         ),
     ))
 }
+
+// The search domain prefixed with `~` is routing-only domain which is not used
+// for host-name lookup.
+pub(crate) fn is_dns_routing_domain(search: &str) -> bool {
+    search.starts_with('~')
+}
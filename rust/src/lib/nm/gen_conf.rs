@@ -1,16 +1,29 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{ErrorKind, MergedNetworkState, NmstateError};
+use crate::{
+    ErrorKind, GenConfNmConnection, MergedNetworkState, NetworkState,
+    NmConnectionOperations, NmstateError,
+};
 
 use super::{
     dns::{store_dns_config_to_iface, store_dns_search_or_option_to_iface},
-    profile::perpare_nm_conns,
+    keyfile_name::keyfile_names,
+    nm_dbus::NmConnection,
+    profile::{perpare_nm_conns, PerparedNmConnections},
     route::store_route_config,
     route_rule::store_route_rule_config,
+    settings::{
+        get_exist_profile, mark_nm_conn_as_nmstate_managed,
+        NM_SETTING_OVS_BRIDGE_SETTING_NAME, NM_SETTING_OVS_PORT_SETTING_NAME,
+    },
 };
 
+// The `hook` is invoked on each generated NetworkManager connection after
+// UUID references are resolved, but before the connections are sorted by ID
+// and serialized as keyfiles.
 pub(crate) fn nm_gen_conf(
     merged_state: &MergedNetworkState,
+    hook: &dyn Fn(&mut GenConfNmConnection),
 ) -> Result<Vec<(String, String)>, NmstateError> {
     if merged_state
         .hostname
@@ -25,6 +38,109 @@ pub(crate) fn nm_gen_conf(
         );
     }
 
+    let mut nm_conns = gen_conf_nm_conns(merged_state, &[])?.to_store;
+    for nm_conn in nm_conns.iter_mut() {
+        hook(&mut GenConfNmConnection::new(nm_conn));
+    }
+    nm_conns_to_keyfiles(nm_conns)
+}
+
+// Compute the keyfiles to create, modify and delete for converging
+// `current` to `desired`. The NetworkManager profiles of current state are
+// generated in gen_conf mode, hence UUID is stable between them and the
+// profiles of desired state.
+// Interfaces only mentioned in current state are left alone unless
+// `purge_unmanaged` is enabled in desired state.
+pub(crate) fn nm_reconcile(
+    desired: &NetworkState,
+    current: &NetworkState,
+) -> Result<NmConnectionOperations, NmstateError> {
+    let cur_merged_state = MergedNetworkState::new(
+        current.clone(),
+        NetworkState::new(),
+        true,  // gen_conf mode
+        false, // memory only
+    )?;
+    let mut cur_nm_conns = gen_conf_nm_conns(&cur_merged_state, &[])?.to_store;
+    if desired.purge_unmanaged {
+        // Treat every profile of current state as previously managed by
+        // nmstate so they are purged when not desired.
+        cur_nm_conns
+            .iter_mut()
+            .for_each(mark_nm_conn_as_nmstate_managed);
+    }
+
+    let merged_state = MergedNetworkState::new(
+        desired.clone(),
+        current.clone(),
+        true,  // gen_conf mode
+        false, // memory only
+    )?;
+    let prepared = gen_conf_nm_conns(&merged_state, &cur_nm_conns)?;
+
+    let mut nm_conns_to_create = Vec::new();
+    let mut nm_conns_to_modify = Vec::new();
+    for nm_conn in prepared.to_store {
+        match cur_nm_conns
+            .iter()
+            .find(|c| c.uuid().is_some() && c.uuid() == nm_conn.uuid())
+        {
+            Some(cur_nm_conn) => {
+                if cur_nm_conn != &nm_conn {
+                    nm_conns_to_modify.push(nm_conn);
+                }
+            }
+            None => nm_conns_to_create.push(nm_conn),
+        }
+    }
+
+    let mut nm_conns_to_delete = prepared.to_delete;
+    for merged_iface in merged_state
+        .interfaces
+        .iter()
+        .filter(|i| i.is_changed() && i.merged.is_absent())
+    {
+        if let Some(nm_conn) = get_exist_profile(
+            &cur_nm_conns,
+            merged_iface.merged.name(),
+            &merged_iface.merged.iface_type(),
+            &[],
+        ) {
+            nm_conns_to_delete.push(nm_conn.clone());
+        }
+    }
+    // Remove OVS port profiles whose OVS bridge profile is deleted
+    let mut orphan_ovs_ports = Vec::new();
+    for nm_conn in cur_nm_conns
+        .iter()
+        .filter(|c| c.iface_type() == Some(NM_SETTING_OVS_PORT_SETTING_NAME))
+    {
+        if let Some(ctrl) = nm_conn.controller() {
+            if nm_conns_to_delete.iter().any(|c| {
+                c.uuid() == Some(ctrl)
+                    || (c.iface_name() == Some(ctrl)
+                        && c.iface_type()
+                            == Some(NM_SETTING_OVS_BRIDGE_SETTING_NAME))
+            }) {
+                orphan_ovs_ports.push(nm_conn.clone());
+            }
+        }
+    }
+    nm_conns_to_delete.extend(orphan_ovs_ports);
+    nm_conns_to_delete.sort_unstable_by(|a, b| a.id().cmp(&b.id()));
+    nm_conns_to_delete.dedup_by(|a, b| a.uuid() == b.uuid());
+
+    Ok(NmConnectionOperations {
+        create: nm_conns_to_keyfiles(nm_conns_to_create)?,
+        modify: nm_conns_to_keyfiles(nm_conns_to_modify)?,
+        delete: keyfile_names(&nm_conns_to_delete),
+    })
+}
+
+fn gen_conf_nm_conns(
+    merged_state: &MergedNetworkState,
+    exist_nm_conns: &[NmConnection],
+) -> Result<PerparedNmConnections, NmstateError> {
     let mut merged_state = merged_state.clone();
     store_route_config(&mut merged_state)?;
     store_route_rule_config(&mut merged_state)?;
@@ -34,21 +150,26 @@ pub(crate) fn nm_gen_conf(
         store_dns_config_to_iface(&mut merged_state, &[], &[])?;
     }
 
-    let nm_conns = perpare_nm_conns(
+    perpare_nm_conns(
         &merged_state,
-        &Vec::new(),
+        exist_nm_conns,
         &Vec::new(),
         true, // gen_conf mode
-    )?
-    .to_store;
+    )
+}
+
+fn nm_conns_to_keyfiles(
+    mut nm_conns: Vec<NmConnection>,
+) -> Result<Vec<(String, String)>, NmstateError> {
+    // Sort by connection id to make the output stable regardless of the
+    // order of interfaces in desired state.
+    nm_conns.sort_unstable_by(|a, b| a.id().cmp(&b.id()));
 
     let mut ret = Vec::new();
-    for nm_conn in nm_conns {
+    for (nm_conn, file_name) in nm_conns.iter().zip(keyfile_names(&nm_conns)) {
         match nm_conn.to_keyfile() {
             Ok(s) => {
-                if let Some(id) = nm_conn.id() {
-                    ret.push((format!("{id}.nmconnection"), s));
-                }
+                ret.push((file_name, s));
             }
             Err(e) => {
                 return Err(NmstateError::new(
@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashSet;
+
+use super::nm_dbus::NmConnection;
+
+const KEYFILE_EXTENSION: &str = ".nmconnection";
+// Linux `NAME_MAX`
+const KEYFILE_NAME_MAX: usize = 255;
+const KEYFILE_ESCAPE_CHAR: char = '_';
+const UUID_PREFIX_LEN: usize = 8;
+
+/// Generate keyfile name `<id>.nmconnection` using the same escaping rules
+/// as NetworkManager keyfile plugin:
+///  * `/` is replaced by `_`.
+///  * Leading `.`(hidden file ignored by NetworkManager) is replaced by `_`.
+///  * Truncated to fit into `NAME_MAX` with the extension appended.
+///
+/// The UUID is used when connection ID is undefined or empty.
+pub(crate) fn keyfile_name(conn: &NmConnection) -> String {
+    let name = conn
+        .id()
+        .filter(|id| !id.is_empty())
+        .or_else(|| conn.uuid())
+        .unwrap_or_default();
+    escape_keyfile_name(name, "")
+}
+
+/// Generate keyfile names for specified connections in the same order.
+/// When name collision found, the later connection will have its UUID
+/// prefix appended: `<id>-<uuid_prefix>.nmconnection`.
+pub(crate) fn keyfile_names(conns: &[NmConnection]) -> Vec<String> {
+    let mut used: HashSet<String> = HashSet::new();
+    let mut ret = Vec::new();
+    for conn in conns {
+        let mut name = keyfile_name(conn);
+        if used.contains(&name) {
+            if let Some(uuid) = conn.uuid() {
+                let uuid_prefix: String =
+                    uuid.chars().take(UUID_PREFIX_LEN).collect();
+                name = escape_keyfile_name(
+                    conn.id().unwrap_or_default(),
+                    &format!("-{uuid_prefix}"),
+                );
+            }
+        }
+        used.insert(name.clone());
+        ret.push(name);
+    }
+    ret
+}
+
+fn escape_keyfile_name(name: &str, suffix: &str) -> String {
+    let max_len = KEYFILE_NAME_MAX - KEYFILE_EXTENSION.len() - suffix.len();
+    let mut ret = String::new();
+    for c in name.chars() {
+        if ret.len() + c.len_utf8() > max_len {
+            break;
+        }
+        ret.push(if c == '/' { KEYFILE_ESCAPE_CHAR } else { c });
+    }
+    if ret.starts_with('.') {
+        ret.replace_range(..1, &KEYFILE_ESCAPE_CHAR.to_string());
+    }
+    format!("{ret}{suffix}{KEYFILE_EXTENSION}")
+}
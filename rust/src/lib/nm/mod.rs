@@ -4,6 +4,9 @@
 mod active_connection;
 #[cfg(feature = "query_apply")]
 mod checkpoint;
+mod compat;
+#[cfg(feature = "query_apply")]
+mod delta;
 #[cfg(feature = "query_apply")]
 mod device;
 pub(crate) mod dns;
@@ -11,6 +14,8 @@ pub(crate) mod dns;
 mod error;
 #[cfg(feature = "gen_conf")]
 mod gen_conf;
+#[cfg(feature = "gen_conf")]
+mod keyfile_name;
 #[allow(unused_imports)]
 mod nm_dbus;
 mod profile;
@@ -23,15 +28,20 @@ mod settings;
 mod show;
 #[cfg(test)]
 mod unit_tests;
+mod version;
 
 #[cfg(feature = "query_apply")]
 pub(crate) use checkpoint::{
     nm_checkpoint_create, nm_checkpoint_destroy, nm_checkpoint_rollback,
     nm_checkpoint_timeout_extend,
 };
+pub use compat::Incompatibility;
+#[cfg(feature = "gen_conf")]
+pub(crate) use gen_conf::{nm_gen_conf, nm_reconcile};
 #[cfg(feature = "gen_conf")]
-pub(crate) use gen_conf::nm_gen_conf;
+pub(crate) use nm_dbus::NmConnection;
 #[cfg(feature = "query_apply")]
 pub(crate) use query_apply::nm_apply;
 #[cfg(feature = "query_apply")]
 pub(crate) use show::nm_retrieve;
+pub use version::NmVersion;
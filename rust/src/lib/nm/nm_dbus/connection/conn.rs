@@ -16,15 +16,19 @@ use super::super::{
     connection::ieee8021x::NmSetting8021X,
     connection::infiniband::NmSettingInfiniBand,
     connection::ip::NmSettingIp,
+    connection::link::NmSettingLink,
     connection::loopback::NmSettingLoopback,
     connection::mac_vlan::NmSettingMacVlan,
     connection::macsec::NmSettingMacSec,
+    connection::matching::NmSettingMatch,
     connection::ovs::{
         NmSettingOvsBridge, NmSettingOvsDpdk, NmSettingOvsExtIds,
         NmSettingOvsIface, NmSettingOvsOtherConfig, NmSettingOvsPatch,
         NmSettingOvsPort,
     },
+    connection::proxy::NmSettingProxy,
     connection::sriov::NmSettingSriov,
+    connection::team::{NmSettingTeam, NmSettingTeamPort},
     connection::user::NmSettingUser,
     connection::veth::NmSettingVeth,
     connection::vlan::NmSettingVlan,
@@ -32,6 +36,7 @@ use super::super::{
     connection::vrf::NmSettingVrf,
     connection::vxlan::NmSettingVxlan,
     connection::wired::NmSettingWired,
+    connection::wireguard::NmSettingWireGuard,
     convert::ToDbusValue,
     NmError,
 };
@@ -106,9 +111,15 @@ pub struct NmConnection {
     pub ethtool: Option<NmSettingEthtool>,
     pub infiniband: Option<NmSettingInfiniBand>,
     pub loopback: Option<NmSettingLoopback>,
+    pub link: Option<NmSettingLink>,
     pub macsec: Option<NmSettingMacSec>,
     pub hsr: Option<NmSettingHsr>,
+    pub team: Option<NmSettingTeam>,
+    pub team_port: Option<NmSettingTeamPort>,
     pub vpn: Option<NmSettingVpn>,
+    pub proxy: Option<NmSettingProxy>,
+    pub wireguard: Option<NmSettingWireGuard>,
+    pub matching: Option<NmSettingMatch>,
     #[serde(skip)]
     pub obj_path: String,
     #[serde(skip)]
@@ -185,8 +196,18 @@ impl TryFrom<NmConnectionDbusOwnedValue> for NmConnection {
                 NmSettingInfiniBand::try_from
             )?,
             loopback: _from_map!(v, "loopback", NmSettingLoopback::try_from)?,
+            link: _from_map!(v, "link", NmSettingLink::try_from)?,
             hsr: _from_map!(v, "hsr", NmSettingHsr::try_from)?,
+            team: _from_map!(v, "team", NmSettingTeam::try_from)?,
+            team_port: _from_map!(v, "team-port", NmSettingTeamPort::try_from)?,
             vpn: _from_map!(v, "vpn", NmSettingVpn::try_from)?,
+            proxy: _from_map!(v, "proxy", NmSettingProxy::try_from)?,
+            wireguard: _from_map!(
+                v,
+                "wireguard",
+                NmSettingWireGuard::try_from
+            )?,
+            matching: _from_map!(v, "match", NmSettingMatch::try_from)?,
             _other: v,
             ..Default::default()
         })
@@ -295,15 +316,33 @@ impl NmConnection {
         if let Some(v) = &self.loopback {
             ret.insert("loopback", v.to_value()?);
         }
+        if let Some(v) = &self.link {
+            ret.insert("link", v.to_value()?);
+        }
         if let Some(hsr) = &self.hsr {
             ret.insert("hsr", hsr.to_value()?);
         }
         if let Some(v) = &self.bond_port {
             ret.insert("bond-port", v.to_value()?);
         }
+        if let Some(v) = &self.team {
+            ret.insert("team", v.to_value()?);
+        }
+        if let Some(v) = &self.team_port {
+            ret.insert("team-port", v.to_value()?);
+        }
         if let Some(v) = &self.vpn {
             ret.insert("vpn", v.to_value()?);
         }
+        if let Some(v) = &self.proxy {
+            ret.insert("proxy", v.to_value()?);
+        }
+        if let Some(v) = &self.wireguard {
+            ret.insert("wireguard", v.to_value()?);
+        }
+        if let Some(v) = &self.matching {
+            ret.insert("match", v.to_value()?);
+        }
         for (key, setting_value) in &self._other {
             let mut other_setting_value: HashMap<&str, zvariant::Value> =
                 HashMap::new();
@@ -318,6 +357,20 @@ impl NmConnection {
         Ok(ret)
     }
 
+    pub fn parent(&self) -> Option<&str> {
+        self.vlan
+            .as_ref()
+            .and_then(|s| s.parent.as_deref())
+            .or_else(|| self.vxlan.as_ref().and_then(|s| s.parent.as_deref()))
+            .or_else(|| {
+                self.infiniband.as_ref().and_then(|s| s.parent.as_deref())
+            })
+            .or_else(|| {
+                self.mac_vlan.as_ref().and_then(|s| s.parent.as_deref())
+            })
+            .or_else(|| self.macsec.as_ref().and_then(|s| s.parent.as_deref()))
+    }
+
     pub fn set_parent(&mut self, parent: &str) {
         if let Some(setting) = self.vlan.as_mut() {
             setting.parent = Some(parent.to_string());
@@ -358,14 +411,29 @@ pub struct NmSettingConnection {
     pub controller_type: Option<String>,
     pub autoconnect: Option<bool>,
     pub autoconnect_ports: Option<bool>,
+    // Use `autoconnect-ports` instead of deprecated `autoconnect-slaves`,
+    // only supported by NetworkManager 1.46+.
+    pub use_autoconnect_ports_key: bool,
     pub lldp: Option<bool>,
     pub mptcp_flags: Option<u32>,
+    pub ip_ping_addresses: Option<Vec<String>>,
+    pub ip_ping_timeout: Option<u32>,
+    pub gateway_ping_timeout: Option<u32>,
+    pub auth_timeout: Option<i32>,
+    pub auth_retries: Option<i32>,
+    pub multi_connect: Option<i32>,
+    pub mdns: Option<i32>,
+    pub llmnr: Option<i32>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
 impl TryFrom<DbusDictionary> for NmSettingConnection {
     type Error = NmError;
     fn try_from(mut v: DbusDictionary) -> Result<Self, Self::Error> {
+        let autoconnect_ports_new =
+            _from_map!(v, "autoconnect-ports", i32::try_from)?;
+        let autoconnect_ports_old =
+            _from_map!(v, "autoconnect-slaves", i32::try_from)?;
         Ok(Self {
             id: _from_map!(v, "id", String::try_from)?,
             uuid: _from_map!(v, "uuid", String::try_from)?,
@@ -375,11 +443,28 @@ impl TryFrom<DbusDictionary> for NmSettingConnection {
             controller_type: _from_map!(v, "slave-type", String::try_from)?,
             autoconnect: _from_map!(v, "autoconnect", bool::try_from)?
                 .or(Some(true)),
+            use_autoconnect_ports_key: autoconnect_ports_new.is_some(),
             autoconnect_ports: NmSettingConnection::i32_to_autoconnect_ports(
-                _from_map!(v, "autoconnect-slaves", i32::try_from)?,
+                autoconnect_ports_new.or(autoconnect_ports_old),
             ),
             lldp: _from_map!(v, "lldp", i32::try_from)?.map(|i| i == 1),
             mptcp_flags: _from_map!(v, "mptcp-flags", u32::try_from)?,
+            ip_ping_addresses: _from_map!(
+                v,
+                "ip-ping-addresses",
+                <Vec<String>>::try_from
+            )?,
+            ip_ping_timeout: _from_map!(v, "ip-ping-timeout", u32::try_from)?,
+            gateway_ping_timeout: _from_map!(
+                v,
+                "gateway-ping-timeout",
+                u32::try_from
+            )?,
+            auth_timeout: _from_map!(v, "auth-timeout", i32::try_from)?,
+            auth_retries: _from_map!(v, "auth-retries", i32::try_from)?,
+            multi_connect: _from_map!(v, "multi-connect", i32::try_from)?,
+            mdns: _from_map!(v, "mdns", i32::try_from)?,
+            llmnr: _from_map!(v, "llmnr", i32::try_from)?,
             _other: v,
         })
     }
@@ -412,6 +497,30 @@ impl ToDbusValue for NmSettingConnection {
         if let Some(v) = &self.mptcp_flags {
             ret.insert("mptcp-flags", zvariant::Value::new(v));
         }
+        if let Some(v) = &self.ip_ping_addresses {
+            ret.insert("ip-ping-addresses", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.ip_ping_timeout {
+            ret.insert("ip-ping-timeout", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.gateway_ping_timeout {
+            ret.insert("gateway-ping-timeout", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.auth_timeout {
+            ret.insert("auth-timeout", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.auth_retries {
+            ret.insert("auth-retries", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.multi_connect {
+            ret.insert("multi-connect", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.mdns {
+            ret.insert("mdns", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.llmnr {
+            ret.insert("llmnr", zvariant::Value::new(v));
+        }
 
         ret.insert(
             "autoconnect",
@@ -422,7 +531,11 @@ impl ToDbusValue for NmSettingConnection {
             },
         );
         ret.insert(
-            "autoconnect-slaves",
+            if self.use_autoconnect_ports_key {
+                "autoconnect-ports"
+            } else {
+                "autoconnect-slaves"
+            },
             match &self.autoconnect_ports {
                 Some(true) => zvariant::Value::new(NM_AUTOCONENCT_PORT_YES),
                 Some(false) => zvariant::Value::new(NM_AUTOCONENCT_PORT_NO),
@@ -482,6 +595,16 @@ pub(crate) fn nm_con_get_from_obj_path(
             }
         }
     }
+    if let Some(wg_conf) = nm_conn.wireguard.as_mut() {
+        if let Ok(nm_secrets) = proxy.call::<&str, NmConnectionDbusOwnedValue>(
+            "GetSecrets",
+            &"wireguard",
+        ) {
+            if let Some(nm_secret) = nm_secrets.get("wireguard") {
+                wg_conf.fill_secrets(nm_secret);
+            }
+        }
+    }
     if let Some(vpn_conf) = nm_conn.vpn.as_mut() {
         if let Ok(nm_secrets) =
             proxy.call::<&str, NmConnectionDbusOwnedValue>("GetSecrets", &"vpn")
@@ -3,9 +3,7 @@ use std::convert::TryFrom;
 
 use serde::Deserialize;
 
-use super::super::{
-    connection::DbusDictionary, ErrorKind, NmError, ToDbusValue,
-};
+use super::super::{connection::DbusDictionary, NmError, ToDbusValue};
 
 const VALID_FEATURES: [&str; 58] = [
     "feature-esp-hw-offload",
@@ -102,6 +100,9 @@ pub struct NmSettingEthtool {
     pub ring_rx_jumbo: Option<u32>,
     pub ring_rx_mini: Option<u32>,
     pub ring_tx: Option<u32>,
+    pub channels_rx: Option<u32>,
+    pub channels_tx: Option<u32>,
+    pub channels_combined: Option<u32>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
@@ -242,24 +243,35 @@ impl TryFrom<DbusDictionary> for NmSettingEthtool {
             ring_rx_jumbo: _from_map!(v, "ring-rx-jumbo", u32::try_from)?,
             ring_rx_mini: _from_map!(v, "ring-rx-mini", u32::try_from)?,
             ring_tx: _from_map!(v, "ring-tx", u32::try_from)?,
+            channels_rx: _from_map!(v, "channels-rx", u32::try_from)?,
+            channels_tx: _from_map!(v, "channels-tx", u32::try_from)?,
+            channels_combined: _from_map!(
+                v,
+                "channels-combined",
+                u32::try_from
+            )?,
             _other: v,
         })
     }
 }
 
 impl NmSettingEthtool {
-    pub fn validate(&self) -> Result<(), NmError> {
-        if let Some(features) = self.features.as_ref() {
-            for k in features.keys() {
-                if !VALID_FEATURES.contains(&k.as_str()) {
-                    return Err(NmError::new(
-                        ErrorKind::InvalidArgument,
-                        format!("Unsupported ethtool feature {k}"),
-                    ));
-                }
-            }
-        }
-        Ok(())
+    /// Feature names not known by this crate, NetworkManager will validate
+    /// them during activation.
+    pub fn unknown_features(&self) -> Vec<&str> {
+        let mut ret: Vec<&str> = self
+            .features
+            .as_ref()
+            .map(|features| {
+                features
+                    .keys()
+                    .map(|k| k.as_str())
+                    .filter(|k| !VALID_FEATURES.contains(k))
+                    .collect()
+            })
+            .unwrap_or_default();
+        ret.sort_unstable();
+        ret
     }
 }
 
@@ -352,6 +364,15 @@ impl ToDbusValue for NmSettingEthtool {
         if let Some(v) = &self.ring_tx {
             ret.insert("ring-tx", zvariant::Value::new(v));
         }
+        if let Some(v) = &self.channels_rx {
+            ret.insert("channels-rx", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.channels_tx {
+            ret.insert("channels-tx", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.channels_combined {
+            ret.insert("channels-combined", zvariant::Value::new(v));
+        }
         Ok(ret)
     }
 }
@@ -9,6 +9,11 @@ use super::super::{
 
 const GLIB_FILE_PATH_PREFIX: &str = "file://";
 
+pub(crate) const NM_SETTING_SECRET_FLAG_NONE: u32 = 0;
+pub(crate) const NM_SETTING_SECRET_FLAG_AGENT_OWNED: u32 = 1;
+pub(crate) const NM_SETTING_SECRET_FLAG_NOT_SAVED: u32 = 2;
+pub(crate) const NM_SETTING_SECRET_FLAG_NOT_REQUIRED: u32 = 4;
+
 #[derive(Debug, Clone, PartialEq, Default, Deserialize)]
 #[serde(try_from = "DbusDictionary")]
 #[non_exhaustive]
@@ -19,6 +24,7 @@ pub struct NmSetting8021X {
     pub client_cert: Option<Vec<u8>>,
     pub ca_cert: Option<Vec<u8>>,
     pub private_key_password: Option<String>,
+    pub private_key_password_flags: Option<u32>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
@@ -32,6 +38,11 @@ impl TryFrom<DbusDictionary> for NmSetting8021X {
             client_cert: _from_map!(v, "client-cert", <Vec<u8>>::try_from)?,
             ca_cert: _from_map!(v, "ca-cert", <Vec<u8>>::try_from)?,
             private_key_password: None,
+            private_key_password_flags: _from_map!(
+                v,
+                "private-key-password-flags",
+                u32::try_from
+            )?,
             _other: v,
         })
     }
@@ -58,6 +69,9 @@ impl ToDbusValue for NmSetting8021X {
         if let Some(v) = &self.private_key_password {
             ret.insert("private-key-password", zvariant::Value::new(v));
         }
+        if let Some(v) = &self.private_key_password_flags {
+            ret.insert("private-key-password-flags", zvariant::Value::new(v));
+        }
         ret.extend(self._other.iter().map(|(key, value)| {
             (key.as_str(), zvariant::Value::from(value.clone()))
         }));
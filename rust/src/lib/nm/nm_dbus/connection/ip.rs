@@ -111,9 +111,18 @@ pub struct NmSettingIp {
     pub dhcp_iaid: Option<String>,
     // IPv6 only
     pub token: Option<String>,
+    // IPv6 only
+    pub dad_transmits: Option<i32>,
+    // IPv6 only
+    pub ip6_privacy: Option<i32>,
     pub dhcp_send_hostname: Option<bool>,
     pub dhcp_fqdn: Option<String>,
     pub dhcp_hostname: Option<String>,
+    // IPv4 only
+    pub dhcp_vendor_class_identifier: Option<String>,
+    // IPv4 only
+    pub dad_timeout: Option<i32>,
+    pub replace_local_rule: Option<i32>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
@@ -149,6 +158,9 @@ impl TryFrom<DbusDictionary> for NmSettingIp {
             may_fail: _from_map!(v, "may-fail", bool::try_from)?,
             route_metric: _from_map!(v, "route-metric", i64::try_from)?,
             token: _from_map!(v, "token", String::try_from)?,
+            dad_transmits: _from_map!(v, "dad-transmits", i32::try_from)?,
+            ip6_privacy: _from_map!(v, "ip6-privacy", i32::try_from)?,
+            dad_timeout: _from_map!(v, "dad-timeout", i32::try_from)?,
             dhcp_send_hostname: _from_map!(
                 v,
                 "dhcp-send-hostname",
@@ -156,6 +168,16 @@ impl TryFrom<DbusDictionary> for NmSettingIp {
             )?,
             dhcp_fqdn: _from_map!(v, "dhcp-fqdn", String::try_from)?,
             dhcp_hostname: _from_map!(v, "dhcp-hostname", String::try_from)?,
+            dhcp_vendor_class_identifier: _from_map!(
+                v,
+                "dhcp-vendor-class-identifier",
+                String::try_from
+            )?,
+            replace_local_rule: _from_map!(
+                v,
+                "replace-local-rule",
+                i32::try_from
+            )?,
             ..Default::default()
         };
 
@@ -281,6 +303,15 @@ impl ToDbusValue for NmSettingIp {
         if let Some(v) = &self.token {
             ret.insert("token", zvariant::Value::new(v));
         }
+        if let Some(v) = self.dad_transmits {
+            ret.insert("dad-transmits", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.ip6_privacy {
+            ret.insert("ip6-privacy", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.dad_timeout {
+            ret.insert("dad-timeout", zvariant::Value::new(v));
+        }
         if let Some(v) = &self.dhcp_send_hostname {
             ret.insert("dhcp-send-hostname", zvariant::Value::new(v));
         }
@@ -290,6 +321,12 @@ impl ToDbusValue for NmSettingIp {
         if let Some(v) = &self.dhcp_hostname {
             ret.insert("dhcp-hostname", zvariant::Value::new(v));
         }
+        if let Some(v) = &self.dhcp_vendor_class_identifier {
+            ret.insert("dhcp-vendor-class-identifier", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.replace_local_rule {
+            ret.insert("replace-local-rule", zvariant::Value::new(v));
+        }
         ret.extend(self._other.iter().map(|(key, value)| {
             (key.as_str(), zvariant::Value::from(value.clone()))
         }));
@@ -0,0 +1,39 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use serde::Deserialize;
+
+use super::super::{connection::DbusDictionary, NmError, ToDbusValue};
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(try_from = "DbusDictionary")]
+#[non_exhaustive]
+pub struct NmSettingLink {
+    pub gso_max_size: Option<u32>,
+    _other: HashMap<String, zvariant::OwnedValue>,
+}
+
+impl TryFrom<DbusDictionary> for NmSettingLink {
+    type Error = NmError;
+    fn try_from(mut v: DbusDictionary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            gso_max_size: _from_map!(v, "gso-max-size", u32::try_from)?,
+            _other: v,
+        })
+    }
+}
+
+impl ToDbusValue for NmSettingLink {
+    fn to_value(&self) -> Result<HashMap<&str, zvariant::Value>, NmError> {
+        let mut ret = HashMap::new();
+        if let Some(v) = &self.gso_max_size {
+            ret.insert("gso-max-size", zvariant::Value::new(v));
+        }
+        ret.extend(self._other.iter().map(|(key, value)| {
+            (key.as_str(), zvariant::Value::from(value.clone()))
+        }));
+        Ok(ret)
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use serde::Deserialize;
+
+use super::super::{connection::DbusDictionary, NmError, ToDbusValue};
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(try_from = "DbusDictionary")]
+#[non_exhaustive]
+pub struct NmSettingMatch {
+    pub interface_name: Option<Vec<String>>,
+    _other: HashMap<String, zvariant::OwnedValue>,
+}
+
+impl TryFrom<DbusDictionary> for NmSettingMatch {
+    type Error = NmError;
+    fn try_from(mut v: DbusDictionary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            interface_name: _from_map!(
+                v,
+                "interface-name",
+                <Vec<String>>::try_from
+            )?,
+            _other: v,
+        })
+    }
+}
+
+impl ToDbusValue for NmSettingMatch {
+    fn to_value(&self) -> Result<HashMap<&str, zvariant::Value<'_>>, NmError> {
+        let mut ret = HashMap::new();
+        if let Some(v) = &self.interface_name {
+            ret.insert("interface-name", zvariant::Value::new(v));
+        }
+        ret.extend(self._other.iter().map(|(key, value)| {
+            (key.as_str(), zvariant::Value::from(value.clone()))
+        }));
+        Ok(ret)
+    }
+}
@@ -25,13 +25,17 @@ mod hsr;
 mod ieee8021x;
 mod infiniband;
 mod ip;
+mod link;
 mod loopback;
 mod mac_vlan;
 mod macsec;
+mod matching;
 mod ovs;
+mod proxy;
 mod route;
 mod route_rule;
 mod sriov;
+mod team;
 mod user;
 mod veth;
 mod vlan;
@@ -39,6 +43,7 @@ mod vpn;
 mod vrf;
 mod vxlan;
 mod wired;
+mod wireguard;
 
 pub use self::bond::{NmSettingBond, NmSettingBondPort};
 pub use self::bridge::{
@@ -52,17 +57,21 @@ pub use self::hsr::NmSettingHsr;
 pub use self::ieee8021x::NmSetting8021X;
 pub use self::infiniband::NmSettingInfiniBand;
 pub use self::ip::{NmSettingIp, NmSettingIpMethod};
+pub use self::link::NmSettingLink;
 pub use self::loopback::NmSettingLoopback;
 pub use self::mac_vlan::NmSettingMacVlan;
 pub use self::macsec::NmSettingMacSec;
+pub use self::matching::NmSettingMatch;
 pub use self::ovs::{
     NmSettingOvsBridge, NmSettingOvsDpdk, NmSettingOvsExtIds,
     NmSettingOvsIface, NmSettingOvsOtherConfig, NmSettingOvsPatch,
     NmSettingOvsPort,
 };
+pub use self::proxy::NmSettingProxy;
 pub use self::route::NmIpRoute;
 pub use self::route_rule::{NmIpRouteRule, NmIpRouteRuleAction};
 pub use self::sriov::{NmSettingSriov, NmSettingSriovVf, NmSettingSriovVfVlan};
+pub use self::team::{NmSettingTeam, NmSettingTeamPort};
 pub use self::user::NmSettingUser;
 pub use self::veth::NmSettingVeth;
 pub use self::vlan::{NmSettingVlan, NmSettingVlanFlag, NmVlanProtocol};
@@ -70,9 +79,21 @@ pub use self::vpn::NmSettingVpn;
 pub use self::vrf::NmSettingVrf;
 pub use self::vxlan::NmSettingVxlan;
 pub use self::wired::NmSettingWired;
+pub use self::wireguard::{NmSettingWireGuard, NmSettingWireGuardPeer};
 
 pub(crate) use self::conn::DbusDictionary;
 #[cfg(feature = "query_apply")]
 pub(crate) use self::conn::{nm_con_get_from_obj_path, NmConnectionDbusValue};
+pub(crate) use self::ieee8021x::{
+    NM_SETTING_SECRET_FLAG_AGENT_OWNED, NM_SETTING_SECRET_FLAG_NONE,
+    NM_SETTING_SECRET_FLAG_NOT_REQUIRED, NM_SETTING_SECRET_FLAG_NOT_SAVED,
+};
 #[cfg(feature = "query_apply")]
 pub(crate) use self::macros::_from_map;
+pub(crate) use self::proxy::{
+    NM_SETTING_PROXY_METHOD_AUTO, NM_SETTING_PROXY_METHOD_NONE,
+};
+pub(crate) use self::sriov::{
+    NM_SRIOV_ESWITCH_ENCAP_MODE_BASIC, NM_SRIOV_ESWITCH_ENCAP_MODE_NONE,
+    NM_SRIOV_ESWITCH_MODE_LEGACY, NM_SRIOV_ESWITCH_MODE_SWITCHDEV,
+};
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use serde::Deserialize;
+
+use super::super::{connection::DbusDictionary, NmError, ToDbusValue};
+
+pub(crate) const NM_SETTING_PROXY_METHOD_NONE: i32 = 0;
+pub(crate) const NM_SETTING_PROXY_METHOD_AUTO: i32 = 1;
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(try_from = "DbusDictionary")]
+#[non_exhaustive]
+pub struct NmSettingProxy {
+    pub method: Option<i32>,
+    pub browser_only: Option<bool>,
+    pub pac_url: Option<String>,
+    pub pac_script: Option<String>,
+    _other: HashMap<String, zvariant::OwnedValue>,
+}
+
+impl TryFrom<DbusDictionary> for NmSettingProxy {
+    type Error = NmError;
+    fn try_from(mut v: DbusDictionary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            method: _from_map!(v, "method", i32::try_from)?,
+            browser_only: _from_map!(v, "browser-only", bool::try_from)?,
+            pac_url: _from_map!(v, "pac-url", String::try_from)?,
+            pac_script: _from_map!(v, "pac-script", String::try_from)?,
+            _other: v,
+        })
+    }
+}
+
+impl ToDbusValue for NmSettingProxy {
+    fn to_value(&self) -> Result<HashMap<&str, zvariant::Value>, NmError> {
+        let mut ret = HashMap::new();
+        if let Some(v) = &self.method {
+            ret.insert("method", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.browser_only {
+            ret.insert("browser-only", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.pac_url {
+            ret.insert("pac-url", zvariant::Value::new(v.as_str()));
+        }
+        if let Some(v) = &self.pac_script {
+            ret.insert("pac-script", zvariant::Value::new(v.as_str()));
+        }
+        ret.extend(self._other.iter().map(|(key, value)| {
+            (key.as_str(), zvariant::Value::from(value.clone()))
+        }));
+        Ok(ret)
+    }
+}
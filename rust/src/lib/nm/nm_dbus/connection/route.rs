@@ -17,6 +17,9 @@ pub struct NmIpRoute {
     pub metric: Option<u32>,
     pub weight: Option<u32>,
     pub route_type: Option<String>,
+    pub onlink: Option<bool>,
+    pub src: Option<String>,
+    pub scope: Option<u8>,
     _other: DbusDictionary,
 }
 
@@ -37,6 +40,9 @@ impl TryFrom<DbusDictionary> for NmIpRoute {
             metric: _from_map!(v, "metric", u32::try_from)?,
             weight,
             route_type: _from_map!(v, "type", String::try_from)?,
+            onlink: _from_map!(v, "onlink", bool::try_from)?,
+            src: _from_map!(v, "src", String::try_from)?,
+            scope: _from_map!(v, "scope", u8::try_from)?,
             _other: v,
         })
     }
@@ -90,6 +96,24 @@ impl NmIpRoute {
                 zvariant::Value::new(zvariant::Value::new(v)),
             )?;
         }
+        if let Some(v) = &self.onlink {
+            ret.append(
+                zvariant::Value::new("onlink"),
+                zvariant::Value::new(zvariant::Value::new(v)),
+            )?;
+        }
+        if let Some(v) = &self.src {
+            ret.append(
+                zvariant::Value::new("src"),
+                zvariant::Value::new(zvariant::Value::new(v)),
+            )?;
+        }
+        if let Some(v) = &self.scope {
+            ret.append(
+                zvariant::Value::new("scope"),
+                zvariant::Value::new(zvariant::Value::new(v)),
+            )?;
+        }
         for (key, value) in self._other.iter() {
             ret.append(
                 zvariant::Value::new(key.as_str()),
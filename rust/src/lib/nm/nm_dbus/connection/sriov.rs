@@ -25,6 +25,11 @@ use super::super::{
 pub(crate) const NM_TERNARY_TRUE: i32 = 1;
 pub(crate) const NM_TERNARY_FALSE: i32 = 0;
 
+pub(crate) const NM_SRIOV_ESWITCH_MODE_LEGACY: i32 = 0;
+pub(crate) const NM_SRIOV_ESWITCH_MODE_SWITCHDEV: i32 = 1;
+pub(crate) const NM_SRIOV_ESWITCH_ENCAP_MODE_NONE: i32 = 0;
+pub(crate) const NM_SRIOV_ESWITCH_ENCAP_MODE_BASIC: i32 = 1;
+
 #[derive(Debug, Clone, PartialEq, Default, Deserialize)]
 #[serde(try_from = "DbusDictionary")]
 #[non_exhaustive]
@@ -32,6 +37,8 @@ pub struct NmSettingSriov {
     pub autoprobe_drivers: Option<bool>,
     pub total_vfs: Option<u32>,
     pub vfs: Option<Vec<NmSettingSriovVf>>,
+    pub eswitch_mode: Option<i32>,
+    pub eswitch_encap_mode: Option<i32>,
     _other: DbusDictionary,
 }
 
@@ -50,6 +57,12 @@ impl TryFrom<DbusDictionary> for NmSettingSriov {
             },
             total_vfs: _from_map!(v, "total-vfs", u32::try_from)?,
             vfs: _from_map!(v, "vfs", own_value_to_vfs)?,
+            eswitch_mode: _from_map!(v, "eswitch-mode", i32::try_from)?,
+            eswitch_encap_mode: _from_map!(
+                v,
+                "eswitch-encap-mode",
+                i32::try_from
+            )?,
             _other: v,
         })
     }
@@ -79,6 +92,12 @@ impl ToDbusValue for NmSettingSriov {
             }
             ret.insert("vfs", zvariant::Value::Array(vf_values));
         }
+        if let Some(v) = &self.eswitch_mode {
+            ret.insert("eswitch-mode", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.eswitch_encap_mode {
+            ret.insert("eswitch-encap-mode", zvariant::Value::new(v));
+        }
 
         ret.extend(self._other.iter().map(|(key, value)| {
             (key.as_str(), zvariant::Value::from(value.clone()))
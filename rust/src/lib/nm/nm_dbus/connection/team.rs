@@ -0,0 +1,98 @@
+use std::collections::HashMap;
+use std::convert::TryFrom;
+
+use serde::Deserialize;
+
+use super::super::{connection::DbusDictionary, NmError, ToDbusValue};
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(try_from = "DbusDictionary")]
+#[non_exhaustive]
+pub struct NmSettingTeam {
+    pub config: Option<String>,
+    pub runner: Option<String>,
+    _other: HashMap<String, zvariant::OwnedValue>,
+}
+
+impl TryFrom<DbusDictionary> for NmSettingTeam {
+    type Error = NmError;
+    fn try_from(mut v: DbusDictionary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            config: _from_map!(v, "config", String::try_from)?,
+            runner: _from_map!(v, "runner", String::try_from)?,
+            _other: v,
+        })
+    }
+}
+
+impl ToDbusValue for NmSettingTeam {
+    fn to_value(&self) -> Result<HashMap<&str, zvariant::Value>, NmError> {
+        let mut ret = HashMap::new();
+        if let Some(v) = &self.config {
+            ret.insert("config", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.runner {
+            ret.insert("runner", zvariant::Value::new(v));
+        }
+        ret.extend(self._other.iter().map(|(key, value)| {
+            (key.as_str(), zvariant::Value::from(value.clone()))
+        }));
+        Ok(ret)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Default, Deserialize)]
+#[serde(try_from = "DbusDictionary")]
+#[non_exhaustive]
+pub struct NmSettingTeamPort {
+    pub config: Option<String>,
+    pub lacp_key: Option<i32>,
+    pub lacp_prio: Option<i32>,
+    pub prio: Option<i32>,
+    pub queue_id: Option<i32>,
+    pub sticky: Option<bool>,
+    _other: HashMap<String, zvariant::OwnedValue>,
+}
+
+impl TryFrom<DbusDictionary> for NmSettingTeamPort {
+    type Error = NmError;
+    fn try_from(mut v: DbusDictionary) -> Result<Self, Self::Error> {
+        Ok(Self {
+            config: _from_map!(v, "config", String::try_from)?,
+            lacp_key: _from_map!(v, "lacp-key", i32::try_from)?,
+            lacp_prio: _from_map!(v, "lacp-prio", i32::try_from)?,
+            prio: _from_map!(v, "prio", i32::try_from)?,
+            queue_id: _from_map!(v, "queue-id", i32::try_from)?,
+            sticky: _from_map!(v, "sticky", bool::try_from)?,
+            _other: v,
+        })
+    }
+}
+
+impl ToDbusValue for NmSettingTeamPort {
+    fn to_value(&self) -> Result<HashMap<&str, zvariant::Value>, NmError> {
+        let mut ret = HashMap::new();
+        if let Some(v) = &self.config {
+            ret.insert("config", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.lacp_key {
+            ret.insert("lacp-key", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.lacp_prio {
+            ret.insert("lacp-prio", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.prio {
+            ret.insert("prio", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.queue_id {
+            ret.insert("queue-id", zvariant::Value::new(v));
+        }
+        if let Some(v) = self.sticky {
+            ret.insert("sticky", zvariant::Value::new(v));
+        }
+        ret.extend(self._other.iter().map(|(key, value)| {
+            (key.as_str(), zvariant::Value::from(value.clone()))
+        }));
+        Ok(ret)
+    }
+}
@@ -17,12 +17,19 @@ use super::super::{
 #[non_exhaustive]
 pub struct NmSettingWired {
     pub cloned_mac_address: Option<String>,
+    // The special values of `cloned-mac-address` like `random` are stored
+    // in `assigned-mac-address` of D-Bus.
+    pub assigned_mac_address: Option<String>,
+    pub generate_mac_address_mask: Option<String>,
     pub mac_address: Option<String>,
     pub mtu: Option<u32>,
     pub accept_all_mac_addresses: Option<i32>,
     pub speed: Option<u32>,
     pub duplex: Option<String>,
     pub auto_negotiate: Option<bool>,
+    pub s390_subchannels: Option<Vec<String>>,
+    pub s390_nettype: Option<String>,
+    pub s390_options: Option<HashMap<String, String>>,
     _other: HashMap<String, zvariant::OwnedValue>,
 }
 
@@ -36,6 +43,16 @@ impl TryFrom<DbusDictionary> for NmSettingWired {
                 own_value_to_bytes_array
             )?
             .map(u8_array_to_mac_string),
+            assigned_mac_address: _from_map!(
+                v,
+                "assigned-mac-address",
+                String::try_from
+            )?,
+            generate_mac_address_mask: _from_map!(
+                v,
+                "generate-mac-address-mask",
+                String::try_from
+            )?,
             mac_address: _from_map!(
                 v,
                 "mac-address",
@@ -51,6 +68,17 @@ impl TryFrom<DbusDictionary> for NmSettingWired {
             speed: _from_map!(v, "speed", u32::try_from)?,
             duplex: _from_map!(v, "duplex", String::try_from)?,
             auto_negotiate: _from_map!(v, "auto-negotiate", bool::try_from)?,
+            s390_subchannels: _from_map!(
+                v,
+                "s390-subchannels",
+                <Vec<String>>::try_from
+            )?,
+            s390_nettype: _from_map!(v, "s390-nettype", String::try_from)?,
+            s390_options: _from_map!(
+                v,
+                "s390-options",
+                <HashMap<String, String>>::try_from
+            )?,
             _other: v,
         })
     }
@@ -65,6 +93,12 @@ impl ToDbusValue for NmSettingWired {
                 zvariant::Value::new(mac_str_to_u8_array(v)),
             );
         }
+        if let Some(v) = &self.assigned_mac_address {
+            ret.insert("assigned-mac-address", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.generate_mac_address_mask {
+            ret.insert("generate-mac-address-mask", zvariant::Value::new(v));
+        }
         if let Some(v) = &self.mac_address {
             ret.insert(
                 "mac-address",
@@ -86,6 +120,15 @@ impl ToDbusValue for NmSettingWired {
         if let Some(v) = &self.duplex {
             ret.insert("duplex", zvariant::Value::new(v));
         }
+        if let Some(v) = &self.s390_subchannels {
+            ret.insert("s390-subchannels", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.s390_nettype {
+            ret.insert("s390-nettype", zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.s390_options {
+            ret.insert("s390-options", zvariant::Value::from(v.clone()));
+        }
         ret.extend(self._other.iter().map(|(key, value)| {
             (key.as_str(), zvariant::Value::from(value.clone()))
         }));
@@ -6,7 +6,9 @@ use std::convert::TryFrom;
 use log::debug;
 
 use super::{
-    connection::{NmConnection, NmConnectionDbusValue},
+    connection::{
+        NmConnection, NmConnectionDbusValue, NmSettingsConnectionFlag,
+    },
     dbus_proxy::{
         NetworkManagerDnsProxy, NetworkManagerProxy, NetworkManagerSettingProxy,
     },
@@ -35,6 +37,7 @@ const NM_SETTINGS_CREATE2_FLAGS_BLOCK_AUTOCONNECT: u32 = 32;
 
 const NM_SETTINGS_UPDATE2_FLAGS_TO_DISK: u32 = 1;
 const NM_SETTINGS_UPDATE2_FLAGS_IN_MEMORY: u32 = 2;
+const NM_SETTINGS_UPDATE2_FLAGS_VOLATILE: u32 = 16;
 const NM_SETTINGS_UPDATE2_FLAGS_BLOCK_AUTOCONNECT: u32 = 32;
 
 pub(crate) struct NmDbus<'a> {
@@ -185,14 +188,25 @@ impl<'a> NmDbus<'a> {
         memory_only: bool,
     ) -> Result<(), NmError> {
         let value = nm_conn.to_value()?;
+        let is_volatile =
+            nm_conn.flags.contains(&NmSettingsConnectionFlag::Volatile);
         let flags = NM_SETTINGS_CREATE2_FLAGS_BLOCK_AUTOCONNECT
-            + if memory_only {
+            + if memory_only
+                || is_volatile
+                || nm_conn.flags.contains(&NmSettingsConnectionFlag::Unsaved)
+            {
                 NM_SETTINGS_CREATE2_FLAGS_IN_MEMORY
             } else {
                 NM_SETTINGS_CREATE2_FLAGS_TO_DISK
             };
-        self.setting_proxy
-            .add_connection2(value, flags, HashMap::new())?;
+        let (obj_path, _) =
+            self.setting_proxy
+                .add_connection2(value, flags, HashMap::new())?;
+        // AddConnection2() has no volatile flag, mark it as volatile via
+        // Update2()
+        if is_volatile {
+            self.connection_update(obj_path.as_str(), nm_conn, true)?;
+        }
         Ok(())
     }
 
@@ -223,8 +237,14 @@ impl<'a> NmDbus<'a> {
             con_obj_path,
             NM_DBUS_INTERFACE_SETTING,
         )?;
+        // Volatile connection must be stored in memory
         let flags = NM_SETTINGS_UPDATE2_FLAGS_BLOCK_AUTOCONNECT
-            + if memory_only {
+            + if nm_conn.flags.contains(&NmSettingsConnectionFlag::Volatile) {
+                NM_SETTINGS_UPDATE2_FLAGS_IN_MEMORY
+                    + NM_SETTINGS_UPDATE2_FLAGS_VOLATILE
+            } else if memory_only
+                || nm_conn.flags.contains(&NmSettingsConnectionFlag::Unsaved)
+            {
                 NM_SETTINGS_UPDATE2_FLAGS_IN_MEMORY
             } else {
                 NM_SETTINGS_UPDATE2_FLAGS_TO_DISK
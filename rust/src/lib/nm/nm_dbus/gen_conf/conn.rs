@@ -3,14 +3,23 @@
 use std::collections::HashMap;
 
 use super::{
-    super::{NmConnection, NmError, NmSettingConnection, ToKeyfile},
+    super::{
+        NmConnection, NmError, NmSettingConnection, NmSettingMatch, ToKeyfile,
+    },
     keyfile::keyfile_sections_to_string,
 };
 
 impl ToKeyfile for NmSettingConnection {}
 
+impl ToKeyfile for NmSettingMatch {}
+
 impl NmConnection {
     pub fn to_keyfile(&self) -> Result<String, NmError> {
+        let wg_peer_sections = self
+            .wireguard
+            .as_ref()
+            .map(|wg_set| wg_set.peers_to_keyfile())
+            .unwrap_or_default();
         let mut sections: Vec<(&str, HashMap<String, zvariant::Value>)> =
             Vec::new();
         if let Some(con_set) = &self.connection {
@@ -22,6 +31,12 @@ impl NmConnection {
         if let Some(bond_port_set) = &self.bond_port {
             sections.push(("bond-port", bond_port_set.to_keyfile()?));
         }
+        if let Some(team_set) = &self.team {
+            sections.push(("team", team_set.to_keyfile()?));
+        }
+        if let Some(team_port_set) = &self.team_port {
+            sections.push(("team-port", team_port_set.to_keyfile()?));
+        }
         if let Some(br_set) = &self.bridge {
             sections.push(("bridge", br_set.to_keyfile()?));
         }
@@ -51,6 +66,9 @@ impl NmConnection {
         }
         if let Some(wired_set) = &self.wired {
             sections.push(("ethernet", wired_set.to_keyfile()?));
+            if let Some(s) = wired_set.s390_options_to_keyfile() {
+                sections.push(("ethernet-s390-options", s));
+            }
         }
         if let Some(vlan) = &self.vlan {
             sections.push(("vlan", vlan.to_keyfile()?));
@@ -79,6 +97,9 @@ impl NmConnection {
         if let Some(ethtool) = &self.ethtool {
             sections.push(("ethtool", ethtool.to_keyfile()?));
         }
+        if let Some(link) = &self.link {
+            sections.push(("link", link.to_keyfile()?));
+        }
         if let Some(ib) = &self.infiniband {
             sections.push(("infiniband", ib.to_keyfile()?));
         }
@@ -94,6 +115,18 @@ impl NmConnection {
                 sections.push(("vpn-secrets", s));
             }
         }
+        if let Some(proxy) = &self.proxy {
+            sections.push(("proxy", proxy.to_keyfile()?));
+        }
+        if let Some(wg_set) = &self.wireguard {
+            sections.push(("wireguard", wg_set.to_keyfile()?));
+        }
+        if let Some(match_set) = &self.matching {
+            sections.push(("match", match_set.to_keyfile()?));
+        }
+        for (section_name, peer_section) in wg_peer_sections.iter() {
+            sections.push((section_name.as_str(), peer_section.clone()));
+        }
 
         keyfile_sections_to_string(&sections)
     }
@@ -52,6 +52,12 @@ impl ToKeyfile for NmSetting8021X {
                 zvariant::Value::new(v),
             );
         }
+        if let Some(v) = &self.private_key_password_flags {
+            ret.insert(
+                "private-key-password-flags".to_string(),
+                zvariant::Value::new(v),
+            );
+        }
         Ok(ret)
     }
 }
@@ -8,11 +8,14 @@ mod ieee8021x;
 mod infiniband;
 mod ip;
 mod keyfile;
+mod link;
 mod mac_vlan;
 mod ovs;
+mod proxy;
 mod route;
 mod route_rule;
 mod sriov;
+mod team;
 mod user;
 mod veth;
 mod vlan;
@@ -20,5 +23,6 @@ mod vpn;
 mod vrf;
 mod vxlan;
 mod wired;
+mod wireguard;
 
 pub(crate) use keyfile::ToKeyfile;
@@ -29,6 +29,15 @@ impl NmIpRoute {
             if let Some(route_type) = self.route_type.as_ref() {
                 write!(opt_string, ",type={}", route_type).ok();
             }
+            if let Some(onlink) = self.onlink {
+                write!(opt_string, ",onlink={}", onlink).ok();
+            }
+            if let Some(src) = self.src.as_ref() {
+                write!(opt_string, ",src={}", src).ok();
+            }
+            if let Some(scope) = self.scope {
+                write!(opt_string, ",scope={}", scope).ok();
+            }
             ret.insert("options".to_string(), opt_string);
         }
         ret
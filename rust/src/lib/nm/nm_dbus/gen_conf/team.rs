@@ -0,0 +1,7 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::{NmSettingTeam, NmSettingTeamPort, ToKeyfile};
+
+impl ToKeyfile for NmSettingTeam {}
+
+impl ToKeyfile for NmSettingTeamPort {}
@@ -9,11 +9,19 @@ impl ToKeyfile for NmSettingWired {
     fn to_keyfile(&self) -> Result<HashMap<String, zvariant::Value>, NmError> {
         let mut ret = HashMap::new();
         for (k, v) in self.to_value()?.drain() {
-            if k != "cloned-mac-address" && k != "mac-address" {
+            if k != "cloned-mac-address"
+                && k != "assigned-mac-address"
+                && k != "mac-address"
+                && k != "s390-options"
+            {
                 ret.insert(k.to_string(), v);
             }
         }
-        if let Some(v) = &self.cloned_mac_address {
+        if let Some(v) = self
+            .cloned_mac_address
+            .as_ref()
+            .or(self.assigned_mac_address.as_ref())
+        {
             ret.insert("cloned-mac-address".to_string(), Value::new(v));
         }
         if let Some(v) = &self.mac_address {
@@ -22,3 +30,20 @@ impl ToKeyfile for NmSettingWired {
         Ok(ret)
     }
 }
+
+impl NmSettingWired {
+    // NetworkManager keyfile stores `s390-options` in dedicated
+    // `ethernet-s390-options` section.
+    pub(crate) fn s390_options_to_keyfile(
+        &self,
+    ) -> Option<HashMap<String, zvariant::Value>> {
+        self.s390_options
+            .as_ref()
+            .filter(|o| !o.is_empty())
+            .map(|o| {
+                o.iter()
+                    .map(|(k, v)| (k.to_string(), Value::new(v)))
+                    .collect()
+            })
+    }
+}
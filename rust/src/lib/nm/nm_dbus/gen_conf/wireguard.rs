@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::collections::HashMap;
+
+use super::super::{
+    NmError, NmSettingWireGuard, NmSettingWireGuardPeer, ToDbusValue, ToKeyfile,
+};
+
+impl ToKeyfile for NmSettingWireGuard {
+    fn to_keyfile(&self) -> Result<HashMap<String, zvariant::Value>, NmError> {
+        let mut ret = HashMap::new();
+        for (k, v) in self.to_value()?.drain() {
+            if k != "peers" {
+                ret.insert(k.to_string(), v);
+            }
+        }
+        Ok(ret)
+    }
+}
+
+impl NmSettingWireGuard {
+    // Each peer is stored in its own `[wireguard-peer.<public-key>]` section
+    pub(crate) fn peers_to_keyfile(
+        &self,
+    ) -> Vec<(String, HashMap<String, zvariant::Value<'_>>)> {
+        let mut ret = Vec::new();
+        for peer in self.peers.as_deref().unwrap_or_default() {
+            if let Some(public_key) = peer.public_key.as_deref() {
+                ret.push((
+                    format!("wireguard-peer.{public_key}"),
+                    peer.to_keyfile(),
+                ));
+            }
+        }
+        ret
+    }
+}
+
+impl NmSettingWireGuardPeer {
+    pub(crate) fn to_keyfile(&self) -> HashMap<String, zvariant::Value<'_>> {
+        let mut ret = HashMap::new();
+        if let Some(v) = &self.endpoint {
+            ret.insert("endpoint".to_string(), zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.allowed_ips {
+            ret.insert("allowed-ips".to_string(), zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.preshared_key {
+            ret.insert("preshared-key".to_string(), zvariant::Value::new(v));
+        }
+        if let Some(v) = &self.persistent_keepalive {
+            ret.insert(
+                "persistent-keepalive".to_string(),
+                zvariant::Value::new(v),
+            );
+        }
+        ret
+    }
+}
@@ -29,13 +29,14 @@ pub use self::connection::{
     NmSetting8021X, NmSettingBond, NmSettingBondPort, NmSettingBridge,
     NmSettingBridgePort, NmSettingBridgeVlanRange, NmSettingConnection,
     NmSettingEthtool, NmSettingInfiniBand, NmSettingIp, NmSettingIpMethod,
-    NmSettingLoopback, NmSettingMacSec, NmSettingMacVlan, NmSettingOvsBridge,
-    NmSettingOvsDpdk, NmSettingOvsExtIds, NmSettingOvsIface,
-    NmSettingOvsOtherConfig, NmSettingOvsPatch, NmSettingOvsPort,
-    NmSettingSriov, NmSettingSriovVf, NmSettingSriovVfVlan, NmSettingUser,
+    NmSettingLink, NmSettingLoopback, NmSettingMacSec, NmSettingMacVlan,
+    NmSettingMatch, NmSettingOvsBridge, NmSettingOvsDpdk, NmSettingOvsExtIds,
+    NmSettingOvsIface, NmSettingOvsOtherConfig, NmSettingOvsPatch,
+    NmSettingOvsPort, NmSettingProxy, NmSettingSriov, NmSettingSriovVf,
+    NmSettingSriovVfVlan, NmSettingTeam, NmSettingTeamPort, NmSettingUser,
     NmSettingVeth, NmSettingVlan, NmSettingVlanFlag, NmSettingVpn,
-    NmSettingVrf, NmSettingVxlan, NmSettingWired, NmSettingsConnectionFlag,
-    NmVlanProtocol,
+    NmSettingVrf, NmSettingVxlan, NmSettingWireGuard, NmSettingWireGuardPeer,
+    NmSettingWired, NmSettingsConnectionFlag, NmVlanProtocol,
 };
 pub use self::device::{NmDevice, NmDeviceState, NmDeviceStateReason};
 #[cfg(feature = "query_apply")]
@@ -53,6 +54,13 @@ pub use self::lldp::{
 #[cfg(feature = "query_apply")]
 pub use self::nm_api::NmApi;
 
+pub(crate) use self::connection::{
+    NM_SETTING_PROXY_METHOD_AUTO, NM_SETTING_PROXY_METHOD_NONE,
+    NM_SETTING_SECRET_FLAG_AGENT_OWNED, NM_SETTING_SECRET_FLAG_NONE,
+    NM_SETTING_SECRET_FLAG_NOT_REQUIRED, NM_SETTING_SECRET_FLAG_NOT_SAVED,
+    NM_SRIOV_ESWITCH_ENCAP_MODE_BASIC, NM_SRIOV_ESWITCH_ENCAP_MODE_NONE,
+    NM_SRIOV_ESWITCH_MODE_LEGACY, NM_SRIOV_ESWITCH_MODE_SWITCHDEV,
+};
 pub(crate) use self::convert::ToDbusValue;
 #[cfg(feature = "gen_conf")]
 pub(crate) use self::gen_conf::ToKeyfile;
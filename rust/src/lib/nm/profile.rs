@@ -3,19 +3,33 @@
 use super::nm_dbus::{NmActiveConnection, NmConnection};
 use super::settings::{
     fix_ip_dhcp_timeout, get_exist_profile, iface_to_nm_connections,
+    is_nm_conn_nmstate_managed, mark_nm_conn_as_nmstate_managed,
     use_uuid_for_controller_reference, use_uuid_for_parent_reference,
+    NM_SETTING_BOND_SETTING_NAME, NM_SETTING_BRIDGE_SETTING_NAME,
+    NM_SETTING_OVS_BRIDGE_SETTING_NAME, NM_SETTING_OVS_IFACE_SETTING_NAME,
+    NM_SETTING_OVS_PORT_SETTING_NAME, NM_SETTING_USER_SPACES,
+    NM_SETTING_VRF_SETTING_NAME,
 };
 
 use crate::{
-    InterfaceType, MergedInterface, MergedInterfaces, MergedNetworkState,
-    NmstateError,
+    ErrorKind, InterfaceType, MergedInterface, MergedInterfaces,
+    MergedNetworkState, NmstateError,
 };
 
+pub(crate) const NM_SETTING_CONTROLLERS: [&str; 5] = [
+    NM_SETTING_BOND_SETTING_NAME,
+    NM_SETTING_BRIDGE_SETTING_NAME,
+    NM_SETTING_OVS_BRIDGE_SETTING_NAME,
+    NM_SETTING_OVS_PORT_SETTING_NAME,
+    NM_SETTING_VRF_SETTING_NAME,
+];
+
 #[allow(dead_code)]
 pub(crate) struct PerparedNmConnections {
     pub(crate) to_store: Vec<NmConnection>,
     pub(crate) to_activate: Vec<NmConnection>,
     pub(crate) to_deactivate: Vec<NmConnection>,
+    pub(crate) to_delete: Vec<NmConnection>,
 }
 
 pub(crate) fn perpare_nm_conns(
@@ -71,7 +85,7 @@ pub(crate) fn perpare_nm_conns(
             continue;
         };
 
-        for mut nm_conn in iface_to_nm_connections(
+        for nm_conn in iface_to_nm_connections(
             merged_iface,
             merged_state,
             exist_nm_conns,
@@ -97,22 +111,61 @@ pub(crate) fn perpare_nm_conns(
                 nm_conns_to_activate.push(nm_conn.clone());
                 nm_conns_to_deactivate.push(nm_conn.clone());
             }
-            if iface.is_down() && gen_conf_mode {
-                if let Some(nm_conn_set) = nm_conn.connection.as_mut() {
-                    nm_conn_set.autoconnect = Some(false);
-                }
-            }
             nm_conns_to_update.push(nm_conn);
         }
     }
 
     fix_ip_dhcp_timeout(&mut nm_conns_to_update);
 
+    // In maintenance mode, profiles are stored without activation and
+    // autoconnect.
+    if merged_state.maintenance {
+        for nm_conn in nm_conns_to_update.iter_mut() {
+            if let Some(nm_conn_set) = nm_conn.connection.as_mut() {
+                nm_conn_set.autoconnect = Some(false);
+            }
+        }
+        nm_conns_to_activate.clear();
+    }
+
+    let nm_conns_to_delete = if merged_state.purge_unmanaged {
+        nm_conns_to_update
+            .iter_mut()
+            .for_each(mark_nm_conn_as_nmstate_managed);
+        gen_nm_conns_to_purge(
+            merged_state,
+            exist_nm_conns,
+            nm_conns_to_update.as_slice(),
+        )
+    } else {
+        Vec::new()
+    };
+
+    if !merged_state.activation_order.is_empty() {
+        nm_conns_to_activate = order_nm_conns_for_activation(
+            nm_conns_to_activate.as_slice(),
+            merged_state.activation_order.as_slice(),
+        )?;
+    } else {
+        // sort_by_key() is stable, controllers are activated before other
+        // connections without changing their relative order.
+        nm_conns_to_activate.sort_by_key(|nm_conn| {
+            nm_conn
+                .iface_type()
+                .map(|t| NM_SETTING_CONTROLLERS.contains(&t))
+                != Some(true)
+        });
+    }
+    if !gen_conf_mode {
+        sort_ovs_nm_conns_for_activation(&mut nm_conns_to_activate);
+    }
+
     use_uuid_for_controller_reference(
         &mut nm_conns_to_update,
         &merged_state.interfaces,
         exist_nm_conns,
         nm_acs,
+        merged_state.controller_by_uuid,
     )?;
 
     use_uuid_for_parent_reference(
@@ -126,9 +179,38 @@ pub(crate) fn perpare_nm_conns(
         to_store: nm_conns_to_update,
         to_activate: nm_conns_to_activate,
         to_deactivate: nm_conns_to_deactivate,
+        to_delete: nm_conns_to_delete,
     })
 }
 
+// Previously nmstate managed profiles which are neither stored in this apply
+// nor belonging to interface mentioned in desired state.
+fn gen_nm_conns_to_purge(
+    merged_state: &MergedNetworkState,
+    exist_nm_conns: &[NmConnection],
+    nm_conns_to_store: &[NmConnection],
+) -> Vec<NmConnection> {
+    let stored_uuids: Vec<&str> =
+        nm_conns_to_store.iter().filter_map(|c| c.uuid()).collect();
+    let desired_iface_names: Vec<&str> = merged_state
+        .interfaces
+        .iter()
+        .filter(|i| i.desired.is_some())
+        .map(|i| i.merged.name())
+        .collect();
+    exist_nm_conns
+        .iter()
+        .filter(|c| is_nm_conn_nmstate_managed(c))
+        .filter(|c| c.uuid().map(|u| !stored_uuids.contains(&u)) == Some(true))
+        .filter(|c| {
+            c.iface_name()
+                .map(|n| !desired_iface_names.contains(&n))
+                .unwrap_or(true)
+        })
+        .cloned()
+        .collect()
+}
+
 // When a new virtual interface is desired, if its controller is also newly
 // created, in NetworkManager, there is no need to activate the subordinates.
 // For OVS stuff, always return false.
@@ -206,3 +288,136 @@ fn can_skip_activation(
     }
     false
 }
+
+// Group NmConnections into ordered waves, connections in the same wave only
+// depend on connections of previous waves through controller or parent
+// reference. Connections in each wave are sorted by ID.
+pub(crate) fn group_nm_conns_into_waves(
+    nm_conns: &[NmConnection],
+) -> Result<Vec<Vec<NmConnection>>, NmstateError> {
+    let deps: Vec<Vec<usize>> = nm_conns
+        .iter()
+        .map(|nm_conn| get_nm_conn_dependencies(nm_conn, nm_conns))
+        .collect();
+
+    let mut wave_indexes: Vec<usize> = vec![0; nm_conns.len()];
+    let mut changed = true;
+    let mut pass = 0;
+    while changed {
+        if pass > nm_conns.len() {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                "Found circular controller or parent reference among \
+                NetworkManager connections"
+                    .to_string(),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        changed = false;
+        for (i, conn_deps) in deps.iter().enumerate() {
+            for dep in conn_deps {
+                if wave_indexes[i] <= wave_indexes[*dep] {
+                    wave_indexes[i] = wave_indexes[*dep] + 1;
+                    changed = true;
+                }
+            }
+        }
+        pass += 1;
+    }
+
+    let wave_count = wave_indexes.iter().max().map(|i| i + 1).unwrap_or(0);
+    let mut ret: Vec<Vec<NmConnection>> = vec![Vec::new(); wave_count];
+    for (nm_conn, wave_index) in nm_conns.iter().zip(wave_indexes) {
+        ret[wave_index].push(nm_conn.clone());
+    }
+    for wave in ret.iter_mut() {
+        wave.sort_by(|a, b| a.id().cmp(&b.id()));
+    }
+    Ok(ret)
+}
+
+// Reorder NmConnections following user provided interface names, while still
+// activating connections in dependency waves. Connections not listed are placed
+// after listed ones of the same wave.
+pub(crate) fn order_nm_conns_for_activation(
+    nm_conns: &[NmConnection],
+    activation_order: &[String],
+) -> Result<Vec<NmConnection>, NmstateError> {
+    let mut ret = Vec::new();
+    for mut wave in group_nm_conns_into_waves(nm_conns)? {
+        // sort_by_key() is stable, unlisted connections are kept sorted by ID
+        wave.sort_by_key(|nm_conn| {
+            nm_conn
+                .iface_name()
+                .and_then(|n| activation_order.iter().position(|o| o == n))
+                .unwrap_or(usize::MAX)
+        });
+        ret.extend(wave);
+    }
+    Ok(ret)
+}
+
+// NetworkManager requires OVS bridge activated before its OVS ports, and OVS
+// port activated before its OVS interfaces or system interfaces. Reorder the
+// OVS connections within the slots they already occupy, so other connections
+// keep their position.
+// Only live apply activates connections, the gen_conf output is sorted by
+// connection ID instead.
+pub(crate) fn sort_ovs_nm_conns_for_activation(nm_conns: &mut [NmConnection]) {
+    let ovs_rank = |nm_conn: &NmConnection| -> Option<u8> {
+        match nm_conn.iface_type() {
+            Some(NM_SETTING_OVS_BRIDGE_SETTING_NAME) => Some(0),
+            Some(NM_SETTING_OVS_PORT_SETTING_NAME) => Some(1),
+            Some(NM_SETTING_OVS_IFACE_SETTING_NAME) => Some(2),
+            _ if nm_conn.controller_type()
+                == Some(NM_SETTING_OVS_PORT_SETTING_NAME) =>
+            {
+                Some(2)
+            }
+            _ => None,
+        }
+    };
+    let indexes: Vec<usize> = nm_conns
+        .iter()
+        .enumerate()
+        .filter_map(|(i, c)| ovs_rank(c).map(|_| i))
+        .collect();
+    let mut ovs_conns: Vec<NmConnection> =
+        indexes.iter().map(|i| nm_conns[*i].clone()).collect();
+    // sort_by_key() is stable, connections of the same rank keep their order
+    ovs_conns.sort_by_key(|c| ovs_rank(c).unwrap_or(u8::MAX));
+    for (i, nm_conn) in indexes.into_iter().zip(ovs_conns) {
+        nm_conns[i] = nm_conn;
+    }
+}
+
+// Return indexes of NmConnections referred as controller or parent.
+// The reference could be UUID or interface name.
+fn get_nm_conn_dependencies(
+    nm_conn: &NmConnection,
+    nm_conns: &[NmConnection],
+) -> Vec<usize> {
+    let mut ret = Vec::new();
+    if let Some(ctrl) = nm_conn.controller() {
+        if let Some(i) = nm_conns.iter().position(|c| {
+            c.uuid() == Some(ctrl)
+                || (c.iface_name() == Some(ctrl)
+                    && c.iface_type() == nm_conn.controller_type())
+        }) {
+            ret.push(i);
+        }
+    }
+    if let Some(parent) = nm_conn.parent() {
+        if let Some(i) = nm_conns.iter().position(|c| {
+            c.uuid() == Some(parent)
+                || (c.iface_name() == Some(parent)
+                    && c.iface_type()
+                        .map(|t| !NM_SETTING_USER_SPACES.contains(&t))
+                        == Some(true))
+        }) {
+            ret.push(i);
+        }
+    }
+    ret
+}
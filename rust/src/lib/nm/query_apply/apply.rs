@@ -1,6 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::HashSet;
+use std::str::FromStr;
 
 use super::super::{
     device::create_index_for_nm_devs,
@@ -14,7 +15,10 @@ use super::super::{
     query_apply::{
         activate_nm_profiles, create_index_for_nm_conns_by_name_type,
         deactivate_nm_profiles, delete_exist_profiles, delete_orphan_ovs_ports,
-        dispatch::apply_dispatch_script,
+        delete_profiles,
+        dispatch::{
+            apply_dispatch_script, apply_mptcp_script, apply_neighbor_script,
+        },
         dns::{
             is_iface_dns_desired, purge_global_dns_config,
             store_dns_config_via_global_api,
@@ -30,6 +34,7 @@ use super::super::{
         iface_type_to_nm, NM_SETTING_OVS_PORT_SETTING_NAME,
         NM_SETTING_VPN_SETTING_NAME,
     },
+    version::NmVersion,
 };
 
 use crate::{
@@ -46,7 +51,7 @@ pub(crate) fn nm_apply(
 ) -> Result<(), NmstateError> {
     let mut nm_api = NmApi::new().map_err(nm_error_to_nmstate)?;
 
-    check_nm_version(&nm_api);
+    let nm_version = check_nm_version(&nm_api);
 
     nm_api.set_checkpoint(checkpoint, timeout);
     nm_api.set_checkpoint_auto_refresh(true);
@@ -79,6 +84,7 @@ pub(crate) fn nm_apply(
     let nm_devs = nm_api.devices_get().map_err(nm_error_to_nmstate)?;
 
     let mut merged_state = merged_state.clone();
+    merged_state.nm_version = nm_version;
 
     store_route_config(&mut merged_state)?;
 
@@ -137,12 +143,22 @@ pub(crate) fn nm_apply(
         to_store: nm_conns_to_store,
         to_activate: nm_conns_to_activate,
         to_deactivate: nm_conns_to_deactivate,
+        to_delete: nm_conns_to_delete,
     } = perpare_nm_conns(
         &merged_state,
         exist_nm_conns.as_slice(),
         nm_acs.as_slice(),
         false,
     )?;
+    if merged_state.maintenance {
+        log::info!(
+            "Maintenance mode, storing profiles without activation: {:?}",
+            nm_conns_to_store
+                .iter()
+                .filter_map(|c| c.uuid())
+                .collect::<Vec<&str>>()
+        );
+    }
 
     let nm_ac_uuids: Vec<&str> =
         nm_acs.iter().map(|nm_ac| &nm_ac.uuid as &str).collect();
@@ -182,9 +198,25 @@ pub(crate) fn nm_apply(
             &exist_nm_conns,
             &nm_conns_to_activate,
         )?;
+        let uuids_to_purge: Vec<&str> =
+            nm_conns_to_delete.iter().filter_map(|c| c.uuid()).collect();
+        if !uuids_to_purge.is_empty() {
+            log::info!(
+                "Purging nmstate managed profiles not desired: {:?}",
+                uuids_to_purge
+            );
+            delete_profiles(&mut nm_api, uuids_to_purge.as_slice())?;
+        }
     }
 
-    activate_nm_profiles(&mut nm_api, nm_conns_to_activate.as_slice())?;
+    apply_neighbor_script(&merged_state.interfaces)?;
+    apply_mptcp_script(&merged_state.interfaces)?;
+
+    activate_nm_profiles(
+        &mut nm_api,
+        nm_conns_to_activate.as_slice(),
+        exist_nm_conns.as_slice(),
+    )?;
 
     deactivate_nm_profiles(&mut nm_api, nm_conns_to_deactivate.as_slice())?;
 
@@ -427,21 +459,17 @@ fn gen_nm_conn_need_to_deactivate_first(
     ret
 }
 
-fn check_nm_version(nm_api: &NmApi) {
-    if let Ok(versions) = nm_api.version().map(|ver_str| {
-        ver_str
-            .split('.')
-            .map(|v| v.parse::<i32>().unwrap_or_default())
-            .collect::<Vec<i32>>()
-    }) {
-        if let (Some(major), Some(minor)) = (versions.first(), versions.get(1))
-        {
-            if *major < 1 || *minor < 40 {
-                log::warn!(
-                    "Unsupported NetworkManager version {major}.{minor}, \
-                    expecting >= 1.40"
-                );
-            }
-        }
+fn check_nm_version(nm_api: &NmApi) -> Option<NmVersion> {
+    let version = nm_api
+        .version()
+        .ok()
+        .and_then(|ver_str| NmVersion::from_str(&ver_str).ok())?;
+    if version < NmVersion::new(1, 40, 0) {
+        log::warn!(
+            "Unsupported NetworkManager version {}.{}, expecting >= 1.40",
+            version.major,
+            version.minor
+        );
     }
+    Some(version)
 }
@@ -4,13 +4,14 @@ use crate::{
     nm::nm_dbus::NmDevice,
     nm::settings::{
         NM_SETTING_BOND_SETTING_NAME, NM_SETTING_BRIDGE_SETTING_NAME,
-        NM_SETTING_DUMMY_SETTING_NAME, NM_SETTING_HSR_SETTING_NAME,
-        NM_SETTING_INFINIBAND_SETTING_NAME, NM_SETTING_LOOPBACK_SETTING_NAME,
-        NM_SETTING_MACSEC_SETTING_NAME, NM_SETTING_MACVLAN_SETTING_NAME,
-        NM_SETTING_OVS_BRIDGE_SETTING_NAME, NM_SETTING_OVS_IFACE_SETTING_NAME,
+        NM_SETTING_DUMMY_SETTING_NAME, NM_SETTING_GENERIC_SETTING_NAME,
+        NM_SETTING_HSR_SETTING_NAME, NM_SETTING_INFINIBAND_SETTING_NAME,
+        NM_SETTING_LOOPBACK_SETTING_NAME, NM_SETTING_MACSEC_SETTING_NAME,
+        NM_SETTING_MACVLAN_SETTING_NAME, NM_SETTING_OVS_BRIDGE_SETTING_NAME,
+        NM_SETTING_OVS_IFACE_SETTING_NAME, NM_SETTING_TEAM_SETTING_NAME,
         NM_SETTING_VETH_SETTING_NAME, NM_SETTING_VLAN_SETTING_NAME,
         NM_SETTING_VRF_SETTING_NAME, NM_SETTING_VXLAN_SETTING_NAME,
-        NM_SETTING_WIRED_SETTING_NAME,
+        NM_SETTING_WIRED_SETTING_NAME, NM_SETTING_WIREGUARD_SETTING_NAME,
     },
     InterfaceType,
 };
@@ -38,6 +39,13 @@ pub(crate) fn nm_dev_iface_type_to_nmstate(nm_dev: &NmDevice) -> InterfaceType {
         NM_SETTING_INFINIBAND_SETTING_NAME => InterfaceType::InfiniBand,
         NM_SETTING_MACSEC_SETTING_NAME => InterfaceType::MacSec,
         NM_SETTING_HSR_SETTING_NAME => InterfaceType::Hsr,
+        NM_SETTING_TEAM_SETTING_NAME => InterfaceType::Team,
+        NM_SETTING_WIREGUARD_SETTING_NAME => InterfaceType::WireGuard,
+        // On NM 1.42- , the loopback is holding "generic" nm interface type,
+        // which is handled as unsupported interface.
+        NM_SETTING_GENERIC_SETTING_NAME if nm_dev.name != "lo" => {
+            InterfaceType::Generic
+        }
         _ => InterfaceType::Other(nm_dev.iface_type.to_string()),
     }
 }
@@ -1,16 +1,31 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::hash_map::Entry;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::io::{Read, Write};
 use std::os::unix::fs::OpenOptionsExt;
 
-use crate::{DispatchConfig, ErrorKind, MergedInterfaces, NmstateError};
+use crate::{
+    mptcp::get_mptcp_flags_overrides, DispatchConfig, ErrorKind,
+    InterfaceIpAddr, MergedInterface, MergedInterfaces, NmstateError,
+    StaticNeighborConfig,
+};
 
 const DEFAULT_DISPATCH_DIR: &str = "/etc/NetworkManager/dispatcher.d";
 
 const SCRIPT_START_COMMENT: &str = "## NMSTATE DISPATCH SCRIPT START";
 const SCRIPT_END_COMMENT: &str = "## NMSTATE DISPATCH SCRIPT END";
+const SYSCTL_SCRIPT_SUFFIX: &str = "sysctl.sh";
+const SYSCTL_PROC_DIR: &str = "/proc/sys/net";
+const NEIGHBOR_SCRIPT_SUFFIX: &str = "neighbor.sh";
+const MPTCP_SCRIPT_SUFFIX: &str = "mptcp.sh";
+// NetworkManager has no property for accepting MTU of IPv6 router
+// advertisement, we use sysctl instead.
+pub(crate) const SYSCTL_IPV6_ACCEPT_RA_MTU: &str = "ipv6.accept_ra_mtu";
+// NetworkManager has no property for IPv6 router advertisement acceptance
+// and forwarding of interface, we use sysctl instead.
+pub(crate) const SYSCTL_IPV6_ACCEPT_RA: &str = "ipv6.accept_ra";
+pub(crate) const SYSCTL_IPV6_FORWARDING: &str = "ipv6.forwarding";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum NmAction {
@@ -34,18 +49,33 @@ impl std::fmt::Display for NmAction {
 pub(crate) fn apply_dispatch_script(
     merged_ifaces: &MergedInterfaces,
 ) -> Result<(), NmstateError> {
-    for iface in merged_ifaces.kernel_ifaces.values().filter_map(|i| {
-        if i.is_desired() {
-            i.for_apply.as_ref()
+    for merged_iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_desired())
+    {
+        let iface = if let Some(i) = merged_iface.for_apply.as_ref() {
+            i
         } else {
-            None
-        }
-    }) {
+            continue;
+        };
         if iface.is_absent() {
             delete_dispatch_script(iface.name(), NmAction::Up)?;
             delete_dispatch_script(iface.name(), NmAction::Down)?;
-        } else if let Some(dispatch_conf) = iface.base_iface().dispatch.as_ref()
-        {
+            delete_sysctl_script(iface.name())?;
+            delete_neighbor_script(iface.name())?;
+            delete_mptcp_script(iface.name())?;
+            continue;
+        }
+        if let Some(sysctl) = gen_iface_sysctl(merged_iface) {
+            if sysctl.is_empty() {
+                delete_sysctl_script(iface.name())?;
+            } else {
+                create_sysctl_script(iface.name(), &sysctl)?;
+                apply_sysctl(iface.name(), &sysctl)?;
+            }
+        }
+        if let Some(dispatch_conf) = iface.base_iface().dispatch.as_ref() {
             let iface_name = iface.name();
             if let Some(post_up) = dispatch_conf.post_activation.as_deref() {
                 if post_up.is_empty() {
@@ -71,6 +101,92 @@ pub(crate) fn apply_dispatch_script(
     Ok(())
 }
 
+// The static neighbor dispatch script should be created before activation,
+// so the neighbors are applied when NetworkManager activates or reapplies the
+// interface.
+pub(crate) fn apply_neighbor_script(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    for iface in merged_ifaces.kernel_ifaces.values().filter_map(|i| {
+        if i.is_desired() {
+            i.for_apply.as_ref()
+        } else {
+            None
+        }
+    }) {
+        if iface.is_absent() {
+            continue;
+        }
+        if let Some(neighbors) = iface.base_iface().static_neighbors.as_deref()
+        {
+            if neighbors.is_empty() {
+                delete_neighbor_script(iface.name())?;
+            } else {
+                create_neighbor_script(iface.name(), neighbors)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// NetworkManager only supports connection level MPTCP flags, the IP address
+// specific MPTCP flags are stored as dispatch script which replaces the MPTCP
+// endpoints set by NetworkManager. Like static neighbors, the script should be
+// created before activation.
+// The script is regenerated only when IP addresses are desired.
+pub(crate) fn apply_mptcp_script(
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    for merged_iface in merged_ifaces
+        .kernel_ifaces
+        .values()
+        .filter(|i| i.is_desired())
+    {
+        if let Some(overrides) = gen_iface_mptcp_overrides(merged_iface) {
+            let iface_name = merged_iface.merged.name();
+            if overrides.is_empty() {
+                delete_mptcp_script(iface_name)?;
+            } else {
+                create_mptcp_script(iface_name, overrides.as_slice())?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// Desired IP addresses holding MPTCP flags different from the merged
+// interface level MPTCP flags. Return None if no IP address desired.
+pub(crate) fn gen_iface_mptcp_overrides(
+    merged_iface: &MergedInterface,
+) -> Option<Vec<&InterfaceIpAddr>> {
+    let apply_iface = merged_iface.for_apply.as_ref()?;
+    if apply_iface.is_absent() {
+        return None;
+    }
+    let base_iface = apply_iface.base_iface();
+    if base_iface
+        .ipv4
+        .as_ref()
+        .and_then(|i| i.addresses.as_ref())
+        .is_none()
+        && base_iface
+            .ipv6
+            .as_ref()
+            .and_then(|i| i.addresses.as_ref())
+            .is_none()
+    {
+        return None;
+    }
+    let iface_flags = merged_iface
+        .merged
+        .base_iface()
+        .mptcp
+        .as_ref()
+        .and_then(|m| m.address_flags.as_deref())
+        .unwrap_or_default();
+    Some(get_mptcp_flags_overrides(base_iface, iface_flags))
+}
+
 fn create_dispatch_script(
     iface_name: &str,
     content: &str,
@@ -214,3 +330,441 @@ fn gen_file_path(iface_name: &str, nm_action: NmAction) -> String {
 
     format!("{dir}/nmstate-{iface_name}-{nm_action}.sh")
 }
+
+// Desired sysctl with `accept-ra-mtu`, `accept-ra` and `forwarding` of IPv6
+// included. When only IPv6 properties are desired, the current sysctl is
+// preserved.
+pub(crate) fn gen_iface_sysctl(
+    merged_iface: &MergedInterface,
+) -> Option<BTreeMap<String, i64>> {
+    let apply_iface = merged_iface.for_apply.as_ref()?;
+    let sysctl = apply_iface.base_iface().sysctl.as_ref();
+    let mut ipv6_sysctl: Vec<(&str, i64)> = Vec::new();
+    if let Some(ipv6) =
+        apply_iface.base_iface().ipv6.as_ref().filter(|i| i.enabled)
+    {
+        if let Some(accept_ra_mtu) = ipv6.accept_ra_mtu {
+            ipv6_sysctl.push((SYSCTL_IPV6_ACCEPT_RA_MTU, accept_ra_mtu.into()));
+        }
+        if let Some(accept_ra) = ipv6.accept_ra {
+            ipv6_sysctl.push((SYSCTL_IPV6_ACCEPT_RA, accept_ra.into()));
+        }
+        if let Some(forwarding) = ipv6.forwarding {
+            ipv6_sysctl.push((SYSCTL_IPV6_FORWARDING, forwarding.into()));
+        }
+    }
+    if ipv6_sysctl.is_empty() {
+        sysctl.cloned()
+    } else {
+        let mut ret = sysctl
+            .or_else(|| {
+                merged_iface
+                    .current
+                    .as_ref()
+                    .and_then(|c| c.base_iface().sysctl.as_ref())
+            })
+            .cloned()
+            .unwrap_or_default();
+        for (key, value) in ipv6_sysctl {
+            ret.insert(key.to_string(), value);
+        }
+        Some(ret)
+    }
+}
+
+// The sysctl settings are stored as dispatch script which reapply them
+// whenever interface activated, each line is in the format of
+// `echo <value> > /proc/sys/net/<family>/conf/<iface_name>/<name>`.
+pub(crate) fn gen_sysctl_script(
+    iface_name: &str,
+    sysctl: &BTreeMap<String, i64>,
+) -> String {
+    let mut lines = Vec::new();
+    for (key, value) in sysctl.iter() {
+        if let Some((family, name)) = key.split_once('.') {
+            lines.push(format!(
+                "echo {value} > {}",
+                gen_sysctl_proc_path(iface_name, family, name)
+            ));
+        }
+    }
+    format!(
+        r#"#!/usr/bin/bash
+if [ "$1" == "{iface_name}" ] && \
+    {{ [ "$2" == "up" ] || [ "$2" == "reapply" ]; }}; then
+{SCRIPT_START_COMMENT}
+{}
+{SCRIPT_END_COMMENT}
+fi
+"#,
+        lines.join("\n")
+    )
+}
+
+fn gen_sysctl_proc_path(iface_name: &str, family: &str, name: &str) -> String {
+    format!("{SYSCTL_PROC_DIR}/{family}/conf/{iface_name}/{name}")
+}
+
+fn gen_sysctl_file_path(iface_name: &str) -> String {
+    let dir = std::env::var("NMSTATE_NM_DISPATCH_DIR")
+        .unwrap_or(DEFAULT_DISPATCH_DIR.to_string());
+
+    format!("{dir}/nmstate-{iface_name}-{SYSCTL_SCRIPT_SUFFIX}")
+}
+
+fn create_sysctl_script(
+    iface_name: &str,
+    sysctl: &BTreeMap<String, i64>,
+) -> Result<(), NmstateError> {
+    let file_path = gen_sysctl_file_path(iface_name);
+    if let Err(e) = write_execute_file(
+        file_path.as_str(),
+        gen_sysctl_script(iface_name, sysctl).as_str(),
+    ) {
+        return Err(NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Failed to create NetworkManager dispatch script \
+                {file_path} for sysctl: {e}"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn delete_sysctl_script(iface_name: &str) -> Result<(), NmstateError> {
+    let file_path = gen_sysctl_file_path(iface_name);
+    let path = std::path::Path::new(&file_path);
+
+    if path.exists() {
+        if let Err(e) = std::fs::remove_file(path) {
+            return Err(NmstateError::new(
+                ErrorKind::PermissionError,
+                format!(
+                    "Failed to remove sysctl dispatch script {file_path}, \
+                    error: {e}"
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+// The dispatch script only invoked on next activation, hence we apply the
+// sysctl settings to kernel also. Interface not exist in kernel(e.g. desired
+// as down) will be skipped.
+fn apply_sysctl(
+    iface_name: &str,
+    sysctl: &BTreeMap<String, i64>,
+) -> Result<(), NmstateError> {
+    for (key, value) in sysctl.iter() {
+        if let Some((family, name)) = key.split_once('.') {
+            let conf_dir =
+                format!("{SYSCTL_PROC_DIR}/{family}/conf/{iface_name}");
+            if !std::path::Path::new(&conf_dir).exists() {
+                log::debug!(
+                    "Skip applying sysctl {key} to {iface_name} as \
+                    {conf_dir} does not exist"
+                );
+                continue;
+            }
+            let file_path = gen_sysctl_proc_path(iface_name, family, name);
+            if let Err(e) = std::fs::write(&file_path, format!("{value}\n")) {
+                let e = NmstateError::new(
+                    ErrorKind::PluginFailure,
+                    format!(
+                        "Failed to set sysctl {key}={value} of interface \
+                        {iface_name} via {file_path}: {e}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn get_sysctls() -> HashMap<String, BTreeMap<String, i64>> {
+    let mut ret: HashMap<String, BTreeMap<String, i64>> = HashMap::new();
+    let dir = std::env::var("NMSTATE_NM_DISPATCH_DIR")
+        .unwrap_or(DEFAULT_DISPATCH_DIR.to_string());
+
+    if let Ok(fd) = std::fs::read_dir(&dir) {
+        for entry in fd.filter_map(Result::ok) {
+            let file_name = if let Ok(s) = entry.file_name().into_string() {
+                s
+            } else {
+                continue;
+            };
+            let iface_name = if let Some(n) =
+                file_name.strip_prefix("nmstate-").and_then(|n| {
+                    n.strip_suffix(&format!("-{SYSCTL_SCRIPT_SUFFIX}"))
+                }) {
+                n.to_string()
+            } else {
+                continue;
+            };
+            let file_path = format!("{dir}/{file_name}");
+            if let Some(content) = read_dispatch_script(&file_path) {
+                let sysctl = parse_sysctl_script(&iface_name, &content);
+                if !sysctl.is_empty() {
+                    ret.insert(iface_name, sysctl);
+                }
+            }
+        }
+    }
+    ret
+}
+
+fn parse_sysctl_script(
+    iface_name: &str,
+    content: &str,
+) -> BTreeMap<String, i64> {
+    let mut ret = BTreeMap::new();
+    let prefix = format!("{SYSCTL_PROC_DIR}/");
+    for line in content.lines() {
+        if let Some((value, path)) =
+            line.strip_prefix("echo ").and_then(|l| l.split_once(" > "))
+        {
+            let value = if let Ok(v) = value.parse::<i64>() {
+                v
+            } else {
+                continue;
+            };
+            if let Some((family, name)) = path
+                .strip_prefix(prefix.as_str())
+                .and_then(|p| p.split_once('/'))
+                .and_then(|(family, p)| {
+                    p.strip_prefix(&format!("conf/{iface_name}/"))
+                        .map(|name| (family, name))
+                })
+            {
+                ret.insert(format!("{family}.{name}"), value);
+            }
+        }
+    }
+    ret
+}
+
+// Each static neighbor is stored as a line in the format of
+// `ip neigh replace <ip> lladdr <mac> nud permanent dev <iface_name>`.
+pub(crate) fn gen_neighbor_script(
+    iface_name: &str,
+    neighbors: &[StaticNeighborConfig],
+) -> String {
+    let lines: Vec<String> = neighbors
+        .iter()
+        .map(|n| {
+            format!(
+                "ip neigh replace {} lladdr {} nud permanent dev {iface_name}",
+                n.ip, n.mac_address
+            )
+        })
+        .collect();
+    format!(
+        r#"#!/usr/bin/bash
+if [ "$1" == "{iface_name}" ] && \
+    {{ [ "$2" == "up" ] || [ "$2" == "reapply" ]; }}; then
+{SCRIPT_START_COMMENT}
+{}
+{SCRIPT_END_COMMENT}
+fi
+"#,
+        lines.join("\n")
+    )
+}
+
+fn gen_neighbor_file_path(iface_name: &str) -> String {
+    let dir = std::env::var("NMSTATE_NM_DISPATCH_DIR")
+        .unwrap_or(DEFAULT_DISPATCH_DIR.to_string());
+
+    format!("{dir}/nmstate-{iface_name}-{NEIGHBOR_SCRIPT_SUFFIX}")
+}
+
+fn create_neighbor_script(
+    iface_name: &str,
+    neighbors: &[StaticNeighborConfig],
+) -> Result<(), NmstateError> {
+    let file_path = gen_neighbor_file_path(iface_name);
+    if let Err(e) = write_execute_file(
+        file_path.as_str(),
+        gen_neighbor_script(iface_name, neighbors).as_str(),
+    ) {
+        return Err(NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Failed to create NetworkManager dispatch script \
+                {file_path} for static neighbors: {e}"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn delete_neighbor_script(iface_name: &str) -> Result<(), NmstateError> {
+    let file_path = gen_neighbor_file_path(iface_name);
+    let path = std::path::Path::new(&file_path);
+
+    if path.exists() {
+        if let Err(e) = std::fs::remove_file(path) {
+            return Err(NmstateError::new(
+                ErrorKind::PermissionError,
+                format!(
+                    "Failed to remove static neighbor dispatch script \
+                    {file_path}, error: {e}"
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
+
+pub(crate) fn get_static_neighbors(
+) -> HashMap<String, Vec<StaticNeighborConfig>> {
+    let mut ret: HashMap<String, Vec<StaticNeighborConfig>> = HashMap::new();
+    let dir = std::env::var("NMSTATE_NM_DISPATCH_DIR")
+        .unwrap_or(DEFAULT_DISPATCH_DIR.to_string());
+
+    if let Ok(fd) = std::fs::read_dir(&dir) {
+        for entry in fd.filter_map(Result::ok) {
+            let file_name = if let Ok(s) = entry.file_name().into_string() {
+                s
+            } else {
+                continue;
+            };
+            let iface_name = if let Some(n) =
+                file_name.strip_prefix("nmstate-").and_then(|n| {
+                    n.strip_suffix(&format!("-{NEIGHBOR_SCRIPT_SUFFIX}"))
+                }) {
+                n.to_string()
+            } else {
+                continue;
+            };
+            let file_path = format!("{dir}/{file_name}");
+            if let Some(content) = read_dispatch_script(&file_path) {
+                let neighbors = parse_neighbor_script(&iface_name, &content);
+                if !neighbors.is_empty() {
+                    ret.insert(iface_name, neighbors);
+                }
+            }
+        }
+    }
+    ret
+}
+
+pub(crate) fn parse_neighbor_script(
+    iface_name: &str,
+    content: &str,
+) -> Vec<StaticNeighborConfig> {
+    let mut ret = Vec::new();
+    for line in content.lines() {
+        if let [ip, "lladdr", mac, "nud", "permanent", "dev", name] = line
+            .trim()
+            .strip_prefix("ip neigh replace ")
+            .unwrap_or_default()
+            .split_whitespace()
+            .collect::<Vec<&str>>()
+            .as_slice()
+        {
+            if *name == iface_name {
+                let mut neighbor = StaticNeighborConfig::new();
+                neighbor.ip = ip.to_string();
+                neighbor.mac_address = mac.to_string();
+                ret.push(neighbor);
+            }
+        }
+    }
+    ret
+}
+
+// Each IP address is stored as two lines which remove the existing MPTCP
+// endpoint of this IP address and add it back with specified flags:
+// `ip mptcp endpoint show | awk '$1 == "<ip>" && $2 == "id" {print $3}' | \
+// xargs -r -n1 ip mptcp endpoint delete id`
+// `ip mptcp endpoint add <ip> dev <iface_name> <flags>`
+pub(crate) fn gen_mptcp_script(
+    iface_name: &str,
+    ip_addrs: &[&InterfaceIpAddr],
+) -> String {
+    let mut lines = Vec::new();
+    for ip_addr in ip_addrs {
+        let flags: Vec<String> = ip_addr
+            .mptcp_flags
+            .as_deref()
+            .unwrap_or_default()
+            .iter()
+            .map(|f| f.to_string())
+            .collect();
+        lines.push(format!(
+            "ip mptcp endpoint show | awk '$1 == \"{}\" && $2 == \"id\" \
+            {{print $3}}' | xargs -r -n1 ip mptcp endpoint delete id",
+            ip_addr.ip
+        ));
+        lines.push(
+            format!(
+                "ip mptcp endpoint add {} dev {iface_name} {}",
+                ip_addr.ip,
+                flags.join(" ")
+            )
+            .trim_end()
+            .to_string(),
+        );
+    }
+    format!(
+        r#"#!/usr/bin/bash
+if [ "$1" == "{iface_name}" ] && \
+    {{ [ "$2" == "up" ] || [ "$2" == "reapply" ]; }}; then
+{SCRIPT_START_COMMENT}
+{}
+{SCRIPT_END_COMMENT}
+fi
+"#,
+        lines.join("\n")
+    )
+}
+
+fn gen_mptcp_file_path(iface_name: &str) -> String {
+    let dir = std::env::var("NMSTATE_NM_DISPATCH_DIR")
+        .unwrap_or(DEFAULT_DISPATCH_DIR.to_string());
+
+    format!("{dir}/nmstate-{iface_name}-{MPTCP_SCRIPT_SUFFIX}")
+}
+
+fn create_mptcp_script(
+    iface_name: &str,
+    ip_addrs: &[&InterfaceIpAddr],
+) -> Result<(), NmstateError> {
+    let file_path = gen_mptcp_file_path(iface_name);
+    if let Err(e) = write_execute_file(
+        file_path.as_str(),
+        gen_mptcp_script(iface_name, ip_addrs).as_str(),
+    ) {
+        return Err(NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Failed to create NetworkManager dispatch script \
+                {file_path} for MPTCP flags: {e}"
+            ),
+        ));
+    }
+    Ok(())
+}
+
+fn delete_mptcp_script(iface_name: &str) -> Result<(), NmstateError> {
+    let file_path = gen_mptcp_file_path(iface_name);
+    let path = std::path::Path::new(&file_path);
+
+    if path.exists() {
+        if let Err(e) = std::fs::remove_file(path) {
+            return Err(NmstateError::new(
+                ErrorKind::PermissionError,
+                format!(
+                    "Failed to remove MPTCP dispatch script {file_path}, \
+                    error: {e}"
+                ),
+            ));
+        }
+    }
+    Ok(())
+}
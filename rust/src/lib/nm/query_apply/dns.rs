@@ -3,7 +3,7 @@
 use std::str::FromStr;
 
 use super::super::{
-    dns::extract_ipv6_link_local_iface_from_dns_srv,
+    dns::{extract_ipv6_link_local_iface_from_dns_srv, is_dns_routing_domain},
     error::nm_error_to_nmstate,
     nm_dbus::{NmApi, NmDnsEntry, NmGlobalDnsConfig, NmSettingIp},
 };
@@ -79,8 +79,13 @@ pub(crate) fn retrieve_dns_info(
         if let Some(srvs) = dns_conf.server.as_ref() {
             config_srvs.extend_from_slice(srvs);
         }
+        // The routing-only domains are stored into both IPv4 and IPv6
         if let Some(schs) = dns_conf.search.as_ref() {
-            config_schs.extend_from_slice(schs);
+            for sch in schs {
+                if !(is_dns_routing_domain(sch) && config_schs.contains(sch)) {
+                    config_schs.push(sch.clone());
+                }
+            }
         }
     }
 
@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::nm_dbus::NmSettingWired;
+
+use crate::{
+    EthernetClonedMac, EthernetConfig, EthernetS390Config, EthernetS390NetType,
+};
+
+pub(crate) fn nm_wired_to_ethernet_conf(
+    nm_wired_set: &NmSettingWired,
+) -> Option<EthernetConfig> {
+    let cloned_mac = nm_wired_set
+        .assigned_mac_address
+        .as_deref()
+        .and_then(|v| v.parse::<EthernetClonedMac>().ok());
+    let generate_mac_address_mask =
+        nm_wired_set.generate_mac_address_mask.clone();
+    let s390 = nm_wired_to_s390_conf(nm_wired_set);
+    if cloned_mac.is_none()
+        && generate_mac_address_mask.is_none()
+        && s390.is_none()
+    {
+        None
+    } else {
+        Some(EthernetConfig {
+            cloned_mac,
+            generate_mac_address_mask,
+            s390,
+            ..Default::default()
+        })
+    }
+}
+
+fn nm_wired_to_s390_conf(
+    nm_wired_set: &NmSettingWired,
+) -> Option<EthernetS390Config> {
+    let subchannels = nm_wired_set
+        .s390_subchannels
+        .clone()
+        .filter(|s| !s.is_empty());
+    let nettype = nm_wired_set.s390_nettype.as_deref().and_then(|t| match t {
+        "qeth" => Some(EthernetS390NetType::Qeth),
+        "lcs" => Some(EthernetS390NetType::Lcs),
+        "ctc" => Some(EthernetS390NetType::Ctc),
+        _ => {
+            log::warn!("Unsupported s390 nettype {t}");
+            None
+        }
+    });
+    let options = nm_wired_set
+        .s390_options
+        .as_ref()
+        .filter(|o| !o.is_empty())
+        .map(|o| {
+            o.iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect()
+        });
+    if subchannels.is_none() && nettype.is_none() && options.is_none() {
+        None
+    } else {
+        Some(EthernetS390Config {
+            subchannels,
+            nettype,
+            options,
+        })
+    }
+}
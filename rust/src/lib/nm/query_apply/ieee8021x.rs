@@ -1,8 +1,11 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use super::super::nm_dbus::NmSetting8021X;
+use super::super::nm_dbus::{
+    NmSetting8021X, NM_SETTING_SECRET_FLAG_AGENT_OWNED,
+    NM_SETTING_SECRET_FLAG_NOT_REQUIRED, NM_SETTING_SECRET_FLAG_NOT_SAVED,
+};
 
-use crate::Ieee8021XConfig;
+use crate::{Ieee8021XConfig, SecretStorageMode};
 
 pub(crate) fn nm_802_1x_to_nmstate(
     nm_setting: &NmSetting8021X,
@@ -20,6 +23,26 @@ pub(crate) fn nm_802_1x_to_nmstate(
             .and_then(vec_u8_to_file_path),
         ca_cert: nm_setting.ca_cert.as_deref().and_then(vec_u8_to_file_path),
         private_key_password: nm_setting.private_key_password.clone(),
+        private_key_password_storage: nm_setting
+            .private_key_password_flags
+            .and_then(nm_secret_flags_to_nmstate),
+        auth_timeout: None,
+        auth_retries: None,
+    }
+}
+
+// Secret stored by system is the default, hence not shown.
+pub(crate) fn nm_secret_flags_to_nmstate(
+    flags: u32,
+) -> Option<SecretStorageMode> {
+    if flags & NM_SETTING_SECRET_FLAG_AGENT_OWNED > 0 {
+        Some(SecretStorageMode::AgentOwned)
+    } else if flags & NM_SETTING_SECRET_FLAG_NOT_SAVED > 0 {
+        Some(SecretStorageMode::NotSaved)
+    } else if flags & NM_SETTING_SECRET_FLAG_NOT_REQUIRED > 0 {
+        Some(SecretStorageMode::NotRequired)
+    } else {
+        None
     }
 }
 
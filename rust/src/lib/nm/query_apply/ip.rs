@@ -10,7 +10,7 @@ use super::dns::nm_dns_to_nmstate;
 
 use crate::{
     AddressFamily, Dhcpv4ClientId, Dhcpv6Duid, InterfaceIpv4, InterfaceIpv6,
-    Ipv6AddrGenMode, RouteRuleAction, RouteRuleEntry, WaitIp,
+    Ipv6AddrGenMode, Ipv6Privacy, RouteRuleAction, RouteRuleEntry, WaitIp,
 };
 
 const ADDR_GEN_MODE_EUI64: i32 = 0;
@@ -18,6 +18,12 @@ const ADDR_GEN_MODE_STABLE_PRIVACY: i32 = 1;
 const ADDR_GEN_MODE_STABLE_DEFAULT_OR_EUI64: i32 = 2;
 const ADDR_GEN_MODE_STABLE_DEFAULT: i32 = 3;
 
+const IP6_PRIVACY_DISABLED: i32 = 0;
+const IP6_PRIVACY_PREFER_PUBLIC_ADDR: i32 = 1;
+const IP6_PRIVACY_PREFER_TEMP_ADDR: i32 = 2;
+
+const NM_REPLACE_LOCAL_RULE_YES: i32 = 1;
+
 pub(crate) fn nm_ip_setting_to_nmstate4(
     nm_ip_setting: &NmSettingIp,
 ) -> InterfaceIpv4 {
@@ -74,6 +80,18 @@ pub(crate) fn nm_ip_setting_to_nmstate4(
             } else {
                 None
             },
+            dhcp_vendor_class_identifier: if enabled && dhcp == Some(true) {
+                nm_ip_setting.dhcp_vendor_class_identifier.clone()
+            } else {
+                None
+            },
+            replace_local_rule: nm_replace_local_rule_to_nmstate(nm_ip_setting),
+            dns_priority: nm_ip_setting.dns_priority.filter(|p| *p != 0),
+            dad_timeout: if enabled {
+                nm_ip_setting.dad_timeout
+            } else {
+                None
+            },
             ..Default::default()
         }
     } else {
@@ -108,6 +126,8 @@ pub(crate) fn nm_ip_setting_to_nmstate6(
             auto_table_id,
             dns: Some(nm_dns_to_nmstate(iface_name, nm_ip_setting)),
             rules: nm_rules_to_nmstate(true, nm_ip_setting),
+            replace_local_rule: nm_replace_local_rule_to_nmstate(nm_ip_setting),
+            dns_priority: nm_ip_setting.dns_priority.filter(|p| *p != 0),
             dhcp_duid: nm_dhcp_duid_to_nmstate(nm_ip_setting),
             addr_gen_mode: {
                 if enabled {
@@ -127,6 +147,25 @@ pub(crate) fn nm_ip_setting_to_nmstate6(
             } else {
                 None
             },
+            dad_transmits: if enabled {
+                nm_ip_setting.dad_transmits
+            } else {
+                None
+            },
+            ip6_privacy: if enabled {
+                Some(nm_ipv6_privacy_to_nmstate(nm_ip_setting))
+            } else {
+                None
+            },
+            // The i32::MAX is the default value set by nmstate
+            ra_timeout: if enabled {
+                nm_ip_setting
+                    .ra_timeout
+                    .filter(|v| *v != i32::MAX)
+                    .map(|v| v as u32)
+            } else {
+                None
+            },
             ..Default::default()
         };
         // NetworkManager only set IPv6 token to kernel when IPv6 autoconf
@@ -194,17 +233,25 @@ fn nm_ipv6_addr_gen_mode_to_nmstate(
             Some(Ipv6AddrGenMode::StablePrivacy)
         }
         Some(&ADDR_GEN_MODE_STABLE_DEFAULT_OR_EUI64) => {
-            Some(Ipv6AddrGenMode::Eui64)
-        }
-        Some(&ADDR_GEN_MODE_STABLE_DEFAULT) => {
-            Some(Ipv6AddrGenMode::StablePrivacy)
+            Some(Ipv6AddrGenMode::DefaultOrEui64)
         }
+        Some(&ADDR_GEN_MODE_STABLE_DEFAULT) => Some(Ipv6AddrGenMode::Default),
         Some(s) => Some(Ipv6AddrGenMode::Other(format!("{s}"))),
         // According to NM document, the None in dbus means stable privacy.
         None => Some(Ipv6AddrGenMode::StablePrivacy),
     }
 }
 
+// The -1 or None in dbus means global default of NetworkManager.
+fn nm_ipv6_privacy_to_nmstate(nm_setting: &NmSettingIp) -> Ipv6Privacy {
+    match nm_setting.ip6_privacy {
+        Some(IP6_PRIVACY_DISABLED) => Ipv6Privacy::Disabled,
+        Some(IP6_PRIVACY_PREFER_PUBLIC_ADDR) => Ipv6Privacy::PreferPublicAddr,
+        Some(IP6_PRIVACY_PREFER_TEMP_ADDR) => Ipv6Privacy::PreferTempAddr,
+        _ => Ipv6Privacy::Default,
+    }
+}
+
 pub(crate) fn query_nmstate_wait_ip(
     ipv4_set: Option<&NmSettingIp>,
     ipv6_set: Option<&NmSettingIp>,
@@ -302,3 +349,14 @@ fn nm_rules_to_nmstate(
     }
     Some(ret)
 }
+
+// Only show replace-local-rule when enabled
+fn nm_replace_local_rule_to_nmstate(
+    nm_ip_setting: &NmSettingIp,
+) -> Option<bool> {
+    if nm_ip_setting.replace_local_rule == Some(NM_REPLACE_LOCAL_RULE_YES) {
+        Some(true)
+    } else {
+        None
+    }
+}
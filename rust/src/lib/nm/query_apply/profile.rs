@@ -6,12 +6,12 @@ use super::super::nm_dbus::{
     self, NmApi, NmConnection, NmSettingsConnectionFlag,
 };
 use super::super::{
+    delta::NmConnectionDelta,
     error::nm_error_to_nmstate,
+    profile::NM_SETTING_CONTROLLERS,
     settings::{
-        NM_SETTING_BOND_SETTING_NAME, NM_SETTING_BRIDGE_SETTING_NAME,
-        NM_SETTING_OVS_BRIDGE_SETTING_NAME, NM_SETTING_OVS_PORT_SETTING_NAME,
         NM_SETTING_VETH_SETTING_NAME, NM_SETTING_VPN_SETTING_NAME,
-        NM_SETTING_VRF_SETTING_NAME, NM_SETTING_WIRED_SETTING_NAME,
+        NM_SETTING_WIRED_SETTING_NAME,
     },
 };
 
@@ -20,14 +20,6 @@ use crate::NmstateError;
 const ACTIVATION_RETRY_COUNT: usize = 6;
 const ACTIVATION_RETRY_INTERVAL: u64 = 1;
 
-pub(crate) const NM_SETTING_CONTROLLERS: [&str; 5] = [
-    NM_SETTING_BOND_SETTING_NAME,
-    NM_SETTING_BRIDGE_SETTING_NAME,
-    NM_SETTING_OVS_BRIDGE_SETTING_NAME,
-    NM_SETTING_OVS_PORT_SETTING_NAME,
-    NM_SETTING_VRF_SETTING_NAME,
-];
-
 pub(crate) fn delete_exist_profiles(
     nm_api: &mut NmApi,
     exist_nm_conns: &[NmConnection],
@@ -136,6 +128,7 @@ pub(crate) fn save_nm_profiles(
 pub(crate) fn activate_nm_profiles(
     nm_api: &mut NmApi,
     nm_conns: &[NmConnection],
+    exist_nm_conns: &[NmConnection],
 ) -> Result<(), NmstateError> {
     let mut nm_conns = nm_conns.to_vec();
     let nm_acs = nm_api
@@ -150,6 +143,7 @@ pub(crate) fn activate_nm_profiles(
                 nm_api,
                 nm_conns.as_slice(),
                 nm_ac_uuids.as_slice(),
+                exist_nm_conns,
             )?;
             if remain_nm_conns.is_empty() {
                 break;
@@ -177,102 +171,102 @@ pub(crate) fn activate_nm_profiles(
     Ok(())
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum NmActivationAction {
+    Activate,
+    ReapplyOrActivate,
+}
+
+// Return `NmConnection` needing activation following the order of `nm_conns`,
+// which is expected to have controllers placed ahead of their ports.
+// Ports of newly activated controller are skipped as NetworkManager will
+// activate them along with controller.
+pub(crate) fn gen_nm_conn_activation_plan<'a>(
+    nm_conns: &'a [NmConnection],
+    nm_ac_uuids: &[&str],
+) -> Vec<(&'a NmConnection, NmActivationAction)> {
+    let mut new_controllers: Vec<&str> = Vec::new();
+    let mut ret = Vec::new();
+    for nm_conn in nm_conns {
+        let uuid = if let Some(u) = nm_conn.uuid() {
+            u
+        } else {
+            continue;
+        };
+        if nm_ac_uuids.contains(&uuid) {
+            ret.push((nm_conn, NmActivationAction::ReapplyOrActivate));
+            continue;
+        }
+        if nm_conn
+            .iface_type()
+            .map(|t| NM_SETTING_CONTROLLERS.contains(&t))
+            == Some(true)
+        {
+            new_controllers.push(uuid);
+        } else if let Some(ctrller) = nm_conn.controller() {
+            // OVS port does not do auto port activation.
+            if nm_conn.iface_type() != Some("ovs-interface")
+                && new_controllers.contains(&ctrller)
+                && nm_conn.controller_type() != Some("ovs-port")
+            {
+                log::info!(
+                    "Skip connection activation as its \
+                    controller already activated its ports: \
+                    {}: {}/{}",
+                    uuid,
+                    nm_conn.iface_name().unwrap_or(""),
+                    nm_conn.iface_type().unwrap_or("")
+                );
+                continue;
+            }
+        }
+        ret.push((nm_conn, NmActivationAction::Activate));
+    }
+    ret
+}
+
 // Return list of activation failed `NmConnection` which we can retry
 fn _activate_nm_profiles(
     nm_api: &mut NmApi,
     nm_conns: &[NmConnection],
     nm_ac_uuids: &[&str],
+    exist_nm_conns: &[NmConnection],
 ) -> Result<Vec<(NmConnection, NmstateError)>, NmstateError> {
-    let mut new_controllers: Vec<&str> = Vec::new();
     let mut failed_nm_conns: Vec<(NmConnection, NmstateError)> = Vec::new();
-    for nm_conn in nm_conns.iter().filter(|c| {
-        c.iface_type().map(|t| NM_SETTING_CONTROLLERS.contains(&t))
-            == Some(true)
-    }) {
-        if let Some(uuid) = nm_conn.uuid() {
-            log::info!(
-                "Activating connection {}: {}/{}",
-                uuid,
-                nm_conn.iface_name().unwrap_or(""),
-                nm_conn.iface_type().unwrap_or("")
-            );
-            if nm_ac_uuids.contains(&uuid) {
-                if let Err(e) = reapply_or_activate(nm_api, nm_conn) {
-                    if e.kind().can_retry() {
-                        failed_nm_conns.push((nm_conn.clone(), e));
-                    } else {
-                        return Err(e);
-                    }
-                }
-            } else {
-                new_controllers.push(uuid);
-                if let Err(e) = nm_api
-                    .connection_activate(uuid)
-                    .map_err(nm_error_to_nmstate)
-                {
-                    if e.kind().can_retry() {
-                        failed_nm_conns.push((nm_conn.clone(), e));
-                    } else {
-                        return Err(e);
-                    }
-                }
-            }
-        }
-    }
-    for nm_conn in nm_conns.iter().filter(|c| {
-        c.iface_type().map(|t| NM_SETTING_CONTROLLERS.contains(&t))
-            != Some(true)
-    }) {
-        if let Some(uuid) = nm_conn.uuid() {
-            if nm_ac_uuids.contains(&uuid) {
+    for (nm_conn, action) in gen_nm_conn_activation_plan(nm_conns, nm_ac_uuids)
+    {
+        let uuid = if let Some(u) = nm_conn.uuid() {
+            u
+        } else {
+            continue;
+        };
+        let result = match action {
+            NmActivationAction::ReapplyOrActivate => {
                 log::info!(
                     "Reapplying connection {}: {}/{}",
                     uuid,
                     nm_conn.iface_name().unwrap_or(""),
                     nm_conn.iface_type().unwrap_or("")
                 );
-                if let Err(e) = reapply_or_activate(nm_api, nm_conn) {
-                    if e.kind().can_retry() {
-                        failed_nm_conns.push((nm_conn.clone(), e));
-                    } else {
-                        return Err(e);
-                    }
-                }
-            } else {
-                if let Some(ctrller) = nm_conn.controller() {
-                    if nm_conn.iface_type() != Some("ovs-interface") {
-                        // OVS port does not do auto port activation.
-                        if new_controllers.contains(&ctrller)
-                            && nm_conn.controller_type() != Some("ovs-port")
-                        {
-                            log::info!(
-                                "Skip connection activation as its \
-                                controller already activated its ports: \
-                                {}: {}/{}",
-                                uuid,
-                                nm_conn.iface_name().unwrap_or(""),
-                                nm_conn.iface_type().unwrap_or("")
-                            );
-                            continue;
-                        }
-                    }
-                }
+                reapply_or_activate(nm_api, nm_conn, exist_nm_conns)
+            }
+            NmActivationAction::Activate => {
                 log::info!(
                     "Activating connection {}: {}/{}",
                     uuid,
                     nm_conn.iface_name().unwrap_or(""),
                     nm_conn.iface_type().unwrap_or("")
                 );
-                if let Err(e) = nm_api
+                nm_api
                     .connection_activate(uuid)
                     .map_err(nm_error_to_nmstate)
-                {
-                    if e.kind().can_retry() {
-                        failed_nm_conns.push((nm_conn.clone(), e));
-                    } else {
-                        return Err(e);
-                    }
-                }
+            }
+        };
+        if let Err(e) = result {
+            if e.kind().can_retry() {
+                failed_nm_conns.push((nm_conn.clone(), e));
+            } else {
+                return Err(e);
             }
         }
     }
@@ -360,10 +354,56 @@ pub(crate) fn delete_profiles(
     Ok(())
 }
 
+// Activate directly when the changes to existing profile cannot be
+// reapplied, otherwise try `Reapply()` and fallback to activation on failure.
 fn reapply_or_activate(
     nm_api: &mut NmApi,
     nm_conn: &NmConnection,
+    exist_nm_conns: &[NmConnection],
 ) -> Result<(), NmstateError> {
+    if let (Some(uuid), Some(exist_nm_conn)) = (
+        nm_conn.uuid(),
+        exist_nm_conns
+            .iter()
+            .find(|c| c.uuid().is_some() && c.uuid() == nm_conn.uuid()),
+    ) {
+        let delta = NmConnectionDelta::new(exist_nm_conn, nm_conn);
+        match exist_nm_conn.diff(nm_conn) {
+            Ok(changes) => {
+                for change in changes {
+                    log::debug!(
+                        "Connection {} changing {}.{} from {:?} to {:?}",
+                        uuid,
+                        change.setting,
+                        change.key,
+                        change.old_value,
+                        change.new_value
+                    );
+                }
+            }
+            Err(e) => {
+                log::debug!("Failed to diff connection {}: {}", uuid, e);
+            }
+        }
+        if delta.need_reactivation(exist_nm_conn) {
+            log::info!(
+                "Changes to settings {:?} of connection {} cannot be \
+                reapplied, activating instead",
+                delta
+                    .changed
+                    .iter()
+                    .chain(delta.removed.iter())
+                    .collect::<Vec<_>>(),
+                uuid
+            );
+            return nm_api
+                .connection_activate(uuid)
+                .map_err(nm_error_to_nmstate);
+        }
+        if delta.is_empty() {
+            log::debug!("No setting changed in connection {}", uuid);
+        }
+    }
     if let Err(e) = nm_api.connection_reapply(nm_conn) {
         if let Some(uuid) = nm_conn.uuid() {
             log::debug!(
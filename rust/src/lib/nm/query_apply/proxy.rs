@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::nm_dbus::{
+    NmSettingProxy, NM_SETTING_PROXY_METHOD_AUTO, NM_SETTING_PROXY_METHOD_NONE,
+};
+
+use crate::{ProxyConfig, ProxyMethod};
+
+// NetworkManager include proxy setting with method `none` in every profile,
+// only show proxy configuration when method is auto.
+pub(crate) fn nm_proxy_to_nmstate(
+    nm_setting: &NmSettingProxy,
+) -> Option<ProxyConfig> {
+    match nm_setting.method {
+        Some(NM_SETTING_PROXY_METHOD_AUTO) => {
+            let mut conf = ProxyConfig::new();
+            conf.method = Some(ProxyMethod::Auto);
+            conf.browser_only = nm_setting.browser_only;
+            conf.pac_url = nm_setting.pac_url.clone();
+            conf.pac_script = nm_setting.pac_script.clone();
+            Some(conf)
+        }
+        Some(NM_SETTING_PROXY_METHOD_NONE) | None => None,
+        Some(v) => {
+            log::warn!("Unknown NetworkManager proxy method {v}");
+            None
+        }
+    }
+}
@@ -0,0 +1,15 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::nm_dbus::NmSettingTeam;
+
+use crate::{TeamConfig, TeamRunner};
+
+pub(crate) fn nm_team_to_nmstate(nm_team_set: &NmSettingTeam) -> TeamConfig {
+    let mut team_conf = TeamConfig::new();
+    team_conf.runner = nm_team_set
+        .runner
+        .as_deref()
+        .and_then(|r| r.parse::<TeamRunner>().ok());
+    team_conf.config = nm_team_set.config.clone();
+    team_conf
+}
@@ -1,8 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use std::collections::BTreeMap;
+
 use super::super::nm_dbus::NmConnection;
 
-use super::super::settings::NMSTATE_DESCRIPTION;
+use super::super::settings::{NMSTATE_DESCRIPTION, NMSTATE_USER_DATA_DOMAIN};
 
 pub(crate) fn get_description(nm_conn: &NmConnection) -> Option<String> {
     Some(
@@ -15,3 +17,21 @@ pub(crate) fn get_description(nm_conn: &NmConnection) -> Option<String> {
             .unwrap_or_default(),
     )
 }
+
+pub(crate) fn get_user_data(
+    nm_conn: &NmConnection,
+) -> Option<BTreeMap<String, String>> {
+    let user_data: BTreeMap<String, String> = nm_conn
+        .user
+        .as_ref()
+        .and_then(|nm_setting| nm_setting.data.as_ref())?
+        .iter()
+        .filter(|(k, _)| !k.starts_with(NMSTATE_USER_DATA_DOMAIN))
+        .map(|(k, v)| (k.to_string(), v.to_string()))
+        .collect();
+    if user_data.is_empty() {
+        None
+    } else {
+        Some(user_data)
+    }
+}
@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::nm_dbus::NmSettingWireGuard;
+use super::ieee8021x::nm_secret_flags_to_nmstate;
+
+use crate::{WireGuardConfig, WireGuardPeerConfig};
+
+// The secrets are only available in saved connection
+pub(crate) fn nm_wireguard_to_nmstate(
+    nm_set: &NmSettingWireGuard,
+    nm_saved_set: Option<&NmSettingWireGuard>,
+) -> WireGuardConfig {
+    let mut wg_conf = WireGuardConfig::new();
+    wg_conf.private_key = nm_saved_set.and_then(|s| s.private_key.clone());
+    wg_conf.private_key_storage = nm_set
+        .private_key_flags
+        .and_then(nm_secret_flags_to_nmstate);
+    wg_conf.listen_port =
+        nm_set.listen_port.and_then(|p| u16::try_from(p).ok());
+    wg_conf.fwmark = nm_set.fwmark;
+    wg_conf.peers = nm_set.peers.as_ref().map(|nm_peers| {
+        let saved_peers = nm_saved_set
+            .and_then(|s| s.peers.as_deref())
+            .unwrap_or_default();
+        nm_peers
+            .iter()
+            .filter_map(|nm_peer| {
+                let public_key = nm_peer.public_key.as_ref()?;
+                let mut peer = WireGuardPeerConfig::new();
+                peer.public_key = public_key.clone();
+                peer.preshared_key = saved_peers
+                    .iter()
+                    .find(|p| p.public_key.as_ref() == Some(public_key))
+                    .and_then(|p| p.preshared_key.clone());
+                peer.endpoint = nm_peer.endpoint.clone();
+                peer.allowed_ips = nm_peer.allowed_ips.clone();
+                peer.persistent_keepalive = nm_peer.persistent_keepalive;
+                Some(peer)
+            })
+            .collect()
+    });
+    wg_conf
+}
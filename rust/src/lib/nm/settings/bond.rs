@@ -2,9 +2,10 @@
 
 use std::collections::HashMap;
 
+use super::mac::canonicalize_mac_address;
 use crate::nm::nm_dbus::{NmConnection, NmSettingBond};
 
-use crate::{BondConfig, BondInterface, BondOptions};
+use crate::{BondConfig, BondInterface, BondMode, BondOptions, NmstateError};
 
 const DEFAULT_ARP_MISSED_MAX: u8 = 2;
 
@@ -29,10 +30,28 @@ pub(crate) fn get_bond_balance_slb(nm_conn: &NmConnection) -> Option<bool> {
     }
 }
 
+// The kernel netlink interface queried by nispor does not expose
+// peer_notif_delay, hence read it from NetworkManager bond setting.
+pub(crate) fn get_bond_peer_notif_delay(nm_conn: &NmConnection) -> Option<u32> {
+    nm_conn
+        .bond
+        .as_ref()
+        .and_then(|nm_bond_setting| {
+            nm_bond_setting.options.get("peer_notif_delay")
+        })
+        .and_then(|v| match v.parse::<u32>() {
+            Ok(i) => Some(i),
+            Err(e) => {
+                log::warn!("Invalid bond peer_notif_delay {v}: {e}");
+                None
+            }
+        })
+}
+
 pub(crate) fn gen_nm_bond_setting(
     bond_iface: &BondInterface,
     nm_conn: &mut NmConnection,
-) {
+) -> Result<(), NmstateError> {
     let mut nm_bond_setting =
         nm_conn.bond.as_ref().cloned().unwrap_or_default();
 
@@ -43,11 +62,12 @@ pub(crate) fn gen_nm_bond_setting(
     if let Some(bond_conf) = bond_iface.bond.as_ref() {
         apply_bond_mode(&mut nm_bond_setting, bond_conf);
         if let Some(bond_opts) = bond_conf.options.as_ref() {
-            apply_bond_options(&mut nm_bond_setting, bond_opts);
+            apply_bond_options(&mut nm_bond_setting, bond_opts)?;
         }
     }
 
     nm_conn.bond = Some(nm_bond_setting);
+    Ok(())
 }
 
 fn apply_bond_mode(nm_bond_set: &mut NmSettingBond, bond_conf: &BondConfig) {
@@ -64,21 +84,33 @@ fn apply_bond_mode(nm_bond_set: &mut NmSettingBond, bond_conf: &BondConfig) {
 fn apply_bond_options(
     nm_bond_set: &mut NmSettingBond,
     bond_opts: &BondOptions,
-) {
+) -> Result<(), NmstateError> {
     if let Some(v) = bond_opts.ad_actor_sys_prio.as_ref() {
         nm_bond_set
             .options
             .insert("ad_actor_sys_prio".to_string(), v.to_string());
     }
     if let Some(v) = bond_opts.ad_actor_system.as_ref() {
-        nm_bond_set
-            .options
-            .insert("ad_actor_system".to_string(), v.to_string());
+        nm_bond_set.options.insert(
+            "ad_actor_system".to_string(),
+            canonicalize_mac_address(v)?,
+        );
     }
+    // The ad_select and min_links are only meaningful in 802.3ad mode
+    let is_lacp =
+        nm_bond_set.options.get("mode") == Some(&BondMode::LACP.to_string());
     if let Some(v) = bond_opts.ad_select.as_ref() {
-        nm_bond_set
-            .options
-            .insert("ad_select".to_string(), v.to_string());
+        if is_lacp {
+            nm_bond_set
+                .options
+                .insert("ad_select".to_string(), v.to_string());
+        } else {
+            log::warn!(
+                "Ignoring bond option ad_select as it is only supported \
+                in 802.3ad mode"
+            );
+            nm_bond_set.options.remove("ad_select");
+        }
     }
     if let Some(v) = bond_opts.ad_user_port_key.as_ref() {
         nm_bond_set
@@ -141,9 +173,17 @@ fn apply_bond_options(
             .insert("miimon".to_string(), v.to_string());
     }
     if let Some(v) = bond_opts.min_links.as_ref() {
-        nm_bond_set
-            .options
-            .insert("min_links".to_string(), v.to_string());
+        if is_lacp {
+            nm_bond_set
+                .options
+                .insert("min_links".to_string(), v.to_string());
+        } else {
+            log::warn!(
+                "Ignoring bond option min_links as it is only supported \
+                in 802.3ad mode"
+            );
+            nm_bond_set.options.remove("min_links");
+        }
     }
     if let Some(v) = bond_opts.num_grat_arp.as_ref() {
         nm_bond_set
@@ -160,6 +200,11 @@ fn apply_bond_options(
             .options
             .insert("packets_per_slave".to_string(), v.to_string());
     }
+    if let Some(v) = bond_opts.peer_notif_delay.as_ref() {
+        nm_bond_set
+            .options
+            .insert("peer_notif_delay".to_string(), v.to_string());
+    }
     if let Some(v) = bond_opts.primary.as_ref() {
         nm_bond_set.options.insert("primary".to_string(), v.clone());
     }
@@ -216,6 +261,7 @@ fn apply_bond_options(
 
     // Remove all empty string option
     nm_bond_set.options.retain(|_, v| !v.is_empty());
+    Ok(())
 }
 
 pub(crate) fn gen_nm_bond_port_setting(
@@ -100,6 +100,9 @@ fn apply_br_options(
     if let Some(v) = br_opts.vlan_default_pvid.as_ref() {
         nm_br_set.vlan_default_pvid = Some((*v).into());
     }
+    if let Some(v) = br_opts.vlan_stats_enabled.as_ref() {
+        nm_br_set.vlan_stats_enabled = Some(*v);
+    }
 
     if let Some(stp_opts) = br_opts.stp.as_ref() {
         apply_stp_setting(nm_br_set, stp_opts);
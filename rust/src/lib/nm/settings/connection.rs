@@ -2,8 +2,9 @@
 
 use super::super::nm_dbus::{
     NmConnection, NmSettingConnection, NmSettingMacVlan, NmSettingVeth,
-    NmSettingVrf, NmSettingVxlan, NmSettingsConnectionFlag,
+    NmSettingVrf, NmSettingsConnectionFlag,
 };
+use super::super::version::NmVersion;
 use super::{
     bond::{gen_nm_bond_port_setting, gen_nm_bond_setting},
     bridge::{gen_nm_br_port_setting, gen_nm_br_setting},
@@ -11,25 +12,35 @@ use super::{
     hsr::gen_nm_hsr_setting,
     ieee8021x::gen_nm_802_1x_setting,
     infiniband::gen_nm_ib_setting,
-    ip::gen_nm_ip_setting,
+    ip::{
+        fallback_nm_addr_gen_mode, fallback_nm_replace_local_rule,
+        gen_nm_ip_setting,
+    },
     loopback::gen_nm_loopback_setting,
+    mac_vlan::gen_nm_mac_vtap_tap_flags,
     macsec::gen_nm_macsec_setting,
     mptcp::apply_mptcp_conf,
     ovs::{
         create_ovs_port_nm_conn, gen_nm_iface_ovs_db_setting,
         gen_nm_ovs_br_setting, gen_nm_ovs_iface_setting, get_ovs_port_name,
     },
+    proxy::gen_nm_proxy_setting,
     sriov::gen_nm_sriov_setting,
+    team::{gen_nm_team_port_setting, gen_nm_team_setting},
     user::gen_nm_user_setting,
     veth::create_veth_peer_profile_if_not_found,
     vlan::gen_nm_vlan_setting,
     vpn::gen_nm_ipsec_vpn_setting,
-    wired::gen_nm_wired_setting,
+    vxlan::gen_nm_vxlan_setting,
+    wired::{check_mtu_against_parent, gen_nm_wired_setting},
+    wireguard::gen_nm_wireguard_setting,
 };
 
 use crate::{
-    ErrorKind, Interface, InterfaceIdentifier, InterfaceType, MergedInterface,
-    MergedNetworkState, NmstateError, OvsBridgePortConfig,
+    ErrorKind, Interface, InterfaceIdentifier, InterfaceMultiConnect,
+    InterfaceNmFlag, InterfaceResolveMode, InterfaceType, MergedInterface,
+    MergedInterfaces, MergedNetworkState, NmstateError, OvsBridgePortConfig,
+    RouteEntry,
 };
 
 pub(crate) const NM_SETTING_BRIDGE_SETTING_NAME: &str = "bridge";
@@ -48,14 +59,27 @@ pub(crate) const NM_SETTING_VXLAN_SETTING_NAME: &str = "vxlan";
 pub(crate) const NM_SETTING_INFINIBAND_SETTING_NAME: &str = "infiniband";
 pub(crate) const NM_SETTING_LOOPBACK_SETTING_NAME: &str = "loopback";
 pub(crate) const NM_SETTING_HSR_SETTING_NAME: &str = "hsr";
+pub(crate) const NM_SETTING_TEAM_SETTING_NAME: &str = "team";
 pub(crate) const NM_SETTING_VPN_SETTING_NAME: &str = "vpn";
+pub(crate) const NM_SETTING_GENERIC_SETTING_NAME: &str = "generic";
+pub(crate) const NM_SETTING_WIREGUARD_SETTING_NAME: &str = "wireguard";
+
+pub(crate) const NM_MULTI_CONNECT_DEFAULT: i32 = 0;
+pub(crate) const NM_MULTI_CONNECT_SINGLE: i32 = 1;
+pub(crate) const NM_MULTI_CONNECT_MANUAL_MULTIPLE: i32 = 2;
+pub(crate) const NM_MULTI_CONNECT_MULTIPLE: i32 = 3;
+
+pub(crate) const NM_RESOLVE_MODE_DEFAULT: i32 = -1;
+pub(crate) const NM_RESOLVE_MODE_NO: i32 = 0;
+pub(crate) const NM_RESOLVE_MODE_RESOLVE: i32 = 1;
+pub(crate) const NM_RESOLVE_MODE_YES: i32 = 2;
 
 pub(crate) const NM_SETTING_USER_SPACES: [&str; 2] = [
     NM_SETTING_OVS_BRIDGE_SETTING_NAME,
     NM_SETTING_OVS_PORT_SETTING_NAME,
 ];
 
-pub(crate) const SUPPORTED_NM_KERNEL_IFACE_TYPES: [&str; 14] = [
+pub(crate) const SUPPORTED_NM_KERNEL_IFACE_TYPES: [&str; 17] = [
     NM_SETTING_WIRED_SETTING_NAME,
     NM_SETTING_VETH_SETTING_NAME,
     NM_SETTING_BOND_SETTING_NAME,
@@ -70,6 +94,9 @@ pub(crate) const SUPPORTED_NM_KERNEL_IFACE_TYPES: [&str; 14] = [
     NM_SETTING_INFINIBAND_SETTING_NAME,
     NM_SETTING_MACSEC_SETTING_NAME,
     NM_SETTING_HSR_SETTING_NAME,
+    NM_SETTING_TEAM_SETTING_NAME,
+    NM_SETTING_WIREGUARD_SETTING_NAME,
+    NM_SETTING_GENERIC_SETTING_NAME,
 ];
 
 pub(crate) fn iface_to_nm_connections(
@@ -87,25 +114,54 @@ pub(crate) fn iface_to_nm_connections(
         return Ok(ret);
     };
 
-    let base_iface = iface.base_iface();
-    let exist_nm_conn =
-        if base_iface.identifier == Some(InterfaceIdentifier::MacAddress) {
-            get_exist_profile_by_profile_name(
-                exist_nm_conns,
-                base_iface
-                    .profile_name
-                    .as_deref()
-                    .unwrap_or(base_iface.name.as_str()),
-                &base_iface.iface_type,
-            )
+    // The controller type might be absent when the controller is only
+    // mentioned in current state, resolve it from merged state so that
+    // port settings are still generated.
+    let resolved_iface;
+    let iface = if iface.base_iface().controller_type.is_none() {
+        if let Some(ctrl_iface) = iface
+            .base_iface()
+            .controller
+            .as_deref()
+            .and_then(|ctrl| get_ctrl_iface(ctrl, None, merged_state))
+        {
+            let mut new_iface = iface.clone();
+            new_iface.base_iface_mut().controller_type =
+                Some(ctrl_iface.merged.iface_type());
+            resolved_iface = new_iface;
+            &resolved_iface
         } else {
-            get_exist_profile(
-                exist_nm_conns,
-                &base_iface.name,
-                &base_iface.iface_type,
-                nm_ac_uuids,
-            )
-        };
+            iface
+        }
+    } else {
+        iface
+    };
+
+    let base_iface = iface.base_iface();
+
+    if merged_state.strict {
+        validate_strict_ignored_props(merged_iface, iface)?;
+    }
+    let exist_nm_conn = if base_iface.identifier
+        == Some(InterfaceIdentifier::MacAddress)
+        || base_iface.is_bind_by_glob()
+    {
+        get_exist_profile_by_profile_name(
+            exist_nm_conns,
+            base_iface
+                .profile_name
+                .as_deref()
+                .unwrap_or(base_iface.name.as_str()),
+            &base_iface.iface_type,
+        )
+    } else {
+        get_exist_profile(
+            exist_nm_conns,
+            &base_iface.name,
+            &base_iface.iface_type,
+            nm_ac_uuids,
+        )
+    };
     if iface.is_up_exist_config() {
         if let Some(nm_conn) = exist_nm_conn {
             if !iface.is_userspace()
@@ -115,13 +171,21 @@ pub(crate) fn iface_to_nm_connections(
                 // But NetworkManager does not include routes for external
                 // managed interfaces.
                 if let Some(cur_iface) = merged_iface.current.as_ref() {
-                    return persisten_iface_cur_conf(
+                    let mut nm_conns = persisten_iface_cur_conf(
                         cur_iface,
                         merged_state,
                         exist_nm_conns,
                         nm_ac_uuids,
                         gen_conf_mode,
-                    );
+                    )?;
+                    for nm_conn in nm_conns.iter_mut() {
+                        nm_conn.flags = if merged_state.volatile_external {
+                            vec![NmSettingsConnectionFlag::Volatile]
+                        } else {
+                            Vec::new()
+                        };
+                    }
+                    return Ok(nm_conns);
                 }
             }
             return Ok(vec![nm_conn.clone()]);
@@ -140,30 +204,94 @@ pub(crate) fn iface_to_nm_connections(
             }
         }
     }
+    let nm_flags = base_iface.nm_flags.as_deref().unwrap_or_default();
+    if nm_flags.contains(&InterfaceNmFlag::External) {
+        if let Some(nm_conn) = exist_nm_conn
+            .filter(|c| c.flags.contains(&NmSettingsConnectionFlag::External))
+        {
+            return Ok(vec![nm_conn.clone()]);
+        }
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Interface {} is not managed externally by NetworkManager, \
+                cannot use `nm-flags: [external]`",
+                base_iface.name
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+
     let mut nm_conn = exist_nm_conn.cloned().unwrap_or_default();
-    nm_conn.flags = Vec::new();
+    nm_conn.flags = nm_flags
+        .iter()
+        .filter_map(|f| match f {
+            InterfaceNmFlag::Volatile => {
+                Some(NmSettingsConnectionFlag::Volatile)
+            }
+            InterfaceNmFlag::Unsaved => Some(NmSettingsConnectionFlag::Unsaved),
+            _ => None,
+        })
+        .collect();
 
     // Use stable UUID if in gen_conf mode.
     // This enable us to generate the same output for `nm_gen_conf()`
     // when the desire state is the same.
     let stable_uuid = gen_conf_mode;
 
-    gen_nm_conn_setting(iface, &mut nm_conn, stable_uuid)?;
+    gen_nm_conn_setting(
+        iface,
+        &mut nm_conn,
+        stable_uuid,
+        merged_state.nm_version.as_ref(),
+    )?;
+    if stable_uuid && merged_state.controller_by_uuid {
+        use_stable_uuid_for_controller(iface, &mut nm_conn);
+    }
+    if merged_state.gather_lldp_neighbors {
+        enable_nm_lldp_rx(iface, &mut nm_conn);
+    }
+    apply_default_resolve_mode(
+        iface,
+        &mut nm_conn,
+        merged_state.default_mdns,
+        merged_state.default_llmnr,
+    );
+    let absent_routes: Option<Vec<RouteEntry>> =
+        if merged_state.keep_unmanaged_routes {
+            Some(get_iface_absent_routes(iface.name(), merged_state))
+        } else {
+            None
+        };
     gen_nm_ip_setting(
         iface,
         iface.base_iface().routes.as_deref(),
+        absent_routes.as_deref(),
+        &mut nm_conn,
+    )?;
+    fallback_nm_addr_gen_mode(&mut nm_conn, merged_state.nm_version.as_ref());
+    fallback_nm_replace_local_rule(
         &mut nm_conn,
+        merged_state.nm_version.as_ref(),
+    );
+    validate_gateway_ping_timeout(
+        iface,
+        &merged_iface.merged,
+        merged_state.routes.indexed.get(iface.name()),
     )?;
     // InfiniBand over IP and loopback can not have layer 2 configuration.
     if iface.iface_type() != InterfaceType::InfiniBand
         && iface.iface_type() != InterfaceType::Loopback
     {
-        gen_nm_wired_setting(iface, &mut nm_conn);
+        check_mtu_against_parent(iface, &merged_state.interfaces)?;
+        gen_nm_wired_setting(iface, &mut nm_conn)?;
     }
     gen_nm_iface_ovs_db_setting(iface, &mut nm_conn);
     gen_nm_802_1x_setting(iface, &mut nm_conn);
-    gen_nm_user_setting(iface, &mut nm_conn);
-    gen_ethtool_setting(iface, &mut nm_conn)?;
+    gen_nm_proxy_setting(iface, &mut nm_conn);
+    gen_nm_user_setting(iface, &mut nm_conn)?;
+    gen_ethtool_setting(iface, &mut nm_conn, merged_state.nm_version.as_ref())?;
 
     match iface {
         Interface::OvsBridge(ovs_br_iface) => {
@@ -177,7 +305,7 @@ pub(crate) fn iface_to_nm_connections(
                     nm_ac_uuids,
                 );
                 ret.push(create_ovs_port_nm_conn(
-                    &ovs_br_iface.base.name,
+                    ovs_br_iface,
                     ovs_port_conf,
                     exist_nm_ovs_port_conn,
                     stable_uuid,
@@ -188,7 +316,7 @@ pub(crate) fn iface_to_nm_connections(
             gen_nm_br_setting(merged_iface, &mut nm_conn);
         }
         Interface::Bond(bond_iface) => {
-            gen_nm_bond_setting(bond_iface, &mut nm_conn);
+            gen_nm_bond_setting(bond_iface, &mut nm_conn)?;
         }
         Interface::OvsInterface(iface) => {
             gen_nm_ovs_iface_setting(iface, &mut nm_conn);
@@ -197,9 +325,7 @@ pub(crate) fn iface_to_nm_connections(
             gen_nm_vlan_setting(vlan_iface, &mut nm_conn);
         }
         Interface::Vxlan(vxlan_iface) => {
-            if let Some(conf) = vxlan_iface.vxlan.as_ref() {
-                nm_conn.vxlan = Some(NmSettingVxlan::from(conf))
-            }
+            gen_nm_vxlan_setting(vxlan_iface, &mut nm_conn)?;
         }
         Interface::Ethernet(eth_iface) => {
             if let Some(veth_conf) = eth_iface.veth.as_ref() {
@@ -230,7 +356,15 @@ pub(crate) fn iface_to_nm_connections(
                     )?);
                 }
             }
-            gen_nm_sriov_setting(eth_iface, &mut nm_conn);
+            gen_nm_sriov_setting(
+                eth_iface.base.name.as_str(),
+                eth_iface
+                    .ethernet
+                    .as_ref()
+                    .and_then(|eth_conf| eth_conf.sr_iov.as_ref()),
+                &mut nm_conn,
+                merged_state.nm_version.as_ref(),
+            )?;
         }
         Interface::MacVlan(iface) => {
             if let Some(conf) = iface.mac_vlan.as_ref() {
@@ -240,6 +374,7 @@ pub(crate) fn iface_to_nm_connections(
         Interface::MacVtap(iface) => {
             if let Some(conf) = iface.mac_vtap.as_ref() {
                 nm_conn.mac_vlan = Some(NmSettingMacVlan::from(conf));
+                gen_nm_mac_vtap_tap_flags(conf, &mut nm_conn);
             }
         }
         Interface::Vrf(iface) => {
@@ -248,7 +383,16 @@ pub(crate) fn iface_to_nm_connections(
             }
         }
         Interface::InfiniBand(iface) => {
-            gen_nm_ib_setting(iface, &mut nm_conn);
+            gen_nm_ib_setting(iface, &merged_state.interfaces, &mut nm_conn)?;
+            gen_nm_sriov_setting(
+                iface.base.name.as_str(),
+                iface
+                    .ib
+                    .as_ref()
+                    .and_then(|ib_conf| ib_conf.sr_iov.as_ref()),
+                &mut nm_conn,
+                merged_state.nm_version.as_ref(),
+            )?;
         }
         Interface::MacSec(iface) => {
             gen_nm_macsec_setting(iface, &mut nm_conn);
@@ -259,12 +403,42 @@ pub(crate) fn iface_to_nm_connections(
         Interface::Hsr(iface) => {
             gen_nm_hsr_setting(iface, &mut nm_conn);
         }
+        Interface::Team(iface) => {
+            gen_nm_team_setting(iface, &mut nm_conn)?;
+        }
         Interface::Ipsec(iface) => {
             gen_nm_ipsec_vpn_setting(iface, &mut nm_conn);
         }
+        Interface::WireGuard(iface) => {
+            gen_nm_wireguard_setting(iface, &mut nm_conn)?;
+        }
+        Interface::Unknown(unknown_iface) if merged_state.strict => {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!(
+                    "Interface {} of type {} is not supported by \
+                    NetworkManager backend in strict mode",
+                    unknown_iface.base.name,
+                    unknown_iface
+                        .unsupported_type()
+                        .map(|t| t.to_string())
+                        .unwrap_or_else(|| InterfaceType::Unknown.to_string())
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
         _ => (),
     };
 
+    if stable_uuid && merged_state.parent_by_uuid {
+        use_stable_uuid_for_parent(
+            iface,
+            &merged_state.interfaces,
+            &mut nm_conn,
+        );
+    }
+
     if nm_conn.controller_type() != Some(NM_SETTING_BOND_SETTING_NAME) {
         nm_conn.bond_port = None;
     }
@@ -273,17 +447,20 @@ pub(crate) fn iface_to_nm_connections(
         nm_conn.bridge_port = None;
     }
 
+    if nm_conn.controller_type() != Some(NM_SETTING_TEAM_SETTING_NAME) {
+        nm_conn.team_port = None;
+    }
+
     if nm_conn.controller_type() != Some(NM_SETTING_OVS_PORT_SETTING_NAME) {
         nm_conn.ovs_iface = None;
     }
 
-    if let (Some(ctrl), Some(ctrl_type)) = (
-        base_iface.controller.as_ref(),
-        base_iface.controller_type.as_ref(),
-    ) {
-        if let Some(ctrl_iface) =
-            merged_state.interfaces.get_iface(ctrl, ctrl_type.clone())
-        {
+    if let Some(ctrl) = base_iface.controller.as_ref() {
+        if let Some(ctrl_iface) = get_ctrl_iface(
+            ctrl,
+            base_iface.controller_type.as_ref(),
+            merged_state,
+        ) {
             match &ctrl_iface.merged {
                 Interface::Bond(bond_iface) => {
                     gen_nm_bond_port_setting(bond_iface, &mut nm_conn);
@@ -291,6 +468,9 @@ pub(crate) fn iface_to_nm_connections(
                 Interface::LinuxBridge(br_iface) => {
                     gen_nm_br_port_setting(br_iface, &mut nm_conn);
                 }
+                Interface::Team(team_iface) => {
+                    gen_nm_team_port_setting(team_iface, &mut nm_conn);
+                }
                 Interface::OvsBridge(ovs_br_iface) => {
                     // When user attaching change controller property
                     // on OVS system or internal interface, we should
@@ -320,7 +500,7 @@ pub(crate) fn iface_to_nm_connections(
                             None
                         };
                         ret.push(create_ovs_port_nm_conn(
-                            ctrl,
+                            ovs_br_iface,
                             &OvsBridgePortConfig {
                                 name: iface.name().to_string(),
                                 ..Default::default()
@@ -346,6 +526,31 @@ pub(crate) fn iface_to_nm_connections(
     Ok(ret)
 }
 
+// Find the controller interface by name and type. When controller type is
+// unknown, search kernel controllers first and then OVS bridges.
+fn get_ctrl_iface<'a>(
+    ctrl: &str,
+    ctrl_type: Option<&InterfaceType>,
+    merged_state: &'a MergedNetworkState,
+) -> Option<&'a MergedInterface> {
+    if ctrl.is_empty() {
+        return None;
+    }
+    if let Some(ctrl_type) = ctrl_type {
+        return merged_state.interfaces.get_iface(ctrl, ctrl_type.clone());
+    }
+    merged_state
+        .interfaces
+        .kernel_ifaces
+        .get(ctrl)
+        .filter(|i| i.merged.is_controller())
+        .or_else(|| {
+            merged_state
+                .interfaces
+                .get_iface(ctrl, InterfaceType::OvsBridge)
+        })
+}
+
 pub(crate) fn iface_type_to_nm(
     iface_type: &InterfaceType,
 ) -> Result<String, NmstateError> {
@@ -378,7 +583,14 @@ pub(crate) fn iface_type_to_nm(
         }
         InterfaceType::MacSec => Ok(NM_SETTING_MACSEC_SETTING_NAME.to_string()),
         InterfaceType::Hsr => Ok(NM_SETTING_HSR_SETTING_NAME.to_string()),
+        InterfaceType::Team => Ok(NM_SETTING_TEAM_SETTING_NAME.to_string()),
         InterfaceType::Ipsec => Ok(NM_SETTING_VPN_SETTING_NAME.to_string()),
+        InterfaceType::WireGuard => {
+            Ok(NM_SETTING_WIREGUARD_SETTING_NAME.to_string())
+        }
+        InterfaceType::Generic => {
+            Ok(NM_SETTING_GENERIC_SETTING_NAME.to_string())
+        }
         InterfaceType::Other(s) => Ok(s.to_string()),
         _ => Err(NmstateError::new(
             ErrorKind::NotImplementedError,
@@ -391,7 +603,10 @@ pub(crate) fn gen_nm_conn_setting(
     iface: &Interface,
     nm_conn: &mut NmConnection,
     stable_uuid: bool,
+    nm_version: Option<&NmVersion>,
 ) -> Result<(), NmstateError> {
+    // Editing existing profile should preserve the properties not
+    // explicitly desired, for example `auth-retries`.
     let mut nm_conn_set = if let Some(cur_nm_conn_set) = &nm_conn.connection {
         cur_nm_conn_set.clone()
     } else {
@@ -437,17 +652,41 @@ pub(crate) fn gen_nm_conn_setting(
     if iface.iface_type() != InterfaceType::Ipsec
         && iface.base_iface().identifier.unwrap_or_default()
             == InterfaceIdentifier::Name
+        && !iface.base_iface().is_bind_by_glob()
     {
         nm_conn_set.iface_name = Some(iface.name().to_string());
     } else {
         nm_conn_set.iface_name = None;
     }
-    nm_conn_set.autoconnect = Some(true);
+    gen_nm_match_setting(iface, nm_conn);
+    // Interface desired as down should not be brought up by NetworkManager
+    // on boot or when its carrier shows up.
+    nm_conn_set.autoconnect = Some(!iface.is_down());
     nm_conn_set.autoconnect_ports = if iface.is_controller() {
-        Some(true)
+        Some(iface.base_iface().autoconnect_ports.unwrap_or(true))
     } else {
         None
     };
+    // Older NetworkManager does not know `autoconnect-ports`, only use it
+    // when the daemon version is known to support it.
+    nm_conn_set.use_autoconnect_ports_key =
+        nm_version.map(|v| v >= &NmVersion::AUTOCONNECT_PORTS) == Some(true);
+    if let Some(multi_connect) = iface.base_iface().multi_connect {
+        nm_conn_set.multi_connect = Some(match multi_connect {
+            InterfaceMultiConnect::Default => NM_MULTI_CONNECT_DEFAULT,
+            InterfaceMultiConnect::Single => NM_MULTI_CONNECT_SINGLE,
+            InterfaceMultiConnect::ManualMultiple => {
+                NM_MULTI_CONNECT_MANUAL_MULTIPLE
+            }
+            InterfaceMultiConnect::Multiple => NM_MULTI_CONNECT_MULTIPLE,
+        });
+    }
+    if let Some(mdns) = iface.base_iface().mdns {
+        nm_conn_set.mdns = Some(resolve_mode_to_nm(mdns));
+    }
+    if let Some(llmnr) = iface.base_iface().llmnr {
+        nm_conn_set.llmnr = Some(resolve_mode_to_nm(llmnr));
+    }
 
     let nm_ctrl_type = iface
         .base_iface()
@@ -458,6 +697,22 @@ pub(crate) fn gen_nm_conn_setting(
     let nm_ctrl_type = nm_ctrl_type.as_deref();
     let ctrl_name = iface.base_iface().controller.as_deref();
     if let Some(ctrl_name) = ctrl_name {
+        // OVS internal interface is allowed to use the same name as its OVS
+        // bridge.
+        if ctrl_name == iface.name()
+            && iface.base_iface().controller_type
+                != Some(InterfaceType::OvsBridge)
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Interface {} cannot use itself as controller",
+                    iface.name()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
         if ctrl_name.is_empty() {
             nm_conn_set.controller = None;
             nm_conn_set.controller_type = None;
@@ -479,13 +734,150 @@ pub(crate) fn gen_nm_conn_setting(
     if let Some(mptcp_conf) = iface.base_iface().mptcp.as_ref() {
         apply_mptcp_conf(&mut nm_conn_set, mptcp_conf)?;
     }
+    gen_nm_ip_ping_setting(iface, &mut nm_conn_set, nm_version)?;
+    if let Some(timeout) = iface.base_iface().gateway_ping_timeout {
+        nm_conn_set.gateway_ping_timeout = Some(timeout);
+    }
 
     nm_conn.connection = Some(nm_conn_set);
 
     Ok(())
 }
 
-fn uuid_from_name_and_type(
+fn gen_nm_ip_ping_setting(
+    iface: &Interface,
+    nm_conn_set: &mut NmSettingConnection,
+    nm_version: Option<&NmVersion>,
+) -> Result<(), NmstateError> {
+    let base_iface = iface.base_iface();
+    if base_iface.ip_ping_addresses.is_none()
+        && base_iface.ip_ping_timeout.is_none()
+    {
+        return Ok(());
+    }
+    if let Some(nm_version) = nm_version {
+        if nm_version < &NmVersion::IP_PING {
+            let e = NmstateError::new(
+                ErrorKind::NotImplementedError,
+                format!(
+                    "The ip-ping-addresses and ip-ping-timeout of interface \
+                    {} require NetworkManager {} or newer, but current \
+                    version is {nm_version}",
+                    iface.name(),
+                    NmVersion::IP_PING,
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    if let Some(addrs) = base_iface.ip_ping_addresses.as_ref() {
+        nm_conn_set.ip_ping_addresses = Some(addrs.clone());
+    }
+    if let Some(timeout) = base_iface.ip_ping_timeout {
+        nm_conn_set.ip_ping_timeout = Some(timeout);
+    }
+    Ok(())
+}
+
+// NetworkManager pings the default gateway of the connection, hence
+// gateway-ping-timeout requires static default route or gateway retrieved
+// dynamically via DHCP or autoconf.
+fn validate_gateway_ping_timeout(
+    iface: &Interface,
+    merged_iface: &Interface,
+    routes: Option<&Vec<RouteEntry>>,
+) -> Result<(), NmstateError> {
+    if iface.base_iface().gateway_ping_timeout.unwrap_or_default() == 0 {
+        return Ok(());
+    }
+    let has_static_gateway = routes
+        .map(|rts| {
+            rts.iter().any(|rt| {
+                !rt.is_absent()
+                    && matches!(
+                        rt.destination.as_deref(),
+                        Some("0.0.0.0/0") | Some("::/0")
+                    )
+                    && rt
+                        .next_hop_addr
+                        .as_deref()
+                        .and_then(|a| a.parse::<std::net::IpAddr>().ok())
+                        .map(|a| !a.is_unspecified())
+                        .unwrap_or_default()
+            })
+        })
+        .unwrap_or_default();
+    let base_iface = merged_iface.base_iface();
+    let has_dynamic_gateway = base_iface
+        .ipv4
+        .as_ref()
+        .map(|i| i.is_auto() && i.auto_gateway != Some(false))
+        .unwrap_or_default()
+        || base_iface
+            .ipv6
+            .as_ref()
+            .map(|i| i.is_auto() && i.auto_gateway != Some(false))
+            .unwrap_or_default();
+    if !has_static_gateway && !has_dynamic_gateway {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "The gateway-ping-timeout of interface {} requires \
+                default gateway, please define default route or enable \
+                DHCP/autoconf with auto-gateway",
+                iface.name()
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
+}
+
+// With stable UUID, the UUID of kernel controller is determined by its name
+// and type, hence we can reference it directly.
+// The OVS port name is resolved later by `use_uuid_for_controller_reference()`.
+fn use_stable_uuid_for_controller(
+    iface: &Interface,
+    nm_conn: &mut NmConnection,
+) {
+    if let (Some(ctrl_name), Some(ctrl_type)) = (
+        iface.base_iface().controller.as_deref(),
+        iface.base_iface().controller_type.as_ref(),
+    ) {
+        if !ctrl_name.is_empty() && !ctrl_type.is_userspace() {
+            if let Some(nm_conn_set) = nm_conn.connection.as_mut() {
+                nm_conn_set.controller =
+                    Some(uuid_from_name_and_type(ctrl_name, ctrl_type));
+            }
+        }
+    }
+}
+
+// The parent not mentioned in desired state is treated as ethernet.
+// InfiniBand parent does not support UUID reference.
+fn use_stable_uuid_for_parent(
+    iface: &Interface,
+    merged_ifaces: &MergedInterfaces,
+    nm_conn: &mut NmConnection,
+) {
+    if iface.iface_type() == InterfaceType::InfiniBand {
+        return;
+    }
+    if let Some(parent) = iface.parent() {
+        let parent_type = merged_ifaces
+            .kernel_ifaces
+            .get(parent)
+            .map(|i| i.merged.iface_type())
+            .unwrap_or(InterfaceType::Ethernet);
+        if !parent_type.is_userspace() {
+            nm_conn.set_parent(&uuid_from_name_and_type(parent, &parent_type));
+        }
+    }
+}
+
+pub(crate) fn uuid_from_name_and_type(
     iface_name: &str,
     iface_type: &InterfaceType,
 ) -> String {
@@ -497,6 +889,21 @@ fn uuid_from_name_and_type(
     .to_string()
 }
 
+// Profile bind to interface by glob pattern is matched via `match` setting
+fn gen_nm_match_setting(iface: &Interface, nm_conn: &mut NmConnection) {
+    let base_iface = iface.base_iface();
+    if base_iface.is_bind_by_glob() {
+        let mut nm_match_set =
+            nm_conn.matching.as_ref().cloned().unwrap_or_default();
+        nm_match_set.interface_name = base_iface.bind_pattern.clone();
+        nm_conn.matching = Some(nm_match_set);
+    } else if base_iface.bind_by.is_some() {
+        if let Some(nm_match_set) = nm_conn.matching.as_mut() {
+            nm_match_set.interface_name = None;
+        }
+    }
+}
+
 // Found existing profile, prefer the activated one
 pub(crate) fn get_exist_profile<'a>(
     exist_nm_conns: &'a [NmConnection],
@@ -521,7 +928,11 @@ pub(crate) fn get_exist_profile<'a>(
                 }
                 found_nm_conns.push(exist_nm_conn);
             }
-        } else if exist_nm_conn.iface_name() == Some(iface_name)
+        } else if (exist_nm_conn.iface_name() == Some(iface_name)
+            // Profile bind to interface via `match` setting has no
+            // interface name, search it by profile name instead.
+            || (exist_nm_conn.iface_name().is_none()
+                && exist_nm_conn.id() == Some(iface_name)))
             && (exist_nm_conn.iface_type() == Some(&nm_iface_type)
                 || (nm_iface_type == NM_SETTING_WIRED_SETTING_NAME
                     && exist_nm_conn.iface_type()
@@ -559,6 +970,39 @@ fn get_exist_profile_by_profile_name<'a>(
     None
 }
 
+// Neighbor gathering requires LLDP receiving of ethernet interface. The
+// conflicting `lldp.enabled: false` is rejected by
+// `MergedNetworkState::validate_lldp_for_neighbor_gathering()`.
+fn enable_nm_lldp_rx(iface: &Interface, nm_conn: &mut NmConnection) {
+    if iface.iface_type() != InterfaceType::Ethernet {
+        return;
+    }
+    if let Some(nm_conn_set) = nm_conn.connection.as_mut() {
+        nm_conn_set.lldp = Some(true);
+    }
+}
+
+// Desired absent routes which might remove route via specified interface.
+fn get_iface_absent_routes(
+    iface_name: &str,
+    merged_state: &MergedNetworkState,
+) -> Vec<RouteEntry> {
+    merged_state
+        .routes
+        .desired
+        .config
+        .as_deref()
+        .unwrap_or_default()
+        .iter()
+        .filter(|r| {
+            r.is_absent()
+                && (r.next_hop_iface.is_none()
+                    || r.next_hop_iface.as_deref() == Some(iface_name))
+        })
+        .cloned()
+        .collect()
+}
+
 fn persisten_iface_cur_conf(
     cur_iface: &Interface,
     merged_state: &MergedNetworkState,
@@ -575,11 +1019,112 @@ fn persisten_iface_cur_conf(
     }
     let merged_iface = MergedInterface::new(Some(iface), None)?;
 
-    iface_to_nm_connections(
+    let mut nm_conns = iface_to_nm_connections(
         &merged_iface,
         merged_state,
         exist_nm_conns,
         nm_ac_uuids,
         gen_conf_mode,
-    )
+    )?;
+    // The NetworkManager generated profile for external or unmanaged
+    // interface has autoconnect disabled, the converted profile should be
+    // activated on boot.
+    for nm_conn in nm_conns.iter_mut() {
+        if let Some(nm_conn_set) = nm_conn.connection.as_mut() {
+            nm_conn_set.autoconnect = Some(true);
+        }
+    }
+    Ok(nm_conns)
+}
+
+fn resolve_mode_to_nm(mode: InterfaceResolveMode) -> i32 {
+    match mode {
+        InterfaceResolveMode::Default => NM_RESOLVE_MODE_DEFAULT,
+        InterfaceResolveMode::No => NM_RESOLVE_MODE_NO,
+        InterfaceResolveMode::Resolve => NM_RESOLVE_MODE_RESOLVE,
+        InterfaceResolveMode::Yes => NM_RESOLVE_MODE_YES,
+    }
+}
+
+// Use the global default of mDNS and LLMNR for interface not defining them.
+fn apply_default_resolve_mode(
+    iface: &Interface,
+    nm_conn: &mut NmConnection,
+    default_mdns: Option<InterfaceResolveMode>,
+    default_llmnr: Option<InterfaceResolveMode>,
+) {
+    if let Some(nm_conn_set) = nm_conn.connection.as_mut() {
+        if let Some(mdns) =
+            default_mdns.filter(|_| iface.base_iface().mdns.is_none())
+        {
+            nm_conn_set.mdns = Some(resolve_mode_to_nm(mdns));
+        }
+        if let Some(llmnr) =
+            default_llmnr.filter(|_| iface.base_iface().llmnr.is_none())
+        {
+            nm_conn_set.llmnr = Some(resolve_mode_to_nm(llmnr));
+        }
+    }
+}
+
+// Properties removed by `sanitize()` are not stored into NetworkManager
+// profile, in strict mode they are treated as error.
+fn validate_strict_ignored_props(
+    merged_iface: &MergedInterface,
+    apply_iface: &Interface,
+) -> Result<(), NmstateError> {
+    let des_base_iface = if let Some(i) =
+        merged_iface.desired.as_ref().map(|i| i.base_iface())
+    {
+        i
+    } else {
+        return Ok(());
+    };
+    let apply_base_iface = apply_iface.base_iface();
+    let mut ignored_props: Vec<&str> = Vec::new();
+    if let (Some(des_ipv4), Some(apply_ipv4)) =
+        (des_base_iface.ipv4.as_ref(), apply_base_iface.ipv4.as_ref())
+    {
+        if des_ipv4.dhcp_client_id.is_some()
+            && apply_ipv4.dhcp_client_id.is_none()
+        {
+            ignored_props.push("ipv4.dhcp-client-id");
+        }
+        if des_ipv4.dhcp_vendor_class_identifier.is_some()
+            && apply_ipv4.dhcp_vendor_class_identifier.is_none()
+        {
+            ignored_props.push("ipv4.dhcp-vendor-class-identifier");
+        }
+        if des_ipv4.dhcp_custom_hostname.is_some()
+            && apply_ipv4.dhcp_custom_hostname.is_none()
+        {
+            ignored_props.push("ipv4.dhcp-custom-hostname");
+        }
+    }
+    if let (Some(des_ipv6), Some(apply_ipv6)) =
+        (des_base_iface.ipv6.as_ref(), apply_base_iface.ipv6.as_ref())
+    {
+        if des_ipv6.dhcp_custom_hostname.is_some()
+            && apply_ipv6.dhcp_custom_hostname.is_none()
+        {
+            ignored_props.push("ipv6.dhcp-custom-hostname");
+        }
+        if des_ipv6.ra_timeout.is_some() && apply_ipv6.ra_timeout.is_none() {
+            ignored_props.push("ipv6.ra-timeout");
+        }
+    }
+    if !ignored_props.is_empty() {
+        let e = NmstateError::new(
+            ErrorKind::NotSupportedError,
+            format!(
+                "Properties {} of interface {} are ignored by current \
+                configuration, not supported in strict mode",
+                ignored_props.join(", "),
+                apply_base_iface.name
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
 }
@@ -1,9 +1,11 @@
 use std::collections::HashMap;
 
 use crate::nm::nm_dbus::{NmConnection, NmSettingEthtool};
+use crate::nm::NmVersion;
 use crate::{
-    ErrorKind, EthtoolCoalesceConfig, EthtoolFeatureConfig, EthtoolPauseConfig,
-    EthtoolRingConfig, Interface, NmstateError,
+    ErrorKind, EthtoolChannelsConfig, EthtoolCoalesceConfig,
+    EthtoolFeatureConfig, EthtoolPauseConfig, EthtoolRingConfig, Interface,
+    NmstateError,
 };
 
 const KERNEL_ETHTOOL_FEATURE_2_NM: [(&str, &str); 10] = [
@@ -22,6 +24,7 @@ const KERNEL_ETHTOOL_FEATURE_2_NM: [(&str, &str); 10] = [
 pub(crate) fn gen_ethtool_setting(
     iface: &Interface,
     nm_conn: &mut NmConnection,
+    nm_version: Option<&NmVersion>,
 ) -> Result<(), NmstateError> {
     if let Some(ethtool_iface) = iface.base_iface().ethtool.as_ref() {
         let mut nm_ethtool_set =
@@ -31,7 +34,7 @@ pub(crate) fn gen_ethtool_setting(
             apply_pause_options(&mut nm_ethtool_set, pause_conf);
         }
         if let Some(feature_conf) = ethtool_iface.feature.as_ref() {
-            apply_feature_options(&mut nm_ethtool_set, feature_conf)?;
+            apply_feature_options(&mut nm_ethtool_set, feature_conf);
         }
         if let Some(coalesce_conf) = ethtool_iface.coalesce.as_ref() {
             apply_coalesce_options(&mut nm_ethtool_set, coalesce_conf);
@@ -39,7 +42,33 @@ pub(crate) fn gen_ethtool_setting(
         if let Some(ring_conf) = ethtool_iface.ring.as_ref() {
             apply_ring_options(&mut nm_ethtool_set, ring_conf);
         }
+        if let Some(channels_conf) = ethtool_iface.channels.as_ref() {
+            apply_channels_options(&mut nm_ethtool_set, channels_conf);
+        }
         nm_conn.ethtool = Some(nm_ethtool_set);
+        // NetworkManager holds GSO max size in `link` setting.
+        if let Some(gso_max_size) = ethtool_iface.gso_max_size {
+            if let Some(nm_version) = nm_version {
+                if nm_version < &NmVersion::LINK {
+                    let e = NmstateError::new(
+                        ErrorKind::NotImplementedError,
+                        format!(
+                            "The ethtool gso-max-size of interface {} \
+                            requires NetworkManager {} or newer, but \
+                            current version is {nm_version}",
+                            iface.name(),
+                            NmVersion::LINK,
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+            nm_conn
+                .link
+                .get_or_insert_with(Default::default)
+                .gso_max_size = Some(gso_max_size);
+        }
     }
     Ok(())
 }
@@ -58,7 +87,7 @@ fn apply_pause_options(
 fn apply_feature_options(
     nm_ethtool_set: &mut NmSettingEthtool,
     feature_conf: &EthtoolFeatureConfig,
-) -> Result<(), NmstateError> {
+) {
     let mut kernel_2_nm = HashMap::new();
     for (k, v) in KERNEL_ETHTOOL_FEATURE_2_NM {
         kernel_2_nm.insert(k, v);
@@ -72,13 +101,13 @@ fn apply_feature_options(
         }
     }
     nm_ethtool_set.features = Some(nm_features);
-    if let Err(e) = nm_ethtool_set.validate() {
-        return Err(NmstateError::new(
-            ErrorKind::InvalidArgument,
-            e.to_string(),
-        ));
+    for nm_feature_name in nm_ethtool_set.unknown_features() {
+        log::warn!(
+            "Ethtool feature {} is unknown to nmstate, passing it to \
+            NetworkManager as {nm_feature_name}",
+            nm_feature_name.trim_start_matches("feature-")
+        );
     }
-    Ok(())
 }
 
 fn apply_coalesce_options(
@@ -101,6 +130,10 @@ fn apply_coalesce_options(
     nm_ethtool_set.coalesce_rx_usecs_high = coalesce_conf.rx_usecs_high;
     nm_ethtool_set.coalesce_rx_usecs_low = coalesce_conf.rx_usecs_low;
     nm_ethtool_set.coalesce_rx_usecs_irq = coalesce_conf.rx_usecs_irq;
+    nm_ethtool_set.coalesce_tx_usecs = coalesce_conf.tx_usecs;
+    nm_ethtool_set.coalesce_tx_usecs_high = coalesce_conf.tx_usecs_high;
+    nm_ethtool_set.coalesce_tx_usecs_low = coalesce_conf.tx_usecs_low;
+    nm_ethtool_set.coalesce_tx_usecs_irq = coalesce_conf.tx_usecs_irq;
     nm_ethtool_set.coalesce_sample_interval = coalesce_conf.sample_interval;
     nm_ethtool_set.coalesce_stats_block_usecs = coalesce_conf.stats_block_usecs;
 }
@@ -114,3 +147,12 @@ fn apply_ring_options(
     nm_ethtool_set.ring_rx_mini = ring_conf.rx_mini;
     nm_ethtool_set.ring_tx = ring_conf.tx;
 }
+
+fn apply_channels_options(
+    nm_ethtool_set: &mut NmSettingEthtool,
+    channels_conf: &EthtoolChannelsConfig,
+) {
+    nm_ethtool_set.channels_rx = channels_conf.rx;
+    nm_ethtool_set.channels_tx = channels_conf.tx;
+    nm_ethtool_set.channels_combined = channels_conf.combined;
+}
@@ -1,15 +1,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use super::super::nm_dbus::{NmConnection, NmSetting8021X};
+use super::super::nm_dbus::{
+    NmConnection, NmSetting8021X, NM_SETTING_SECRET_FLAG_AGENT_OWNED,
+    NM_SETTING_SECRET_FLAG_NONE, NM_SETTING_SECRET_FLAG_NOT_REQUIRED,
+    NM_SETTING_SECRET_FLAG_NOT_SAVED,
+};
 
-use crate::{Interface, NetworkState};
+use crate::{Interface, NetworkState, SecretStorageMode};
 
 pub(crate) fn gen_nm_802_1x_setting(
     iface: &Interface,
     nm_conn: &mut NmConnection,
 ) {
     if let Some(conf) = iface.base_iface().ieee8021x.as_ref() {
-        let mut nm_setting = NmSetting8021X::default();
+        // Start from existing setting so secrets and properties not managed
+        // by nmstate are preserved unless explicitly defined in desired state.
+        let cur_setting = nm_conn.ieee8021x.as_ref();
+        let mut nm_setting = cur_setting.cloned().unwrap_or_default();
         nm_setting.identity = conf.identity.clone();
         nm_setting.eap = conf.eap.clone();
         nm_setting.private_key = conf
@@ -24,19 +31,49 @@ pub(crate) fn gen_nm_802_1x_setting(
             .ca_cert
             .as_deref()
             .map(NmSetting8021X::file_path_to_glib_bytes);
-        if conf.private_key_password.as_deref()
-            == Some(NetworkState::PASSWORD_HID_BY_NMSTATE)
-        {
-            if let Some(cur_pass) = nm_conn
-                .ieee8021x
-                .as_ref()
-                .and_then(|c| c.private_key_password.as_deref())
-            {
-                nm_setting.private_key_password = Some(cur_pass.to_string());
+        match conf.private_key_password.as_deref() {
+            Some(NetworkState::PASSWORD_HID_BY_NMSTATE) | None => {
+                nm_setting.private_key_password =
+                    cur_setting.and_then(|c| c.private_key_password.clone());
+            }
+            Some("") => {
+                nm_setting.private_key_password = None;
+            }
+            Some(v) => {
+                nm_setting.private_key_password = Some(v.to_string());
+            }
+        }
+        if let Some(mode) = conf.private_key_password_storage {
+            nm_setting.private_key_password_flags =
+                Some(secret_storage_mode_to_nm_flags(mode));
+            // Only secret stored by system should be saved in NM profile
+            if mode != SecretStorageMode::None {
+                nm_setting.private_key_password = None;
             }
-        } else {
-            nm_setting.private_key_password = conf.private_key_password.clone();
         }
         nm_conn.ieee8021x = Some(nm_setting);
+        if let Some(v) = conf.auth_timeout {
+            // Validated to be no bigger than i32::MAX by sanitize()
+            nm_conn
+                .connection
+                .get_or_insert_with(Default::default)
+                .auth_timeout = Some(v as i32);
+        }
+        if let Some(v) = conf.auth_retries {
+            // Validated to be no bigger than i32::MAX by sanitize()
+            nm_conn
+                .connection
+                .get_or_insert_with(Default::default)
+                .auth_retries = Some(v as i32);
+        }
+    }
+}
+
+pub(crate) fn secret_storage_mode_to_nm_flags(mode: SecretStorageMode) -> u32 {
+    match mode {
+        SecretStorageMode::None => NM_SETTING_SECRET_FLAG_NONE,
+        SecretStorageMode::AgentOwned => NM_SETTING_SECRET_FLAG_AGENT_OWNED,
+        SecretStorageMode::NotSaved => NM_SETTING_SECRET_FLAG_NOT_SAVED,
+        SecretStorageMode::NotRequired => NM_SETTING_SECRET_FLAG_NOT_REQUIRED,
     }
 }
@@ -1,11 +1,26 @@
 use crate::nm::nm_dbus::NmConnection;
 
-use crate::InfiniBandInterface;
+use crate::{
+    ErrorKind, InfiniBandInterface, InfiniBandMode, Interface,
+    MergedInterfaces, NmstateError,
+};
+
+// IPoIB datagram mode is limited by the 4096 bytes InfiniBand MTU minus the
+// 4 bytes IPoIB header.
+const IB_DATAGRAM_MTU_MAX: u64 = 4092;
+// IPoIB connected mode
+const IB_CONNECTED_MTU_MAX: u64 = 65520;
+// P_Key 0xffff indicates the default partition of physical interface.
+const IB_DEFAULT_PKEY: u16 = u16::MAX;
+// The lower 15 bits of P_Key holding 0 is reserved as invalid P_Key.
+const IB_PKEY_BASE_MASK: u16 = 0x7fff;
 
 pub(crate) fn gen_nm_ib_setting(
     iface: &InfiniBandInterface,
+    merged_ifaces: &MergedInterfaces,
     nm_conn: &mut NmConnection,
-) {
+) -> Result<(), NmstateError> {
+    validate_ib_conf(iface, merged_ifaces)?;
     let mut nm_ib_set =
         nm_conn.infiniband.as_ref().cloned().unwrap_or_default();
     if let Some(ib_conf) = iface.ib.as_ref() {
@@ -28,5 +43,106 @@ pub(crate) fn gen_nm_ib_setting(
     if let Some(mtu) = iface.base.mtu {
         nm_ib_set.mtu = Some(mtu as u32);
     }
-    nm_conn.infiniband = Some(nm_ib_set)
+    nm_conn.infiniband = Some(nm_ib_set);
+    Ok(())
+}
+
+// Validate MTU against transport mode, P_Key against parent and the parent
+// itself, all the invalid properties are reported in single error.
+fn validate_ib_conf(
+    iface: &InfiniBandInterface,
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let iface_name = iface.base.name.as_str();
+    let mut errors: Vec<String> = Vec::new();
+    let parent = iface.parent().filter(|p| !p.is_empty());
+    let ib_conf = iface.ib.as_ref();
+
+    if let Some(mtu) = iface.base.mtu {
+        let mode = ib_conf.map(|c| c.mode).unwrap_or_default();
+        let max_mtu = match mode {
+            InfiniBandMode::Datagram => IB_DATAGRAM_MTU_MAX,
+            InfiniBandMode::Connected => IB_CONNECTED_MTU_MAX,
+        };
+        if mtu > max_mtu {
+            errors.push(format!(
+                "MTU {mtu} exceeds the maximum {max_mtu} of {mode} mode"
+            ));
+        }
+    }
+
+    let pkey = ib_conf
+        .and_then(|c| c.pkey)
+        .filter(|p| *p != IB_DEFAULT_PKEY);
+    match (pkey, parent) {
+        (Some(pkey), None) => errors.push(format!(
+            "pkey {pkey:#06x} requires base-iface to be defined"
+        )),
+        (None, Some(parent)) => errors.push(format!(
+            "base-iface {parent} requires a pkey other than 0xffff"
+        )),
+        _ => (),
+    }
+    if let Some(pkey) = pkey {
+        if pkey & IB_PKEY_BASE_MASK == 0 {
+            errors.push(format!("pkey {pkey:#06x} is reserved as invalid"));
+        }
+    }
+
+    if let Some(parent) = parent {
+        if let Err(e) = validate_ib_parent(iface_name, parent, merged_ifaces) {
+            errors.push(e.msg().to_string());
+        }
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Invalid InfiniBand interface {iface_name}: {}",
+                errors.join("; ")
+            ),
+        );
+        log::error!("{}", e);
+        Err(e)
+    }
+}
+
+// The parent of IB pkey interface should be a physical IB interface, creating
+// pkey interface on top of another pkey interface is not supported by kernel.
+fn validate_ib_parent(
+    iface_name: &str,
+    parent: &str,
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let parent_iface =
+        match merged_ifaces.kernel_ifaces.get(parent).map(|i| &i.merged) {
+            Some(Interface::InfiniBand(i)) => i,
+            Some(_) | None => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "The base-iface {parent} of InfiniBand interface \
+                    {iface_name} is not an InfiniBand interface"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        };
+    if parent_iface.parent().map(|p| !p.is_empty()) == Some(true) {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "The base-iface {parent} of InfiniBand interface \
+                {iface_name} is an InfiniBand pkey interface, only \
+                physical InfiniBand interface is supported as base-iface"
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
 }
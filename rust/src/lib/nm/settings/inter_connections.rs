@@ -18,6 +18,7 @@ pub(crate) fn use_uuid_for_controller_reference(
     merged_ifaces: &MergedInterfaces,
     exist_nm_conns: &[NmConnection],
     nm_acs: &[NmActiveConnection],
+    kernel_ctrl_by_uuid: bool,
 ) -> Result<(), NmstateError> {
     let mut name_type_2_uuid_index: HashMap<(String, String), String> =
         HashMap::new();
@@ -95,6 +96,13 @@ pub(crate) fn use_uuid_for_controller_reference(
             continue;
         }
 
+        // OVS port and OVS bridge cannot be referenced by name as OVS
+        // internal interface sharing the same name.
+        if !kernel_ctrl_by_uuid && !NM_SETTING_USER_SPACES.contains(&ctrl_type)
+        {
+            continue;
+        }
+
         if ctrl_type == "ovs-port" {
             if let Some(merged_iface) = merged_ifaces
                 .user_ifaces
@@ -3,22 +3,35 @@
 use std::ops::BitXor;
 
 use super::{
-    dns::apply_nm_dns_setting, route::gen_nm_ip_routes,
+    dns::apply_nm_dns_setting,
+    route::{gen_nm_ip_routes, merge_nm_ip_routes},
     route_rule::gen_nm_ip_rules,
 };
 use crate::nm::nm_dbus::{NmConnection, NmSettingIp, NmSettingIpMethod};
+use crate::nm::NmVersion;
 use crate::{
-    BaseInterface, Dhcpv4ClientId, Dhcpv6Duid, ErrorKind, Interface,
-    InterfaceIpAddr, InterfaceIpv4, InterfaceIpv6, Ipv6AddrGenMode,
-    NmstateError, RouteEntry, WaitIp,
+    BaseInterface, Dhcpv4ClientId, Dhcpv6Duid, DnsClientState, ErrorKind,
+    Interface, InterfaceIpAddr, InterfaceIpv4, InterfaceIpv6, Ipv6AddrGenMode,
+    Ipv6Privacy, NmstateError, RouteEntry, WaitIp,
 };
 
 const ADDR_GEN_MODE_EUI64: i32 = 0;
 const ADDR_GEN_MODE_STABLE_PRIVACY: i32 = 1;
+const ADDR_GEN_MODE_DEFAULT_OR_EUI64: i32 = 2;
+const ADDR_GEN_MODE_DEFAULT: i32 = 3;
+
+const IP6_PRIVACY_DEFAULT: i32 = -1;
+const IP6_PRIVACY_DISABLED: i32 = 0;
+const IP6_PRIVACY_PREFER_PUBLIC_ADDR: i32 = 1;
+const IP6_PRIVACY_PREFER_TEMP_ADDR: i32 = 2;
+
+const NM_REPLACE_LOCAL_RULE_NO: i32 = 0;
+const NM_REPLACE_LOCAL_RULE_YES: i32 = 1;
 
 fn gen_nm_ipv4_setting(
     iface_ip: Option<&InterfaceIpv4>,
     routes: Option<&[RouteEntry]>,
+    keep_routes: Option<&[RouteEntry]>,
     nm_conn: &mut NmConnection,
 ) -> Result<(), NmstateError> {
     let iface_ip = match iface_ip {
@@ -43,6 +56,7 @@ fn gen_nm_ipv4_setting(
         .collect();
 
     let mut nm_setting = nm_conn.ipv4.as_ref().cloned().unwrap_or_default();
+    let exist_routes = nm_setting.routes.clone();
     let mut addresses: Vec<String> = Vec::new();
     let method = if iface_ip.enabled {
         let ipv4_routes = gen_nm_ip_routes(routes.unwrap_or_default(), false)?;
@@ -64,6 +78,9 @@ fn gen_nm_ipv4_setting(
     }
     nm_setting.method = Some(method);
     nm_setting.addresses = addresses;
+    if let Some(v) = iface_ip.dad_timeout {
+        nm_setting.dad_timeout = Some(v);
+    }
     if iface_ip.is_auto() {
         nm_setting.dhcp_timeout = Some(i32::MAX);
         nm_setting.route_metric = iface_ip.auto_route_metric.map(|i| i.into());
@@ -73,6 +90,13 @@ fn gen_nm_ipv4_setting(
                 .as_ref()
                 .unwrap_or(&Dhcpv4ClientId::LinkLayerAddress),
         ));
+        if let Some(v) = iface_ip.dhcp_vendor_class_identifier.as_deref() {
+            nm_setting.dhcp_vendor_class_identifier = if v.is_empty() {
+                None
+            } else {
+                Some(v.to_string())
+            };
+        }
 
         apply_dhcp_opts(
             &mut nm_setting,
@@ -111,6 +135,13 @@ fn gen_nm_ipv4_setting(
             nm_setting.routes = gen_nm_ip_routes(routes, false)?;
             nm_setting.gateway = None;
         }
+        if let Some(absent_routes) = keep_routes {
+            nm_setting.routes = merge_nm_ip_routes(
+                std::mem::take(&mut nm_setting.routes),
+                exist_routes.as_slice(),
+                absent_routes,
+            );
+        }
     } else {
         // Clean up static routes if ip is disabled
         nm_setting.routes = Vec::new();
@@ -119,9 +150,18 @@ fn gen_nm_ipv4_setting(
     if let Some(rules) = iface_ip.rules.as_ref() {
         nm_setting.route_rules = gen_nm_ip_rules(rules, false)?;
     }
+    if let Some(v) = iface_ip.replace_local_rule {
+        nm_setting.replace_local_rule =
+            Some(nmstate_replace_local_rule_to_nm(v));
+    }
     if let Some(dns) = &iface_ip.dns {
         apply_nm_dns_setting(&mut nm_setting, dns);
     }
+    // User defined DNS priority takes precedence over the one chosen by
+    // nmstate for global DNS
+    if let Some(v) = iface_ip.dns_priority {
+        nm_setting.dns_priority = Some(v);
+    }
     nm_conn.ipv4 = Some(nm_setting);
     Ok(())
 }
@@ -129,6 +169,7 @@ fn gen_nm_ipv4_setting(
 fn gen_nm_ipv6_setting(
     iface_ip: Option<&InterfaceIpv6>,
     routes: Option<&[RouteEntry]>,
+    keep_routes: Option<&[RouteEntry]>,
     nm_conn: &mut NmConnection,
 ) -> Result<(), NmstateError> {
     let iface_ip = match iface_ip {
@@ -151,6 +192,7 @@ fn gen_nm_ipv6_setting(
         .cloned()
         .collect();
     let mut nm_setting = nm_conn.ipv6.as_ref().cloned().unwrap_or_default();
+    let exist_routes = nm_setting.routes.clone();
     let mut addresses: Vec<String> = Vec::new();
     let method = if iface_ip.enabled {
         match (
@@ -188,9 +230,17 @@ fn gen_nm_ipv6_setting(
     nm_setting.addresses = addresses;
     nm_setting.addr_gen_mode =
         Some(nmstate_addr_gen_mode_to_nm(iface_ip.addr_gen_mode.as_ref()));
+    if let Some(v) = iface_ip.dad_transmits {
+        nm_setting.dad_transmits = Some(v);
+    }
+    if let Some(v) = iface_ip.ip6_privacy {
+        nm_setting.ip6_privacy = Some(nmstate_ip6_privacy_to_nm(v));
+    }
     if iface_ip.is_auto() {
         nm_setting.dhcp_timeout = Some(i32::MAX);
-        nm_setting.ra_timeout = Some(i32::MAX);
+        // Validated to be no bigger than i32::MAX by sanitize()
+        nm_setting.ra_timeout =
+            Some(iface_ip.ra_timeout.map(|v| v as i32).unwrap_or(i32::MAX));
         nm_setting.dhcp_duid = Some(
             iface_ip
                 .dhcp_duid
@@ -237,6 +287,13 @@ fn gen_nm_ipv6_setting(
             nm_setting.routes = gen_nm_ip_routes(routes, true)?;
             nm_setting.gateway = None;
         }
+        if let Some(absent_routes) = keep_routes {
+            nm_setting.routes = merge_nm_ip_routes(
+                std::mem::take(&mut nm_setting.routes),
+                exist_routes.as_slice(),
+                absent_routes,
+            );
+        }
     } else {
         // Clean up static routes if ip is disabled
         nm_setting.routes = Vec::new();
@@ -245,22 +302,62 @@ fn gen_nm_ipv6_setting(
     if let Some(rules) = iface_ip.rules.as_ref() {
         nm_setting.route_rules = gen_nm_ip_rules(rules, true)?;
     }
+    if let Some(v) = iface_ip.replace_local_rule {
+        nm_setting.replace_local_rule =
+            Some(nmstate_replace_local_rule_to_nm(v));
+    }
     if let Some(dns) = &iface_ip.dns {
         apply_nm_dns_setting(&mut nm_setting, dns);
     }
+    // User defined DNS priority takes precedence over the one chosen by
+    // nmstate for global DNS
+    if let Some(v) = iface_ip.dns_priority {
+        nm_setting.dns_priority = Some(v);
+    }
     nm_conn.ipv6 = Some(nm_setting);
     Ok(())
 }
 
+// When `keep_routes` is set, routes of existing profile not defined in
+// `routes` are preserved unless matching any of the absent routes it holds.
 pub(crate) fn gen_nm_ip_setting(
     iface: &Interface,
     routes: Option<&[RouteEntry]>,
+    keep_routes: Option<&[RouteEntry]>,
     nm_conn: &mut NmConnection,
 ) -> Result<(), NmstateError> {
     let base_iface = iface.base_iface();
     if base_iface.can_have_ip() {
-        gen_nm_ipv4_setting(base_iface.ipv4.as_ref(), routes, nm_conn)?;
-        gen_nm_ipv6_setting(base_iface.ipv6.as_ref(), routes, nm_conn)?;
+        if let Some(ipv4) = base_iface.ipv4.as_ref().filter(|i| !i.enabled) {
+            validate_ip_disabled(
+                base_iface.name.as_str(),
+                false,
+                ipv4.addresses.as_deref(),
+                routes,
+                ipv4.dns.as_ref(),
+            )?;
+        }
+        if let Some(ipv6) = base_iface.ipv6.as_ref().filter(|i| !i.enabled) {
+            validate_ip_disabled(
+                base_iface.name.as_str(),
+                true,
+                ipv6.addresses.as_deref(),
+                routes,
+                ipv6.dns.as_ref(),
+            )?;
+        }
+        gen_nm_ipv4_setting(
+            base_iface.ipv4.as_ref(),
+            routes,
+            keep_routes,
+            nm_conn,
+        )?;
+        gen_nm_ipv6_setting(
+            base_iface.ipv6.as_ref(),
+            routes,
+            keep_routes,
+            nm_conn,
+        )?;
         apply_nmstate_wait_ip(base_iface, nm_conn);
     } else {
         nm_conn.ipv4 = None;
@@ -269,6 +366,45 @@ pub(crate) fn gen_nm_ip_setting(
     Ok(())
 }
 
+// Static IP address, route or DNS name server cannot be applied to interface
+// with IP stack disabled, raise error instead of silently discarding them.
+fn validate_ip_disabled(
+    iface_name: &str,
+    is_ipv6: bool,
+    addresses: Option<&[InterfaceIpAddr]>,
+    routes: Option<&[RouteEntry]>,
+    dns: Option<&DnsClientState>,
+) -> Result<(), NmstateError> {
+    let family = if is_ipv6 { "IPv6" } else { "IPv4" };
+    let found = if addresses.map(|a| !a.is_empty()) == Some(true) {
+        Some("static IP address")
+    } else if routes
+        .unwrap_or_default()
+        .iter()
+        .any(|r| !r.is_absent() && r.is_ipv6() == is_ipv6)
+    {
+        Some("static route")
+    } else if dns.and_then(|d| d.server.as_ref()).map(|s| !s.is_empty())
+        == Some(true)
+    {
+        Some("DNS name server")
+    } else {
+        None
+    };
+    if let Some(found) = found {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Interface {iface_name} has {family} disabled but \
+                {family} {found} is defined"
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
+}
+
 fn apply_dhcp_opts(
     nm_setting: &mut NmSettingIp,
     auto_dns: Option<bool>,
@@ -306,12 +442,86 @@ fn nmstate_addr_gen_mode_to_nm(addr_gen_mode: Option<&Ipv6AddrGenMode>) -> i32 {
     match addr_gen_mode {
         Some(Ipv6AddrGenMode::StablePrivacy) => ADDR_GEN_MODE_STABLE_PRIVACY,
         Some(Ipv6AddrGenMode::Eui64) | None => ADDR_GEN_MODE_EUI64,
+        Some(Ipv6AddrGenMode::DefaultOrEui64) => ADDR_GEN_MODE_DEFAULT_OR_EUI64,
+        Some(Ipv6AddrGenMode::Default) => ADDR_GEN_MODE_DEFAULT,
         Some(Ipv6AddrGenMode::Other(s)) => {
             s.parse::<i32>().unwrap_or(ADDR_GEN_MODE_EUI64)
         }
     }
 }
 
+fn nmstate_ip6_privacy_to_nm(ip6_privacy: Ipv6Privacy) -> i32 {
+    match ip6_privacy {
+        Ipv6Privacy::Default => IP6_PRIVACY_DEFAULT,
+        Ipv6Privacy::Disabled => IP6_PRIVACY_DISABLED,
+        Ipv6Privacy::PreferPublicAddr => IP6_PRIVACY_PREFER_PUBLIC_ADDR,
+        Ipv6Privacy::PreferTempAddr => IP6_PRIVACY_PREFER_TEMP_ADDR,
+    }
+}
+
+fn nmstate_replace_local_rule_to_nm(replace_local_rule: bool) -> i32 {
+    if replace_local_rule {
+        NM_REPLACE_LOCAL_RULE_YES
+    } else {
+        NM_REPLACE_LOCAL_RULE_NO
+    }
+}
+
+// Older NetworkManager does not support `replace-local-rule`, drop it with
+// warning as NetworkManager keeps the default local rule anyway.
+pub(crate) fn fallback_nm_replace_local_rule(
+    nm_conn: &mut NmConnection,
+    nm_version: Option<&NmVersion>,
+) {
+    let nm_version = match nm_version {
+        Some(v) if v < &NmVersion::REPLACE_LOCAL_RULE => v,
+        _ => return,
+    };
+    for nm_setting in [nm_conn.ipv4.as_mut(), nm_conn.ipv6.as_mut()]
+        .into_iter()
+        .flatten()
+    {
+        if nm_setting.replace_local_rule.take().is_some() {
+            log::warn!(
+                "The replace-local-rule requires NetworkManager {} or \
+                newer, but current version is {nm_version}, ignoring it",
+                NmVersion::REPLACE_LOCAL_RULE
+            );
+        }
+    }
+}
+
+// Older NetworkManager does not support `default-or-eui64` and `default`
+// addr-gen-mode, fallback to the mode they default to.
+pub(crate) fn fallback_nm_addr_gen_mode(
+    nm_conn: &mut NmConnection,
+    nm_version: Option<&NmVersion>,
+) {
+    let nm_version = match nm_version {
+        Some(v) if v < &NmVersion::ADDR_GEN_MODE_DEFAULT => v,
+        _ => return,
+    };
+    if let Some(nm_setting) = nm_conn.ipv6.as_mut() {
+        let (mode, fallback) = match nm_setting.addr_gen_mode {
+            Some(ADDR_GEN_MODE_DEFAULT_OR_EUI64) => {
+                (Ipv6AddrGenMode::DefaultOrEui64, Ipv6AddrGenMode::Eui64)
+            }
+            Some(ADDR_GEN_MODE_DEFAULT) => {
+                (Ipv6AddrGenMode::Default, Ipv6AddrGenMode::StablePrivacy)
+            }
+            _ => return,
+        };
+        log::warn!(
+            "The IPv6 addr-gen-mode {mode} requires NetworkManager {} or \
+            newer, but current version is {nm_version}, falling back to \
+            {fallback}",
+            NmVersion::ADDR_GEN_MODE_DEFAULT
+        );
+        nm_setting.addr_gen_mode =
+            Some(nmstate_addr_gen_mode_to_nm(Some(&fallback)));
+    }
+}
+
 fn apply_nmstate_wait_ip(
     base_iface: &BaseInterface,
     nm_conn: &mut NmConnection,
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ErrorKind, NmstateError};
+
+// Ethernet MAC address holds 6 bytes, EUI-64 holds 8 bytes while
+// InfiniBand holds 20 bytes.
+const ETH_MAC_LEN: usize = 6;
+const EUI64_MAC_LEN: usize = 8;
+const IB_MAC_LEN: usize = 20;
+
+// Convert MAC address to lower case hex string separated by `:`, both `:`
+// and `-` are accepted as separator.
+pub(crate) fn canonicalize_mac_address(
+    mac: &str,
+) -> Result<String, NmstateError> {
+    let octets: Vec<&str> = mac.split([':', '-']).collect();
+    if [ETH_MAC_LEN, EUI64_MAC_LEN, IB_MAC_LEN].contains(&octets.len())
+        && octets
+            .iter()
+            .all(|o| o.len() == 2 && o.chars().all(|c| c.is_ascii_hexdigit()))
+    {
+        Ok(octets.join(":").to_ascii_lowercase())
+    } else {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!("Invalid MAC address {mac}"),
+        );
+        log::error!("{}", e);
+        Err(e)
+    }
+}
@@ -1,4 +1,4 @@
-use crate::nm::nm_dbus::NmSettingMacVlan;
+use crate::nm::nm_dbus::{NmConnection, NmSettingMacVlan};
 
 use crate::{MacVlanConfig, MacVtapConfig};
 
@@ -27,3 +27,29 @@ impl From<&MacVtapConfig> for NmSettingMacVlan {
         settings
     }
 }
+
+// NetworkManager has no property for tap flags of MAC VTAP, we store them in
+// user data for VM hypervisor to consume when opening the tap device.
+const NMSTATE_MAC_VTAP_VNET_HDR: &str = "nmstate.mac-vtap.vnet-hdr";
+const NMSTATE_MAC_VTAP_MULTI_QUEUE: &str = "nmstate.mac-vtap.multi-queue";
+
+pub(crate) fn gen_nm_mac_vtap_tap_flags(
+    config: &MacVtapConfig,
+    nm_conn: &mut NmConnection,
+) {
+    if config.vnet_hdr.is_none() && config.multi_queue.is_none() {
+        return;
+    }
+    let mut nm_setting = nm_conn.user.clone().unwrap_or_default();
+    let mut data = nm_setting.data.take().unwrap_or_default();
+    for (key, value) in [
+        (NMSTATE_MAC_VTAP_VNET_HDR, config.vnet_hdr),
+        (NMSTATE_MAC_VTAP_MULTI_QUEUE, config.multi_queue),
+    ] {
+        if let Some(v) = value {
+            data.insert(key.to_string(), v.to_string());
+        }
+    }
+    nm_setting.data = Some(data);
+    nm_conn.user = Some(nm_setting);
+}
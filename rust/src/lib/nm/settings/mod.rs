@@ -11,13 +11,16 @@ mod infiniband;
 mod inter_connections;
 mod ip;
 mod loopback;
+mod mac;
 mod mac_vlan;
 mod macsec;
 mod mptcp;
 mod ovs;
+mod proxy;
 mod route;
 mod route_rule;
 mod sriov;
+mod team;
 mod user;
 mod veth;
 mod vlan;
@@ -25,28 +28,47 @@ mod vpn;
 mod vrf;
 mod vxlan;
 mod wired;
+mod wireguard;
 
+#[cfg(test)]
+pub(crate) use self::bond::gen_nm_bond_setting;
+#[cfg(test)]
 pub(crate) use self::connection::{
-    get_exist_profile, iface_to_nm_connections, SUPPORTED_NM_KERNEL_IFACE_TYPES,
+    gen_nm_conn_setting, uuid_from_name_and_type, NM_MULTI_CONNECT_DEFAULT,
+};
+pub(crate) use self::connection::{
+    get_exist_profile, iface_to_nm_connections, NM_SETTING_BOND_SETTING_NAME,
+    NM_SETTING_BRIDGE_SETTING_NAME, NM_SETTING_OVS_BRIDGE_SETTING_NAME,
+    NM_SETTING_OVS_IFACE_SETTING_NAME, NM_SETTING_OVS_PORT_SETTING_NAME,
+    NM_SETTING_USER_SPACES, NM_SETTING_VRF_SETTING_NAME,
+    SUPPORTED_NM_KERNEL_IFACE_TYPES,
 };
 #[cfg(feature = "query_apply")]
 pub(crate) use self::connection::{
-    iface_type_to_nm, NM_SETTING_BOND_SETTING_NAME,
-    NM_SETTING_BRIDGE_SETTING_NAME, NM_SETTING_DUMMY_SETTING_NAME,
+    iface_type_to_nm, NM_MULTI_CONNECT_MANUAL_MULTIPLE,
+    NM_MULTI_CONNECT_MULTIPLE, NM_MULTI_CONNECT_SINGLE, NM_RESOLVE_MODE_NO,
+    NM_RESOLVE_MODE_RESOLVE, NM_RESOLVE_MODE_YES,
+    NM_SETTING_DUMMY_SETTING_NAME, NM_SETTING_GENERIC_SETTING_NAME,
     NM_SETTING_HSR_SETTING_NAME, NM_SETTING_INFINIBAND_SETTING_NAME,
     NM_SETTING_LOOPBACK_SETTING_NAME, NM_SETTING_MACSEC_SETTING_NAME,
-    NM_SETTING_MACVLAN_SETTING_NAME, NM_SETTING_OVS_BRIDGE_SETTING_NAME,
-    NM_SETTING_OVS_IFACE_SETTING_NAME, NM_SETTING_OVS_PORT_SETTING_NAME,
+    NM_SETTING_MACVLAN_SETTING_NAME, NM_SETTING_TEAM_SETTING_NAME,
     NM_SETTING_VETH_SETTING_NAME, NM_SETTING_VLAN_SETTING_NAME,
-    NM_SETTING_VPN_SETTING_NAME, NM_SETTING_VRF_SETTING_NAME,
-    NM_SETTING_VXLAN_SETTING_NAME, NM_SETTING_WIRED_SETTING_NAME,
+    NM_SETTING_VPN_SETTING_NAME, NM_SETTING_VXLAN_SETTING_NAME,
+    NM_SETTING_WIRED_SETTING_NAME, NM_SETTING_WIREGUARD_SETTING_NAME,
 };
 pub(crate) use self::inter_connections::{
     use_uuid_for_controller_reference, use_uuid_for_parent_reference,
 };
 pub(crate) use self::ip::fix_ip_dhcp_timeout;
+#[cfg(test)]
+pub(crate) use self::ip::gen_nm_ip_setting;
+#[cfg(test)]
+pub(crate) use self::mac::canonicalize_mac_address;
 
 #[cfg(feature = "query_apply")]
-pub(crate) use self::bond::get_bond_balance_slb;
+pub(crate) use self::bond::{get_bond_balance_slb, get_bond_peer_notif_delay};
+pub(crate) use self::user::{
+    is_nm_conn_nmstate_managed, mark_nm_conn_as_nmstate_managed,
+};
 #[cfg(feature = "query_apply")]
-pub(crate) use self::user::NMSTATE_DESCRIPTION;
+pub(crate) use self::user::{NMSTATE_DESCRIPTION, NMSTATE_USER_DATA_DOMAIN};
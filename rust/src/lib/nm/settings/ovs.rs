@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use super::super::nm_dbus::{
     NmConnection, NmRange, NmSettingOvsDpdk, NmSettingOvsExtIds,
@@ -10,12 +10,13 @@ use super::super::settings::connection::gen_nm_conn_setting;
 
 use crate::{
     BaseInterface, BridgePortTrunkTag, Interface, InterfaceType, NmstateError,
-    OvsBridgeBondMode, OvsBridgeInterface, OvsBridgePortConfig,
-    OvsDbIfaceConfig, OvsInterface, UnknownInterface,
+    OvsBridgeBondMode, OvsBridgeInterface, OvsBridgeOptions,
+    OvsBridgePortConfig, OvsDbIfaceConfig, OvsDpdkConfig, OvsInterface,
+    UnknownInterface,
 };
 
 pub(crate) fn create_ovs_port_nm_conn(
-    br_name: &str,
+    br_iface: &OvsBridgeInterface,
     port_conf: &OvsBridgePortConfig,
     exist_nm_conn: Option<&NmConnection>,
     stable_uuid: bool,
@@ -24,11 +25,23 @@ pub(crate) fn create_ovs_port_nm_conn(
     let mut base_iface = BaseInterface::new();
     base_iface.name = port_conf.name.clone();
     base_iface.iface_type = InterfaceType::Other("ovs-port".to_string());
-    base_iface.controller = Some(br_name.to_string());
+    base_iface.controller = Some(br_iface.base.name.clone());
     base_iface.controller_type = Some(InterfaceType::OvsBridge);
     let mut iface = UnknownInterface::new();
     iface.base = base_iface;
-    gen_nm_conn_setting(&Interface::Unknown(iface), &mut nm_conn, stable_uuid)?;
+    gen_nm_conn_setting(
+        &Interface::Unknown(iface),
+        &mut nm_conn,
+        stable_uuid,
+        None,
+    )?;
+    // The ovs-port is controller of OVS system or internal interface, inherit
+    // autoconnect-ports from OVS bridge to have activation of OVS bridge
+    // bringing up the whole stack unless disabled.
+    if let Some(nm_conn_set) = nm_conn.connection.as_mut() {
+        nm_conn_set.autoconnect_ports =
+            Some(br_iface.base.autoconnect_ports.unwrap_or(true));
+    }
 
     let mut nm_ovs_port_set =
         nm_conn.ovs_port.as_ref().cloned().unwrap_or_default();
@@ -131,11 +144,35 @@ pub(crate) fn gen_nm_ovs_br_setting(
                     nm_ovs_br_set.datapath_type = Some(dp_type.to_string());
                 }
             }
+            if let Some(dp_id) = br_opts.datapath_id.as_deref() {
+                apply_ovs_br_datapath_id(dp_id, nm_conn);
+            }
         }
     }
     nm_conn.ovs_bridge = Some(nm_ovs_br_set);
 }
 
+// NetworkManager has no property for `datapath-id`, store it into
+// `other_config` of OVS bridge.
+fn apply_ovs_br_datapath_id(dp_id: &str, nm_conn: &mut NmConnection) {
+    let mut nm_setting = nm_conn
+        .ovs_other_config
+        .as_ref()
+        .cloned()
+        .unwrap_or_default();
+    let mut data = nm_setting.data.take().unwrap_or_default();
+    if dp_id.is_empty() {
+        data.remove(OvsBridgeOptions::DATAPATH_ID_KEY);
+    } else {
+        data.insert(
+            OvsBridgeOptions::DATAPATH_ID_KEY.to_string(),
+            dp_id.to_string(),
+        );
+    }
+    nm_setting.data = Some(data);
+    nm_conn.ovs_other_config = Some(nm_setting);
+}
+
 pub(crate) fn gen_nm_ovs_iface_setting(
     iface: &OvsInterface,
     nm_conn: &mut NmConnection,
@@ -164,6 +201,9 @@ pub(crate) fn gen_nm_ovs_iface_setting(
             nm_ovs_dpdk.n_txq_desc = dpdk_iface.n_txq_desc;
             nm_conn.ovs_dpdk = Some(nm_ovs_dpdk);
             nm_conn.ovs_iface = Some(nm_ovs_iface_set);
+            if let Some(affinity) = dpdk_iface.pmd_rxq_affinity.as_ref() {
+                apply_pmd_rxq_affinity(affinity, nm_conn);
+            }
         }
     }
     if nm_conn.ovs_iface.is_none() {
@@ -171,6 +211,42 @@ pub(crate) fn gen_nm_ovs_iface_setting(
         nm_set.iface_type = Some("internal".to_string());
         nm_conn.ovs_iface = Some(nm_set);
     }
+    // NetworkManager set `mtu_request` of OVS interface from the MTU of
+    // wired setting.
+    if let Some(mtu) = iface.mtu_request {
+        let mut nm_wired_set =
+            nm_conn.wired.as_ref().cloned().unwrap_or_default();
+        nm_wired_set.mtu = Some(mtu as u32);
+        nm_conn.wired = Some(nm_wired_set);
+    }
+}
+
+// NetworkManager has no property for `pmd-rxq-affinity`, store it into
+// `other_config` in the format of `<queue_id>:<core_id>,<queue_id>:<core_id>`
+fn apply_pmd_rxq_affinity(
+    affinity: &BTreeMap<u32, u32>,
+    nm_conn: &mut NmConnection,
+) {
+    let mut nm_setting = nm_conn
+        .ovs_other_config
+        .as_ref()
+        .cloned()
+        .unwrap_or_default();
+    let mut data = nm_setting.data.take().unwrap_or_default();
+    if affinity.is_empty() {
+        data.remove(OvsDpdkConfig::PMD_RXQ_AFFINITY_KEY);
+    } else {
+        data.insert(
+            OvsDpdkConfig::PMD_RXQ_AFFINITY_KEY.to_string(),
+            affinity
+                .iter()
+                .map(|(queue_id, core_id)| format!("{queue_id}:{core_id}"))
+                .collect::<Vec<String>>()
+                .join(","),
+        );
+    }
+    nm_setting.data = Some(data);
+    nm_conn.ovs_other_config = Some(nm_setting);
 }
 
 fn apply_iface_ovsdb_conf(conf: &OvsDbIfaceConfig, nm_conn: &mut NmConnection) {
@@ -0,0 +1,38 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::nm_dbus::{
+    NmConnection, NM_SETTING_PROXY_METHOD_AUTO, NM_SETTING_PROXY_METHOD_NONE,
+};
+
+use crate::{Interface, ProxyMethod};
+
+pub(crate) fn gen_nm_proxy_setting(
+    iface: &Interface,
+    nm_conn: &mut NmConnection,
+) {
+    if let Some(conf) = iface.base_iface().proxy.as_ref() {
+        let mut nm_setting =
+            nm_conn.proxy.as_ref().cloned().unwrap_or_default();
+        if let Some(method) = conf.method {
+            nm_setting.method = Some(match method {
+                ProxyMethod::None => NM_SETTING_PROXY_METHOD_NONE,
+                ProxyMethod::Auto => NM_SETTING_PROXY_METHOD_AUTO,
+            });
+        }
+        if let Some(v) = conf.browser_only {
+            nm_setting.browser_only = Some(v);
+        }
+        if nm_setting.method == Some(NM_SETTING_PROXY_METHOD_AUTO) {
+            if let Some(v) = conf.pac_url.as_ref() {
+                nm_setting.pac_url = Some(v.to_string());
+            }
+            if let Some(v) = conf.pac_script.as_ref() {
+                nm_setting.pac_script = Some(v.to_string());
+            }
+        } else {
+            nm_setting.pac_url = None;
+            nm_setting.pac_script = None;
+        }
+        nm_conn.proxy = Some(nm_setting);
+    }
+}
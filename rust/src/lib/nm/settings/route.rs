@@ -3,7 +3,8 @@
 use super::super::nm_dbus::NmIpRoute;
 
 use crate::{
-    ip::is_ipv6_addr, InterfaceIpAddr, NmstateError, RouteEntry, RouteType,
+    ip::is_ipv6_addr, ErrorKind, InterfaceIpAddr, NmstateError, RouteEntry,
+    RouteType,
 };
 
 pub(crate) fn gen_nm_ip_routes(
@@ -39,9 +40,119 @@ pub(crate) fn gen_nm_ip_routes(
             Some(RouteType::Blackhole) => Some("blackhole".to_string()),
             Some(RouteType::Prohibit) => Some("prohibit".to_string()),
             Some(RouteType::Unreachable) => Some("unreachable".to_string()),
+            Some(RouteType::Local) => Some("local".to_string()),
+            Some(t) => {
+                let e = NmstateError::new(
+                    ErrorKind::NotSupportedError,
+                    format!(
+                        "NetworkManager does not support route type {t}: \
+                        {route}"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
             None => None,
         };
+        nm_route.onlink = route.onlink;
+        nm_route.src = route.source.as_ref().cloned();
+        nm_route.scope = route.scope.map(u8::from);
+        // NetworkManager refuses duplicate routes, the first one is preferred.
+        if ret.iter().any(|r| is_nm_route_duplicate(r, &nm_route)) {
+            log::debug!("Ignoring duplicate route {route}");
+            continue;
+        }
         ret.push(nm_route);
     }
     Ok(ret)
 }
+
+// Preserve routes of existing NetworkManager profile which are not managed by
+// nmstate. Route defined by nmstate takes precedence over existing route with
+// the same destination and route table. Existing route matching desired
+// absent route is removed.
+pub(crate) fn merge_nm_ip_routes(
+    new_routes: Vec<NmIpRoute>,
+    exist_routes: &[NmIpRoute],
+    absent_routes: &[RouteEntry],
+) -> Vec<NmIpRoute> {
+    let mut ret = new_routes.clone();
+    for exist_route in exist_routes {
+        if new_routes
+            .iter()
+            .any(|r| is_nm_route_same_dest(r, exist_route))
+        {
+            continue;
+        }
+        if absent_routes
+            .iter()
+            .any(|r| is_nm_route_match_absent(exist_route, r))
+        {
+            continue;
+        }
+        if ret.iter().any(|r| is_nm_route_duplicate(r, exist_route)) {
+            continue;
+        }
+        log::debug!(
+            "Keeping existing route {:?}/{:?} not managed by nmstate",
+            exist_route.dest,
+            exist_route.prefix
+        );
+        ret.push(exist_route.clone());
+    }
+    ret
+}
+
+fn is_nm_route_same_dest(a: &NmIpRoute, b: &NmIpRoute) -> bool {
+    a.dest == b.dest && a.prefix == b.prefix && a.table == b.table
+}
+
+fn is_nm_route_match_absent(nm_route: &NmIpRoute, absent: &RouteEntry) -> bool {
+    if let Some(dest) = absent.destination.as_deref().filter(|d| !d.is_empty())
+    {
+        match InterfaceIpAddr::try_from(dest) {
+            Ok(ip_addr) => {
+                if nm_route.dest.as_deref()
+                    != Some(ip_addr.ip.to_string().as_str())
+                    || nm_route.prefix != Some(ip_addr.prefix_length as u32)
+                {
+                    return false;
+                }
+            }
+            Err(_) => return false,
+        }
+    }
+    if absent.next_hop_addr.is_some()
+        && absent.next_hop_addr != nm_route.next_hop
+    {
+        return false;
+    }
+    if let Some(table_id) = absent
+        .table_id
+        .filter(|t| *t != RouteEntry::USE_DEFAULT_ROUTE_TABLE)
+    {
+        if nm_route.table != Some(table_id) {
+            return false;
+        }
+    }
+    if let Some(weight) = absent.weight {
+        if nm_route.weight != Some(weight as u32) {
+            return false;
+        }
+    }
+    if absent.source.is_some() && absent.source != nm_route.src {
+        return false;
+    }
+    if absent.scope.is_some() && absent.scope.map(u8::from) != nm_route.scope {
+        return false;
+    }
+    true
+}
+
+fn is_nm_route_duplicate(a: &NmIpRoute, b: &NmIpRoute) -> bool {
+    a.dest == b.dest
+        && a.prefix == b.prefix
+        && a.next_hop == b.next_hop
+        && a.table == b.table
+        && a.metric == b.metric
+}
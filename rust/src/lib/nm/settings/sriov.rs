@@ -1,24 +1,49 @@
+use super::mac::canonicalize_mac_address;
 use crate::nm::nm_dbus::{
     NmConnection, NmSettingSriovVf, NmSettingSriovVfVlan,
+    NM_SRIOV_ESWITCH_ENCAP_MODE_BASIC, NM_SRIOV_ESWITCH_ENCAP_MODE_NONE,
+    NM_SRIOV_ESWITCH_MODE_LEGACY, NM_SRIOV_ESWITCH_MODE_SWITCHDEV,
+};
+use crate::nm::NmVersion;
+use crate::{
+    ErrorKind, NmstateError, SrIovConfig, SrIovEswitchEncapMode,
+    SrIovEswitchMode, SrIovVfConfig,
 };
-use crate::{EthernetInterface, SrIovVfConfig};
 
 pub(crate) fn gen_nm_sriov_setting(
-    iface: &EthernetInterface,
+    iface_name: &str,
+    sriov_conf: Option<&SrIovConfig>,
     nm_conn: &mut NmConnection,
-) {
-    let sriov_conf = match iface
-        .ethernet
-        .as_ref()
-        .and_then(|eth_conf| eth_conf.sr_iov.as_ref())
-    {
+    nm_version: Option<&NmVersion>,
+) -> Result<(), NmstateError> {
+    let sriov_conf = match sriov_conf {
         Some(c) => c,
-        None => return,
+        None => return Ok(()),
     };
 
+    if sriov_conf.eswitch_mode.is_some()
+        || sriov_conf.eswitch_encap_mode.is_some()
+    {
+        if let Some(nm_version) = nm_version {
+            if nm_version < &NmVersion::SRIOV_ESWITCH {
+                let e = NmstateError::new(
+                    ErrorKind::NotImplementedError,
+                    format!(
+                        "The SR-IOV eswitch-mode and eswitch-encap-mode of \
+                        interface {iface_name} require NetworkManager {} or \
+                        newer, but current version is {nm_version}",
+                        NmVersion::SRIOV_ESWITCH,
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        }
+    }
+
     if sriov_conf.total_vfs == Some(0) {
         nm_conn.sriov = None;
-        return;
+        return Ok(());
     }
 
     let mut nm_sriov_set = nm_conn.sriov.as_ref().cloned().unwrap_or_default();
@@ -27,20 +52,35 @@ pub(crate) fn gen_nm_sriov_setting(
         nm_sriov_set.total_vfs = Some(v);
     }
 
+    if let Some(v) = sriov_conf.eswitch_mode {
+        nm_sriov_set.eswitch_mode = Some(match v {
+            SrIovEswitchMode::Legacy => NM_SRIOV_ESWITCH_MODE_LEGACY,
+            SrIovEswitchMode::Switchdev => NM_SRIOV_ESWITCH_MODE_SWITCHDEV,
+        });
+    }
+
+    if let Some(v) = sriov_conf.eswitch_encap_mode {
+        nm_sriov_set.eswitch_encap_mode = Some(match v {
+            SrIovEswitchEncapMode::None => NM_SRIOV_ESWITCH_ENCAP_MODE_NONE,
+            SrIovEswitchEncapMode::Basic => NM_SRIOV_ESWITCH_ENCAP_MODE_BASIC,
+        });
+    }
+
     if let Some(vfs) = &sriov_conf.vfs {
         nm_sriov_set.vfs = Some(gen_nm_vfs(
             vfs,
             nm_sriov_set.vfs.as_ref().cloned().unwrap_or_default(),
-        ));
+        )?);
     }
 
     nm_conn.sriov = Some(nm_sriov_set);
+    Ok(())
 }
 
 fn gen_nm_vfs(
     vfs: &[SrIovVfConfig],
     exist_nm_sriov_sets: Vec<NmSettingSriovVf>,
-) -> Vec<NmSettingSriovVf> {
+) -> Result<Vec<NmSettingSriovVf>, NmstateError> {
     let mut ret = Vec::with_capacity(vfs.len());
     for (i, vf) in vfs.iter().enumerate() {
         let mut nm_vf =
@@ -51,7 +91,7 @@ fn gen_nm_vfs(
             };
         nm_vf.index = Some(vf.id);
         if let Some(v) = &vf.mac_address {
-            nm_vf.mac = Some(v.to_string());
+            nm_vf.mac = Some(canonicalize_mac_address(v)?);
         }
         if let Some(v) = vf.spoof_check {
             nm_vf.spoof_check = Some(v);
@@ -74,5 +114,5 @@ fn gen_nm_vfs(
         }
         ret.push(nm_vf);
     }
-    ret
+    Ok(ret)
 }
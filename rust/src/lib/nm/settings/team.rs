@@ -0,0 +1,81 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use super::super::nm_dbus::NmConnection;
+
+use crate::{NmstateError, TeamInterface};
+
+pub(crate) fn gen_nm_team_setting(
+    team_iface: &TeamInterface,
+    nm_conn: &mut NmConnection,
+) -> Result<(), NmstateError> {
+    let mut nm_team_set = nm_conn.team.as_ref().cloned().unwrap_or_default();
+    if let Some(team_conf) = team_iface.team.as_ref() {
+        // Desired `config` overrides the whole existing teamd config, while
+        // `runner` only overrides the `runner.name` of it.
+        let config = team_conf
+            .config
+            .as_deref()
+            .or(nm_team_set.config.as_deref())
+            .filter(|c| !c.is_empty());
+        let mut conf_value: serde_json::Value = if let Some(config) = config {
+            serde_json::from_str(config)?
+        } else {
+            serde_json::Value::Object(serde_json::Map::new())
+        };
+        if let (Some(runner), Some(conf_obj)) =
+            (team_conf.runner, conf_value.as_object_mut())
+        {
+            let runner_value = conf_obj.entry("runner").or_insert_with(|| {
+                serde_json::Value::Object(Default::default())
+            });
+            if let Some(runner_obj) = runner_value.as_object_mut() {
+                runner_obj.insert(
+                    "name".to_string(),
+                    serde_json::Value::String(runner.to_string()),
+                );
+            }
+        }
+        nm_team_set.runner = conf_value
+            .get("runner")
+            .and_then(|r| r.get("name"))
+            .and_then(|n| n.as_str())
+            .map(|n| n.to_string());
+        nm_team_set.config = Some(serde_json::to_string(&conf_value)?);
+    }
+    nm_conn.team = Some(nm_team_set);
+    Ok(())
+}
+
+pub(crate) fn gen_nm_team_port_setting(
+    team_iface: &TeamInterface,
+    nm_conn: &mut NmConnection,
+) {
+    let port_conf = if let Some(p) = nm_conn.iface_name().and_then(|n| {
+        team_iface
+            .team
+            .as_ref()
+            .and_then(|c| c.port.as_ref())
+            .and_then(|ports| ports.iter().find(|p| p.name == n))
+    }) {
+        p
+    } else {
+        return;
+    };
+    let mut nm_set = nm_conn.team_port.as_ref().cloned().unwrap_or_default();
+    if let Some(v) = port_conf.prio {
+        nm_set.prio = Some(v);
+    }
+    if let Some(v) = port_conf.sticky {
+        nm_set.sticky = Some(v);
+    }
+    if let Some(v) = port_conf.queue_id {
+        nm_set.queue_id = Some(v);
+    }
+    if let Some(v) = port_conf.lacp_prio {
+        nm_set.lacp_prio = Some(v);
+    }
+    if let Some(v) = port_conf.lacp_key {
+        nm_set.lacp_key = Some(v);
+    }
+    nm_conn.team_port = Some(nm_set);
+}
@@ -1,25 +1,104 @@
-use std::collections::HashMap;
+use crate::nm::nm_dbus::NmConnection;
 
-use crate::nm::nm_dbus::{NmConnection, NmSettingUser};
-
-use crate::Interface;
+use crate::{ErrorKind, Interface, NmstateError};
 
 pub(crate) const NMSTATE_DESCRIPTION: &str = "nmstate.interface.description";
+pub(crate) const NMSTATE_USER_DATA_DOMAIN: &str = "nmstate.";
+// Marking profile as owned by nmstate for purging unmanaged profiles
+pub(crate) const NMSTATE_MANAGED: &str = "nmstate.managed";
+
+// Maximum length of user data key defined by NetworkManager
+const NM_USER_DATA_KEY_MAX_LEN: usize = 255;
 
 pub(crate) fn gen_nm_user_setting(
     iface: &Interface,
     nm_conn: &mut NmConnection,
-) {
-    if let Some(description) = iface.base_iface().description.as_ref() {
-        let mut data: HashMap<String, String> = HashMap::new();
-        if !description.is_empty() {
+) -> Result<(), NmstateError> {
+    let base_iface = iface.base_iface();
+    if base_iface.description.is_none() && base_iface.user_data.is_none() {
+        return Ok(());
+    }
+    let mut nm_setting = nm_conn.user.clone().unwrap_or_default();
+    let mut data = nm_setting.data.take().unwrap_or_default();
+    if let Some(description) = base_iface.description.as_ref() {
+        if description.is_empty() {
+            data.remove(NMSTATE_DESCRIPTION);
+        } else {
             data.insert(
                 NMSTATE_DESCRIPTION.to_string(),
                 description.to_string(),
             );
         }
-        let mut nm_setting = NmSettingUser::default();
-        nm_setting.data = Some(data);
-        nm_conn.user = Some(nm_setting);
     }
+    if let Some(user_data) = base_iface.user_data.as_ref() {
+        data.retain(|k, _| k.starts_with(NMSTATE_USER_DATA_DOMAIN));
+        for (key, value) in user_data.iter() {
+            validate_user_data_key(iface.name(), key)?;
+            data.insert(key.to_string(), value.to_string());
+        }
+    }
+    nm_setting.data = Some(data);
+    nm_conn.user = Some(nm_setting);
+    Ok(())
+}
+
+// NetworkManager requires user data key:
+//  * Only contains ASCII alphanumeric characters or any of `-_+/=.`
+//  * Contains at least one `.` but does not start or end with it
+//  * Does not contain two successive `.`
+//  * No longer than 255 bytes
+fn validate_user_data_key(
+    iface_name: &str,
+    key: &str,
+) -> Result<(), NmstateError> {
+    let reason = if key.len() > NM_USER_DATA_KEY_MAX_LEN {
+        Some(format!(
+            "should not be longer than {NM_USER_DATA_KEY_MAX_LEN} bytes"
+        ))
+    } else if let Some(c) = key
+        .chars()
+        .find(|c| !(c.is_ascii_alphanumeric() || "-_+/=.".contains(*c)))
+    {
+        Some(format!("contains invalid character '{c}'"))
+    } else if !key.contains('.')
+        || key.starts_with('.')
+        || key.ends_with('.')
+        || key.contains("..")
+    {
+        Some("should be in the format of `domain.key`".to_string())
+    } else if key.starts_with(NMSTATE_USER_DATA_DOMAIN) {
+        Some("is using the domain reserved by nmstate".to_string())
+    } else {
+        None
+    };
+    if let Some(reason) = reason {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "Invalid user data key '{key}' of interface {iface_name}: \
+                {reason}"
+            ),
+        );
+        log::error!("{}", e);
+        return Err(e);
+    }
+    Ok(())
+}
+
+pub(crate) fn mark_nm_conn_as_nmstate_managed(nm_conn: &mut NmConnection) {
+    let mut nm_setting = nm_conn.user.clone().unwrap_or_default();
+    let mut data = nm_setting.data.take().unwrap_or_default();
+    data.insert(NMSTATE_MANAGED.to_string(), "true".to_string());
+    nm_setting.data = Some(data);
+    nm_conn.user = Some(nm_setting);
+}
+
+pub(crate) fn is_nm_conn_nmstate_managed(nm_conn: &NmConnection) -> bool {
+    nm_conn
+        .user
+        .as_ref()
+        .and_then(|u| u.data.as_ref())
+        .and_then(|data| data.get(NMSTATE_MANAGED))
+        .map(|v| v == "true")
+        == Some(true)
 }
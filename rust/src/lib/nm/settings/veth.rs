@@ -51,8 +51,8 @@ pub(crate) fn create_veth_peer_profile_if_not_found(
     };
     let iface = Interface::Ethernet(eth_iface);
     let mut nm_conn = NmConnection::default();
-    gen_nm_conn_setting(&iface, &mut nm_conn, stable_uuid)?;
-    gen_nm_ip_setting(&iface, None, &mut nm_conn)?;
+    gen_nm_conn_setting(&iface, &mut nm_conn, stable_uuid, None)?;
+    gen_nm_ip_setting(&iface, None, None, &mut nm_conn)?;
     nm_conn.veth = Some(NmSettingVeth::from(&VethConfig {
         peer: end_name.to_string(),
     }));
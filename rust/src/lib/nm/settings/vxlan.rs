@@ -1,8 +1,32 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use super::super::nm_dbus::NmSettingVxlan;
+use super::super::nm_dbus::{NmConnection, NmSettingVxlan};
 
-use crate::VxlanConfig;
+use crate::{ErrorKind, NmstateError, VxlanConfig, VxlanInterface};
+
+pub(crate) fn gen_nm_vxlan_setting(
+    iface: &VxlanInterface,
+    nm_conn: &mut NmConnection,
+) -> Result<(), NmstateError> {
+    if let Some(conf) = iface.vxlan.as_ref() {
+        // NetworkManager has no property for these kernel VXLAN flags, and
+        // they can only be set when creating the kernel interface.
+        if conf.gbp == Some(true) || conf.gpe == Some(true) {
+            let e = NmstateError::new(
+                ErrorKind::NotImplementedError,
+                format!(
+                    "The VXLAN gbp and gpe extensions of interface {} are \
+                    not supported by NetworkManager backend",
+                    iface.base.name.as_str()
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+        nm_conn.vxlan = Some(NmSettingVxlan::from(conf));
+    }
+    Ok(())
+}
 
 impl From<&VxlanConfig> for NmSettingVxlan {
     fn from(config: &VxlanConfig) -> Self {
@@ -1,13 +1,22 @@
 // SPDX-License-Identifier: Apache-2.0
 
+use super::mac::canonicalize_mac_address;
 use crate::nm::nm_dbus::NmConnection;
 
-use crate::{Interface, InterfaceIdentifier};
+use crate::{
+    ErrorKind, Interface, InterfaceIdentifier, InterfaceType, MergedInterfaces,
+    NmstateError,
+};
+
+// Encapsulation overhead over IPv4 underlay
+const VXLAN_OVERHEAD: u64 = 50;
+// SecTAG and ICV
+const MACSEC_OVERHEAD: u64 = 32;
 
 pub(crate) fn gen_nm_wired_setting(
     iface: &Interface,
     nm_conn: &mut NmConnection,
-) {
+) -> Result<(), NmstateError> {
     let mut nm_wired_set = nm_conn.wired.as_ref().cloned().unwrap_or_default();
 
     let mut flag_need_wired = false;
@@ -15,10 +24,11 @@ pub(crate) fn gen_nm_wired_setting(
     let base_iface = iface.base_iface();
 
     if let Some(mac) = &base_iface.mac_address {
+        let mac = canonicalize_mac_address(mac)?;
         if base_iface.identifier == Some(InterfaceIdentifier::MacAddress) {
-            nm_wired_set.mac_address = Some(mac.to_string());
+            nm_wired_set.mac_address = Some(mac);
         } else {
-            nm_wired_set.cloned_mac_address = Some(mac.to_string());
+            nm_wired_set.cloned_mac_address = Some(mac);
         }
         flag_need_wired = true;
     }
@@ -29,6 +39,22 @@ pub(crate) fn gen_nm_wired_setting(
 
     if let Interface::Ethernet(eth_iface) = iface {
         if let Some(eth_conf) = eth_iface.ethernet.as_ref() {
+            if let Some(cloned_mac) = eth_conf.cloned_mac {
+                if base_iface.mac_address.is_none() {
+                    nm_wired_set.cloned_mac_address = None;
+                    nm_wired_set.assigned_mac_address =
+                        Some(cloned_mac.to_string());
+                    flag_need_wired = true;
+                }
+            }
+            if let Some(mask) = eth_conf.generate_mac_address_mask.as_deref() {
+                nm_wired_set.generate_mac_address_mask = if mask.is_empty() {
+                    None
+                } else {
+                    Some(mask.to_string())
+                };
+                flag_need_wired = true;
+            }
             match eth_conf.auto_neg {
                 Some(true) => {
                     flag_need_wired = true;
@@ -48,6 +74,22 @@ pub(crate) fn gen_nm_wired_setting(
                 }
                 None => (),
             }
+            if let Some(s390_conf) = eth_conf.s390.as_ref() {
+                if let Some(v) = s390_conf.subchannels.as_ref() {
+                    nm_wired_set.s390_subchannels = Some(v.clone());
+                }
+                if let Some(v) = s390_conf.nettype {
+                    nm_wired_set.s390_nettype = Some(v.to_string());
+                }
+                if let Some(v) = s390_conf.options.as_ref() {
+                    nm_wired_set.s390_options = Some(
+                        v.iter()
+                            .map(|(k, v)| (k.to_string(), v.to_string()))
+                            .collect(),
+                    );
+                }
+                flag_need_wired = true;
+            }
         }
     }
 
@@ -61,4 +103,50 @@ pub(crate) fn gen_nm_wired_setting(
     if flag_need_wired {
         nm_conn.wired = Some(nm_wired_set);
     }
+    Ok(())
+}
+
+// The MTU of interface itself always takes precedence, we do not copy or cap
+// MTU from parent interface. Kernel will refuse MTU bigger than what parent
+// could carry, so fail early here instead of activation failure.
+pub(crate) fn check_mtu_against_parent(
+    iface: &Interface,
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let overhead = match iface.iface_type() {
+        InterfaceType::Vlan
+        | InterfaceType::MacVlan
+        | InterfaceType::MacVtap => 0,
+        InterfaceType::Vxlan => VXLAN_OVERHEAD,
+        InterfaceType::MacSec => MACSEC_OVERHEAD,
+        _ => return Ok(()),
+    };
+    let mtu = match iface.base_iface().mtu {
+        Some(m) => m,
+        None => return Ok(()),
+    };
+    if let Some((parent, parent_mtu)) = iface.parent().and_then(|parent| {
+        merged_ifaces
+            .kernel_ifaces
+            .get(parent)
+            .and_then(|p| p.merged.base_iface().mtu)
+            .map(|parent_mtu| (parent, parent_mtu))
+    }) {
+        if mtu + overhead > parent_mtu {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "The MTU {mtu} of {} interface {} is bigger than the \
+                    maximum {} its parent {parent} with MTU {parent_mtu} \
+                    could carry",
+                    iface.iface_type(),
+                    iface.name(),
+                    parent_mtu.saturating_sub(overhead),
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    Ok(())
 }
@@ -0,0 +1,118 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::net::ToSocketAddrs;
+
+use super::super::nm_dbus::{NmConnection, NmSettingWireGuardPeer};
+use super::ieee8021x::secret_storage_mode_to_nm_flags;
+
+use crate::{
+    ErrorKind, NetworkState, NmstateError, SecretStorageMode,
+    WireGuardEndpointResolver, WireGuardInterface, WireGuardPeerConfig,
+};
+
+pub(crate) fn gen_nm_wireguard_setting(
+    iface: &WireGuardInterface,
+    nm_conn: &mut NmConnection,
+) -> Result<(), NmstateError> {
+    let cur_wg_set = nm_conn.wireguard.as_ref();
+    let mut nm_wg_set = cur_wg_set.cloned().unwrap_or_default();
+    if let Some(wg_conf) = iface.wireguard.as_ref() {
+        if let Some(v) = wg_conf.private_key.as_deref() {
+            nm_wg_set.private_key =
+                if v == NetworkState::PASSWORD_HID_BY_NMSTATE {
+                    cur_wg_set.and_then(|c| c.private_key.clone())
+                } else {
+                    Some(v.to_string())
+                };
+        }
+        if let Some(mode) = wg_conf.private_key_storage {
+            nm_wg_set.private_key_flags =
+                Some(secret_storage_mode_to_nm_flags(mode));
+            // Only secret stored by system should be saved in NM profile
+            if mode != SecretStorageMode::None {
+                nm_wg_set.private_key = None;
+            }
+        }
+        if let Some(v) = wg_conf.listen_port {
+            nm_wg_set.listen_port = Some(v.into());
+        }
+        if let Some(v) = wg_conf.fwmark {
+            nm_wg_set.fwmark = Some(v);
+        }
+        if let Some(peers) = wg_conf.peers.as_ref() {
+            let resolver = wg_conf.endpoint_resolver.unwrap_or_default();
+            let mut nm_peers = Vec::new();
+            for peer in peers {
+                let cur_peer = cur_wg_set
+                    .and_then(|c| c.peers.as_deref())
+                    .and_then(|cur_peers| {
+                        cur_peers.iter().find(|p| {
+                            p.public_key.as_deref()
+                                == Some(peer.public_key.as_str())
+                        })
+                    });
+                nm_peers.push(gen_nm_wg_peer(peer, cur_peer, resolver)?);
+            }
+            nm_wg_set.peers = Some(nm_peers);
+        }
+    }
+    nm_conn.wireguard = Some(nm_wg_set);
+    Ok(())
+}
+
+fn gen_nm_wg_peer(
+    peer: &WireGuardPeerConfig,
+    cur_peer: Option<&NmSettingWireGuardPeer>,
+    resolver: WireGuardEndpointResolver,
+) -> Result<NmSettingWireGuardPeer, NmstateError> {
+    let mut nm_peer = NmSettingWireGuardPeer::default();
+    nm_peer.public_key = Some(peer.public_key.clone());
+    // Preserve existing preshared key unless explicitly defined, empty
+    // string means removing it.
+    nm_peer.preshared_key = match peer.preshared_key.as_deref() {
+        Some(NetworkState::PASSWORD_HID_BY_NMSTATE) | None => {
+            cur_peer.and_then(|p| p.preshared_key.clone())
+        }
+        Some("") => None,
+        Some(v) => Some(v.to_string()),
+    };
+    nm_peer.endpoint = match peer.endpoint.as_deref() {
+        Some(endpoint) if resolver == WireGuardEndpointResolver::Nmstate => {
+            Some(resolve_wg_endpoint(endpoint)?)
+        }
+        Some(endpoint) => Some(endpoint.to_string()),
+        None => None,
+    };
+    nm_peer.allowed_ips = peer.allowed_ips.clone();
+    nm_peer.persistent_keepalive = peer.persistent_keepalive;
+    Ok(nm_peer)
+}
+
+// Resolve the host of endpoint to its first IP address.
+fn resolve_wg_endpoint(endpoint: &str) -> Result<String, NmstateError> {
+    match endpoint.to_socket_addrs().map(|mut addrs| addrs.next()) {
+        Ok(Some(addr)) => Ok(addr.to_string()),
+        Ok(None) => {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Failed to resolve WireGuard peer endpoint {endpoint}: \
+                    no address found"
+                ),
+            );
+            log::error!("{}", e);
+            Err(e)
+        }
+        Err(err) => {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Failed to resolve WireGuard peer endpoint {endpoint}: \
+                    {err}"
+                ),
+            );
+            log::error!("{}", e);
+            Err(e)
+        }
+    }
+}
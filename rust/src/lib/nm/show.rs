@@ -12,26 +12,38 @@ use super::{
     error::nm_error_to_nmstate,
     query_apply::{
         create_index_for_nm_conns_by_name_type,
-        device::nm_dev_iface_type_to_nmstate, dispatch::get_dispatches,
-        dns::nm_global_dns_to_nmstate, get_description, get_lldp,
-        is_lldp_enabled, nm_802_1x_to_nmstate, nm_ip_setting_to_nmstate4,
-        nm_ip_setting_to_nmstate6, ovs::merge_ovs_netdev_tun_iface,
+        device::nm_dev_iface_type_to_nmstate,
+        dispatch::{
+            get_dispatches, get_static_neighbors, get_sysctls,
+            SYSCTL_IPV6_ACCEPT_RA, SYSCTL_IPV6_ACCEPT_RA_MTU,
+            SYSCTL_IPV6_FORWARDING,
+        },
+        dns::nm_global_dns_to_nmstate,
+        get_description, get_lldp, get_user_data, is_lldp_enabled,
+        nm_802_1x_to_nmstate, nm_ip_setting_to_nmstate4,
+        nm_ip_setting_to_nmstate6, nm_proxy_to_nmstate, nm_team_to_nmstate,
+        nm_wired_to_ethernet_conf, nm_wireguard_to_nmstate,
+        ovs::merge_ovs_netdev_tun_iface,
         query_nmstate_wait_ip, retrieve_dns_info,
         vpn::get_supported_vpn_ifaces,
     },
     settings::{
-        get_bond_balance_slb, NM_SETTING_OVS_IFACE_SETTING_NAME,
+        get_bond_balance_slb, get_bond_peer_notif_delay,
+        NM_MULTI_CONNECT_MANUAL_MULTIPLE, NM_MULTI_CONNECT_MULTIPLE,
+        NM_MULTI_CONNECT_SINGLE, NM_RESOLVE_MODE_NO, NM_RESOLVE_MODE_RESOLVE,
+        NM_RESOLVE_MODE_YES, NM_SETTING_OVS_IFACE_SETTING_NAME,
         NM_SETTING_VETH_SETTING_NAME, NM_SETTING_WIRED_SETTING_NAME,
     },
 };
 use crate::{
     BaseInterface, BondConfig, BondInterface, BondOptions, DummyInterface,
-    EthernetInterface, HsrInterface, InfiniBandInterface, Interface,
-    InterfaceIdentifier, InterfaceState, InterfaceType, LinuxBridgeInterface,
+    EthernetInterface, GenericInterface, HsrInterface, InfiniBandInterface,
+    Interface, InterfaceBindBy, InterfaceIdentifier, InterfaceMultiConnect,
+    InterfaceResolveMode, InterfaceState, InterfaceType, LinuxBridgeInterface,
     LoopbackInterface, MacSecConfig, MacSecInterface, MacVlanInterface,
     MacVtapInterface, NetworkState, NmstateError, OvsBridgeInterface,
-    OvsInterface, UnknownInterface, VlanInterface, VrfInterface,
-    VxlanInterface,
+    OvsInterface, TeamInterface, UnknownInterface, VlanInterface, VrfInterface,
+    VxlanInterface, WireGuardInterface,
 };
 
 pub(crate) fn nm_retrieve(
@@ -204,6 +216,33 @@ pub(crate) fn nm_retrieve(
         }
     }
 
+    for (iface_name, sysctl) in get_sysctls().drain() {
+        if let Some(iface) =
+            net_state.interfaces.kernel_ifaces.get_mut(&iface_name)
+        {
+            if let Some(ipv6) =
+                iface.base_iface_mut().ipv6.as_mut().filter(|i| i.enabled)
+            {
+                ipv6.accept_ra_mtu =
+                    sysctl.get(SYSCTL_IPV6_ACCEPT_RA_MTU).map(|v| *v != 0);
+                ipv6.accept_ra = sysctl
+                    .get(SYSCTL_IPV6_ACCEPT_RA)
+                    .and_then(|v| u8::try_from(*v).ok());
+                ipv6.forwarding =
+                    sysctl.get(SYSCTL_IPV6_FORWARDING).map(|v| *v != 0);
+            }
+            iface.base_iface_mut().sysctl = Some(sysctl);
+        }
+    }
+
+    for (iface_name, neighbors) in get_static_neighbors().drain() {
+        if let Some(iface) =
+            net_state.interfaces.kernel_ifaces.get_mut(&iface_name)
+        {
+            iface.base_iface_mut().static_neighbors = Some(neighbors);
+        }
+    }
+
     merge_ovs_netdev_tun_iface(&mut net_state, &nm_devs, &nm_conns);
 
     Ok(net_state)
@@ -242,7 +281,12 @@ pub(crate) fn nm_conn_to_base_iface(
         base_iface.wait_ip =
             query_nmstate_wait_ip(nm_conn.ipv4.as_ref(), nm_conn.ipv6.as_ref());
         base_iface.description = get_description(nm_conn);
+        base_iface.user_data = get_user_data(nm_conn);
         base_iface.identifier = Some(get_identifier(nm_conn));
+        if let Some(patterns) = get_bind_pattern(nm_conn) {
+            base_iface.bind_by = Some(InterfaceBindBy::MatchGlob);
+            base_iface.bind_pattern = Some(patterns);
+        }
         base_iface.profile_name = get_connection_name(nm_conn);
         if base_iface.profile_name.as_ref() == Some(&base_iface.name) {
             base_iface.profile_name = None;
@@ -250,10 +294,53 @@ pub(crate) fn nm_conn_to_base_iface(
 
         base_iface.lldp =
             Some(lldp_neighbors.map(get_lldp).unwrap_or_default());
+        base_iface.proxy = nm_conn.proxy.as_ref().and_then(nm_proxy_to_nmstate);
+        if let Some(nm_set) = nm_conn.connection.as_ref() {
+            // Only show autoconnect-ports when explicitly disabled
+            if nm_set.autoconnect_ports == Some(false) {
+                base_iface.autoconnect_ports = Some(false);
+            }
+            base_iface.ip_ping_addresses = nm_set.ip_ping_addresses.clone();
+            base_iface.ip_ping_timeout = nm_set.ip_ping_timeout;
+            base_iface.gateway_ping_timeout = nm_set.gateway_ping_timeout;
+            // Only show multi-connect when not using backend default
+            base_iface.multi_connect = match nm_set.multi_connect {
+                Some(NM_MULTI_CONNECT_SINGLE) => {
+                    Some(InterfaceMultiConnect::Single)
+                }
+                Some(NM_MULTI_CONNECT_MANUAL_MULTIPLE) => {
+                    Some(InterfaceMultiConnect::ManualMultiple)
+                }
+                Some(NM_MULTI_CONNECT_MULTIPLE) => {
+                    Some(InterfaceMultiConnect::Multiple)
+                }
+                _ => None,
+            };
+            // Only show mdns and llmnr when not using backend default
+            base_iface.mdns = nm_set.mdns.and_then(nm_resolve_mode_to_nmstate);
+            base_iface.llmnr =
+                nm_set.llmnr.and_then(nm_resolve_mode_to_nmstate);
+        }
         if let Some(nm_saved_conn) = nm_saved_conn {
             // 802.1x password is only available in saved connection
             base_iface.ieee8021x =
                 nm_saved_conn.ieee8021x.as_ref().map(nm_802_1x_to_nmstate);
+            if let Some(conf) = base_iface.ieee8021x.as_mut() {
+                // The 0 or -1 means backend default
+                conf.auth_timeout = nm_saved_conn
+                    .connection
+                    .as_ref()
+                    .and_then(|c| c.auth_timeout)
+                    .filter(|v| *v > 0)
+                    .map(|v| v as u32);
+                // The -1 means backend default
+                conf.auth_retries = nm_saved_conn
+                    .connection
+                    .as_ref()
+                    .and_then(|c| c.auth_retries)
+                    .filter(|v| *v >= 0)
+                    .map(|v| v as u32);
+            }
         }
         return Some(base_iface);
     }
@@ -262,7 +349,7 @@ pub(crate) fn nm_conn_to_base_iface(
 
 // Applied connection does not hold OVS config, we need the NmConnection
 // used by `NmActiveConnection` also.
-fn iface_get(
+pub(crate) fn iface_get(
     nm_dev: &NmDevice,
     nm_conn: &NmConnection,
     nm_saved_conn: Option<&NmConnection>,
@@ -283,6 +370,8 @@ fn iface_get(
             InterfaceType::Ethernet => Interface::Ethernet({
                 let mut iface = EthernetInterface::new();
                 iface.base = base_iface;
+                iface.ethernet =
+                    nm_conn.wired.as_ref().and_then(nm_wired_to_ethernet_conf);
                 iface
             }),
             InterfaceType::Bond => Interface::Bond({
@@ -291,6 +380,7 @@ fn iface_get(
                 let bond_config = BondConfig {
                     options: Some(BondOptions {
                         balance_slb: get_bond_balance_slb(nm_conn),
+                        peer_notif_delay: get_bond_peer_notif_delay(nm_conn),
                         ..Default::default()
                     }),
                     ..Default::default()
@@ -367,6 +457,28 @@ fn iface_get(
                 iface.base = base_iface;
                 iface
             }),
+            InterfaceType::Team => Interface::Team({
+                let mut iface = TeamInterface::new();
+                iface.base = base_iface;
+                iface.team = nm_conn.team.as_ref().map(nm_team_to_nmstate);
+                iface
+            }),
+            InterfaceType::WireGuard => Interface::WireGuard({
+                let mut iface = WireGuardInterface::new();
+                iface.base = base_iface;
+                iface.wireguard = nm_conn.wireguard.as_ref().map(|nm_wg_set| {
+                    nm_wireguard_to_nmstate(
+                        nm_wg_set,
+                        nm_saved_conn.and_then(|c| c.wireguard.as_ref()),
+                    )
+                });
+                iface
+            }),
+            InterfaceType::Generic => Interface::Generic({
+                let mut iface = GenericInterface::new();
+                iface.base = base_iface;
+                iface
+            }),
             _ => {
                 log::debug!("Skip unsupported interface {:?}", base_iface);
                 return None;
@@ -514,12 +626,27 @@ fn nm_dev_to_nm_iface(nm_dev: &NmDevice) -> Option<Interface> {
             iface.base = base_iface;
             iface
         }),
+        InterfaceType::Team => Interface::Team({
+            let mut iface = TeamInterface::new();
+            iface.base = base_iface;
+            iface
+        }),
+        InterfaceType::WireGuard => Interface::WireGuard({
+            let mut iface = WireGuardInterface::new();
+            iface.base = base_iface;
+            iface
+        }),
         InterfaceType::InfiniBand => Interface::InfiniBand({
             InfiniBandInterface {
                 base: base_iface,
                 ..Default::default()
             }
         }),
+        InterfaceType::Generic => Interface::Generic({
+            let mut iface = GenericInterface::new();
+            iface.base = base_iface;
+            iface
+        }),
         iface_type
             if iface_type == &InterfaceType::Other("ovs-port".to_string()) =>
         {
@@ -560,6 +687,17 @@ fn nm_dev_to_nm_iface(nm_dev: &NmDevice) -> Option<Interface> {
     Some(iface)
 }
 
+fn get_bind_pattern(nm_conn: &NmConnection) -> Option<Vec<String>> {
+    if nm_conn.iface_name().is_some() {
+        return None;
+    }
+    nm_conn
+        .matching
+        .as_ref()
+        .and_then(|m| m.interface_name.clone())
+        .filter(|p| !p.is_empty())
+}
+
 fn get_identifier(nm_conn: &NmConnection) -> InterfaceIdentifier {
     if let Some(nm_set) = nm_conn.wired.as_ref() {
         if nm_set
@@ -581,3 +719,12 @@ fn get_connection_name(nm_conn: &NmConnection) -> Option<String> {
     }
     None
 }
+
+fn nm_resolve_mode_to_nmstate(mode: i32) -> Option<InterfaceResolveMode> {
+    match mode {
+        NM_RESOLVE_MODE_NO => Some(InterfaceResolveMode::No),
+        NM_RESOLVE_MODE_RESOLVE => Some(InterfaceResolveMode::Resolve),
+        NM_RESOLVE_MODE_YES => Some(InterfaceResolveMode::Yes),
+        _ => None,
+    }
+}
@@ -0,0 +1,97 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::nm_dbus::NmConnection;
+use crate::nm::settings::{gen_nm_bond_setting, get_bond_peer_notif_delay};
+use crate::{BondInterface, ErrorKind, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_bond_ad_select_and_min_links() {
+    let iface: BondInterface = serde_yaml::from_str(
+        r"---
+        name: bond99
+        type: bond
+        state: up
+        link-aggregation:
+          mode: 802.3ad
+          options:
+            ad_select: bandwidth
+            min_links: 2",
+    )
+    .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_bond_setting(&iface, &mut nm_conn).unwrap();
+
+    let nm_bond_set = nm_conn.bond.as_ref().unwrap();
+    assert_eq!(
+        nm_bond_set.options.get("ad_select").map(String::as_str),
+        Some("bandwidth")
+    );
+    assert_eq!(
+        nm_bond_set.options.get("min_links").map(String::as_str),
+        Some("2")
+    );
+}
+
+#[test]
+fn test_bond_peer_notif_options() {
+    let iface: BondInterface = serde_yaml::from_str(
+        r"---
+        name: bond99
+        type: bond
+        state: up
+        link-aggregation:
+          mode: active-backup
+          options:
+            miimon: 100
+            num_grat_arp: 5
+            num_unsol_na: 5
+            peer_notif_delay: 200",
+    )
+    .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_bond_setting(&iface, &mut nm_conn).unwrap();
+
+    let nm_bond_set = nm_conn.bond.as_ref().unwrap();
+    assert_eq!(
+        nm_bond_set.options.get("num_grat_arp").map(String::as_str),
+        Some("5")
+    );
+    assert_eq!(
+        nm_bond_set.options.get("num_unsol_na").map(String::as_str),
+        Some("5")
+    );
+    assert_eq!(
+        nm_bond_set
+            .options
+            .get("peer_notif_delay")
+            .map(String::as_str),
+        Some("200")
+    );
+    assert_eq!(get_bond_peer_notif_delay(&nm_conn), Some(200));
+}
+
+#[test]
+fn test_bond_peer_notif_delay_out_of_range() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: bond99
+          type: bond
+          state: up
+          link-aggregation:
+            mode: active-backup
+            options:
+              peer_notif_delay: 300001",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
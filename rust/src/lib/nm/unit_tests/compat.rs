@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{NetworkState, NmVersion};
+
+#[test]
+fn test_nm_compat_wireguard_on_old_version() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  ipv6:
+    enabled: true
+    addr-gen-mode: default
+- name: wg1
+  type: wireguard
+  state: absent
+",
+    )
+    .unwrap();
+
+    let incompatibilities = desired.check_nm_compat(NmVersion::new(1, 14, 0));
+
+    assert_eq!(incompatibilities.len(), 1);
+    assert_eq!(incompatibilities[0].iface_name, "wg0");
+    assert_eq!(incompatibilities[0].property, "type: wireguard");
+    assert_eq!(
+        incompatibilities[0].required_version,
+        NmVersion::new(1, 16, 0)
+    );
+    assert!(desired.check_nm_compat(NmVersion::new(1, 16, 0)).is_empty());
+}
+
+#[test]
+fn test_nm_compat_autoconnect_ports_on_old_version() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: br0
+  type: linux-bridge
+  state: up
+  autoconnect-ports: false
+",
+    )
+    .unwrap();
+
+    assert!(desired.check_nm_compat(NmVersion::new(1, 44, 0)).is_empty());
+}
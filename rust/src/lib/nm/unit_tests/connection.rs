@@ -0,0 +1,1758 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use crate::nm::nm_dbus::{
+    NmConnection, NmSettingConnection, NmSettingIpMethod,
+    NmSettingsConnectionFlag, ToDbusValue,
+};
+use crate::nm::profile::perpare_nm_conns;
+use crate::nm::settings::{
+    gen_nm_conn_setting, get_exist_profile, uuid_from_name_and_type,
+    NM_MULTI_CONNECT_DEFAULT, NM_MULTI_CONNECT_MANUAL_MULTIPLE,
+    NM_MULTI_CONNECT_MULTIPLE, NM_MULTI_CONNECT_SINGLE, NM_RESOLVE_MODE_NO,
+    NM_RESOLVE_MODE_YES,
+};
+use crate::nm::NmVersion;
+use crate::{
+    ErrorKind, Interface, InterfaceResolveMode, InterfaceType,
+    MergedNetworkState, NetworkState, NmstateError,
+};
+
+const BR0_UUID: &str = "5b5e1f5c-2f0e-4a6b-9f3e-6d7c8e2a1b40";
+
+const BR_WITH_PORT_YAML: &str = r"---
+interfaces:
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth1
+- name: eth1
+  type: ethernet
+  state: up
+";
+
+fn gen_nm_conn_controller(net_state: NetworkState) -> Option<String> {
+    let merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+    nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("eth1"))
+        .and_then(|c| c.controller())
+        .map(|c| c.to_string())
+}
+
+#[test]
+fn test_port_reference_controller_by_uuid() {
+    let mut net_state: NetworkState =
+        serde_yaml::from_str(BR_WITH_PORT_YAML).unwrap();
+    net_state.set_controller_by_uuid(true);
+
+    assert_eq!(
+        gen_nm_conn_controller(net_state),
+        Some(uuid_from_name_and_type("br0", &InterfaceType::LinuxBridge))
+    );
+}
+
+#[test]
+fn test_port_reference_controller_by_name() {
+    let mut net_state: NetworkState =
+        serde_yaml::from_str(BR_WITH_PORT_YAML).unwrap();
+    net_state.set_controller_by_uuid(false);
+
+    assert_eq!(gen_nm_conn_controller(net_state), Some("br0".to_string()));
+}
+
+fn gen_nm_conn_of_br0(nm_version: Option<NmVersion>) -> NmConnection {
+    let net_state: NetworkState =
+        serde_yaml::from_str(BR_WITH_PORT_YAML).unwrap();
+    let mut merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    merged_state.nm_version = nm_version;
+    perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store
+        .into_iter()
+        .find(|c| c.iface_name() == Some("br0"))
+        .unwrap()
+}
+
+#[test]
+fn test_autoconnect_slaves_on_old_nm() {
+    let nm_conn = gen_nm_conn_of_br0(Some(NmVersion::new(1, 44, 0)));
+    let nm_conn_set = nm_conn.connection.as_ref().unwrap();
+    let values = nm_conn_set.to_value().unwrap();
+
+    assert_eq!(nm_conn_set.autoconnect_ports, Some(true));
+    assert_eq!(
+        values.get("autoconnect-slaves"),
+        Some(&zvariant::Value::new(1i32))
+    );
+    assert!(!values.contains_key("autoconnect-ports"));
+}
+
+#[test]
+fn test_autoconnect_slaves_on_unknown_nm_version() {
+    let nm_conn = gen_nm_conn_of_br0(None);
+    let values = nm_conn.connection.as_ref().unwrap().to_value().unwrap();
+
+    assert!(values.contains_key("autoconnect-slaves"));
+    assert!(!values.contains_key("autoconnect-ports"));
+}
+
+#[test]
+fn test_autoconnect_ports_on_new_nm() {
+    let nm_conn = gen_nm_conn_of_br0(Some(NmVersion::new(1, 46, 0)));
+    let nm_conn_set = nm_conn.connection.as_ref().unwrap();
+    let values = nm_conn_set.to_value().unwrap();
+
+    assert_eq!(nm_conn_set.autoconnect_ports, Some(true));
+    assert_eq!(
+        values.get("autoconnect-ports"),
+        Some(&zvariant::Value::new(1i32))
+    );
+    assert!(!values.contains_key("autoconnect-slaves"));
+}
+
+#[test]
+fn test_nm_version_from_str() {
+    assert_eq!(
+        NmVersion::from_str("1.45.90-dev").unwrap(),
+        NmVersion::new(1, 45, 90)
+    );
+    assert_eq!(
+        NmVersion::from_str("1.46").unwrap(),
+        NmVersion::new(1, 46, 0)
+    );
+    assert!(NmVersion::from_str("abc").is_err());
+    assert!(NmVersion::new(1, 46, 0) >= NmVersion::AUTOCONNECT_PORTS);
+    assert!(NmVersion::new(1, 44, 10) < NmVersion::AUTOCONNECT_PORTS);
+}
+
+#[test]
+fn test_generic_iface_with_static_ip() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: helper0
+  type: generic
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: false
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    let nm_conn = &nm_conns[0];
+    assert_eq!(nm_conn.iface_type(), Some("generic"));
+    assert_eq!(nm_conn.iface_name(), Some("helper0"));
+    let nm_ipv4_set = nm_conn.ipv4.as_ref().unwrap();
+    assert_eq!(nm_ipv4_set.method, Some(NmSettingIpMethod::Manual));
+    assert_eq!(nm_ipv4_set.addresses, vec!["192.0.2.1/24".to_string()]);
+}
+
+#[cfg(feature = "query_apply")]
+#[test]
+fn test_generic_iface_round_trip() {
+    use crate::nm::nm_dbus::NmDevice;
+    use crate::nm::show::iface_get;
+
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: helper0
+  type: generic
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: false
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_dev = NmDevice {
+        name: "helper0".to_string(),
+        iface_type: "generic".to_string(),
+        real: true,
+        ..Default::default()
+    };
+    let iface = iface_get(&nm_dev, &nm_conns[0], None, None).unwrap();
+
+    assert!(matches!(iface, Interface::Generic(_)));
+    assert_eq!(iface.name(), "helper0");
+    let ipv4_conf = iface.base_iface().ipv4.as_ref().unwrap();
+    assert!(ipv4_conf.enabled);
+    assert_eq!(ipv4_conf.dhcp, Some(false));
+}
+
+#[test]
+fn test_bridge_port_setting_with_bridge_in_current_only() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 1400
+",
+    )
+    .unwrap();
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth1
+      stp-priority: 10
+- name: eth1
+  type: ethernet
+  state: up
+  controller: br0
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    let mut br0_nm_conn = NmConnection::default();
+    let mut nm_conn_set = NmSettingConnection::default();
+    nm_conn_set.id = Some("br0".to_string());
+    nm_conn_set.uuid = Some(BR0_UUID.to_string());
+    nm_conn_set.iface_type = Some("bridge".to_string());
+    nm_conn_set.iface_name = Some("br0".to_string());
+    br0_nm_conn.connection = Some(nm_conn_set);
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[br0_nm_conn], &[], false)
+        .unwrap()
+        .to_store;
+
+    let nm_conn = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("eth1"))
+        .unwrap();
+
+    assert_eq!(nm_conn.controller(), Some(BR0_UUID));
+    assert_eq!(nm_conn.controller_type(), Some("bridge"));
+    assert_eq!(
+        nm_conn.bridge_port.as_ref().and_then(|p| p.priority),
+        Some(10)
+    );
+}
+
+const ETH1_IP_PING_YAML: &str = r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ip-ping-addresses:
+  - 192.0.2.1
+  - 2001:db8:0::1
+  ip-ping-timeout: 10
+";
+
+fn gen_nm_conns_of_eth1_ip_ping(
+    nm_version: Option<NmVersion>,
+) -> Result<Vec<NmConnection>, NmstateError> {
+    let net_state: NetworkState =
+        serde_yaml::from_str(ETH1_IP_PING_YAML).unwrap();
+    let mut merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    merged_state.nm_version = nm_version;
+    Ok(perpare_nm_conns(&merged_state, &[], &[], true)?.to_store)
+}
+
+#[test]
+fn test_ip_ping_on_new_nm() {
+    let nm_conns =
+        gen_nm_conns_of_eth1_ip_ping(Some(NmVersion::new(1, 48, 0))).unwrap();
+
+    let nm_conn_set = nm_conns[0].connection.as_ref().unwrap();
+    assert_eq!(
+        nm_conn_set.ip_ping_addresses,
+        Some(vec!["192.0.2.1".to_string(), "2001:db8::1".to_string()])
+    );
+    assert_eq!(nm_conn_set.ip_ping_timeout, Some(10));
+    let nm_conn_set_value = nm_conn_set.to_value().unwrap();
+    assert!(nm_conn_set_value.contains_key("ip-ping-addresses"));
+    assert!(nm_conn_set_value.contains_key("ip-ping-timeout"));
+}
+
+#[test]
+fn test_ip_ping_on_old_nm() {
+    let result = gen_nm_conns_of_eth1_ip_ping(Some(NmVersion::new(1, 46, 0)));
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotImplementedError);
+    }
+}
+
+#[test]
+fn test_gateway_ping_timeout_with_default_route() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  gateway-ping-timeout: 30
+  ipv4:
+    enabled: true
+    address:
+    - ip: 192.0.2.2
+      prefix-length: 24
+routes:
+  config:
+  - destination: 0.0.0.0/0
+    next-hop-address: 192.0.2.1
+    next-hop-interface: eth1
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_conn_set = nm_conns[0].connection.as_ref().unwrap();
+    assert_eq!(nm_conn_set.gateway_ping_timeout, Some(30));
+    assert!(nm_conn_set
+        .to_value()
+        .unwrap()
+        .contains_key("gateway-ping-timeout"));
+}
+
+#[test]
+fn test_gateway_ping_timeout_without_default_route() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  gateway-ping-timeout: 30
+  ipv4:
+    enabled: true
+    address:
+    - ip: 192.0.2.2
+      prefix-length: 24
+routes:
+  config:
+  - destination: 198.51.100.0/24
+    next-hop-address: 192.0.2.1
+    next-hop-interface: eth1
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+fn gen_nm_conn_of_eth1_addr_gen_mode(
+    nm_version: Option<NmVersion>,
+) -> NmConnection {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    dhcp: true
+    autoconf: true
+    addr-gen-mode: default-or-eui64
+",
+    )
+    .unwrap();
+    let mut merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    merged_state.nm_version = nm_version;
+    perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store
+        .remove(0)
+}
+
+#[test]
+fn test_addr_gen_mode_default_or_eui64_on_new_nm() {
+    let nm_conn =
+        gen_nm_conn_of_eth1_addr_gen_mode(Some(NmVersion::new(1, 40, 0)));
+
+    assert_eq!(nm_conn.ipv6.as_ref().unwrap().addr_gen_mode, Some(2));
+}
+
+#[test]
+fn test_addr_gen_mode_default_or_eui64_fallback_on_old_nm() {
+    let nm_conn =
+        gen_nm_conn_of_eth1_addr_gen_mode(Some(NmVersion::new(1, 36, 0)));
+
+    assert_eq!(nm_conn.ipv6.as_ref().unwrap().addr_gen_mode, Some(0));
+}
+
+fn gen_nm_conns_of_eth1_replace_local_rule(
+    nm_version: Option<NmVersion>,
+) -> Result<Vec<NmConnection>, NmstateError> {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: false
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+    replace-local-rule: true
+",
+    )
+    .unwrap();
+    let mut merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    merged_state.nm_version = nm_version;
+    Ok(perpare_nm_conns(&merged_state, &[], &[], true)?.to_store)
+}
+
+#[test]
+fn test_replace_local_rule_on_new_nm() {
+    let nm_conns =
+        gen_nm_conns_of_eth1_replace_local_rule(Some(NmVersion::new(1, 44, 0)))
+            .unwrap();
+
+    let nm_ip_set = nm_conns[0].ipv4.as_ref().unwrap();
+    assert_eq!(nm_ip_set.replace_local_rule, Some(1));
+    assert!(nm_ip_set
+        .to_value()
+        .unwrap()
+        .contains_key("replace-local-rule"));
+}
+
+#[test]
+fn test_replace_local_rule_on_old_nm() {
+    let nm_conns =
+        gen_nm_conns_of_eth1_replace_local_rule(Some(NmVersion::new(1, 42, 0)))
+            .unwrap();
+
+    let nm_ip_set = nm_conns[0].ipv4.as_ref().unwrap();
+    assert_eq!(nm_ip_set.replace_local_rule, None);
+    assert!(!nm_ip_set
+        .to_value()
+        .unwrap()
+        .contains_key("replace-local-rule"));
+}
+
+const ETH1_UUID: &str = "1a9e2b0c-6c60-4d8c-9d5c-3b7f2e0c4a11";
+
+fn gen_nm_conns_of_external_eth1(
+    external_persistent: bool,
+) -> Vec<NmConnection> {
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+    desired.set_external_persistent(external_persistent);
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: false
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    let mut exist_nm_conn = NmConnection::default();
+    let mut nm_conn_set = NmSettingConnection::default();
+    nm_conn_set.id = Some("eth1".to_string());
+    nm_conn_set.uuid = Some(ETH1_UUID.to_string());
+    nm_conn_set.iface_type = Some("802-3-ethernet".to_string());
+    nm_conn_set.iface_name = Some("eth1".to_string());
+    nm_conn_set.autoconnect = Some(false);
+    exist_nm_conn.connection = Some(nm_conn_set);
+    exist_nm_conn.flags = vec![NmSettingsConnectionFlag::External];
+
+    perpare_nm_conns(&merged_state, &[exist_nm_conn], &[], false)
+        .unwrap()
+        .to_store
+}
+
+#[test]
+fn test_convert_external_to_persistent() {
+    let nm_conns = gen_nm_conns_of_external_eth1(true);
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(nm_conns[0].uuid(), Some(ETH1_UUID));
+    assert!(nm_conns[0].flags.is_empty());
+    assert_eq!(
+        nm_conns[0].ipv4.as_ref().unwrap().addresses,
+        vec!["192.0.2.1/24".to_string()]
+    );
+}
+
+#[test]
+fn test_convert_external_to_persistent_autoconnect() {
+    let nm_conns = gen_nm_conns_of_external_eth1(true);
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(
+        nm_conns[0].connection.as_ref().unwrap().autoconnect,
+        Some(true)
+    );
+}
+
+#[test]
+fn test_convert_external_to_volatile() {
+    let nm_conns = gen_nm_conns_of_external_eth1(false);
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(nm_conns[0].flags, vec![NmSettingsConnectionFlag::Volatile]);
+}
+
+#[test]
+fn test_gen_volatile_connection() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          nm-flags:
+          - volatile",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(nm_conns[0].flags, vec![NmSettingsConnectionFlag::Volatile]);
+}
+
+#[test]
+fn test_bridge_autoconnect_ports_no() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: br0
+  type: linux-bridge
+  state: up
+  autoconnect-ports: false
+  bridge:
+    port: []
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_conn_set = nm_conns[0].connection.as_ref().unwrap();
+    assert_eq!(nm_conn_set.autoconnect_ports, Some(false));
+    let nm_conn_set_value = nm_conn_set.to_value().unwrap();
+    assert_eq!(
+        nm_conn_set_value.get("autoconnect-slaves"),
+        Some(&zvariant::Value::new(0i32))
+    );
+}
+
+#[test]
+fn test_bridge_group_address() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    options:
+      group-address: 01:80:c2:00:00:0e
+    port: []
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(
+        nm_conns[0]
+            .bridge
+            .as_ref()
+            .and_then(|b| b.group_address.as_deref()),
+        Some("01:80:C2:00:00:0E")
+    );
+}
+
+#[test]
+fn test_autoconnect_ports_on_non_controller() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  autoconnect-ports: false
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_bridge_vlan_stats_enabled() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    options:
+      vlan-stats-enabled: true
+    port:
+    - name: eth1
+      vlan:
+        mode: access
+        tag: 100
+- name: eth1
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let br_conn = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("br0"))
+        .unwrap();
+    let nm_br_set = br_conn.bridge.as_ref().unwrap();
+    assert_eq!(nm_br_set.vlan_filtering, Some(true));
+    assert_eq!(nm_br_set.vlan_stats_enabled, Some(true));
+}
+
+#[test]
+fn test_bridge_vlan_stats_enabled_without_vlan_filtering() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    options:
+      vlan-stats-enabled: true
+    port: []
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_gen_nm_conn_setting_self_controller() {
+    let mut iface: Interface = serde_yaml::from_str(
+        r"---
+        name: bond0
+        type: bond
+        state: up
+        controller: bond0",
+    )
+    .unwrap();
+    iface.base_iface_mut().controller_type = Some(InterfaceType::Bond);
+
+    let result =
+        gen_nm_conn_setting(&iface, &mut NmConnection::default(), true, None);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_gen_nm_conn_setting_dummy_down_no_autoconnect() {
+    let iface: Interface = serde_yaml::from_str(
+        r"---
+        name: dummy0
+        type: dummy
+        state: down",
+    )
+    .unwrap();
+    let mut nm_conn = NmConnection::default();
+
+    gen_nm_conn_setting(&iface, &mut nm_conn, true, None).unwrap();
+
+    assert_eq!(
+        nm_conn.connection.as_ref().and_then(|c| c.autoconnect),
+        Some(false)
+    );
+}
+
+#[test]
+fn test_nm_user_data() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          description: uplink
+          user-data:
+            inventory.rack: r12
+            inventory.asset-tag: A-1234
+            org.example.owner: netops",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let data = nm_conns[0]
+        .user
+        .as_ref()
+        .and_then(|u| u.data.as_ref())
+        .unwrap();
+
+    assert_eq!(data.len(), 4);
+    assert_eq!(
+        data.get("nmstate.interface.description")
+            .map(|s| s.as_str()),
+        Some("uplink")
+    );
+    assert_eq!(data.get("inventory.rack").map(|s| s.as_str()), Some("r12"));
+    assert_eq!(
+        data.get("inventory.asset-tag").map(|s| s.as_str()),
+        Some("A-1234")
+    );
+    assert_eq!(
+        data.get("org.example.owner").map(|s| s.as_str()),
+        Some("netops")
+    );
+}
+
+#[test]
+fn test_nm_user_data_invalid_key() {
+    for key in ["rack", ".rack", "inventory..rack", "inventory.r@ck"] {
+        let desired: NetworkState = serde_yaml::from_str(&format!(
+            r"---
+            interfaces:
+            - name: eth1
+              type: ethernet
+              state: up
+              user-data:
+                {key}: r12"
+        ))
+        .unwrap();
+        let merged_state =
+            MergedNetworkState::new(desired, NetworkState::new(), true, false)
+                .unwrap();
+
+        let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+        assert!(result.is_err());
+        if let Err(e) = result {
+            assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        }
+    }
+}
+
+#[test]
+fn test_nm_user_data_reserved_domain() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          user-data:
+            nmstate.interface.description: uplink",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_vlan_mtu_bigger_than_parent() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          mtu: 1500
+        - name: eth1.101
+          type: vlan
+          state: up
+          mtu: 9000
+          vlan:
+            base-iface: eth1
+            id: 101",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_vlan_mtu_equal_to_parent() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          mtu: 1500
+        - name: eth1.101
+          type: vlan
+          state: up
+          mtu: 1500
+          vlan:
+            base-iface: eth1
+            id: 101",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let get_wired_mtu = |iface_name: &str| {
+        nm_conns
+            .iter()
+            .find(|c| c.iface_name() == Some(iface_name))
+            .and_then(|c| c.wired.as_ref())
+            .and_then(|w| w.mtu)
+    };
+
+    assert_eq!(get_wired_mtu("eth1"), Some(1500));
+    assert_eq!(get_wired_mtu("eth1.101"), Some(1500));
+}
+
+#[test]
+fn test_vxlan_mtu_exceed_parent_minus_overhead() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          mtu: 1500
+        - name: vxlan0
+          type: vxlan
+          state: up
+          mtu: 1460
+          vxlan:
+            base-iface: eth1
+            id: 100
+            remote: 192.0.2.1",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_vxlan_local_source_address() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: vxlan0
+          type: vxlan
+          state: up
+          vxlan:
+            base-iface: eth1
+            id: 100
+            local: 192.0.2.10
+            remote: 192.0.2.1",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_vxlan_set = nm_conns[0].vxlan.as_ref().unwrap();
+    assert_eq!(nm_vxlan_set.local.as_deref(), Some("192.0.2.10"));
+    assert_eq!(nm_vxlan_set.remote.as_deref(), Some("192.0.2.1"));
+    assert!(nm_vxlan_set.to_value().unwrap().contains_key("local"));
+}
+
+#[test]
+fn test_vxlan_gbp_not_supported_by_nm() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: vxlan0
+          type: vxlan
+          state: up
+          vxlan:
+            base-iface: eth1
+            id: 100
+            gbp: true",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotImplementedError);
+    }
+}
+
+#[test]
+fn test_ovs_internal_iface_mtu_request() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: ovs0
+          type: ovs-interface
+          state: up
+          mtu: 1500
+          mtu-request: 9000
+        - name: br0
+          type: ovs-bridge
+          state: up
+          bridge:
+            port:
+            - name: ovs0",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_conn = nm_conns
+        .iter()
+        .find(|c| {
+            c.iface_name() == Some("ovs0")
+                && c.iface_type() == Some("ovs-interface")
+        })
+        .unwrap();
+
+    assert_eq!(
+        nm_conn
+            .ovs_iface
+            .as_ref()
+            .and_then(|i| i.iface_type.as_deref()),
+        Some("internal")
+    );
+    assert_eq!(nm_conn.wired.as_ref().and_then(|w| w.mtu), Some(9000));
+}
+
+#[test]
+fn test_ovs_dpdk_pmd_rxq_affinity() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: ovs0
+          type: ovs-interface
+          state: up
+          dpdk:
+            devargs: 0000:af:00.1
+            rx-queue: 2
+            pmd-rxq-affinity:
+              0: 3
+              1: 7
+        - name: br0
+          type: ovs-bridge
+          state: up
+          bridge:
+            options:
+              datapath: netdev
+            port:
+            - name: ovs0",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_conn = nm_conns
+        .iter()
+        .find(|c| {
+            c.iface_name() == Some("ovs0")
+                && c.iface_type() == Some("ovs-interface")
+        })
+        .unwrap();
+
+    assert_eq!(nm_conn.ovs_dpdk.as_ref().and_then(|d| d.n_rxq), Some(2));
+    assert_eq!(
+        nm_conn
+            .ovs_other_config
+            .as_ref()
+            .and_then(|o| o.data.as_ref())
+            .and_then(|d| d.get("pmd-rxq-affinity"))
+            .map(|s| s.as_str()),
+        Some("0:3,1:7")
+    );
+}
+
+#[test]
+fn test_ovs_bridge_datapath_id_and_protocols() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: ovs0
+          type: ovs-interface
+          state: up
+        - name: br0
+          type: ovs-bridge
+          state: up
+          bridge:
+            options:
+              datapath-id: 00000000000000a1
+              protocols:
+              - OpenFlow13
+            port:
+            - name: ovs0",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_conn = nm_conns
+        .iter()
+        .find(|c| {
+            c.iface_name() == Some("br0")
+                && c.iface_type() == Some("ovs-bridge")
+        })
+        .unwrap();
+
+    assert_eq!(
+        nm_conn
+            .ovs_other_config
+            .as_ref()
+            .and_then(|o| o.data.as_ref())
+            .and_then(|d| d.get("datapath-id"))
+            .map(|s| s.as_str()),
+        Some("00000000000000a1")
+    );
+}
+
+#[test]
+fn test_bind_by_match_glob() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: uplink
+          type: ethernet
+          state: up
+          bind-by: match-glob
+          bind-pattern:
+          - en*",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(nm_conns[0].iface_name(), None);
+    assert_eq!(
+        nm_conns[0]
+            .matching
+            .as_ref()
+            .and_then(|m| m.interface_name.as_deref()),
+        Some(["en*".to_string()].as_slice())
+    );
+    assert_eq!(
+        get_exist_profile(&nm_conns, "uplink", &InterfaceType::Ethernet, &[])
+            .and_then(|c| c.uuid()),
+        nm_conns[0].uuid()
+    );
+}
+
+#[test]
+fn test_vlan_reference_parent_by_uuid() {
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1.10
+          type: vlan
+          state: up
+          vlan:
+            base-iface: eth1
+            id: 10",
+    )
+    .unwrap();
+    desired.set_parent_by_uuid(true);
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(
+        nm_conns[0].vlan.as_ref().and_then(|v| v.parent.clone()),
+        Some(uuid_from_name_and_type("eth1", &InterfaceType::Ethernet))
+    );
+}
+
+#[test]
+fn test_edit_mtu_preserve_auth_retries() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 1400
+",
+    )
+    .unwrap();
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 1500
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    let mut exist_nm_conn = NmConnection::default();
+    let mut nm_conn_set = NmSettingConnection::default();
+    nm_conn_set.id = Some("eth1".to_string());
+    nm_conn_set.uuid = Some(ETH1_UUID.to_string());
+    nm_conn_set.iface_type = Some("802-3-ethernet".to_string());
+    nm_conn_set.iface_name = Some("eth1".to_string());
+    nm_conn_set.auth_retries = Some(3);
+    exist_nm_conn.connection = Some(nm_conn_set);
+
+    let nm_conns =
+        perpare_nm_conns(&merged_state, &[exist_nm_conn], &[], false)
+            .unwrap()
+            .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(nm_conns[0].uuid(), Some(ETH1_UUID));
+    assert_eq!(nm_conns[0].wired.as_ref().unwrap().mtu, Some(1400));
+    assert_eq!(
+        nm_conns[0].connection.as_ref().unwrap().auth_retries,
+        Some(3)
+    );
+}
+
+fn gen_multi_connect(multi_connect_yaml: &str) -> Option<i32> {
+    let iface: Interface = serde_yaml::from_str(&format!(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        {multi_connect_yaml}"
+    ))
+    .unwrap();
+    let mut nm_conn = NmConnection::default();
+
+    gen_nm_conn_setting(&iface, &mut nm_conn, true, None).unwrap();
+
+    nm_conn.connection.as_ref().and_then(|c| c.multi_connect)
+}
+
+#[test]
+fn test_gen_nm_conn_setting_multi_connect() {
+    assert_eq!(gen_multi_connect(""), None);
+    assert_eq!(
+        gen_multi_connect("multi-connect: default"),
+        Some(NM_MULTI_CONNECT_DEFAULT)
+    );
+    assert_eq!(
+        gen_multi_connect("multi-connect: single"),
+        Some(NM_MULTI_CONNECT_SINGLE)
+    );
+    assert_eq!(
+        gen_multi_connect("multi-connect: manual-multiple"),
+        Some(NM_MULTI_CONNECT_MANUAL_MULTIPLE)
+    );
+}
+
+#[test]
+fn test_multi_connect_multiple_with_match_glob() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: uplink
+          type: ethernet
+          state: up
+          multi-connect: multiple
+          bind-by: match-glob
+          bind-pattern:
+          - en*",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(
+        nm_conns[0]
+            .connection
+            .as_ref()
+            .and_then(|c| c.multi_connect),
+        Some(NM_MULTI_CONNECT_MULTIPLE)
+    );
+}
+
+#[test]
+fn test_multi_connect_multiple_without_match() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          multi-connect: multiple",
+    )
+    .unwrap();
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+const OVS_BRIDGES_WITH_PATCH_YAML: &str = r"---
+interfaces:
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth1
+    - name: patch0
+- name: br1
+  type: ovs-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth2
+    - name: patch1
+- name: patch0
+  type: ovs-interface
+  state: up
+  patch:
+    peer: patch1
+- name: patch1
+  type: ovs-interface
+  state: up
+  patch:
+    peer: patch0
+- name: eth1
+  type: ethernet
+  state: up
+- name: eth2
+  type: ethernet
+  state: up
+";
+
+#[test]
+fn test_ovs_bridges_connected_by_patch() {
+    let desired: NetworkState =
+        serde_yaml::from_str(OVS_BRIDGES_WITH_PATCH_YAML).unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let get_uuid = |id: &str| {
+        nm_conns
+            .iter()
+            .find(|c| c.id() == Some(id))
+            .and_then(|c| c.uuid())
+            .unwrap()
+    };
+    let get_controller = |id: &str| {
+        nm_conns
+            .iter()
+            .find(|c| c.id() == Some(id))
+            .and_then(|c| c.controller())
+            .unwrap()
+    };
+
+    for (br_name, port_names) in
+        [("br0", ["eth1", "patch0"]), ("br1", ["eth2", "patch1"])]
+    {
+        let br_uuid = get_uuid(&format!("{br_name}-br"));
+        for port_name in port_names {
+            let port_id = format!("{port_name}-port");
+            assert_eq!(get_controller(&port_id), br_uuid);
+            let iface_id = if port_name.starts_with("patch") {
+                format!("{port_name}-if")
+            } else {
+                port_name.to_string()
+            };
+            assert_eq!(get_controller(&iface_id), get_uuid(&port_id));
+        }
+    }
+    let patch0_nm_conn = nm_conns
+        .iter()
+        .find(|c| c.id() == Some("patch0-if"))
+        .unwrap();
+    assert_eq!(
+        patch0_nm_conn
+            .ovs_patch
+            .as_ref()
+            .and_then(|p| p.peer.as_deref()),
+        Some("patch1")
+    );
+}
+
+#[test]
+fn test_ovs_port_autoconnect_ports() {
+    let desired: NetworkState =
+        serde_yaml::from_str(OVS_BRIDGES_WITH_PATCH_YAML).unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    for id in ["br0-br", "eth1-port", "patch0-port"] {
+        let nm_conn = nm_conns.iter().find(|c| c.id() == Some(id)).unwrap();
+        assert_eq!(
+            nm_conn
+                .connection
+                .as_ref()
+                .and_then(|c| c.autoconnect_ports),
+            Some(true)
+        );
+    }
+}
+
+#[test]
+fn test_ovs_port_inherit_autoconnect_ports_from_bridge() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: br0
+  type: ovs-bridge
+  state: up
+  autoconnect-ports: false
+  bridge:
+    port:
+    - name: eth1
+- name: eth1
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    for id in ["br0-br", "eth1-port"] {
+        let nm_conn = nm_conns.iter().find(|c| c.id() == Some(id)).unwrap();
+        assert_eq!(
+            nm_conn
+                .connection
+                .as_ref()
+                .and_then(|c| c.autoconnect_ports),
+            Some(false)
+        );
+    }
+}
+
+#[test]
+fn test_ovs_bridge_as_port_of_ovs_bridge() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: br0
+          type: ovs-bridge
+          state: up
+          bridge:
+            port:
+            - name: br1
+            - name: eth1
+        - name: br1
+          type: ovs-bridge
+          state: up
+          bridge:
+            port:
+            - name: eth2",
+    )
+    .unwrap();
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+const UNSUPPORTED_IFACE_TYPE_YAML: &str = r"---
+interfaces:
+- name: foo0
+  type: foo
+  state: up
+";
+
+#[test]
+fn test_unsupported_iface_type_in_strict_mode() {
+    let mut desired: NetworkState =
+        serde_yaml::from_str(UNSUPPORTED_IFACE_TYPE_YAML).unwrap();
+    desired.set_strict(true);
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotImplementedError);
+    }
+}
+
+#[test]
+fn test_unsupported_iface_type_in_lenient_mode() {
+    let desired: NetworkState =
+        serde_yaml::from_str(UNSUPPORTED_IFACE_TYPE_YAML).unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(nm_conns[0].iface_name(), Some("foo0"));
+}
+
+const IGNORED_IPV6_RA_TIMEOUT_YAML: &str = r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    dhcp: false
+    autoconf: false
+    ra-timeout: 30
+    address:
+    - ip: 2001:db8::1
+      prefix-length: 64
+";
+
+#[test]
+fn test_ignored_property_in_strict_mode() {
+    let mut desired: NetworkState =
+        serde_yaml::from_str(IGNORED_IPV6_RA_TIMEOUT_YAML).unwrap();
+    desired.set_strict(true);
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+        assert!(e.msg().contains("ipv6.ra-timeout"));
+    }
+}
+
+#[test]
+fn test_ignored_property_in_lenient_mode() {
+    let desired: NetworkState =
+        serde_yaml::from_str(IGNORED_IPV6_RA_TIMEOUT_YAML).unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    assert_eq!(nm_conns[0].ipv6.as_ref().unwrap().ra_timeout, None);
+}
+
+#[test]
+fn test_gather_lldp_neighbors_force_lldp_rx() {
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+        - name: eth2
+          type: ethernet
+          state: up
+          lldp:
+            enabled: true",
+    )
+    .unwrap();
+    desired.set_gather_lldp_neighbors(true);
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    for iface_name in ["eth1", "eth2"] {
+        let nm_conn = nm_conns
+            .iter()
+            .find(|c| c.iface_name() == Some(iface_name))
+            .unwrap();
+        assert_eq!(
+            nm_conn.connection.as_ref().and_then(|c| c.lldp),
+            Some(true)
+        );
+    }
+}
+
+#[test]
+fn test_default_mdns_for_iface_without_mdns() {
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+        - name: eth2
+          type: ethernet
+          state: up
+          mdns: yes",
+    )
+    .unwrap();
+    desired.set_default_mdns(InterfaceResolveMode::No);
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let mdns_of = |iface_name| {
+        nm_conns
+            .iter()
+            .find(|c| c.iface_name() == Some(iface_name))
+            .and_then(|c| c.connection.as_ref())
+            .map(|c| (c.mdns, c.llmnr))
+    };
+    assert_eq!(mdns_of("eth1"), Some((Some(NM_RESOLVE_MODE_NO), None)));
+    assert_eq!(mdns_of("eth2"), Some((Some(NM_RESOLVE_MODE_YES), None)));
+}
+
+#[test]
+fn test_autoconnect_ports_multi_level_stack() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+        - name: eth2
+          type: ethernet
+          state: up
+        - name: bond0
+          type: bond
+          state: up
+          link-aggregation:
+            mode: active-backup
+            port:
+            - eth1
+            - eth2
+        - name: bond0.100
+          type: vlan
+          state: up
+          vlan:
+            base-iface: bond0
+            id: 100
+        - name: br0
+          type: linux-bridge
+          state: up
+          bridge:
+            port:
+            - name: bond0.100
+        - name: vrf0
+          type: vrf
+          state: up
+          vrf:
+            port:
+            - br0
+            route-table-id: 100",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let get_autoconnect_ports = |iface_name| {
+        nm_conns
+            .iter()
+            .find(|c| c.iface_name() == Some(iface_name))
+            .and_then(|c| c.connection.as_ref())
+            .and_then(|c| c.autoconnect_ports)
+    };
+    for ctrl_name in ["vrf0", "br0", "bond0"] {
+        assert_eq!(get_autoconnect_ports(ctrl_name), Some(true));
+    }
+    for port_name in ["bond0.100", "eth1", "eth2"] {
+        assert_eq!(get_autoconnect_ports(port_name), None);
+    }
+}
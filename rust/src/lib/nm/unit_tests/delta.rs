@@ -0,0 +1,172 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::delta::NmConnectionDelta;
+use crate::nm::nm_dbus::{NmConnection, NmSetting8021X};
+use crate::nm::settings::iface_to_nm_connections;
+use crate::{InterfaceType, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_nm_conn_delta_ipv4_only() {
+    let cur_net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 1500
+  ipv4:
+    enabled: true
+    dhcp: false
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+  ipv6:
+    enabled: false
+",
+    )
+    .unwrap();
+    let des_net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: false
+    address:
+    - ip: 192.0.2.2
+      prefix-length: 24
+",
+    )
+    .unwrap();
+
+    let merged_state = MergedNetworkState::new(
+        cur_net_state.clone(),
+        NetworkState::new(),
+        true,
+        false,
+    )
+    .unwrap();
+    let merged_iface = merged_state
+        .interfaces
+        .get_iface("eth1", InterfaceType::Ethernet)
+        .unwrap();
+    let exist_nm_conn =
+        iface_to_nm_connections(merged_iface, &merged_state, &[], &[], true)
+            .unwrap()
+            .remove(0);
+
+    let merged_state =
+        MergedNetworkState::new(des_net_state, cur_net_state, false, false)
+            .unwrap();
+    let merged_iface = merged_state
+        .interfaces
+        .get_iface("eth1", InterfaceType::Ethernet)
+        .unwrap();
+
+    let new_nm_conn = iface_to_nm_connections(
+        merged_iface,
+        &merged_state,
+        std::slice::from_ref(&exist_nm_conn),
+        &[],
+        false,
+    )
+    .unwrap()
+    .remove(0);
+
+    let delta = NmConnectionDelta::new(&exist_nm_conn, &new_nm_conn);
+
+    assert_eq!(delta.changed, vec!["ipv4"]);
+    assert!(delta.removed.is_empty());
+    assert_eq!(delta.patch.uuid(), exist_nm_conn.uuid());
+    assert_eq!(
+        delta.patch.ipv4.as_ref().unwrap().addresses,
+        vec!["192.0.2.2/24".to_string()]
+    );
+    assert!(delta.patch.ipv6.is_none());
+    assert!(delta.patch.wired.is_none());
+    assert!(delta.patch.ethtool.is_none());
+    assert!(!delta.need_reactivation(&exist_nm_conn));
+}
+
+#[test]
+fn test_nm_conn_delta_no_change() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 1500
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    let merged_iface = merged_state
+        .interfaces
+        .get_iface("eth1", InterfaceType::Ethernet)
+        .unwrap();
+    let nm_conn =
+        iface_to_nm_connections(merged_iface, &merged_state, &[], &[], true)
+            .unwrap()
+            .remove(0);
+
+    let delta = NmConnectionDelta::new(&nm_conn, &nm_conn);
+
+    assert!(delta.is_empty());
+    assert!(!delta.need_reactivation(&nm_conn));
+}
+
+#[test]
+fn test_nm_conn_diff_mtu_only() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 1500
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(net_state, NetworkState::new(), true, false)
+            .unwrap();
+    let merged_iface = merged_state
+        .interfaces
+        .get_iface("eth1", InterfaceType::Ethernet)
+        .unwrap();
+    let cur_nm_conn =
+        iface_to_nm_connections(merged_iface, &merged_state, &[], &[], true)
+            .unwrap()
+            .remove(0);
+    let mut new_nm_conn = cur_nm_conn.clone();
+    if let Some(wired) = new_nm_conn.wired.as_mut() {
+        wired.mtu = Some(9000);
+    }
+
+    let changes = cur_nm_conn.diff(&new_nm_conn).unwrap();
+
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].setting, "802-3-ethernet");
+    assert_eq!(changes[0].key, "mtu");
+    assert_eq!(changes[0].old_value, Some(1500u32.into()));
+    assert_eq!(changes[0].new_value, Some(9000u32.into()));
+}
+
+#[test]
+fn test_nm_conn_diff_secret_by_presence() {
+    let mut cur_nm_conn = NmConnection::default();
+    let mut nm_8021x_set = NmSetting8021X::default();
+    nm_8021x_set.private_key_password = Some("password1".to_string());
+    cur_nm_conn.ieee8021x = Some(nm_8021x_set);
+    let mut new_nm_conn = cur_nm_conn.clone();
+    if let Some(nm_8021x_set) = new_nm_conn.ieee8021x.as_mut() {
+        nm_8021x_set.private_key_password = Some("password2".to_string());
+    }
+
+    assert!(cur_nm_conn.diff(&new_nm_conn).unwrap().is_empty());
+}
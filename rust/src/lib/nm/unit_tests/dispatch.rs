@@ -0,0 +1,190 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::query_apply::dispatch::{
+    gen_iface_mptcp_overrides, gen_iface_sysctl, gen_mptcp_script,
+    gen_neighbor_script, gen_sysctl_script, parse_neighbor_script,
+};
+use crate::{Interface, MergedInterface};
+
+#[test]
+fn test_sysctl_arp_ignore() {
+    let iface: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        sysctl:
+          ipv4.arp_ignore: 1",
+    )
+    .unwrap();
+
+    let script = gen_sysctl_script(
+        iface.name(),
+        iface.base_iface().sysctl.as_ref().unwrap(),
+    );
+
+    assert!(script.contains(r#"if [ "$1" == "eth1" ]"#));
+    assert!(script
+        .lines()
+        .any(|l| l == "echo 1 > /proc/sys/net/ipv4/conf/eth1/arp_ignore"));
+}
+
+#[test]
+fn test_sysctl_disable_ipv6_accept_ra_mtu() {
+    let desired: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv6:
+          enabled: true
+          autoconf: true
+          dhcp: true
+          ra-timeout: 30
+          accept-ra-mtu: false",
+    )
+    .unwrap();
+    let current: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        sysctl:
+          ipv4.arp_ignore: 1",
+    )
+    .unwrap();
+    let merged_iface =
+        MergedInterface::new(Some(desired), Some(current)).unwrap();
+
+    let sysctl = gen_iface_sysctl(&merged_iface).unwrap();
+
+    assert_eq!(sysctl.get("ipv6.accept_ra_mtu"), Some(&0));
+    assert_eq!(sysctl.get("ipv4.arp_ignore"), Some(&1));
+}
+
+#[test]
+fn test_sysctl_ipv6_router_forwarding_accept_ra() {
+    let desired: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv6:
+          enabled: true
+          autoconf: true
+          dhcp: false
+          accept-ra: 2
+          forwarding: true",
+    )
+    .unwrap();
+    let merged_iface = MergedInterface::new(Some(desired), None).unwrap();
+
+    let sysctl = gen_iface_sysctl(&merged_iface).unwrap();
+    let script = gen_sysctl_script("eth1", &sysctl);
+
+    assert_eq!(sysctl.get("ipv6.accept_ra"), Some(&2));
+    assert_eq!(sysctl.get("ipv6.forwarding"), Some(&1));
+    assert!(script
+        .lines()
+        .any(|l| l == "echo 1 > /proc/sys/net/ipv6/conf/eth1/forwarding"));
+    assert!(script
+        .lines()
+        .any(|l| l == "echo 2 > /proc/sys/net/ipv6/conf/eth1/accept_ra"));
+}
+
+#[test]
+fn test_static_neighbors_arp() {
+    let mut iface: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        static-neighbors:
+        - ip: 192.0.2.2
+          mac-address: 00:23:45:67:89:1b
+        - ip: 192.0.2.1
+          mac-address: 00-23-45-67-89-1A",
+    )
+    .unwrap();
+    iface.sanitize(true).unwrap();
+    let neighbors = iface.base_iface().static_neighbors.as_deref().unwrap();
+
+    let script = gen_neighbor_script(iface.name(), neighbors);
+
+    assert!(script.lines().any(|l| l
+        == "ip neigh replace 192.0.2.1 lladdr 00:23:45:67:89:1A nud \
+            permanent dev eth1"));
+    assert!(script.lines().any(|l| l
+        == "ip neigh replace 192.0.2.2 lladdr 00:23:45:67:89:1B nud \
+            permanent dev eth1"));
+    assert_eq!(parse_neighbor_script(iface.name(), &script), neighbors);
+}
+
+#[test]
+fn test_mptcp_per_addr_flags() {
+    let desired: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        mptcp:
+          address-flags:
+          - subflow
+        ipv4:
+          enabled: true
+          dhcp: false
+          address:
+          - ip: 192.0.2.1
+            prefix-length: 24
+            mptcp-flags:
+            - signal
+          - ip: 192.0.2.2
+            prefix-length: 24
+            mptcp-flags:
+            - subflow
+            - backup
+          - ip: 192.0.2.3
+            prefix-length: 24
+            mptcp-flags:
+            - subflow",
+    )
+    .unwrap();
+    let mut merged_iface = MergedInterface::new(Some(desired), None).unwrap();
+    merged_iface.post_inter_ifaces_process().unwrap();
+
+    let overrides = gen_iface_mptcp_overrides(&merged_iface).unwrap();
+
+    assert_eq!(overrides.len(), 2);
+    assert_eq!(overrides[0].ip.to_string(), "192.0.2.1");
+    assert_eq!(overrides[1].ip.to_string(), "192.0.2.2");
+
+    let script = gen_mptcp_script("eth1", overrides.as_slice());
+
+    assert!(script.lines().any(|l| l
+        == "ip mptcp endpoint show | awk '$1 == \"192.0.2.1\" && $2 == \
+            \"id\" {print $3}' | xargs -r -n1 ip mptcp endpoint delete id"));
+    assert!(script
+        .lines()
+        .any(|l| l == "ip mptcp endpoint add 192.0.2.1 dev eth1 signal"));
+    assert!(script.lines().any(
+        |l| l == "ip mptcp endpoint add 192.0.2.2 dev eth1 subflow backup"
+    ));
+    assert!(!script.contains("192.0.2.3"));
+}
+
+#[test]
+fn test_mptcp_no_addr_desired() {
+    let desired: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        mptcp:
+          address-flags:
+          - subflow",
+    )
+    .unwrap();
+    let merged_iface = MergedInterface::new(Some(desired), None).unwrap();
+
+    assert!(gen_iface_mptcp_overrides(&merged_iface).is_none());
+}
@@ -0,0 +1,131 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::profile::perpare_nm_conns;
+use crate::nm::NmVersion;
+use crate::{ErrorKind, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_ethtool_coalesce() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ethtool:
+            coalesce:
+              adaptive-rx: true
+              rx-usecs: 50
+              tx-usecs: 60",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_ethtool_set = nm_conns[0].ethtool.as_ref().unwrap();
+    assert_eq!(nm_ethtool_set.coalesce_adaptive_rx, Some(true));
+    assert_eq!(nm_ethtool_set.coalesce_rx_usecs, Some(50));
+    assert_eq!(nm_ethtool_set.coalesce_tx_usecs, Some(60));
+}
+
+#[test]
+fn test_ethtool_unknown_feature_pass_through() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ethtool:
+            feature:
+              rx-checksum: true
+              rx-imaginary-offload: false",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_ethtool_set = nm_conns[0].ethtool.as_ref().unwrap();
+    let features = nm_ethtool_set.features.as_ref().unwrap();
+    assert_eq!(features.get("feature-rx"), Some(&true));
+    assert_eq!(features.get("feature-rx-imaginary-offload"), Some(&false));
+    assert_eq!(
+        nm_ethtool_set.unknown_features(),
+        vec!["feature-rx-imaginary-offload"]
+    );
+}
+
+#[test]
+fn test_ethtool_channels_and_gso_max_size() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ethtool:
+            channels:
+              rx: 4
+              tx: 4
+            gso-max-size: 65536",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_ethtool_set = nm_conns[0].ethtool.as_ref().unwrap();
+    assert_eq!(nm_ethtool_set.channels_rx, Some(4));
+    assert_eq!(nm_ethtool_set.channels_tx, Some(4));
+    assert_eq!(nm_ethtool_set.channels_combined, None);
+    assert_eq!(
+        nm_conns[0].link.as_ref().and_then(|l| l.gso_max_size),
+        Some(65536)
+    );
+}
+
+#[test]
+fn test_ethtool_gso_max_size_on_old_nm() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          ethtool:
+            gso-max-size: 65536",
+    )
+    .unwrap();
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up",
+    )
+    .unwrap();
+    let mut merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+    merged_state.nm_version = Some(NmVersion::new(1, 42, 0));
+
+    let result = perpare_nm_conns(&merged_state, &[], &[], false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotImplementedError);
+    }
+}
@@ -0,0 +1,297 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ErrorKind, NetworkState};
+
+const BR_YAML: &str = r"---
+interfaces:
+- name: eth2
+  type: ethernet
+  state: up
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth2
+    - name: eth1
+- name: eth1
+  type: ethernet
+  state: up
+";
+
+const BR_REVERSED_YAML: &str = r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth1
+    - name: eth2
+- name: eth2
+  type: ethernet
+  state: up
+";
+
+fn gen_nm_conf_files(yml: &str) -> Vec<(String, String)> {
+    let desired: NetworkState = serde_yaml::from_str(yml).unwrap();
+    desired
+        .gen_conf()
+        .unwrap()
+        .remove("NetworkManager")
+        .unwrap()
+}
+
+#[test]
+fn test_gen_conf_output_is_ordered() {
+    let conf = gen_nm_conf_files(BR_YAML);
+
+    let file_names: Vec<&str> =
+        conf.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(
+        file_names,
+        vec!["br0.nmconnection", "eth1.nmconnection", "eth2.nmconnection"]
+    );
+    assert_eq!(conf, gen_nm_conf_files(BR_YAML));
+}
+
+#[test]
+fn test_gen_conf_with_hook() {
+    let desired: NetworkState = serde_yaml::from_str(BR_YAML).unwrap();
+    let conf = desired
+        .gen_conf_with_hook(|nm_conn| {
+            nm_conn.set_user_data("example.site", "lab1");
+        })
+        .unwrap()
+        .remove("NetworkManager")
+        .unwrap();
+
+    assert_eq!(conf.len(), 3);
+    for (_, content) in conf {
+        assert!(content.contains("[user]\nexample.site=lab1\n"));
+    }
+}
+
+#[test]
+fn test_gen_conf_output_ignore_desired_order() {
+    assert_eq!(
+        gen_nm_conf_files(BR_YAML),
+        gen_nm_conf_files(BR_REVERSED_YAML)
+    );
+}
+
+#[test]
+fn test_gen_conf_wireguard_peer_sections() {
+    let conf = gen_nm_conf_files(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    private-key: yAnz5TF+lXXJte14tji3zlMNq+hd2rYUIgJBgB3fBmk=
+    peers:
+    - public-key: 8bdQrVLqiw3ZoHCucNh1YfH0iCWuyStniRr8t7H24Fk=
+      endpoint: vpn.example.org:51820
+      allowed-ips:
+      - 192.0.2.0/24
+",
+    );
+
+    let content = conf[0].1.as_str();
+    assert!(content.contains(
+        "[wireguard]\nprivate-key=yAnz5TF+lXXJte14tji3zlMNq+hd2rYUIgJBgB3fBmk=\n"
+    ));
+    assert!(content.contains(
+        "[wireguard-peer.8bdQrVLqiw3ZoHCucNh1YfH0iCWuyStniRr8t7H24Fk=]\n\
+        allowed-ips=192.0.2.0/24\nendpoint=vpn.example.org:51820\n"
+    ));
+}
+
+#[test]
+fn test_reconcile_create_modify_delete() {
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 1500
+- name: eth2
+  type: ethernet
+  state: up
+- name: dummy0
+  type: dummy
+  state: up
+",
+    )
+    .unwrap();
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 9000
+- name: dummy0
+  type: dummy
+  state: absent
+- name: dummy1
+  type: dummy
+  state: up
+",
+    )
+    .unwrap();
+
+    let ops = desired.reconcile(&current).unwrap();
+
+    let create: Vec<&str> =
+        ops.create.iter().map(|(name, _)| name.as_str()).collect();
+    let modify: Vec<&str> =
+        ops.modify.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(create, vec!["dummy1.nmconnection"]);
+    assert_eq!(modify, vec!["eth1.nmconnection"]);
+    assert!(ops.modify[0].1.contains("mtu=9000"));
+    assert_eq!(ops.delete, vec!["dummy0.nmconnection".to_string()]);
+}
+
+#[test]
+fn test_reconcile_purge_not_desired() {
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: eth2
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+    desired.set_purge_unmanaged(true);
+
+    let ops = desired.reconcile(&current).unwrap();
+
+    assert!(ops.create.is_empty());
+    assert!(ops.modify.is_empty());
+    assert_eq!(ops.delete, vec!["eth2.nmconnection".to_string()]);
+}
+
+#[test]
+fn test_gen_conf_s390_qeth() {
+    let conf = gen_nm_conf_files(
+        r#"---
+interfaces:
+- name: enc600
+  type: ethernet
+  state: up
+  ethernet:
+    s390:
+      subchannels:
+      - 0.0.0600
+      - 0.0.0601
+      - 0.0.0602
+      nettype: qeth
+      options:
+        layer2: "1"
+"#,
+    );
+
+    assert_eq!(conf.len(), 1);
+    let content = conf[0].1.as_str();
+    assert!(content.contains("s390-subchannels=0.0.0600;0.0.0601;0.0.0602\n"));
+    assert!(content.contains("s390-nettype=qeth\n"));
+    assert!(content.contains("[ethernet-s390-options]\nlayer2=1\n"));
+}
+
+#[test]
+fn test_gen_conf_ipv6_accept_ra_mtu_not_supported() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    autoconf: true
+    dhcp: true
+    accept-ra-mtu: false
+",
+    )
+    .unwrap();
+
+    let result = desired.gen_conf();
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_gen_conf_per_addr_mptcp_flags_not_supported() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ipv4:
+    enabled: true
+    dhcp: false
+    address:
+    - ip: 192.0.2.1
+      prefix-length: 24
+      mptcp-flags:
+      - signal
+",
+    )
+    .unwrap();
+
+    let result = desired.gen_conf();
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_gen_conf_ipv6_accept_ra_and_forwarding_not_supported() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    autoconf: true
+    dhcp: true
+    accept-ra: 2
+    forwarding: true
+",
+    )
+    .unwrap();
+
+    let result = desired.gen_conf();
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
@@ -0,0 +1,224 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::nm_dbus::{
+    NmConnection, NmSetting8021X, NmSettingConnection,
+    NM_SETTING_SECRET_FLAG_AGENT_OWNED, NM_SETTING_SECRET_FLAG_NONE,
+};
+use crate::nm::profile::perpare_nm_conns;
+use crate::{ErrorKind, MergedNetworkState, NetworkState};
+
+fn gen_802_1x_nm_conn_set(
+    storage_mode: &str,
+) -> crate::nm::nm_dbus::NmSetting8021X {
+    let desired: NetworkState = serde_yaml::from_str(&format!(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  802.1x:
+    identity: client.example.org
+    eap-methods:
+    - tls
+    private-key: /etc/pki/802-1x-test/client.example.org.key
+    private-key-password: password
+    private-key-password-storage: {storage_mode}
+"
+    ))
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    nm_conns[0].ieee8021x.clone().unwrap()
+}
+
+#[test]
+fn test_802_1x_password_stored_by_system() {
+    let nm_set = gen_802_1x_nm_conn_set("none");
+
+    assert_eq!(
+        nm_set.private_key_password_flags,
+        Some(NM_SETTING_SECRET_FLAG_NONE)
+    );
+    assert_eq!(nm_set.private_key_password.as_deref(), Some("password"));
+}
+
+#[test]
+fn test_802_1x_password_agent_owned() {
+    let nm_set = gen_802_1x_nm_conn_set("agent-owned");
+
+    assert_eq!(
+        nm_set.private_key_password_flags,
+        Some(NM_SETTING_SECRET_FLAG_AGENT_OWNED)
+    );
+    assert_eq!(nm_set.private_key_password, None);
+}
+
+#[test]
+fn test_802_1x_auth_timeout() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  802.1x:
+    identity: client.example.org
+    eap-methods:
+    - tls
+    auth-timeout: 30
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(
+        nm_conns[0].connection.as_ref().unwrap().auth_timeout,
+        Some(30)
+    );
+}
+
+#[test]
+fn test_802_1x_zero_auth_timeout() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  802.1x:
+    identity: client.example.org
+    auth-timeout: 0
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_802_1x_auth_retries() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  802.1x:
+    identity: client.example.org
+    eap-methods:
+    - tls
+    auth-retries: 5
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(
+        nm_conns[0].connection.as_ref().unwrap().auth_retries,
+        Some(5)
+    );
+}
+
+fn gen_nm_conn_with_exist_password(desired_yml: &str) -> NmConnection {
+    let desired: NetworkState = serde_yaml::from_str(desired_yml).unwrap();
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    let mut exist_nm_conn = NmConnection::default();
+    let mut nm_conn_set = NmSettingConnection::default();
+    nm_conn_set.id = Some("eth1".to_string());
+    nm_conn_set.uuid = Some("6b8f1a3e-5d2c-4e7a-9b1f-0c3d2e4f5a60".to_string());
+    nm_conn_set.iface_type = Some("802-3-ethernet".to_string());
+    nm_conn_set.iface_name = Some("eth1".to_string());
+    exist_nm_conn.connection = Some(nm_conn_set);
+    let mut nm_8021x_set = NmSetting8021X::default();
+    nm_8021x_set.identity = Some("old.example.org".to_string());
+    nm_8021x_set.private_key_password = Some("password".to_string());
+    nm_8021x_set.private_key_password_flags = Some(NM_SETTING_SECRET_FLAG_NONE);
+    exist_nm_conn.ieee8021x = Some(nm_8021x_set);
+
+    perpare_nm_conns(&merged_state, &[exist_nm_conn], &[], false)
+        .unwrap()
+        .to_store
+        .remove(0)
+}
+
+#[test]
+fn test_802_1x_edit_identity_preserve_password() {
+    let nm_conn = gen_nm_conn_with_exist_password(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  802.1x:
+    identity: new.example.org
+    eap-methods:
+    - tls
+    private-key: /etc/pki/802-1x-test/client.example.org.key
+",
+    );
+
+    let nm_set = nm_conn.ieee8021x.as_ref().unwrap();
+    assert_eq!(nm_set.identity.as_deref(), Some("new.example.org"));
+    assert_eq!(nm_set.private_key_password.as_deref(), Some("password"));
+    assert_eq!(
+        nm_set.private_key_password_flags,
+        Some(NM_SETTING_SECRET_FLAG_NONE)
+    );
+}
+
+#[test]
+fn test_802_1x_remove_password() {
+    let nm_conn = gen_nm_conn_with_exist_password(
+        r#"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  802.1x:
+    identity: new.example.org
+    eap-methods:
+    - tls
+    private-key: /etc/pki/802-1x-test/client.example.org.key
+    private-key-password: ""
+"#,
+    );
+
+    let nm_set = nm_conn.ieee8021x.as_ref().unwrap();
+    assert_eq!(nm_set.identity.as_deref(), Some("new.example.org"));
+    assert_eq!(nm_set.private_key_password, None);
+}
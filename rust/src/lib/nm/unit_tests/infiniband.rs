@@ -0,0 +1,251 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::profile::perpare_nm_conns;
+use crate::{ErrorKind, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_ib_pkey_over_pkey_is_rejected() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r#"---
+interfaces:
+- name: mlx5_ib2
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "connected"
+- name: mlx5_ib2.8001
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0x8001"
+    mode: "connected"
+    base-iface: "mlx5_ib2"
+- name: mlx5_ib2.8001.8002
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0x8002"
+    mode: "connected"
+    base-iface: "mlx5_ib2.8001"
+"#,
+    )
+    .unwrap();
+
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ib_pkey_over_physical_ib() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r#"---
+interfaces:
+- name: mlx5_ib2
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "connected"
+- name: mlx5_ib2.8001
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0x8001"
+    mode: "connected"
+    base-iface: "mlx5_ib2"
+"#,
+    )
+    .unwrap();
+
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+    let nm_conn = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("mlx5_ib2.8001"))
+        .unwrap();
+
+    assert_eq!(
+        nm_conn.infiniband.as_ref().unwrap().parent.as_deref(),
+        Some("mlx5_ib2")
+    );
+    assert_eq!(nm_conn.infiniband.as_ref().unwrap().pkey, Some(0x8001));
+}
+
+#[test]
+fn test_ib_sriov_vfs() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r#"---
+interfaces:
+- name: mlx5_ib0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+    sr-iov:
+      total-vfs: 4
+      vfs:
+      - id: 1
+        trust: true
+        max-tx-rate: 1000
+"#,
+    )
+    .unwrap();
+
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    assert!(nm_conns[0].infiniband.is_some());
+    let nm_sriov_set = nm_conns[0].sriov.as_ref().unwrap();
+    assert_eq!(nm_sriov_set.total_vfs, Some(4));
+    let nm_vfs = nm_sriov_set.vfs.as_ref().unwrap();
+    assert_eq!(nm_vfs.len(), 1);
+    assert_eq!(nm_vfs[0].index, Some(1));
+    assert_eq!(nm_vfs[0].trust, Some(true));
+    assert_eq!(nm_vfs[0].max_tx_rate, Some(1000));
+}
+
+#[test]
+fn test_ib_sriov_vf_vlan_is_rejected() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r#"---
+interfaces:
+- name: mlx5_ib0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+    sr-iov:
+      total-vfs: 4
+      vfs:
+      - id: 0
+        vlan-id: 100
+"#,
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+fn gen_ib_nm_conns_result(
+    ib_yaml: &str,
+) -> Result<Vec<crate::nm::nm_dbus::NmConnection>, crate::NmstateError> {
+    let desired: NetworkState = serde_yaml::from_str(ib_yaml).unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    perpare_nm_conns(&merged_state, &[], &[], true).map(|c| c.to_store)
+}
+
+#[test]
+fn test_ib_datagram_mtu_too_large() {
+    let result = gen_ib_nm_conns_result(
+        r#"---
+interfaces:
+- name: mlx5_ib2
+  type: infiniband
+  state: up
+  mtu: 65520
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+"#,
+    );
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("MTU 65520 exceeds the maximum 4092"));
+    }
+}
+
+#[test]
+fn test_ib_connected_mode_large_mtu() {
+    let nm_conns = gen_ib_nm_conns_result(
+        r#"---
+interfaces:
+- name: mlx5_ib2
+  type: infiniband
+  state: up
+  mtu: 65520
+  infiniband:
+    pkey: "0xffff"
+    mode: "connected"
+"#,
+    )
+    .unwrap();
+
+    assert_eq!(nm_conns[0].infiniband.as_ref().unwrap().mtu, Some(65520));
+}
+
+#[test]
+fn test_ib_pkey_without_parent() {
+    let result = gen_ib_nm_conns_result(
+        r#"---
+interfaces:
+- name: mlx5_ib2.8001
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0x8001"
+    mode: "datagram"
+"#,
+    );
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("requires base-iface"));
+    }
+}
+
+#[test]
+fn test_ib_invalid_errors_aggregated() {
+    let result = gen_ib_nm_conns_result(
+        r#"---
+interfaces:
+- name: mlx5_ib2.8000
+  type: infiniband
+  state: up
+  mtu: 9000
+  infiniband:
+    pkey: "0x8000"
+    mode: "datagram"
+"#,
+    );
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("MTU 9000 exceeds"));
+        assert!(e.msg().contains("requires base-iface"));
+        assert!(e.msg().contains("reserved as invalid"));
+    }
+}
@@ -0,0 +1,608 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::dns::store_dns_config_to_iface;
+use crate::nm::nm_dbus::NmConnection;
+use crate::nm::profile::perpare_nm_conns;
+use crate::nm::settings::gen_nm_ip_setting;
+use crate::{
+    DnsClientState, ErrorKind, Interface, InterfaceType, MergedNetworkState,
+    NetworkState, RouteEntry, RouteScope, RouteState, RouteType,
+};
+
+fn gen_iface(ipv4_enabled: bool, ipv6_enabled: bool) -> Interface {
+    serde_yaml::from_str(&format!(
+        r"---
+name: eth1
+type: ethernet
+state: up
+ipv4:
+  enabled: {ipv4_enabled}
+ipv6:
+  enabled: {ipv6_enabled}
+"
+    ))
+    .unwrap()
+}
+
+fn gen_route(destination: &str) -> RouteEntry {
+    let mut route = RouteEntry::new();
+    route.destination = Some(destination.to_string());
+    route.next_hop_iface = Some("eth1".to_string());
+    route
+}
+
+#[test]
+fn test_ipv6_disabled_with_ipv6_route() {
+    let iface = gen_iface(true, false);
+    let routes = vec![gen_route("2001:db8:1::/64")];
+
+    let result = gen_nm_ip_setting(
+        &iface,
+        Some(routes.as_slice()),
+        None,
+        &mut NmConnection::default(),
+    );
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ipv4_disabled_with_ipv4_route() {
+    let iface = gen_iface(false, true);
+    let routes = vec![gen_route("198.51.100.0/24")];
+
+    let result = gen_nm_ip_setting(
+        &iface,
+        Some(routes.as_slice()),
+        None,
+        &mut NmConnection::default(),
+    );
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ipv6_disabled_with_ipv6_dns() {
+    let mut iface = gen_iface(true, false);
+    if let Some(ipv6) = iface.base_iface_mut().ipv6.as_mut() {
+        ipv6.dns = Some(DnsClientState {
+            server: Some(vec!["2001:db8:1::1".to_string()]),
+            ..Default::default()
+        });
+    }
+
+    let result =
+        gen_nm_ip_setting(&iface, None, None, &mut NmConnection::default());
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ipv4_disabled_with_ipv4_dns() {
+    let mut iface = gen_iface(false, true);
+    if let Some(ipv4) = iface.base_iface_mut().ipv4.as_mut() {
+        ipv4.dns = Some(DnsClientState {
+            server: Some(vec!["192.0.2.1".to_string()]),
+            ..Default::default()
+        });
+    }
+
+    let result =
+        gen_nm_ip_setting(&iface, None, None, &mut NmConnection::default());
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ipv4_disabled_with_ipv6_route() {
+    let iface = gen_iface(false, true);
+    let routes = vec![gen_route("2001:db8:1::/64")];
+    let mut nm_conn = NmConnection::default();
+
+    gen_nm_ip_setting(&iface, Some(routes.as_slice()), None, &mut nm_conn)
+        .unwrap();
+
+    assert_eq!(nm_conn.ipv6.as_ref().unwrap().routes.len(), 1);
+}
+
+#[test]
+fn test_link_scope_route_via_iface() {
+    let iface = gen_iface(true, true);
+    let routes =
+        vec![gen_route("198.51.100.0/24"), gen_route("2001:db8:1::/64")];
+    let mut nm_conn = NmConnection::default();
+
+    gen_nm_ip_setting(&iface, Some(routes.as_slice()), None, &mut nm_conn)
+        .unwrap();
+
+    let nm_ipv4_routes = &nm_conn.ipv4.as_ref().unwrap().routes;
+    assert_eq!(nm_ipv4_routes.len(), 1);
+    assert_eq!(nm_ipv4_routes[0].dest.as_deref(), Some("198.51.100.0"));
+    assert_eq!(nm_ipv4_routes[0].prefix, Some(24));
+    assert_eq!(nm_ipv4_routes[0].next_hop, None);
+    let nm_ipv6_routes = &nm_conn.ipv6.as_ref().unwrap().routes;
+    assert_eq!(nm_ipv6_routes.len(), 1);
+    assert_eq!(nm_ipv6_routes[0].dest.as_deref(), Some("2001:db8:1::"));
+    assert_eq!(nm_ipv6_routes[0].next_hop, None);
+}
+
+#[test]
+fn test_dns_routing_domain_stored_in_both_ip_families() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        dns-resolver:
+          config:
+            search:
+            - example.com
+            - ~example.org
+            server:
+            - 2001:4860:4860::8888
+            - 8.8.8.8
+        interfaces:
+          - name: dummy0
+            type: dummy
+            state: up
+            ipv4:
+              enabled: true
+              dhcp: true
+              auto-dns: false
+            ipv6:
+              enabled: true
+              dhcp: true
+              autoconf: true
+              auto-dns: false",
+    )
+    .unwrap();
+
+    let mut merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false)
+            .unwrap();
+
+    store_dns_config_to_iface(&mut merged_state, &[], &[]).unwrap();
+
+    let iface = merged_state
+        .interfaces
+        .get_iface("dummy0", InterfaceType::Dummy)
+        .unwrap()
+        .for_apply
+        .as_ref()
+        .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(iface, None, None, &mut nm_conn).unwrap();
+
+    assert_eq!(
+        nm_conn.ipv6.as_ref().unwrap().dns_search.as_deref(),
+        Some(
+            ["example.com".to_string(), "~example.org".to_string()].as_slice()
+        )
+    );
+    assert_eq!(
+        nm_conn.ipv4.as_ref().unwrap().dns_search.as_deref(),
+        Some(["~example.org".to_string()].as_slice())
+    );
+}
+
+#[test]
+fn test_ipv4_negative_dns_priority() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        dns-resolver:
+          config:
+            server:
+            - 192.0.2.53
+        interfaces:
+          - name: dummy0
+            type: dummy
+            state: up
+            ipv4:
+              enabled: true
+              dhcp: false
+              address:
+              - ip: 192.0.2.1
+                prefix-length: 24
+              dns-priority: -100",
+    )
+    .unwrap();
+
+    let mut merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false)
+            .unwrap();
+
+    store_dns_config_to_iface(&mut merged_state, &[], &[]).unwrap();
+
+    let iface = merged_state
+        .interfaces
+        .get_iface("dummy0", InterfaceType::Dummy)
+        .unwrap()
+        .for_apply
+        .as_ref()
+        .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(iface, None, None, &mut nm_conn).unwrap();
+
+    let nm_ipv4_set = nm_conn.ipv4.as_ref().unwrap();
+    assert_eq!(
+        nm_ipv4_set.dns.as_deref(),
+        Some(["192.0.2.53".to_string()].as_slice())
+    );
+    assert_eq!(nm_ipv4_set.dns_priority, Some(-100));
+}
+
+#[test]
+fn test_ipv6_dad_transmits() {
+    let iface: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv6:
+          enabled: true
+          dad-transmits: 0
+          address:
+          - ip: 2001:db8:1::1
+            prefix-length: 64",
+    )
+    .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(&iface, None, None, &mut nm_conn).unwrap();
+
+    assert_eq!(nm_conn.ipv6.as_ref().unwrap().dad_transmits, Some(0));
+}
+
+fn gen_nm_ipv6_privacy(ip6_privacy: &str) -> Option<i32> {
+    let iface: Interface = serde_yaml::from_str(&format!(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv6:
+          enabled: true
+          autoconf: true
+          dhcp: true
+          ip6-privacy: {ip6_privacy}"
+    ))
+    .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(&iface, None, None, &mut nm_conn).unwrap();
+
+    nm_conn.ipv6.as_ref().unwrap().ip6_privacy
+}
+
+#[test]
+fn test_ipv6_privacy_prefer_temp_addr() {
+    assert_eq!(gen_nm_ipv6_privacy("prefer-temp-addr"), Some(2));
+}
+
+#[test]
+fn test_ipv6_privacy_disabled() {
+    assert_eq!(gen_nm_ipv6_privacy("disabled"), Some(0));
+}
+
+#[test]
+fn test_ipv4_dad_timeout_disabled() {
+    let iface: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv4:
+          enabled: true
+          dad-timeout: 0
+          address:
+          - ip: 192.0.2.251
+            prefix-length: 24",
+    )
+    .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(&iface, None, None, &mut nm_conn).unwrap();
+
+    assert_eq!(nm_conn.ipv4.as_ref().unwrap().dad_timeout, Some(0));
+}
+
+#[test]
+fn test_duplicate_routes_coalesced() {
+    let iface = gen_iface(true, true);
+    let mut route = gen_route("198.51.100.0/24");
+    route.next_hop_addr = Some("192.0.2.1".to_string());
+    route.metric = Some(100);
+    let routes = vec![route.clone(), route];
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(&iface, Some(routes.as_slice()), None, &mut nm_conn)
+        .unwrap();
+
+    let nm_ipv4_routes = &nm_conn.ipv4.as_ref().unwrap().routes;
+    assert_eq!(nm_ipv4_routes.len(), 1);
+    assert_eq!(nm_ipv4_routes[0].dest.as_deref(), Some("198.51.100.0"));
+    assert_eq!(nm_ipv4_routes[0].metric, Some(100));
+}
+
+#[test]
+fn test_onlink_route() {
+    let iface = gen_iface(true, true);
+    let mut route = gen_route("198.51.100.0/24");
+    route.next_hop_addr = Some("203.0.113.1".to_string());
+    route.onlink = Some(true);
+    let routes = vec![route];
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(&iface, Some(routes.as_slice()), None, &mut nm_conn)
+        .unwrap();
+
+    let nm_ipv4_routes = &nm_conn.ipv4.as_ref().unwrap().routes;
+    assert_eq!(nm_ipv4_routes.len(), 1);
+    assert_eq!(nm_ipv4_routes[0].next_hop.as_deref(), Some("203.0.113.1"));
+    assert_eq!(nm_ipv4_routes[0].onlink, Some(true));
+    assert_eq!(
+        nm_ipv4_routes[0]
+            .to_keyfile()
+            .get("options")
+            .map(String::as_str),
+        Some("table=254,onlink=true")
+    );
+}
+
+#[test]
+fn test_source_route() {
+    let iface = gen_iface(true, true);
+    let mut route = gen_route("198.51.100.0/24");
+    route.next_hop_addr = Some("192.0.2.1".to_string());
+    route.source = Some("192.0.2.251".to_string());
+    let routes = vec![route];
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(&iface, Some(routes.as_slice()), None, &mut nm_conn)
+        .unwrap();
+
+    let nm_ipv4_routes = &nm_conn.ipv4.as_ref().unwrap().routes;
+    assert_eq!(nm_ipv4_routes.len(), 1);
+    assert_eq!(nm_ipv4_routes[0].src.as_deref(), Some("192.0.2.251"));
+    assert_eq!(
+        nm_ipv4_routes[0]
+            .to_keyfile()
+            .get("options")
+            .map(String::as_str),
+        Some("table=254,src=192.0.2.251")
+    );
+}
+
+#[test]
+fn test_ipv6_ra_timeout() {
+    let iface: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv6:
+          enabled: true
+          autoconf: true
+          dhcp: true
+          ra-timeout: 30
+          accept-ra-mtu: false",
+    )
+    .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(&iface, None, None, &mut nm_conn).unwrap();
+
+    assert_eq!(nm_conn.ipv6.as_ref().unwrap().ra_timeout, Some(30));
+}
+
+#[test]
+fn test_ipv4_dhcp_vendor_class_identifier() {
+    let iface: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv4:
+          enabled: true
+          dhcp: true
+          dhcp-vendor-class-identifier: PXEClient",
+    )
+    .unwrap();
+
+    let mut nm_conn = NmConnection::default();
+    gen_nm_ip_setting(&iface, None, None, &mut nm_conn).unwrap();
+
+    assert_eq!(
+        nm_conn
+            .ipv4
+            .as_ref()
+            .unwrap()
+            .dhcp_vendor_class_identifier
+            .as_deref(),
+        Some("PXEClient")
+    );
+}
+
+#[test]
+fn test_dummy_with_multiple_anycast_addresses() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: dummy0
+          type: dummy
+          state: up
+          ipv4:
+            enabled: true
+            address:
+            - ip: 192.0.2.1
+              prefix-length: 32
+            - ip: 192.0.2.2
+              prefix-length: 32
+            - ip: 192.0.2.3
+              prefix-length: 32",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(nm_conns.len(), 1);
+    let nm_ipv4_set = nm_conns[0].ipv4.as_ref().unwrap();
+    assert_eq!(
+        nm_ipv4_set.addresses,
+        vec![
+            "192.0.2.1/32".to_string(),
+            "192.0.2.2/32".to_string(),
+            "192.0.2.3/32".to_string()
+        ]
+    );
+    assert_eq!(nm_ipv4_set.gateway, None);
+    assert!(nm_ipv4_set.routes.is_empty());
+}
+
+fn gen_route_via(destination: &str, next_hop: &str) -> RouteEntry {
+    let mut route = gen_route(destination);
+    route.next_hop_addr = Some(next_hop.to_string());
+    route
+}
+
+#[test]
+fn test_keep_unmanaged_routes() {
+    let iface = gen_iface(true, true);
+    let mut nm_conn = NmConnection::default();
+    let exist_routes = vec![
+        gen_route_via("198.51.100.0/24", "192.0.2.1"),
+        gen_route_via("203.0.113.0/24", "192.0.2.1"),
+    ];
+    gen_nm_ip_setting(
+        &iface,
+        Some(exist_routes.as_slice()),
+        None,
+        &mut nm_conn,
+    )
+    .unwrap();
+
+    let routes = vec![
+        gen_route_via("198.51.100.0/24", "192.0.2.254"),
+        gen_route_via("192.0.2.128/25", "192.0.2.254"),
+    ];
+    gen_nm_ip_setting(
+        &iface,
+        Some(routes.as_slice()),
+        Some(Vec::new().as_slice()),
+        &mut nm_conn,
+    )
+    .unwrap();
+
+    let nm_ipv4_routes = &nm_conn.ipv4.as_ref().unwrap().routes;
+    assert_eq!(nm_ipv4_routes.len(), 3);
+    // Route defined by nmstate wins over existing one with same destination
+    assert_eq!(nm_ipv4_routes[0].dest.as_deref(), Some("198.51.100.0"));
+    assert_eq!(nm_ipv4_routes[0].next_hop.as_deref(), Some("192.0.2.254"));
+    assert_eq!(nm_ipv4_routes[1].dest.as_deref(), Some("192.0.2.128"));
+    assert_eq!(nm_ipv4_routes[2].dest.as_deref(), Some("203.0.113.0"));
+    assert_eq!(nm_ipv4_routes[2].next_hop.as_deref(), Some("192.0.2.1"));
+}
+
+#[test]
+fn test_keep_unmanaged_routes_with_absent_route() {
+    let iface = gen_iface(true, true);
+    let mut nm_conn = NmConnection::default();
+    let exist_routes = vec![
+        gen_route_via("198.51.100.0/24", "192.0.2.1"),
+        gen_route_via("203.0.113.0/24", "192.0.2.1"),
+    ];
+    gen_nm_ip_setting(
+        &iface,
+        Some(exist_routes.as_slice()),
+        None,
+        &mut nm_conn,
+    )
+    .unwrap();
+
+    let mut absent_route = gen_route("203.0.113.0/24");
+    absent_route.state = Some(RouteState::Absent);
+    let absent_routes = vec![absent_route];
+    gen_nm_ip_setting(
+        &iface,
+        Some(Vec::new().as_slice()),
+        Some(absent_routes.as_slice()),
+        &mut nm_conn,
+    )
+    .unwrap();
+
+    let nm_ipv4_routes = &nm_conn.ipv4.as_ref().unwrap().routes;
+    assert_eq!(nm_ipv4_routes.len(), 1);
+    assert_eq!(nm_ipv4_routes[0].dest.as_deref(), Some("198.51.100.0"));
+}
+
+#[test]
+fn test_local_route_type() {
+    let iface = gen_iface(true, true);
+    let mut route = gen_route("198.51.100.1/32");
+    route.route_type = Some(RouteType::Local);
+    let routes = vec![route];
+    let mut nm_conn = NmConnection::default();
+
+    gen_nm_ip_setting(&iface, Some(routes.as_slice()), None, &mut nm_conn)
+        .unwrap();
+
+    let nm_routes = nm_conn.ipv4.as_ref().unwrap().routes.as_slice();
+    assert_eq!(nm_routes.len(), 1);
+    assert_eq!(nm_routes[0].route_type.as_deref(), Some("local"));
+}
+
+#[test]
+fn test_multicast_route_type_not_supported() {
+    let iface = gen_iface(true, true);
+    let mut route = gen_route("224.0.0.0/24");
+    route.route_type = Some(RouteType::Multicast);
+    let routes = vec![route];
+
+    let result = gen_nm_ip_setting(
+        &iface,
+        Some(routes.as_slice()),
+        None,
+        &mut NmConnection::default(),
+    );
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
+
+#[test]
+fn test_route_scope() {
+    let iface = gen_iface(true, true);
+    let mut route = gen_route("198.51.100.0/24");
+    route.next_hop_addr = Some("192.0.2.1".to_string());
+    route.scope = Some(RouteScope::Link);
+    let routes = vec![route];
+    let mut nm_conn = NmConnection::default();
+
+    gen_nm_ip_setting(&iface, Some(routes.as_slice()), None, &mut nm_conn)
+        .unwrap();
+
+    let nm_routes = nm_conn.ipv4.as_ref().unwrap().routes.as_slice();
+    assert_eq!(nm_routes.len(), 1);
+    assert_eq!(nm_routes[0].scope, Some(253));
+    assert_eq!(
+        nm_routes[0].to_keyfile().get("options").map(String::as_str),
+        Some("table=254,scope=253")
+    );
+}
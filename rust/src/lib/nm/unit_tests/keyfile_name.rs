@@ -0,0 +1,67 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::keyfile_name::{keyfile_name, keyfile_names};
+use crate::nm::nm_dbus::{NmConnection, NmSettingConnection};
+
+fn new_nm_conn(id: &str, uuid: &str) -> NmConnection {
+    let mut nm_conn = NmConnection::default();
+    let mut nm_conn_set = NmSettingConnection::default();
+    nm_conn_set.id = Some(id.to_string());
+    nm_conn_set.uuid = Some(uuid.to_string());
+    nm_conn.connection = Some(nm_conn_set);
+    nm_conn
+}
+
+#[test]
+fn test_keyfile_name_with_slash() {
+    let nm_conn =
+        new_nm_conn("lab/uplink", "3f1c2a9e-0b7d-4e55-9a1c-6d2e8f4b7c10");
+
+    assert_eq!(keyfile_name(&nm_conn), "lab_uplink.nmconnection");
+}
+
+#[test]
+fn test_keyfile_name_with_space() {
+    let nm_conn = new_nm_conn(
+        "Wired connection 1",
+        "3f1c2a9e-0b7d-4e55-9a1c-6d2e8f4b7c10",
+    );
+
+    assert_eq!(keyfile_name(&nm_conn), "Wired connection 1.nmconnection");
+}
+
+#[test]
+fn test_keyfile_name_leading_dot() {
+    let nm_conn =
+        new_nm_conn(".hidden", "3f1c2a9e-0b7d-4e55-9a1c-6d2e8f4b7c10");
+
+    assert_eq!(keyfile_name(&nm_conn), "_hidden.nmconnection");
+}
+
+#[test]
+fn test_keyfile_name_too_long() {
+    let nm_conn =
+        new_nm_conn(&"a".repeat(300), "3f1c2a9e-0b7d-4e55-9a1c-6d2e8f4b7c10");
+
+    let name = keyfile_name(&nm_conn);
+    assert_eq!(name.len(), 255);
+    assert!(name.ends_with(".nmconnection"));
+}
+
+#[test]
+fn test_keyfile_names_dedup_collision() {
+    let nm_conns = vec![
+        new_nm_conn("lab/uplink", "3f1c2a9e-0b7d-4e55-9a1c-6d2e8f4b7c10"),
+        new_nm_conn("lab_uplink", "b7e4d2c1-5a3f-4c8e-9d0b-1e2f3a4b5c6d"),
+        new_nm_conn("eth1", "0c9d8e7f-6a5b-4c3d-2e1f-0a9b8c7d6e5f"),
+    ];
+
+    assert_eq!(
+        keyfile_names(&nm_conns),
+        vec![
+            "lab_uplink.nmconnection".to_string(),
+            "lab_uplink-b7e4d2c1.nmconnection".to_string(),
+            "eth1.nmconnection".to_string(),
+        ]
+    );
+}
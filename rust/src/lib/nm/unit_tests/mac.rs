@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::profile::perpare_nm_conns;
+use crate::nm::settings::canonicalize_mac_address;
+use crate::{ErrorKind, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_canonicalize_mac_address_with_dash() {
+    assert_eq!(
+        canonicalize_mac_address("AA-BB-CC-DD-EE-FF").unwrap(),
+        "aa:bb:cc:dd:ee:ff".to_string()
+    );
+}
+
+#[test]
+fn test_canonicalize_mac_address_eui64() {
+    assert_eq!(
+        canonicalize_mac_address("02:00:5E:10:00:00:00:01").unwrap(),
+        "02:00:5e:10:00:00:00:01".to_string()
+    );
+}
+
+#[test]
+fn test_canonicalize_mac_address_invalid() {
+    for mac in [
+        "AA:BB:CC:DD:EE",
+        "AA:BB:CC:DD:EE:FF:00",
+        "AA:BB:CC:DD:EE:FG",
+        "AABB:CC:DD:EE:FF",
+    ] {
+        let result = canonicalize_mac_address(mac);
+        assert!(result.is_err());
+        if let Err(e) = result {
+            assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        }
+    }
+}
+
+#[test]
+fn test_wired_and_sriov_vf_mac_normalized() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mac-address: AA-BB-CC-DD-EE-FF
+  ethernet:
+    sr-iov:
+      total-vfs: 1
+      vfs:
+      - id: 0
+        mac-address: 00-11-22-33-44-AA
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    assert_eq!(
+        nm_conns[0]
+            .wired
+            .as_ref()
+            .and_then(|w| w.cloned_mac_address.as_deref()),
+        Some("aa:bb:cc:dd:ee:ff")
+    );
+    assert_eq!(
+        nm_conns[0]
+            .sriov
+            .as_ref()
+            .and_then(|s| s.vfs.as_ref())
+            .and_then(|vfs| vfs[0].mac.as_deref()),
+        Some("00:11:22:33:44:aa")
+    );
+}
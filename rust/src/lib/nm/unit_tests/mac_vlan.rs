@@ -0,0 +1,95 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::profile::perpare_nm_conns;
+use crate::{MergedNetworkState, NetworkState};
+
+#[test]
+fn test_mac_vlan_over_bond() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: bond0
+  type: bond
+  state: up
+  link-aggregation:
+    mode: active-backup
+    port:
+    - eth1
+    - eth2
+- name: mac0
+  type: mac-vlan
+  state: up
+  mac-vlan:
+    base-iface: bond0
+    mode: bridge
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let bond_nm_conn = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("bond0"))
+        .unwrap();
+    let nm_conn = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("mac0"))
+        .unwrap();
+    let nm_mac_vlan_set = nm_conn.mac_vlan.as_ref().unwrap();
+    // The bond parent should be referred by the UUID of its profile
+    assert_eq!(nm_mac_vlan_set.parent.as_deref(), bond_nm_conn.uuid());
+    assert_eq!(nm_mac_vlan_set.tap, Some(false));
+}
+
+#[test]
+fn test_mac_vtap_multi_queue() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: mac0
+  type: mac-vtap
+  state: up
+  mac-vtap:
+    base-iface: eth1
+    mode: passthru
+    vnet-hdr: true
+    multi-queue: true
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_conn = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("mac0"))
+        .unwrap();
+    assert_eq!(nm_conn.mac_vlan.as_ref().unwrap().tap, Some(true));
+    let user_data = nm_conn.user.as_ref().unwrap().data.as_ref().unwrap();
+    assert_eq!(
+        user_data
+            .get("nmstate.mac-vtap.vnet-hdr")
+            .map(String::as_str),
+        Some("true")
+    );
+    assert_eq!(
+        user_data
+            .get("nmstate.mac-vtap.multi-queue")
+            .map(String::as_str),
+        Some("true")
+    );
+}
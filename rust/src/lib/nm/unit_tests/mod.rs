@@ -1,2 +1,36 @@
 #[cfg(test)]
+mod bond;
+#[cfg(test)]
+mod compat;
+#[cfg(test)]
+mod connection;
+#[cfg(all(test, feature = "query_apply"))]
+mod delta;
+#[cfg(all(test, feature = "query_apply"))]
+mod dispatch;
+#[cfg(test)]
+mod ethtool;
+#[cfg(all(test, feature = "gen_conf"))]
+mod gen_conf;
+#[cfg(test)]
+mod ieee8021x;
+#[cfg(test)]
+mod infiniband;
+#[cfg(test)]
+mod ip;
+#[cfg(all(test, feature = "gen_conf"))]
+mod keyfile_name;
+#[cfg(test)]
+mod mac;
+#[cfg(test)]
+mod mac_vlan;
+#[cfg(test)]
 mod profiles;
+#[cfg(test)]
+mod proxy;
+#[cfg(test)]
+mod sriov;
+#[cfg(test)]
+mod team;
+#[cfg(test)]
+mod wireguard;
@@ -1,10 +1,15 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::nm::nm_dbus::{NmConnection, NmSettingConnection};
+use crate::nm::profile::{
+    group_nm_conns_into_waves, perpare_nm_conns,
+    sort_ovs_nm_conns_for_activation,
+};
 use crate::{
     nm::settings::use_uuid_for_controller_reference, Interface, InterfaceType,
-    Interfaces, MergedInterfaces, OvsBridgeBondConfig, OvsBridgeBondPortConfig,
-    OvsBridgeConfig, OvsBridgeInterface, OvsBridgePortConfig,
+    Interfaces, MergedInterfaces, MergedNetworkState, NetworkState,
+    OvsBridgeBondConfig, OvsBridgeBondPortConfig, OvsBridgeConfig,
+    OvsBridgeInterface, OvsBridgePortConfig,
 };
 
 const UUID1: &str = "8aca0200-accc-4d13-a62f-3c89a6da53c5";
@@ -79,8 +84,14 @@ fn test_use_uuid_for_controller_reference_with_ovs_bond() {
     let merged_ifaces =
         MergedInterfaces::new(ifaces, Interfaces::new(), false, false).unwrap();
 
-    use_uuid_for_controller_reference(&mut nm_conns, &merged_ifaces, &[], &[])
-        .unwrap();
+    use_uuid_for_controller_reference(
+        &mut nm_conns,
+        &merged_ifaces,
+        &[],
+        &[],
+        false,
+    )
+    .unwrap();
 
     println!("nm_conns {nm_conns:?}");
 
@@ -113,3 +124,357 @@ fn test_use_uuid_for_controller_reference_with_ovs_bond() {
     assert!(p2_nm_con_set.controller == Some(UUID2.to_string()));
     assert!(p2_nm_con_set.controller_type == Some("ovs-port".to_string()));
 }
+
+#[test]
+fn test_group_nm_conns_into_waves_vlan_on_bond_on_bridge() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: bond0.10
+  type: vlan
+  state: up
+  vlan:
+    base-iface: bond0
+    id: 10
+- name: eth1
+  type: ethernet
+  state: up
+- name: bond0
+  type: bond
+  state: up
+  link-aggregation:
+    mode: active-backup
+    port:
+    - eth1
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    port:
+    - name: bond0
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let waves = group_nm_conns_into_waves(&nm_conns).unwrap();
+
+    let wave_ids: Vec<Vec<&str>> = waves
+        .iter()
+        .map(|wave| wave.iter().filter_map(|c| c.id()).collect())
+        .collect();
+    assert_eq!(
+        wave_ids,
+        vec![vec!["br0"], vec!["bond0"], vec!["bond0.10", "eth1"]]
+    );
+}
+
+#[test]
+fn test_group_nm_conns_into_waves_circular_reference() {
+    let mut nm_conns: Vec<NmConnection> = Vec::new();
+    for (uuid, ctrl) in [(UUID1, UUID2), (UUID2, UUID1)] {
+        let mut nm_conn = NmConnection::default();
+        let mut nm_conn_set = NmSettingConnection::default();
+        nm_conn_set.uuid = Some(uuid.to_string());
+        nm_conn_set.iface_type = Some("bond".to_string());
+        nm_conn_set.controller = Some(ctrl.to_string());
+        nm_conn_set.controller_type = Some("bond".to_string());
+        nm_conn.connection = Some(nm_conn_set);
+        nm_conns.push(nm_conn);
+    }
+
+    assert!(group_nm_conns_into_waves(&nm_conns).is_err());
+}
+
+#[test]
+fn test_activation_order_honor_dependency() {
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: dummy0
+  type: dummy
+  state: up
+- name: dummy1
+  type: dummy
+  state: up
+- name: dummy0.10
+  type: vlan
+  state: up
+  vlan:
+    base-iface: dummy0
+    id: 10
+- name: bond0
+  type: bond
+  state: up
+  link-aggregation:
+    mode: active-backup
+",
+    )
+    .unwrap();
+    desired.set_activation_order(&["dummy0.10", "dummy1", "bond0"]);
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], false)
+        .unwrap()
+        .to_activate;
+
+    let iface_names: Vec<&str> =
+        nm_conns.iter().filter_map(|c| c.iface_name()).collect();
+    assert_eq!(iface_names, vec!["dummy1", "bond0", "dummy0", "dummy0.10"]);
+}
+
+#[cfg(feature = "query_apply")]
+#[test]
+fn test_activation_order_honored_by_activation_plan() {
+    use crate::nm::query_apply::profile::{
+        gen_nm_conn_activation_plan, NmActivationAction,
+    };
+
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: dummy1
+  type: dummy
+  state: up
+- name: dummy2
+  type: dummy
+  state: up
+- name: dummy3
+  type: dummy
+  state: up
+- name: bond0
+  type: bond
+  state: up
+  link-aggregation:
+    mode: active-backup
+    port:
+    - dummy2
+    - dummy3
+",
+    )
+    .unwrap();
+    desired.set_activation_order(&["dummy3", "dummy1", "bond0"]);
+    // Use existing bond, so its new ports are not activated by the bond
+    // automatically and are kept in `to_activate`.
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: bond0
+  type: bond
+  state: up
+  link-aggregation:
+    mode: active-backup
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], false)
+        .unwrap()
+        .to_activate;
+    let bond_uuid = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("bond0"))
+        .and_then(|c| c.uuid())
+        .unwrap();
+
+    let plan = gen_nm_conn_activation_plan(&nm_conns, &[bond_uuid]);
+
+    let iface_names: Vec<(&str, NmActivationAction)> = plan
+        .iter()
+        .filter_map(|(c, action)| c.iface_name().map(|n| (n, *action)))
+        .collect();
+    assert_eq!(
+        iface_names,
+        vec![
+            ("dummy1", NmActivationAction::Activate),
+            ("bond0", NmActivationAction::ReapplyOrActivate),
+            ("dummy3", NmActivationAction::Activate),
+            ("dummy2", NmActivationAction::Activate),
+        ]
+    );
+}
+
+#[test]
+fn test_ovs_activation_order() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: ovs0
+  type: ovs-interface
+  state: up
+- name: eth1
+  type: ethernet
+  state: up
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    port:
+    - name: ovs0
+    - name: eth1
+",
+    )
+    .unwrap();
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], false)
+        .unwrap()
+        .to_activate;
+
+    let ids: Vec<&str> = nm_conns.iter().filter_map(|c| c.id()).collect();
+
+    assert_eq!(
+        ids,
+        vec!["br0-br", "eth1-port", "ovs0-port", "eth1", "ovs0-if"]
+    );
+}
+
+#[test]
+fn test_sort_ovs_nm_conns_for_activation() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: dummy0
+  type: dummy
+  state: up
+- name: ovs0
+  type: ovs-interface
+  state: up
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    port:
+    - name: ovs0
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let mut nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+    nm_conns.sort_unstable_by_key(|c| {
+        ["ovs0-if", "dummy0", "ovs0-port", "br0-br"]
+            .iter()
+            .position(|id| c.id() == Some(id))
+    });
+
+    sort_ovs_nm_conns_for_activation(&mut nm_conns);
+
+    let ids: Vec<&str> = nm_conns.iter().filter_map(|c| c.id()).collect();
+    assert_eq!(ids, vec!["br0-br", "dummy0", "ovs0-port", "ovs0-if"]);
+}
+
+#[test]
+fn test_purge_unmanaged_removes_previously_owned_vlan() {
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: eth1.101
+  type: vlan
+  state: up
+  vlan:
+    base-iface: eth1
+    id: 101
+",
+    )
+    .unwrap();
+    desired.set_purge_unmanaged(true);
+    let current = desired.clone();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let mut exist_nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    // Profile not owned by nmstate should never be purged
+    let mut nm_conn = NmConnection::default();
+    let mut nm_conn_set = NmSettingConnection::default();
+    nm_conn_set.id = Some("dummy0".to_string());
+    nm_conn_set.uuid = Some(UUID1.to_string());
+    nm_conn_set.iface_type = Some("dummy".to_string());
+    nm_conn_set.iface_name = Some("dummy0".to_string());
+    nm_conn.connection = Some(nm_conn_set);
+    exist_nm_conns.push(nm_conn);
+
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  mtu: 1400
+",
+    )
+    .unwrap();
+    desired.set_purge_unmanaged(true);
+    let merged_state =
+        MergedNetworkState::new(desired, current, false, false).unwrap();
+
+    let nm_conns_to_delete =
+        perpare_nm_conns(&merged_state, &exist_nm_conns, &[], false)
+            .unwrap()
+            .to_delete;
+
+    assert_eq!(nm_conns_to_delete.len(), 1);
+    assert_eq!(nm_conns_to_delete[0].iface_name(), Some("eth1.101"));
+}
+
+#[test]
+fn test_maintenance_store_without_activation() {
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: eth1.101
+  type: vlan
+  state: up
+  vlan:
+    base-iface: eth1
+    id: 101
+",
+    )
+    .unwrap();
+    desired.set_maintenance(true);
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true).unwrap();
+
+    assert_eq!(nm_conns.to_store.len(), 2);
+    assert!(nm_conns.to_activate.is_empty());
+    for nm_conn in nm_conns.to_store.iter() {
+        assert_eq!(
+            nm_conn.connection.as_ref().and_then(|c| c.autoconnect),
+            Some(false)
+        );
+    }
+}
@@ -0,0 +1,76 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::nm_dbus::NM_SETTING_PROXY_METHOD_AUTO;
+use crate::nm::profile::perpare_nm_conns;
+use crate::{ErrorKind, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_proxy_pac_url() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  proxy:
+    method: auto
+    pac-url: http://proxy.example.org/wpad.dat
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_proxy_set = nm_conns[0].proxy.as_ref().unwrap();
+    assert_eq!(nm_proxy_set.method, Some(NM_SETTING_PROXY_METHOD_AUTO));
+    assert_eq!(
+        nm_proxy_set.pac_url.as_deref(),
+        Some("http://proxy.example.org/wpad.dat")
+    );
+    assert_eq!(nm_proxy_set.pac_script, None);
+}
+
+#[test]
+fn test_proxy_pac_url_without_auto_method() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  proxy:
+    method: none
+    pac-url: http://proxy.example.org/wpad.dat
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_proxy_invalid_method() {
+    let result = serde_yaml::from_str::<NetworkState>(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  proxy:
+    method: manual
+",
+    );
+
+    assert!(result.is_err());
+}
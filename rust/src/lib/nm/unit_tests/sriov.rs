@@ -0,0 +1,103 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::nm_dbus::{
+    NM_SRIOV_ESWITCH_ENCAP_MODE_BASIC, NM_SRIOV_ESWITCH_MODE_SWITCHDEV,
+};
+use crate::nm::profile::perpare_nm_conns;
+use crate::nm::NmVersion;
+use crate::{ErrorKind, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_sriov_eswitch_switchdev_mode() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ethernet:
+    sr-iov:
+      total-vfs: 2
+      eswitch-mode: switchdev
+      eswitch-encap-mode: basic
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_sriov_set = nm_conns[0].sriov.as_ref().unwrap();
+    assert_eq!(nm_sriov_set.total_vfs, Some(2));
+    assert_eq!(
+        nm_sriov_set.eswitch_mode,
+        Some(NM_SRIOV_ESWITCH_MODE_SWITCHDEV)
+    );
+    assert_eq!(
+        nm_sriov_set.eswitch_encap_mode,
+        Some(NM_SRIOV_ESWITCH_ENCAP_MODE_BASIC)
+    );
+}
+
+#[test]
+fn test_sriov_eswitch_mode_on_old_nm() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ethernet:
+    sr-iov:
+      total-vfs: 2
+      eswitch-mode: switchdev
+",
+    )
+    .unwrap();
+    let mut merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    merged_state.nm_version = Some(NmVersion::new(1, 44, 0));
+
+    let result = perpare_nm_conns(&merged_state, &[], &[], true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotImplementedError);
+    }
+}
+
+#[test]
+fn test_sriov_vf_max_tx_rate() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+  ethernet:
+    sr-iov:
+      total-vfs: 4
+      vfs:
+      - id: 2
+        max-tx-rate: 1000
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_vfs = nm_conns[0].sriov.as_ref().unwrap().vfs.as_ref().unwrap();
+    let nm_vf = nm_vfs.iter().find(|v| v.index == Some(2)).unwrap();
+    assert_eq!(nm_vf.max_tx_rate, Some(1000));
+    assert_eq!(nm_vf.min_tx_rate, None);
+}
@@ -0,0 +1,205 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::profile::perpare_nm_conns;
+use crate::{ErrorKind, MergedNetworkState, NetworkState};
+
+#[test]
+fn test_gen_team_activebackup_with_two_ports() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: eth2
+  type: ethernet
+  state: up
+- name: team0
+  type: team
+  state: up
+  team:
+    runner: activebackup
+    port:
+    - name: eth1
+    - name: eth2
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let team_conn = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("team0"))
+        .unwrap();
+    assert_eq!(team_conn.iface_type(), Some("team"));
+    let nm_team_set = team_conn.team.as_ref().unwrap();
+    assert_eq!(nm_team_set.runner.as_deref(), Some("activebackup"));
+    assert_eq!(
+        nm_team_set.config.as_deref(),
+        Some(r#"{"runner":{"name":"activebackup"}}"#)
+    );
+
+    for port_name in ["eth1", "eth2"] {
+        let port_conn = nm_conns
+            .iter()
+            .find(|c| c.iface_name() == Some(port_name))
+            .unwrap();
+        assert_eq!(port_conn.controller_type(), Some("team"));
+        assert!(port_conn.team_port.is_some());
+    }
+}
+
+#[test]
+fn test_team_invalid_json_config() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r#"---
+interfaces:
+- name: team0
+  type: team
+  state: up
+  team:
+    config: '{"runner": '
+"#,
+    )
+    .unwrap();
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_gen_team_port_prio_and_sticky() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: team0
+  type: team
+  state: up
+  team:
+    runner: activebackup
+    port:
+    - name: eth1
+      prio: 100
+      sticky: true
+",
+    )
+    .unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let port_conn = nm_conns
+        .iter()
+        .find(|c| c.iface_name() == Some("eth1"))
+        .unwrap();
+    let nm_team_port_set = port_conn.team_port.as_ref().unwrap();
+    assert_eq!(nm_team_port_set.prio, Some(100));
+    assert_eq!(nm_team_port_set.sticky, Some(true));
+    assert_eq!(nm_team_port_set.lacp_prio, None);
+}
+
+#[test]
+fn test_team_port_lacp_prio_without_lacp_runner() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: team0
+  type: team
+  state: up
+  team:
+    runner: activebackup
+    port:
+    - name: eth1
+      lacp-prio: 10
+",
+    )
+    .unwrap();
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_team_port_lacp_prio_with_current_lacp_runner() {
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: team0
+  type: team
+  state: up
+  team:
+    runner: lacp
+",
+    )
+    .unwrap();
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: team0
+  type: team
+  state: up
+  team:
+    port:
+    - name: eth1
+      lacp-prio: 10
+",
+    )
+    .unwrap();
+    MergedNetworkState::new(desired, current, false, false).unwrap();
+}
+
+#[test]
+fn test_team_port_lacp_prio_with_current_activebackup_runner() {
+    let current: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: team0
+  type: team
+  state: up
+  team:
+    runner: activebackup
+",
+    )
+    .unwrap();
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: team0
+  type: team
+  state: up
+  team:
+    port:
+    - name: eth1
+      lacp-prio: 10
+",
+    )
+    .unwrap();
+    let result = MergedNetworkState::new(desired, current, false, false);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
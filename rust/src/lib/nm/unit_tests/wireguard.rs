@@ -0,0 +1,266 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::nm::nm_dbus::{
+    NmConnection, NmSettingConnection, NmSettingWireGuard,
+    NmSettingWireGuardPeer,
+};
+use crate::nm::profile::perpare_nm_conns;
+use crate::{ErrorKind, MergedNetworkState, NetworkState};
+
+const PEER1_PUBLIC_KEY: &str = "8bdQrVLqiw3ZoHCucNh1YfH0iCWuyStniRr8t7H24Fk=";
+const PEER2_PUBLIC_KEY: &str = "fJ2SdZJsT3JEpwAiTVrPBjb8ueCm8TXDm3UcvkR7xmw=";
+
+fn gen_wg_merged_state(
+    yml: &str,
+) -> Result<MergedNetworkState, crate::NmstateError> {
+    let desired: NetworkState = serde_yaml::from_str(yml).unwrap();
+    MergedNetworkState::new(desired, NetworkState::new(), true, false)
+}
+
+#[test]
+fn test_wireguard_peers() {
+    let merged_state = gen_wg_merged_state(&format!(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    private-key: yAnz5TF+lXXJte14tji3zlMNq+hd2rYUIgJBgB3fBmk=
+    listen-port: 51820
+    peers:
+    - public-key: {PEER1_PUBLIC_KEY}
+      preshared-key: FpCyhws9cxwWoV4xELtfJvjJN+zQVRPISllRWgeopVE=
+      endpoint: vpn.example.org:51820
+      allowed-ips:
+      - 192.0.2.1/24
+      persistent-keepalive: 25
+    - public-key: {PEER2_PUBLIC_KEY}
+      endpoint: '[2001:db8::1]:51820'
+      allowed-ips:
+      - 2001:db8:1::/64
+"
+    ))
+    .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_wg_set = nm_conns[0].wireguard.as_ref().unwrap();
+    assert_eq!(
+        nm_wg_set.private_key.as_deref(),
+        Some("yAnz5TF+lXXJte14tji3zlMNq+hd2rYUIgJBgB3fBmk=")
+    );
+    assert_eq!(nm_wg_set.listen_port, Some(51820));
+    let nm_peers = nm_wg_set.peers.as_ref().unwrap();
+    assert_eq!(nm_peers.len(), 2);
+    assert_eq!(nm_peers[0].public_key.as_deref(), Some(PEER1_PUBLIC_KEY));
+    assert_eq!(
+        nm_peers[0].preshared_key.as_deref(),
+        Some("FpCyhws9cxwWoV4xELtfJvjJN+zQVRPISllRWgeopVE=")
+    );
+    // Hostname is left to NetworkManager to resolve by default
+    assert_eq!(
+        nm_peers[0].endpoint.as_deref(),
+        Some("vpn.example.org:51820")
+    );
+    assert_eq!(
+        nm_peers[0].allowed_ips,
+        Some(vec!["192.0.2.0/24".to_string()])
+    );
+    assert_eq!(nm_peers[0].persistent_keepalive, Some(25));
+    assert_eq!(nm_peers[1].public_key.as_deref(), Some(PEER2_PUBLIC_KEY));
+    assert_eq!(nm_peers[1].preshared_key, None);
+    assert_eq!(nm_peers[1].endpoint.as_deref(), Some("[2001:db8::1]:51820"));
+}
+
+#[test]
+fn test_wireguard_endpoint_resolved_by_nmstate() {
+    let merged_state = gen_wg_merged_state(&format!(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    endpoint-resolver: nmstate
+    peers:
+    - public-key: {PEER1_PUBLIC_KEY}
+      endpoint: localhost:51820
+"
+    ))
+    .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_peers = nm_conns[0]
+        .wireguard
+        .as_ref()
+        .and_then(|s| s.peers.as_ref())
+        .unwrap();
+    let endpoint: std::net::SocketAddr =
+        nm_peers[0].endpoint.as_deref().unwrap().parse().unwrap();
+    assert!(endpoint.ip().is_loopback());
+    assert_eq!(endpoint.port(), 51820);
+}
+
+#[test]
+fn test_wireguard_duplicate_peer_public_key() {
+    let result = gen_wg_merged_state(&format!(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    peers:
+    - public-key: {PEER1_PUBLIC_KEY}
+    - public-key: {PEER1_PUBLIC_KEY}
+"
+    ));
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_wireguard_invalid_allowed_ips() {
+    let result = gen_wg_merged_state(&format!(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    peers:
+    - public-key: {PEER1_PUBLIC_KEY}
+      allowed-ips:
+      - 192.0.2.0/33
+"
+    ));
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_wireguard_invalid_endpoint() {
+    let result = gen_wg_merged_state(&format!(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    peers:
+    - public-key: {PEER1_PUBLIC_KEY}
+      endpoint: 2001:db8::1
+"
+    ));
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_wireguard_private_key_agent_owned() {
+    let merged_state = gen_wg_merged_state(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    private-key: yAnz5TF+lXXJte14tji3zlMNq+hd2rYUIgJBgB3fBmk=
+    private-key-storage: agent-owned
+    listen-port: 51820
+",
+    )
+    .unwrap();
+
+    let nm_conns = perpare_nm_conns(&merged_state, &[], &[], true)
+        .unwrap()
+        .to_store;
+
+    let nm_wg_set = nm_conns[0].wireguard.as_ref().unwrap();
+    assert_eq!(nm_wg_set.private_key, None);
+    assert_eq!(nm_wg_set.private_key_flags, Some(1));
+}
+
+fn gen_nm_conn_with_exist_preshared_key(desired_yml: &str) -> NmConnection {
+    let desired: NetworkState = serde_yaml::from_str(desired_yml).unwrap();
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false)
+            .unwrap();
+
+    let mut exist_nm_conn = NmConnection::default();
+    let mut nm_conn_set = NmSettingConnection::default();
+    nm_conn_set.id = Some("wg0".to_string());
+    nm_conn_set.uuid = Some("0f6e1c2a-3b4d-4e5f-8a9b-1c2d3e4f5a6b".to_string());
+    nm_conn_set.iface_type = Some("wireguard".to_string());
+    nm_conn_set.iface_name = Some("wg0".to_string());
+    exist_nm_conn.connection = Some(nm_conn_set);
+    let mut nm_peer = NmSettingWireGuardPeer::default();
+    nm_peer.public_key = Some(PEER1_PUBLIC_KEY.to_string());
+    nm_peer.preshared_key =
+        Some("FpCyhws9cxwWoV4xELtfJvjJN+zQVRPISllRWgeopVE=".to_string());
+    let mut nm_wg_set = NmSettingWireGuard::default();
+    nm_wg_set.peers = Some(vec![nm_peer]);
+    exist_nm_conn.wireguard = Some(nm_wg_set);
+
+    perpare_nm_conns(&merged_state, &[exist_nm_conn], &[], false)
+        .unwrap()
+        .to_store
+        .remove(0)
+}
+
+#[test]
+fn test_wireguard_preserve_preshared_key() {
+    let nm_conn = gen_nm_conn_with_exist_preshared_key(&format!(
+        r"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    peers:
+    - public-key: {PEER1_PUBLIC_KEY}
+      persistent-keepalive: 30
+"
+    ));
+
+    let nm_peers = nm_conn.wireguard.as_ref().unwrap().peers.as_ref().unwrap();
+    assert_eq!(nm_peers[0].persistent_keepalive, Some(30));
+    assert_eq!(
+        nm_peers[0].preshared_key.as_deref(),
+        Some("FpCyhws9cxwWoV4xELtfJvjJN+zQVRPISllRWgeopVE=")
+    );
+}
+
+#[test]
+fn test_wireguard_remove_preshared_key() {
+    let nm_conn = gen_nm_conn_with_exist_preshared_key(&format!(
+        r#"---
+interfaces:
+- name: wg0
+  type: wireguard
+  state: up
+  wireguard:
+    peers:
+    - public-key: {PEER1_PUBLIC_KEY}
+      preshared-key: ""
+"#
+    ));
+
+    let nm_peers = nm_conn.wireguard.as_ref().unwrap().peers.as_ref().unwrap();
+    assert_eq!(nm_peers[0].preshared_key, None);
+}
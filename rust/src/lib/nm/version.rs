@@ -0,0 +1,80 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use std::str::FromStr;
+
+use crate::{ErrorKind, NmstateError};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Default)]
+/// Version of NetworkManager daemon, could be parsed from string like
+/// `1.46.0` via [std::str::FromStr].
+pub struct NmVersion {
+    pub(crate) major: u32,
+    pub(crate) minor: u32,
+    pub(crate) micro: u32,
+}
+
+impl NmVersion {
+    // NetworkManager 1.46 deprecated `connection.autoconnect-slaves` in favor
+    // of `connection.autoconnect-ports`.
+    pub(crate) const AUTOCONNECT_PORTS: Self = Self::new(1, 46, 0);
+    // NetworkManager 1.48 introduced `connection.ip-ping-addresses` and
+    // `connection.ip-ping-timeout`.
+    pub(crate) const IP_PING: Self = Self::new(1, 48, 0);
+    // NetworkManager 1.40 introduced `default-or-eui64` and `default` of
+    // `ipv6.addr-gen-mode`.
+    pub(crate) const ADDR_GEN_MODE_DEFAULT: Self = Self::new(1, 40, 0);
+    // NetworkManager 1.44 introduced `ipv4.replace-local-rule` and
+    // `ipv6.replace-local-rule`.
+    pub(crate) const REPLACE_LOCAL_RULE: Self = Self::new(1, 44, 0);
+    // NetworkManager 1.44 introduced `link` setting holding
+    // `link.gso-max-size`.
+    pub(crate) const LINK: Self = Self::new(1, 44, 0);
+    // NetworkManager 1.46 introduced `sriov.eswitch-mode` and
+    // `sriov.eswitch-encap-mode`.
+    pub(crate) const SRIOV_ESWITCH: Self = Self::new(1, 46, 0);
+    // NetworkManager 1.16 introduced WireGuard support.
+    pub(crate) const WIREGUARD: Self = Self::new(1, 16, 0);
+    // NetworkManager 1.42 introduced loopback support.
+    pub(crate) const LOOPBACK: Self = Self::new(1, 42, 0);
+
+    pub const fn new(major: u32, minor: u32, micro: u32) -> Self {
+        Self {
+            major,
+            minor,
+            micro,
+        }
+    }
+}
+
+impl FromStr for NmVersion {
+    type Err = NmstateError;
+
+    // The NetworkManager version string might contain suffix like
+    // `1.45.90-dev`, only leading digits of each part are used.
+    fn from_str(ver_str: &str) -> Result<Self, Self::Err> {
+        let mut nums = ver_str.split('.').map(|v| {
+            let digits: String =
+                v.chars().take_while(|c| c.is_ascii_digit()).collect();
+            digits.parse::<u32>().ok()
+        });
+        match (
+            nums.next().flatten(),
+            nums.next().flatten(),
+            nums.next().flatten(),
+        ) {
+            (Some(major), Some(minor), micro) => {
+                Ok(Self::new(major, minor, micro.unwrap_or_default()))
+            }
+            _ => Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!("Invalid NetworkManager version string: {ver_str}"),
+            )),
+        }
+    }
+}
+
+impl std::fmt::Display for NmVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.micro)
+    }
+}
@@ -1,15 +1,89 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{ovsdb::db::OvsDbConnection, MergedNetworkState, NmstateError};
+use crate::{
+    ovsdb::db::OvsDbConnection, Interface, MergedNetworkState, NmstateError,
+};
 
 pub(crate) fn ovsdb_apply(
     merged_state: &MergedNetworkState,
 ) -> Result<(), NmstateError> {
-    if merged_state.ovsdb.is_changed {
+    let br_controllers = get_ovs_bridge_controllers(merged_state);
+    let br_protocols = get_ovs_bridge_protocols(merged_state);
+    if merged_state.ovsdb.is_changed
+        || !br_controllers.is_empty()
+        || !br_protocols.is_empty()
+    {
         let mut cli = OvsDbConnection::new()?;
-        cli.apply_global_conf(&merged_state.ovsdb)
+        if merged_state.ovsdb.is_changed {
+            cli.apply_global_conf(&merged_state.ovsdb)?;
+        }
+        for (br_name, target) in br_controllers {
+            cli.apply_bridge_controller(br_name, target)?;
+        }
+        for (br_name, protocols) in br_protocols {
+            cli.apply_bridge_protocols(br_name, protocols)?;
+        }
+        Ok(())
     } else {
         log::debug!("No OVSDB changes");
         Ok(())
     }
 }
+
+pub(crate) fn ovsdb_need_apply(merged_state: &MergedNetworkState) -> bool {
+    merged_state.ovsdb.is_changed
+        || !get_ovs_bridge_controllers(merged_state).is_empty()
+        || !get_ovs_bridge_protocols(merged_state).is_empty()
+}
+
+// NetworkManager has no support of OpenFlow controller, hence we store it to
+// OVS database directly after NetworkManager created the OVS bridge.
+fn get_ovs_bridge_controllers(
+    merged_state: &MergedNetworkState,
+) -> Vec<(&str, &str)> {
+    let mut ret = Vec::new();
+    for merged_iface in merged_state.interfaces.user_ifaces.values() {
+        if let Some(Interface::OvsBridge(br_iface)) =
+            merged_iface.for_apply.as_ref()
+        {
+            if merged_iface.merged.is_absent() {
+                continue;
+            }
+            if let Some(target) = br_iface
+                .bridge
+                .as_ref()
+                .and_then(|br_conf| br_conf.options.as_ref())
+                .and_then(|opts| opts.controller.as_deref())
+            {
+                ret.push((br_iface.base.name.as_str(), target));
+            }
+        }
+    }
+    ret
+}
+
+// NetworkManager has no support of OpenFlow protocols, hence we store it to
+// OVS database directly after NetworkManager created the OVS bridge.
+fn get_ovs_bridge_protocols(
+    merged_state: &MergedNetworkState,
+) -> Vec<(&str, &[String])> {
+    let mut ret = Vec::new();
+    for merged_iface in merged_state.interfaces.user_ifaces.values() {
+        if let Some(Interface::OvsBridge(br_iface)) =
+            merged_iface.for_apply.as_ref()
+        {
+            if merged_iface.merged.is_absent() {
+                continue;
+            }
+            if let Some(protocols) = br_iface
+                .bridge
+                .as_ref()
+                .and_then(|br_conf| br_conf.options.as_ref())
+                .and_then(|opts| opts.protocols.as_deref())
+            {
+                ret.push((br_iface.base.name.as_str(), protocols));
+            }
+        }
+    }
+    ret
+}
@@ -12,6 +12,8 @@ use crate::{
 
 const OVS_DB_NAME: &str = "Open_vSwitch";
 pub(crate) const GLOBAL_CONFIG_TABLE: &str = "Open_vSwitch";
+const CONTROLLER_TABLE: &str = "Controller";
+const CONTROLLER_UUID_NAME: &str = "nmstate_controller";
 const NM_RESERVED_EXTERNAL_ID: &str = "NM.connection.uuid";
 
 const DEFAULT_OVS_DB_SOCKET_PATH: &str = "/run/openvswitch/db.sock";
@@ -205,6 +207,8 @@ impl OvsDbConnection {
                 "mcast_snooping_enable",
                 "fail_mode",
                 "datapath_type",
+                "controller",
+                "protocols",
             ],
         )
     }
@@ -259,6 +263,95 @@ impl OvsDbConnection {
             }
         }
     }
+    // Return HashMap of controller UUID to its target
+    pub(crate) fn get_ovs_controllers(
+        &mut self,
+    ) -> Result<HashMap<String, String>, NmstateError> {
+        let select = OvsDbSelect {
+            table: CONTROLLER_TABLE.to_string(),
+            conditions: vec![],
+            columns: Some(vec!["_uuid", "target"]),
+        };
+        let mut ret = HashMap::new();
+        match self.rpc.exec(
+            "transact",
+            &Value::Array(vec![
+                Value::String(OVS_DB_NAME.to_string()),
+                select.to_value(),
+            ]),
+        )? {
+            Value::Array(reply) => {
+                if let Some(entries) = reply
+                    .first()
+                    .and_then(|v| v.as_object())
+                    .and_then(|v| v.get("rows"))
+                    .and_then(|v| v.as_array())
+                {
+                    for entry in entries.iter().filter_map(|e| e.as_object()) {
+                        if let (
+                            Some(Value::Array(uuid)),
+                            Some(Value::String(target)),
+                        ) = (entry.get("_uuid"), entry.get("target"))
+                        {
+                            if let Some(Value::String(uuid)) = uuid.get(1) {
+                                ret.insert(
+                                    uuid.to_string(),
+                                    target.to_string(),
+                                );
+                            }
+                        }
+                    }
+                    Ok(ret)
+                } else {
+                    let e = NmstateError::new(
+                        ErrorKind::PluginFailure,
+                        format!(
+                            "Invalid reply from OVSDB for querying \
+                            {CONTROLLER_TABLE} table: {reply:?}"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    Err(e)
+                }
+            }
+            reply => {
+                let e = NmstateError::new(
+                    ErrorKind::PluginFailure,
+                    format!(
+                        "Invalid reply from OVSDB for querying \
+                        {CONTROLLER_TABLE} table: {reply:?}"
+                    ),
+                );
+                log::error!("{}", e);
+                Err(e)
+            }
+        }
+    }
+
+    pub(crate) fn apply_bridge_controller(
+        &mut self,
+        br_name: &str,
+        target: &str,
+    ) -> Result<(), NmstateError> {
+        let mut params = vec![Value::String(OVS_DB_NAME.to_string())];
+        params.extend(gen_bridge_controller_ops(br_name, target));
+        self.rpc.exec("transact", &Value::Array(params))?;
+        Ok(())
+    }
+
+    pub(crate) fn apply_bridge_protocols(
+        &mut self,
+        br_name: &str,
+        protocols: &[String],
+    ) -> Result<(), NmstateError> {
+        let params = vec![
+            Value::String(OVS_DB_NAME.to_string()),
+            gen_bridge_protocols_op(br_name, protocols),
+        ];
+        self.rpc.exec("transact", &Value::Array(params))?;
+        Ok(())
+    }
+
     pub(crate) fn apply_global_conf(
         &mut self,
         ovs_conf: &MergedOvsDbGlobalConfig,
@@ -419,3 +512,103 @@ impl OvsDbUpdate {
         Value::Object(ret)
     }
 }
+
+// The Controller table is not root table, the original controller entry will
+// be garbage collected once no bridge is referring it.
+// Empty target means removing the controller.
+pub(crate) fn gen_bridge_controller_ops(
+    br_name: &str,
+    target: &str,
+) -> Vec<Value> {
+    let mut ret = Vec::new();
+    let controller_value = if target.is_empty() {
+        Value::Array(vec![
+            Value::String("set".to_string()),
+            Value::Array(Vec::new()),
+        ])
+    } else {
+        let mut insert = Map::new();
+        insert.insert("op".to_string(), Value::String("insert".to_string()));
+        insert.insert(
+            "table".to_string(),
+            Value::String(CONTROLLER_TABLE.to_string()),
+        );
+        let mut row = Map::new();
+        row.insert("target".to_string(), Value::String(target.to_string()));
+        insert.insert("row".to_string(), Value::Object(row));
+        insert.insert(
+            "uuid-name".to_string(),
+            Value::String(CONTROLLER_UUID_NAME.to_string()),
+        );
+        ret.push(Value::Object(insert));
+        Value::Array(vec![
+            Value::String("named-uuid".to_string()),
+            Value::String(CONTROLLER_UUID_NAME.to_string()),
+        ])
+    };
+    let update = OvsDbUpdate {
+        table: "Bridge".to_string(),
+        conditions: vec![OvsDbCondition {
+            column: "name".to_string(),
+            function: "==".to_string(),
+            value: Value::String(br_name.to_string()),
+        }],
+        row: HashMap::from([("controller".to_string(), controller_value)]),
+    };
+    ret.push(update.to_value());
+    ret
+}
+
+// Empty protocols means using OVS default protocols.
+pub(crate) fn gen_bridge_protocols_op(
+    br_name: &str,
+    protocols: &[String],
+) -> Value {
+    let update = OvsDbUpdate {
+        table: "Bridge".to_string(),
+        conditions: vec![OvsDbCondition {
+            column: "name".to_string(),
+            function: "==".to_string(),
+            value: Value::String(br_name.to_string()),
+        }],
+        row: HashMap::from([(
+            "protocols".to_string(),
+            Value::Array(vec![
+                Value::String("set".to_string()),
+                Value::Array(
+                    protocols
+                        .iter()
+                        .map(|p| Value::String(p.to_string()))
+                        .collect(),
+                ),
+            ]),
+        )]),
+    };
+    update.to_value()
+}
+
+// The set column holding single item is represented as plain value.
+pub(crate) fn parse_str_set(v: &Value) -> Vec<String> {
+    match v {
+        Value::String(s) => vec![s.to_string()],
+        Value::Array(v) => {
+            if let (Some(Value::String(t)), Some(Value::Array(vs))) =
+                (v.first(), v.get(1))
+            {
+                if t == "set" {
+                    return vs
+                        .iter()
+                        .filter_map(|v| v.as_str())
+                        .map(|v| v.to_string())
+                        .collect();
+                }
+            }
+            log::warn!("Got unknown value: {v:?}");
+            Vec::new()
+        }
+        v => {
+            log::warn!("Got unknown value: {v:?}");
+            Vec::new()
+        }
+    }
+}
@@ -4,6 +4,8 @@ mod global_conf;
 mod json_rpc;
 mod show;
 
-pub(crate) use apply::ovsdb_apply;
+pub(crate) use apply::{ovsdb_apply, ovsdb_need_apply};
+#[cfg(test)]
+pub(crate) use db::{gen_bridge_controller_ops, gen_bridge_protocols_op};
 pub(crate) use show::ovsdb_is_running;
 pub(crate) use show::ovsdb_retrieve;
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 use serde_json::Value;
 
@@ -14,7 +14,9 @@ use crate::{
     UnknownInterface,
 };
 
-use super::db::{parse_str_map, OvsDbConnection, OvsDbEntry};
+use super::db::{
+    parse_str_map, parse_str_set, parse_uuid_array, OvsDbConnection, OvsDbEntry,
+};
 
 pub(crate) fn ovsdb_is_running() -> bool {
     if let Ok(mut cli) = OvsDbConnection::new() {
@@ -30,6 +32,7 @@ pub(crate) fn ovsdb_retrieve() -> Result<NetworkState, NmstateError> {
     let ovsdb_ifaces = cli.get_ovs_ifaces()?;
     let ovsdb_brs = cli.get_ovs_bridges()?;
     let ovsdb_ports = cli.get_ovs_ports()?;
+    let ovsdb_controllers = cli.get_ovs_controllers()?;
 
     for ovsdb_br in ovsdb_brs.values() {
         let mut iface = OvsBridgeInterface::new();
@@ -41,19 +44,25 @@ pub(crate) fn ovsdb_retrieve() -> Result<NetworkState, NmstateError> {
                 .drain()
                 .map(|(k, v)| (k, Some(v))),
         );
-        let other_config = HashMap::from_iter(
+        let mut other_config = HashMap::from_iter(
             ovsdb_br
                 .other_config
                 .clone()
                 .drain()
                 .map(|(k, v)| (k, Some(v))),
         );
+        // Already shown in OVS bridge options
+        other_config.remove(OvsBridgeOptions::DATAPATH_ID_KEY);
         iface.base.ovsdb = Some(OvsDbIfaceConfig {
             external_ids: Some(external_ids),
             other_config: Some(other_config),
         });
-        iface.bridge =
-            Some(parse_ovs_bridge_conf(ovsdb_br, &ovsdb_ports, &ovsdb_ifaces));
+        iface.bridge = Some(parse_ovs_bridge_conf(
+            ovsdb_br,
+            &ovsdb_ports,
+            &ovsdb_ifaces,
+            &ovsdb_controllers,
+        ));
         ret.append_interface_data(Interface::OvsBridge(iface));
     }
 
@@ -74,6 +83,7 @@ fn parse_ovs_bridge_conf(
     ovsdb_br: &OvsDbEntry,
     ovsdb_ports: &HashMap<String, OvsDbEntry>,
     ovsdb_ifaces: &HashMap<String, OvsDbEntry>,
+    ovsdb_controllers: &HashMap<String, String>,
 ) -> OvsBridgeConfig {
     let mut ret = OvsBridgeConfig::new();
     let mut port_confs = Vec::new();
@@ -89,7 +99,13 @@ fn parse_ovs_bridge_conf(
             port_confs.push(port_conf);
         }
     }
-    ret.options = Some(parse_ovs_bridge_options(&ovsdb_br.options));
+    let mut options =
+        parse_ovs_bridge_options(&ovsdb_br.options, ovsdb_controllers);
+    options.datapath_id = ovsdb_br
+        .other_config
+        .get(OvsBridgeOptions::DATAPATH_ID_KEY)
+        .cloned();
+    ret.options = Some(options);
     port_confs.sort_unstable_by(|a, b| {
         (a.bond.is_some(), a.name.as_str())
             .cmp(&(b.bond.is_some(), b.name.as_str()))
@@ -100,6 +116,7 @@ fn parse_ovs_bridge_conf(
 
 fn parse_ovs_bridge_options(
     ovsdb_opts: &HashMap<String, Value>,
+    ovsdb_controllers: &HashMap<String, String>,
 ) -> OvsBridgeOptions {
     let mut ret = OvsBridgeOptions::new();
     if let Some(Value::String(v)) = ovsdb_opts.get("fail_mode") {
@@ -119,6 +136,19 @@ fn parse_ovs_bridge_options(
     if let Some(Value::String(v)) = ovsdb_opts.get("datapath_type") {
         ret.datapath = Some(v.to_string())
     }
+    // Only single controller is supported
+    ret.controller = ovsdb_opts
+        .get("controller")
+        .and_then(|v| v.as_array())
+        .map(|v| parse_uuid_array(v))
+        .unwrap_or_default()
+        .first()
+        .and_then(|uuid| ovsdb_controllers.get(uuid))
+        .cloned();
+    ret.protocols = ovsdb_opts
+        .get("protocols")
+        .map(parse_str_set)
+        .filter(|p| !p.is_empty());
     ret
 }
 
@@ -322,12 +352,36 @@ fn parse_ovs_iface_dpdk_conf(
                     conf.n_txq_desc = Some(i)
                 }
             }
+            conf.pmd_rxq_affinity = ovsdb_iface
+                .other_config
+                .get(OvsDpdkConfig::PMD_RXQ_AFFINITY_KEY)
+                .map(|v| parse_pmd_rxq_affinity(v));
             return Some(conf);
         }
     }
     None
 }
 
+// The `pmd-rxq-affinity` is in the format of
+// `<queue_id>:<core_id>,<queue_id>:<core_id>`
+fn parse_pmd_rxq_affinity(value: &str) -> BTreeMap<u32, u32> {
+    let mut ret = BTreeMap::new();
+    for item in value.split(',').map(|i| i.trim()).filter(|i| !i.is_empty()) {
+        match item
+            .split_once(':')
+            .and_then(|(q, c)| Some((q.parse().ok()?, c.parse().ok()?)))
+        {
+            Some((queue_id, core_id)) => {
+                ret.insert(queue_id, core_id);
+            }
+            None => {
+                log::warn!("Invalid OVS pmd-rxq-affinity item {item}");
+            }
+        }
+    }
+    ret
+}
+
 fn ovsdb_iface_to_nmstate(
     ovsdb_iface: &OvsDbEntry,
     ifaces: &Interfaces,
@@ -380,13 +434,17 @@ fn ovsdb_iface_to_nmstate(
             .drain()
             .map(|(k, v)| (k, Some(v))),
     );
-    let other_config = HashMap::from_iter(
+    let mut other_config = HashMap::from_iter(
         ovsdb_iface
             .other_config
             .clone()
             .drain()
             .map(|(k, v)| (k, Some(v))),
     );
+    // Already shown in DPDK configuration
+    if ovsdb_iface.iface_type == "dpdk" {
+        other_config.remove(OvsDpdkConfig::PMD_RXQ_AFFINITY_KEY);
+    }
     if !external_ids.is_empty() || !other_config.is_empty() {
         iface.base_iface_mut().ovsdb = Some(OvsDbIfaceConfig {
             external_ids: Some(external_ids),
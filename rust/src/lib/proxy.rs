@@ -0,0 +1,112 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::{Deserialize, Serialize};
+
+use crate::{ErrorKind, NmstateError};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case", deny_unknown_fields)]
+#[non_exhaustive]
+/// Interface level proxy configuration, only supported by NetworkManager
+/// backend. The example yaml output of [crate::NetworkState] with proxy
+/// configured using PAC URL:
+/// ```yml
+/// ---
+/// interfaces:
+///   - name: eth1
+///     type: ethernet
+///     state: up
+///     proxy:
+///       method: auto
+///       pac-url: http://proxy.example.org/wpad.dat
+/// ```
+pub struct ProxyConfig {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub method: Option<ProxyMethod>,
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    /// Only use the proxy configuration for web browsers.
+    /// Deserialize and serialize from/to `browser-only`.
+    pub browser_only: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// URL of Proxy Auto-Configuration(PAC) file.
+    /// Only valid when `method` is [ProxyMethod::Auto].
+    /// Deserialize and serialize from/to `pac-url`.
+    pub pac_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    /// Content of Proxy Auto-Configuration(PAC) script.
+    /// Only valid when `method` is [ProxyMethod::Auto].
+    /// Deserialize and serialize from/to `pac-script`.
+    pub pac_script: Option<String>,
+}
+
+impl ProxyConfig {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn sanitize(
+        &self,
+        iface_name: &str,
+    ) -> Result<(), NmstateError> {
+        if self.method != Some(ProxyMethod::Auto)
+            && (self.pac_url.is_some() || self.pac_script.is_some())
+        {
+            return Err(NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "The proxy pac-url and pac-script of interface \
+                    {iface_name} are only valid when proxy method is auto"
+                ),
+            ));
+        }
+        if let Some(pac_url) = self.pac_url.as_deref() {
+            if !["http://", "https://", "file://"]
+                .iter()
+                .any(|p| pac_url.starts_with(p))
+            {
+                return Err(NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Invalid proxy pac-url {pac_url} of interface \
+                        {iface_name}, should start with http://, https:// \
+                        or file://"
+                    ),
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize,
+)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum ProxyMethod {
+    /// No proxy.
+    /// Deserialize and serialize from/to `none`.
+    #[default]
+    None,
+    /// Use Proxy Auto-Configuration(PAC) from `pac-url` or `pac-script`,
+    /// or from WPAD if both are not defined.
+    /// Deserialize and serialize from/to `auto`.
+    Auto,
+}
+
+impl std::fmt::Display for ProxyMethod {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::None => "none",
+                Self::Auto => "auto",
+            }
+        )
+    }
+}
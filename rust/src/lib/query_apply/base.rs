@@ -1,6 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{BaseInterface, InterfaceState, InterfaceType, OvsDbIfaceConfig};
+use crate::{
+    mptcp::get_mptcp_flags_overrides, BaseInterface, InterfaceBindBy,
+    InterfaceMultiConnect, InterfaceResolveMode, InterfaceState, InterfaceType,
+    OvsDbIfaceConfig, ProxyConfig, ProxyMethod,
+};
 
 impl BaseInterface {
     pub(crate) fn sanitize_current_for_verify(&mut self) {
@@ -27,6 +31,51 @@ impl BaseInterface {
         if let Some(dispatch_conf) = self.dispatch.as_mut() {
             dispatch_conf.sanitize_current_for_verify();
         }
+        // ip-ping-addresses None equal to empty
+        if self.ip_ping_addresses.is_none() {
+            self.ip_ping_addresses = Some(Vec::new());
+        }
+        // ip-ping-timeout None equal to 0(default)
+        if self.ip_ping_timeout.is_none() {
+            self.ip_ping_timeout = Some(0);
+        }
+        // gateway-ping-timeout None equal to 0(no ping)
+        if self.gateway_ping_timeout.is_none() {
+            self.gateway_ping_timeout = Some(0);
+        }
+        // user-data None equal to empty
+        if self.user_data.is_none() {
+            self.user_data = Some(Default::default());
+        }
+        // bind-by None equal to exact-name
+        if self.bind_by.is_none() {
+            self.bind_by = Some(InterfaceBindBy::ExactName);
+        }
+        // multi-connect None equal to default
+        if self.multi_connect.is_none() {
+            self.multi_connect = Some(InterfaceMultiConnect::Default);
+        }
+        // mdns and llmnr None equal to default
+        if self.mdns.is_none() {
+            self.mdns = Some(InterfaceResolveMode::Default);
+        }
+        if self.llmnr.is_none() {
+            self.llmnr = Some(InterfaceResolveMode::Default);
+        }
+        // sysctl None equal to empty
+        if self.sysctl.is_none() {
+            self.sysctl = Some(Default::default());
+        }
+        // static-neighbors None equal to empty
+        if self.static_neighbors.is_none() {
+            self.static_neighbors = Some(Vec::new());
+        }
+        // proxy None equal to method none
+        if self.proxy.is_none() {
+            let mut proxy_conf = ProxyConfig::new();
+            proxy_conf.method = Some(ProxyMethod::None);
+            self.proxy = Some(proxy_conf);
+        }
     }
 
     pub(crate) fn sanitize_desired_for_verify(&mut self) {
@@ -39,11 +88,30 @@ impl BaseInterface {
         if let Some(mptcp_conf) = self.mptcp.as_mut() {
             mptcp_conf.sanitize_desired_for_verify();
         }
+        // NetworkManager only shows autoconnect-ports when disabled, enabled
+        // is the default of controller.
+        if self.autoconnect_ports == Some(true) {
+            self.autoconnect_ports = None;
+        }
+        // Interface level MPTCP flags is not shown when IP addresses holding
+        // different MPTCP flags, those are verified via IP addresses instead.
+        if let Some(iface_flags) =
+            self.mptcp.as_ref().and_then(|m| m.address_flags.as_deref())
+        {
+            if !get_mptcp_flags_overrides(self, iface_flags).is_empty() {
+                self.mptcp = None;
+            }
+        }
         // When `profile_name` is the same with iface name, it was hidden during
         // query, we should ignore it during verify
         if self.profile_name.as_deref() == Some(self.name.as_str()) {
             self.profile_name = None;
         }
+        if let Some(ieee8021x_conf) = self.ieee8021x.as_mut() {
+            ieee8021x_conf.sanitize_desired_for_verify();
+        }
+        // NetworkManager might append extra flags, hence not verified
+        self.nm_flags = None;
     }
 
     pub(crate) fn update(&mut self, other: &BaseInterface) {
@@ -74,6 +142,9 @@ impl BaseInterface {
         if other.controller.is_some() {
             self.controller = other.controller.clone();
         }
+        if other.autoconnect_ports.is_some() {
+            self.autoconnect_ports = other.autoconnect_ports;
+        }
         if other.controller_type.is_some() {
             self.controller_type = other.controller_type.clone();
         }
@@ -86,6 +157,21 @@ impl BaseInterface {
         if other.ieee8021x.is_some() {
             self.ieee8021x = other.ieee8021x.clone();
         }
+        if other.proxy.is_some() {
+            self.proxy = other.proxy.clone();
+        }
+        if other.user_data.is_some() {
+            self.user_data = other.user_data.clone();
+        }
+        if other.sysctl.is_some() {
+            self.sysctl = other.sysctl.clone();
+        }
+        if other.static_neighbors.is_some() {
+            self.static_neighbors = other.static_neighbors.clone();
+        }
+        if other.nm_flags.is_some() {
+            self.nm_flags = other.nm_flags.clone();
+        }
         if other.lldp.is_some() {
             self.lldp = other.lldp.clone();
         }
@@ -98,6 +184,15 @@ impl BaseInterface {
         if other.wait_ip.is_some() {
             self.wait_ip = other.wait_ip;
         }
+        if other.ip_ping_addresses.is_some() {
+            self.ip_ping_addresses = other.ip_ping_addresses.clone();
+        }
+        if other.ip_ping_timeout.is_some() {
+            self.ip_ping_timeout = other.ip_ping_timeout;
+        }
+        if other.gateway_ping_timeout.is_some() {
+            self.gateway_ping_timeout = other.gateway_ping_timeout;
+        }
 
         if other.ipv4.is_some() {
             if let Some(ref other_ipv4) = other.ipv4 {
@@ -124,6 +219,21 @@ impl BaseInterface {
         if other.identifier.is_some() {
             self.identifier = other.identifier;
         }
+        if other.bind_by.is_some() {
+            self.bind_by = other.bind_by;
+        }
+        if other.bind_pattern.is_some() {
+            self.bind_pattern = other.bind_pattern.clone();
+        }
+        if other.multi_connect.is_some() {
+            self.multi_connect = other.multi_connect;
+        }
+        if other.mdns.is_some() {
+            self.mdns = other.mdns;
+        }
+        if other.llmnr.is_some() {
+            self.llmnr = other.llmnr;
+        }
         if other.profile_name.is_some() {
             self.profile_name = other.profile_name.clone();
         }
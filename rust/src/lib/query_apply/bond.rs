@@ -31,13 +31,17 @@ impl BondConfig {
 }
 
 impl BondOptions {
-    // Only allow update `balance_slb` as that is userspace value.
+    // Only allow update `balance_slb` as that is userspace value and
+    // `peer_notif_delay` as nispor does not provide it.
     // Other options should be provided by nispor via kernel netlink.
     pub(crate) fn update(&mut self, other: Option<&Self>) {
         if let Some(other) = other {
             if let Some(value) = other.balance_slb {
                 self.balance_slb = Some(value);
             }
+            if let Some(value) = other.peer_notif_delay {
+                self.peer_notif_delay = Some(value);
+            }
         }
     }
 }
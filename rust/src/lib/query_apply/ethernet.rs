@@ -1,9 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use crate::{
-    ErrorKind, EthernetConfig, EthernetInterface, Interface, InterfaceType,
-    Interfaces, MergedInterfaces, NetworkState, NmstateError, SrIovConfig,
-    VethConfig,
+    ErrorKind, EthernetConfig, EthernetInterface, InfiniBandInterface,
+    Interface, InterfaceType, Interfaces, MergedInterfaces, NetworkState,
+    NmstateError, SrIovConfig, VethConfig,
 };
 
 impl EthernetInterface {
@@ -46,7 +46,11 @@ impl EthernetInterface {
     ) -> Result<(), NmstateError> {
         if let Some(eth_conf) = &self.ethernet {
             if let Some(sriov_conf) = &eth_conf.sr_iov {
-                sriov_conf.verify_sriov(self.base.name.as_str(), cur_ifaces)?;
+                sriov_conf.verify_sriov(
+                    self.base.name.as_str(),
+                    InterfaceType::Ethernet,
+                    cur_ifaces,
+                )?;
             }
         }
         Ok(())
@@ -61,6 +65,16 @@ impl EthernetConfig {
             } else {
                 self.sr_iov = other.sr_iov.clone()
             }
+            if other.cloned_mac.is_some() {
+                self.cloned_mac = other.cloned_mac;
+            }
+            if other.generate_mac_address_mask.is_some() {
+                self.generate_mac_address_mask =
+                    other.generate_mac_address_mask.clone();
+            }
+            if other.s390.is_some() {
+                self.s390 = other.s390.clone();
+            }
         }
     }
 }
@@ -118,14 +132,24 @@ impl NetworkState {
         for iface in
             self.interfaces.kernel_ifaces.values().filter(|i| i.is_up())
         {
-            if let (
-                Interface::Ethernet(iface),
-                Some(Interface::Ethernet(cur_iface)),
-            ) = (iface, current.interfaces.kernel_ifaces.get(iface.name()))
-            {
-                if iface.sriov_is_enabled() || cur_iface.sriov_is_enabled() {
+            match (iface, current.interfaces.kernel_ifaces.get(iface.name())) {
+                (
+                    Interface::Ethernet(iface),
+                    Some(Interface::Ethernet(cur_iface)),
+                ) if iface.sriov_is_enabled()
+                    || cur_iface.sriov_is_enabled() =>
+                {
                     return true;
                 }
+                (
+                    Interface::InfiniBand(iface),
+                    Some(Interface::InfiniBand(cur_iface)),
+                ) if iface.sriov_is_enabled()
+                    || cur_iface.sriov_is_enabled() =>
+                {
+                    return true;
+                }
+                _ => (),
             }
         }
         false
@@ -138,37 +162,47 @@ impl NetworkState {
             .filter(|i| {
                 i.is_up()
                     && (i.iface_type() == InterfaceType::Ethernet
+                        || i.iface_type() == InterfaceType::InfiniBand
                         || i.iface_type() == InterfaceType::Unknown)
             })
             .any(|i| !current.interfaces.kernel_ifaces.contains_key(i.name()))
     }
 
-    // Return newly create NetworkState containing only ethernet section of
-    // interface with SR-IOV PF changes.
+    // Return newly create NetworkState containing only ethernet or infiniband
+    // section of interface with SR-IOV PF changes.
     pub(crate) fn get_sriov_pf_conf_state(&self) -> Option<Self> {
         let mut pf_ifaces: Vec<Interface> = Vec::new();
 
-        for iface in self.interfaces.kernel_ifaces.values().filter_map(|i| {
-            if i.is_up() {
-                if let Interface::Ethernet(iface) = i {
-                    Some(iface)
-                } else {
-                    None
+        for iface in
+            self.interfaces.kernel_ifaces.values().filter(|i| i.is_up())
+        {
+            match iface {
+                Interface::Ethernet(iface) => {
+                    if let Some(eth_conf) =
+                        iface.ethernet.as_ref().filter(|e| e.sr_iov.is_some())
+                    {
+                        pf_ifaces.push(Interface::Ethernet(
+                            EthernetInterface {
+                                base: iface.base.clone_name_type_only(),
+                                ethernet: Some(eth_conf.clone()),
+                                ..Default::default()
+                            },
+                        ));
+                    }
                 }
-            } else {
-                None
-            }
-        }) {
-            if let Some(true) =
-                iface.ethernet.as_ref().map(|e| e.sr_iov.is_some())
-            {
-                if let Some(eth_conf) = iface.ethernet.as_ref() {
-                    pf_ifaces.push(Interface::Ethernet(EthernetInterface {
-                        base: iface.base.clone_name_type_only(),
-                        ethernet: Some(eth_conf.clone()),
-                        ..Default::default()
-                    }));
+                Interface::InfiniBand(iface) => {
+                    if let Some(ib_conf) =
+                        iface.ib.as_ref().filter(|i| i.sr_iov.is_some())
+                    {
+                        pf_ifaces.push(Interface::InfiniBand(
+                            InfiniBandInterface {
+                                base: iface.base.clone_name_type_only(),
+                                ib: Some(ib_conf.clone()),
+                            },
+                        ));
+                    }
                 }
+                _ => (),
             }
         }
 
@@ -187,18 +221,25 @@ impl NetworkState {
 impl MergedInterfaces {
     pub(crate) fn get_sriov_vf_count(&self) -> u32 {
         let mut ret = 0u32;
-        for iface in self.kernel_ifaces.values().filter(|i| {
-            (i.is_desired() || i.is_changed())
-                && i.merged.iface_type() == InterfaceType::Ethernet
-        }) {
-            if let Interface::Ethernet(iface) = &iface.merged {
-                ret += iface
+        for iface in self
+            .kernel_ifaces
+            .values()
+            .filter(|i| i.is_desired() || i.is_changed())
+        {
+            ret += match &iface.merged {
+                Interface::Ethernet(iface) => iface
                     .ethernet
                     .as_ref()
                     .and_then(|e| e.sr_iov.as_ref())
-                    .and_then(|s| s.total_vfs)
-                    .unwrap_or_default();
+                    .and_then(|s| s.total_vfs),
+                Interface::InfiniBand(iface) => iface
+                    .ib
+                    .as_ref()
+                    .and_then(|i| i.sr_iov.as_ref())
+                    .and_then(|s| s.total_vfs),
+                _ => None,
             }
+            .unwrap_or_default();
         }
         ret
     }
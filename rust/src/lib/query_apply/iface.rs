@@ -15,6 +15,9 @@ impl Interface {
         if let Interface::OvsBridge(iface) = self {
             iface.sanitize_current_for_verify()
         }
+        if let Interface::OvsInterface(iface) = self {
+            iface.sanitize_current_for_verify()
+        }
     }
 
     // This function will clean up desired state before verification
@@ -24,6 +27,16 @@ impl Interface {
             iface.sanitize_desired_for_verify();
         } else if let Interface::Hsr(iface) = self {
             iface.sanitize_desired_for_verify();
+        } else if let Interface::Team(iface) = self {
+            iface.sanitize_desired_for_verify();
+        } else if let Interface::WireGuard(iface) = self {
+            iface.sanitize_desired_for_verify();
+        } else if let Interface::OvsInterface(iface) = self {
+            iface.sanitize_desired_for_verify();
+        } else if let Interface::InfiniBand(iface) = self {
+            iface.sanitize_desired_for_verify();
+        } else if let Interface::MacVtap(iface) = self {
+            iface.sanitize_desired_for_verify();
         }
     }
 
@@ -33,6 +46,7 @@ impl Interface {
     ) -> Result<(), NmstateError> {
         let mut current = current.clone();
         self.process_allow_extra_address(&mut current);
+        self.process_addr_gen_mode_fallback(&current);
 
         let self_value = serde_json::to_value(&self)?;
         let current_value = serde_json::to_value(&current)?;
@@ -189,6 +203,17 @@ impl Interface {
                     );
                 }
             }
+            Self::Team(iface) => {
+                if let Self::Team(other_iface) = other {
+                    iface.update_team(other_iface);
+                } else {
+                    log::warn!(
+                        "Don't know how to update iface {:?} with {:?}",
+                        iface,
+                        other
+                    );
+                }
+            }
             Self::InfiniBand(iface) => {
                 if let Self::InfiniBand(other_iface) = other {
                     iface.update_ib(other_iface);
@@ -244,13 +269,24 @@ impl Interface {
                     );
                 }
             }
+            Self::WireGuard(iface) => {
+                if let Self::WireGuard(other_iface) = other {
+                    iface.update_wireguard(other_iface);
+                } else {
+                    log::warn!(
+                        "Don't know how to update iface {:?} with {:?}",
+                        iface,
+                        other
+                    );
+                }
+            }
             _ => (),
         }
     }
 }
 
 impl InterfaceType {
-    pub(crate) const SUPPORTED_LIST: [InterfaceType; 18] = [
+    pub(crate) const SUPPORTED_LIST: [InterfaceType; 21] = [
         InterfaceType::Bond,
         InterfaceType::LinuxBridge,
         InterfaceType::Dummy,
@@ -266,8 +302,11 @@ impl InterfaceType {
         InterfaceType::Loopback,
         InterfaceType::MacSec,
         InterfaceType::Vrf,
+        InterfaceType::Team,
         InterfaceType::Hsr,
         InterfaceType::Ipsec,
         InterfaceType::Xfrm,
+        InterfaceType::WireGuard,
+        InterfaceType::Generic,
     ];
 }
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{InfiniBandConfig, InfiniBandInterface};
+use crate::{
+    InfiniBandConfig, InfiniBandInterface, InterfaceType, Interfaces,
+    NmstateError, SrIovConfig,
+};
 
 impl InfiniBandInterface {
     pub(crate) fn update_ib(&mut self, other: &InfiniBandInterface) {
@@ -10,6 +13,39 @@ impl InfiniBandInterface {
             self.ib = other.ib.clone();
         }
     }
+
+    pub(crate) fn sanitize_desired_for_verify(&mut self) {
+        if let Some(sriov_conf) =
+            self.ib.as_mut().and_then(|ib_conf| ib_conf.sr_iov.as_mut())
+        {
+            sriov_conf.sanitize_desired_for_verify();
+        }
+    }
+
+    pub(crate) fn sriov_is_enabled(&self) -> bool {
+        self.ib
+            .as_ref()
+            .and_then(|ib_conf| {
+                ib_conf.sr_iov.as_ref().map(SrIovConfig::sriov_is_enabled)
+            })
+            .unwrap_or_default()
+    }
+
+    pub(crate) fn verify_sriov(
+        &self,
+        cur_ifaces: &Interfaces,
+    ) -> Result<(), NmstateError> {
+        if let Some(sriov_conf) =
+            self.ib.as_ref().and_then(|ib_conf| ib_conf.sr_iov.as_ref())
+        {
+            sriov_conf.verify_sriov(
+                self.base.name.as_str(),
+                InterfaceType::InfiniBand,
+                cur_ifaces,
+            )?;
+        }
+        Ok(())
+    }
 }
 
 impl InfiniBandConfig {
@@ -18,6 +54,11 @@ impl InfiniBandConfig {
             self.mode = other.mode;
             self.pkey = other.pkey;
             self.base_iface = other.base_iface.clone();
+            if let Some(sriov_conf) = &mut self.sr_iov {
+                sriov_conf.update(other.sr_iov.as_ref())
+            } else {
+                self.sr_iov = other.sr_iov.clone()
+            }
         }
     }
 }
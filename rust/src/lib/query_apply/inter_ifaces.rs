@@ -181,10 +181,18 @@ impl MergedInterfaces {
                 // Do not verify physical interface with state:down
                 if iface.is_up() {
                     iface.verify(cur_iface)?;
-                    if let Interface::Ethernet(eth_iface) = iface {
-                        if eth_iface.sriov_is_enabled() {
+                    match iface {
+                        Interface::Ethernet(eth_iface)
+                            if eth_iface.sriov_is_enabled() =>
+                        {
                             eth_iface.verify_sriov(&current)?;
                         }
+                        Interface::InfiniBand(ib_iface)
+                            if ib_iface.sriov_is_enabled() =>
+                        {
+                            ib_iface.verify_sriov(&current)?;
+                        }
+                        _ => (),
                     }
                 }
             } else if iface.is_up() {
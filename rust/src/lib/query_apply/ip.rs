@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{Interface, InterfaceIpv4, InterfaceIpv6};
+use crate::{Interface, InterfaceIpv4, InterfaceIpv6, Ipv6AddrGenMode};
 
 impl InterfaceIpv4 {
     // Sort addresses and dedup
@@ -12,6 +12,21 @@ impl InterfaceIpv4 {
         if self.dhcp_custom_hostname.is_none() {
             self.dhcp_custom_hostname = Some(String::new());
         }
+        if self.dhcp_vendor_class_identifier.is_none() {
+            self.dhcp_vendor_class_identifier = Some(String::new());
+        }
+        // replace-local-rule None equal to false
+        if self.replace_local_rule.is_none() {
+            self.replace_local_rule = Some(false);
+        }
+        // dns-priority None equal to 0(default)
+        if self.dns_priority.is_none() {
+            self.dns_priority = Some(0);
+        }
+        // dad-timeout None equal to -1(NetworkManager default)
+        if self.enabled && self.dad_timeout.is_none() {
+            self.dad_timeout = Some(-1);
+        }
 
         // No IP address means empty.
         if self.enabled && self.addresses.is_none() {
@@ -75,6 +90,19 @@ impl InterfaceIpv4 {
         if other.dhcp_custom_hostname.is_some() {
             self.dhcp_custom_hostname = other.dhcp_custom_hostname.clone();
         }
+        if other.dhcp_vendor_class_identifier.is_some() {
+            self.dhcp_vendor_class_identifier =
+                other.dhcp_vendor_class_identifier.clone();
+        }
+        if other.replace_local_rule.is_some() {
+            self.replace_local_rule = other.replace_local_rule;
+        }
+        if other.dns_priority.is_some() {
+            self.dns_priority = other.dns_priority;
+        }
+        if other.dad_timeout.is_some() {
+            self.dad_timeout = other.dad_timeout;
+        }
     }
 }
 
@@ -85,6 +113,14 @@ impl InterfaceIpv6 {
             addrs.sort_unstable();
             addrs.dedup();
         }
+        // replace-local-rule None equal to false
+        if self.replace_local_rule.is_none() {
+            self.replace_local_rule = Some(false);
+        }
+        // dns-priority None equal to 0(default)
+        if self.dns_priority.is_none() {
+            self.dns_priority = Some(0);
+        }
 
         // None IPv6 token should be treat as "::"
         if self.token.is_none() {
@@ -108,6 +144,11 @@ impl InterfaceIpv6 {
         if self.enabled && self.autoconf.is_none() {
             self.autoconf = Some(false);
         }
+
+        // No ra-timeout means waiting infinitely
+        if self.enabled && self.ra_timeout.is_none() {
+            self.ra_timeout = Some(i32::MAX as u32);
+        }
     }
 
     // Sort addresses and dedup
@@ -169,6 +210,30 @@ impl InterfaceIpv6 {
         if other.dhcp_custom_hostname.is_some() {
             self.dhcp_custom_hostname = other.dhcp_custom_hostname.clone();
         }
+        if other.dad_transmits.is_some() {
+            self.dad_transmits = other.dad_transmits;
+        }
+        if other.ip6_privacy.is_some() {
+            self.ip6_privacy = other.ip6_privacy;
+        }
+        if other.ra_timeout.is_some() {
+            self.ra_timeout = other.ra_timeout;
+        }
+        if other.accept_ra_mtu.is_some() {
+            self.accept_ra_mtu = other.accept_ra_mtu;
+        }
+        if other.accept_ra.is_some() {
+            self.accept_ra = other.accept_ra;
+        }
+        if other.forwarding.is_some() {
+            self.forwarding = other.forwarding;
+        }
+        if other.replace_local_rule.is_some() {
+            self.replace_local_rule = other.replace_local_rule;
+        }
+        if other.dns_priority.is_some() {
+            self.dns_priority = other.dns_priority;
+        }
     }
 }
 
@@ -205,4 +270,25 @@ impl Interface {
             }
         }
     }
+
+    // NetworkManager older than 1.40 does not support `default` and
+    // `default-or-eui64` addr-gen-mode, the mode they default to is applied
+    // instead.
+    pub(crate) fn process_addr_gen_mode_fallback(&mut self, current: &Self) {
+        if let (Some(des_ip), Some(cur_ip)) = (
+            self.base_iface_mut().ipv6.as_mut(),
+            current.base_iface().ipv6.as_ref(),
+        ) {
+            let fallback = match des_ip.addr_gen_mode.as_ref() {
+                Some(Ipv6AddrGenMode::DefaultOrEui64) => Ipv6AddrGenMode::Eui64,
+                Some(Ipv6AddrGenMode::Default) => {
+                    Ipv6AddrGenMode::StablePrivacy
+                }
+                _ => return,
+            };
+            if cur_ip.addr_gen_mode.as_ref() == Some(&fallback) {
+                des_ip.addr_gen_mode = Some(fallback);
+            }
+        }
+    }
 }
@@ -11,6 +11,14 @@ impl MacVtapInterface {
             self.mac_vtap = other.mac_vtap.clone();
         }
     }
+
+    // Kernel does not expose tap flags
+    pub(crate) fn sanitize_desired_for_verify(&mut self) {
+        if let Some(conf) = self.mac_vtap.as_mut() {
+            conf.vnet_hdr = None;
+            conf.multi_queue = None;
+        }
+    }
 }
 
 impl MacVtapConfig {
@@ -19,6 +27,12 @@ impl MacVtapConfig {
             self.base_iface = other.base_iface.clone();
             self.mode = other.mode;
             self.accept_all_mac = other.accept_all_mac;
+            if other.vnet_hdr.is_some() {
+                self.vnet_hdr = other.vnet_hdr;
+            }
+            if other.multi_queue.is_some() {
+                self.multi_queue = other.multi_queue;
+            }
         }
     }
 }
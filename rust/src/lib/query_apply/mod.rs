@@ -23,6 +23,8 @@ mod ovs;
 mod route;
 mod route_rule;
 mod sriov;
+mod team;
 mod vlan;
 mod vrf;
 mod vxlan;
+mod wireguard;
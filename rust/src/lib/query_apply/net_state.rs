@@ -6,7 +6,7 @@ use crate::{
         nm_apply, nm_checkpoint_create, nm_checkpoint_destroy,
         nm_checkpoint_rollback, nm_checkpoint_timeout_extend, nm_retrieve,
     },
-    ovsdb::{ovsdb_apply, ovsdb_is_running, ovsdb_retrieve},
+    ovsdb::{ovsdb_apply, ovsdb_is_running, ovsdb_need_apply, ovsdb_retrieve},
     ErrorKind, MergedInterfaces, MergedNetworkState, NetworkState,
     NmstateError,
 };
@@ -221,7 +221,7 @@ impl NetworkState {
         with_retry(RETRY_NM_INTERVAL_MILLISECONDS, RETRY_NM_COUNT, || {
             nm_checkpoint_timeout_extend(checkpoint, timeout)?;
             nm_apply(merged_state, checkpoint, timeout)?;
-            if merged_state.ovsdb.is_changed && ovsdb_is_running() {
+            if ovsdb_need_apply(merged_state) && ovsdb_is_running() {
                 ovsdb_apply(merged_state)?;
             }
             if let Some(running_hostname) =
@@ -229,7 +229,7 @@ impl NetworkState {
             {
                 set_running_hostname(running_hostname)?;
             }
-            if !self.no_verify {
+            if !self.no_verify && !self.maintenance {
                 with_retry(
                     VERIFY_RETRY_INTERVAL_MILLISECONDS,
                     retry_count,
@@ -91,6 +91,22 @@ impl OvsBridgeConfig {
 
 impl OvsBridgeInterface {
     pub(crate) fn sanitize_current_for_verify(&mut self) {
+        // Unset controller, datapath-id and protocols equal to empty
+        if let Some(br_opts) = self
+            .bridge
+            .as_mut()
+            .and_then(|br_conf| br_conf.options.as_mut())
+        {
+            if br_opts.controller.is_none() {
+                br_opts.controller = Some(String::new());
+            }
+            if br_opts.datapath_id.is_none() {
+                br_opts.datapath_id = Some(String::new());
+            }
+            if br_opts.protocols.is_none() {
+                br_opts.protocols = Some(Vec::new());
+            }
+        }
         if let Some(port_confs) = self
             .bridge
             .as_mut()
@@ -114,6 +130,15 @@ impl OvsBridgeInterface {
 }
 
 impl OvsInterface {
+    pub(crate) fn sanitize_current_for_verify(&mut self) {
+        // pmd-rxq-affinity None equal to empty
+        if let Some(dpdk_conf) = self.dpdk.as_mut() {
+            if dpdk_conf.pmd_rxq_affinity.is_none() {
+                dpdk_conf.pmd_rxq_affinity = Some(Default::default());
+            }
+        }
+    }
+
     pub(crate) fn update_ovs_iface(&mut self, other: &Self) {
         if other.patch.is_some() {
             self.patch = other.patch.clone();
@@ -121,6 +146,16 @@ impl OvsInterface {
         if other.dpdk.is_some() {
             self.dpdk = other.dpdk.clone();
         }
+        if other.mtu_request.is_some() {
+            self.mtu_request = other.mtu_request;
+        }
+    }
+
+    // OpenvSwitch set the MTU of interface to `mtu_request`
+    pub(crate) fn sanitize_desired_for_verify(&mut self) {
+        if let Some(mtu) = self.mtu_request.take() {
+            self.base.mtu = Some(mtu);
+        }
     }
 }
 
@@ -10,7 +10,10 @@ use crate::{
 impl SrIovConfig {
     // * Set 'vfs: []' to None which is just reverting all VF config to default.
     // * Set `vf.iface_name` empty string,
+    // * Remove eswitch configures as they are not queried yet.
     pub(crate) fn sanitize_desired_for_verify(&mut self) {
+        self.eswitch_mode = None;
+        self.eswitch_encap_mode = None;
         if let Some(vfs) = self.vfs.as_mut() {
             for vf in vfs.iter_mut() {
                 vf.iface_name = String::new();
@@ -29,6 +32,12 @@ impl SrIovConfig {
             if let Some(vfs) = other.vfs.as_ref() {
                 self.vfs = Some(vfs.clone());
             }
+            if other.eswitch_mode.is_some() {
+                self.eswitch_mode = other.eswitch_mode;
+            }
+            if other.eswitch_encap_mode.is_some() {
+                self.eswitch_encap_mode = other.eswitch_encap_mode;
+            }
         }
     }
 
@@ -38,14 +47,21 @@ impl SrIovConfig {
     // This function does not handle the decrease of SRIOV count(interface been
     // removed from kernel) as our test showed kernel does not require extra
     // time on deleting interface.
+    // The VF is holding the same interface type as its PF.
     pub(crate) fn verify_sriov(
         &self,
         pf_name: &str,
+        pf_type: InterfaceType,
         cur_ifaces: &Interfaces,
     ) -> Result<(), NmstateError> {
-        let cur_pf_iface =
-            match cur_ifaces.get_iface(pf_name, InterfaceType::Ethernet) {
-                Some(Interface::Ethernet(i)) => i,
+        let cur_sriov_conf =
+            match cur_ifaces.get_iface(pf_name, pf_type.clone()) {
+                Some(Interface::Ethernet(i)) => {
+                    i.ethernet.as_ref().and_then(|e| e.sr_iov.as_ref())
+                }
+                Some(Interface::InfiniBand(i)) => {
+                    i.ib.as_ref().and_then(|i| i.sr_iov.as_ref())
+                }
                 _ => {
                     return Err(NmstateError::new(
                         ErrorKind::SrIovVfNotFound,
@@ -54,11 +70,8 @@ impl SrIovConfig {
                 }
             };
 
-        let vfs = if let Some(vfs) = cur_pf_iface
-            .ethernet
-            .as_ref()
-            .and_then(|eth_conf| eth_conf.sr_iov.as_ref())
-            .and_then(|sriov_conf| sriov_conf.vfs.as_ref())
+        let vfs = if let Some(vfs) =
+            cur_sriov_conf.and_then(|sriov_conf| sriov_conf.vfs.as_ref())
         {
             vfs
         } else {
@@ -74,7 +87,7 @@ impl SrIovConfig {
                     ),
                 ));
             } else if cur_ifaces
-                .get_iface(vf.iface_name.as_str(), InterfaceType::Ethernet)
+                .get_iface(vf.iface_name.as_str(), pf_type.clone())
                 .is_none()
             {
                 return Err(NmstateError::new(
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{TeamConfig, TeamInterface, TeamPortConfig};
+
+impl TeamInterface {
+    pub(crate) fn update_team(&mut self, other: &TeamInterface) {
+        if let Some(team_conf) = &mut self.team {
+            team_conf.update(other.team.as_ref());
+        } else {
+            self.team = other.team.clone();
+        }
+    }
+
+    // NetworkManager might reformat the teamd JSON config, hence only
+    // verify the runner. The kernel does not expose team port settings,
+    // hence only verify the port names.
+    pub(crate) fn sanitize_desired_for_verify(&mut self) {
+        if let Some(conf) = &mut self.team {
+            conf.config = None;
+            if let Some(ports) = conf.port.as_mut() {
+                for port in ports.iter_mut() {
+                    *port = TeamPortConfig {
+                        name: port.name.clone(),
+                        ..Default::default()
+                    };
+                }
+            }
+        }
+    }
+}
+
+impl TeamConfig {
+    fn update(&mut self, other: Option<&Self>) {
+        if let Some(other) = other {
+            if other.port.is_some() {
+                self.port = other.port.clone();
+            }
+            if other.runner.is_some() {
+                self.runner = other.runner;
+            }
+            if other.config.is_some() {
+                self.config = other.config.clone();
+            }
+        }
+    }
+}
@@ -22,6 +22,8 @@ impl VxlanConfig {
             self.local = other.local;
             self.remote = other.remote;
             self.dst_port = other.dst_port;
+            self.gbp = other.gbp;
+            self.gpe = other.gpe;
         }
     }
 }
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{SecretStorageMode, WireGuardEndpointResolver, WireGuardInterface};
+
+impl WireGuardInterface {
+    pub(crate) fn update_wireguard(&mut self, other: &WireGuardInterface) {
+        if other.wireguard.is_some() {
+            self.wireguard = other.wireguard.clone();
+        }
+    }
+
+    pub(crate) fn sanitize_desired_for_verify(&mut self) {
+        if let Some(conf) = self.wireguard.as_mut() {
+            // The hostname resolved by nmstate will be shown as IP address
+            if conf.endpoint_resolver
+                == Some(WireGuardEndpointResolver::Nmstate)
+            {
+                for peer in conf.peers.as_deref_mut().unwrap_or_default() {
+                    peer.endpoint = None;
+                }
+            }
+            conf.endpoint_resolver = None;
+            // Empty preshared key means removal
+            for peer in conf.peers.as_deref_mut().unwrap_or_default() {
+                if peer.preshared_key.as_deref() == Some("") {
+                    peer.preshared_key = None;
+                }
+            }
+            // Secret stored by system is the default
+            if conf.private_key_storage == Some(SecretStorageMode::None) {
+                conf.private_key_storage = None;
+            }
+        }
+    }
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::BaseInterface;
+use crate::{BaseInterface, MptcpConfig};
 
 impl BaseInterface {
     pub(crate) fn generate_revert_extra(
@@ -27,6 +27,34 @@ impl BaseInterface {
         {
             self.ipv6 = current.ipv6.clone();
         }
+        // Per-interface DNS config is not serialized, hence not covered by
+        // `gen_revert_state()`. Restore current DNS config if desired changed
+        // or dropped it.
+        if let (Some(revert_ip), Some(des_ip), Some(cur_ip)) = (
+            self.ipv4.as_mut(),
+            desired.ipv4.as_ref(),
+            current.ipv4.as_ref(),
+        ) {
+            if des_ip.dns != cur_ip.dns {
+                revert_ip.dns = cur_ip.dns.clone();
+            }
+        }
+        if let (Some(revert_ip), Some(des_ip), Some(cur_ip)) = (
+            self.ipv6.as_mut(),
+            desired.ipv6.as_ref(),
+            current.ipv6.as_ref(),
+        ) {
+            if des_ip.dns != cur_ip.dns {
+                revert_ip.dns = cur_ip.dns.clone();
+            }
+        }
+        // When desired changes MPTCP flags, restore the full flag list of
+        // current. If current has no MPTCP config, revert to empty flags.
+        if desired.mptcp.is_some() && desired.mptcp != current.mptcp {
+            self.mptcp = Some(current.mptcp.clone().unwrap_or(MptcpConfig {
+                address_flags: Some(Vec::new()),
+            }));
+        }
         self.ipv4.as_mut().and_then(|i| i.sanitize(false).ok());
         self.ipv6.as_mut().and_then(|i| i.sanitize(false).ok());
     }
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EthernetConfig, EthernetInterface, Interface, SrIovConfig};
+use crate::{
+    EthernetClonedMac, EthernetConfig, EthernetInterface, Interface,
+    SrIovConfig,
+};
 
 impl EthernetInterface {
     pub(crate) fn generate_revert_extra(
@@ -20,6 +23,37 @@ impl EthernetInterface {
                         ..Default::default()
                     });
             }
+            if let Some(des_conf) = desired.ethernet.as_ref() {
+                let cur_conf = current.ethernet.as_ref();
+                // The cloned MAC is only shown by NM when explicitly set,
+                // revert to `preserve` which is the NetworkManager default.
+                if des_conf.cloned_mac.is_some()
+                    && des_conf.cloned_mac
+                        != cur_conf.and_then(|c| c.cloned_mac)
+                {
+                    self.ethernet
+                        .get_or_insert(EthernetConfig::new())
+                        .cloned_mac = Some(
+                        cur_conf
+                            .and_then(|c| c.cloned_mac)
+                            .unwrap_or(EthernetClonedMac::Preserve),
+                    );
+                }
+                // Empty mask means backend default.
+                if des_conf.generate_mac_address_mask.is_some()
+                    && des_conf.generate_mac_address_mask
+                        != cur_conf
+                            .and_then(|c| c.generate_mac_address_mask.clone())
+                {
+                    self.ethernet
+                        .get_or_insert(EthernetConfig::new())
+                        .generate_mac_address_mask = Some(
+                        cur_conf
+                            .and_then(|c| c.generate_mac_address_mask.clone())
+                            .unwrap_or_default(),
+                    );
+                }
+            }
         }
     }
 }
@@ -46,8 +46,20 @@ impl Interface {
             .base_iface_mut()
             .generate_revert_extra(self.base_iface(), current.base_iface());
 
-        if let Interface::Ethernet(ref mut eth_iface) = revert_iface {
-            eth_iface.generate_revert_extra(self, current);
+        match revert_iface {
+            Interface::Ethernet(ref mut eth_iface) => {
+                eth_iface.generate_revert_extra(self, current);
+            }
+            Interface::LinuxBridge(ref mut br_iface) => {
+                br_iface.generate_revert_extra(self, current);
+            }
+            Interface::Vlan(ref mut vlan_iface) => {
+                vlan_iface.generate_revert_extra(self, current);
+            }
+            Interface::Vxlan(ref mut vxlan_iface) => {
+                vxlan_iface.generate_revert_extra(self, current);
+            }
+            _ => (),
         }
 
         Ok(revert_iface)
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{
+    Interface, LinuxBridgeConfig, LinuxBridgeInterface, LinuxBridgeOptions,
+};
+
+impl LinuxBridgeInterface {
+    // The generic revert only restores properties mentioned in desired state.
+    // Changing STP options could reset the others, hence hold the full STP
+    // config of current. For bridge ports, restore the full port config of
+    // current when any port setting is changed.
+    pub(crate) fn generate_revert_extra(
+        &mut self,
+        desired: &Interface,
+        current: &Interface,
+    ) {
+        if let (
+            Interface::LinuxBridge(desired),
+            Interface::LinuxBridge(current),
+        ) = (desired, current)
+        {
+            let (des_conf, cur_conf) =
+                match (desired.bridge.as_ref(), current.bridge.as_ref()) {
+                    (Some(d), Some(c)) => (d, c),
+                    _ => return,
+                };
+            let cur_stp =
+                cur_conf.options.as_ref().and_then(|o| o.stp.as_ref());
+            if let Some(des_stp) =
+                des_conf.options.as_ref().and_then(|o| o.stp.as_ref())
+            {
+                if Some(des_stp) != cur_stp && cur_stp.is_some() {
+                    self.bridge
+                        .get_or_insert(LinuxBridgeConfig::new())
+                        .options
+                        .get_or_insert(LinuxBridgeOptions::new())
+                        .stp = cur_stp.cloned();
+                }
+            }
+            if let (Some(des_ports), Some(cur_ports)) =
+                (des_conf.port.as_ref(), cur_conf.port.as_ref())
+            {
+                let port_changed = des_ports.iter().any(|des_port| {
+                    cur_ports
+                        .iter()
+                        .find(|p| p.name == des_port.name)
+                        .map(|cur_port| des_port.is_changed(cur_port))
+                        .unwrap_or_default()
+                });
+                if port_changed {
+                    self.bridge.get_or_insert(LinuxBridgeConfig::new()).port =
+                        Some(cur_ports.clone());
+                }
+            }
+        }
+    }
+}
@@ -4,3 +4,6 @@ mod base;
 mod ethernet;
 mod iface;
 mod inter_ifaces;
+mod linux_bridge;
+mod vlan;
+mod vxlan;
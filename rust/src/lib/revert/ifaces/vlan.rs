@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{Interface, VlanInterface};
+
+impl VlanInterface {
+    // Changing VLAN ID or base interface recreates the VLAN interface, the
+    // revert state should hold the full VLAN config of current to restore
+    // other VLAN properties not mentioned in desired state.
+    pub(crate) fn generate_revert_extra(
+        &mut self,
+        desired: &Interface,
+        current: &Interface,
+    ) {
+        if let (Interface::Vlan(desired), Interface::Vlan(current)) =
+            (desired, current)
+        {
+            if let (Some(des_conf), Some(cur_conf)) =
+                (desired.vlan.as_ref(), current.vlan.as_ref())
+            {
+                if des_conf.id != cur_conf.id
+                    || des_conf.base_iface != cur_conf.base_iface
+                {
+                    self.vlan = current.vlan.clone();
+                }
+            }
+        }
+    }
+}
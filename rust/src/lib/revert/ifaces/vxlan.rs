@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{Interface, VxlanInterface};
+
+impl VxlanInterface {
+    // Changing VXLAN ID or base interface recreates the VXLAN interface, the
+    // revert state should hold the full VXLAN config of current as empty
+    // `base-iface` is not serialized in desired state.
+    pub(crate) fn generate_revert_extra(
+        &mut self,
+        desired: &Interface,
+        current: &Interface,
+    ) {
+        if let (Interface::Vxlan(desired), Interface::Vxlan(current)) =
+            (desired, current)
+        {
+            if let (Some(des_conf), Some(cur_conf)) =
+                (desired.vxlan.as_ref(), current.vxlan.as_ref())
+            {
+                if des_conf.id != cur_conf.id
+                    || des_conf.base_iface != cur_conf.base_iface
+                {
+                    self.vxlan = current.vxlan.clone();
+                }
+            }
+        }
+    }
+}
@@ -1,7 +1,7 @@
 // SPDX-License-Identifier: Apache-2.0
 
 use std::collections::{hash_map::Entry, HashMap, HashSet};
-use std::net::Ipv4Addr;
+use std::net::{IpAddr, Ipv4Addr};
 use std::str::FromStr;
 
 use serde::{Deserialize, Serialize};
@@ -72,7 +72,8 @@ impl Routes {
         if let Some(config_routes) = self.config.as_ref() {
             for route in config_routes.iter() {
                 if !route.is_absent() {
-                    if !route.is_unicast()
+                    validate_route_type(route)?;
+                    if route.is_no_next_hop_type()
                         && (route.next_hop_iface.is_some()
                             && route.next_hop_iface
                                 != Some(LOOPBACK_IFACE_NAME.to_string())
@@ -87,7 +88,18 @@ impl Routes {
                             ),
                         ));
                     } else if route.next_hop_iface.is_none()
-                        && route.is_unicast()
+                        && route.next_hop_addr.is_none()
+                        && !route.is_no_next_hop_type()
+                    {
+                        return Err(NmstateError::new(
+                            ErrorKind::InvalidArgument,
+                            format!(
+                                "Route should have next hop interface or \
+                                next hop address defined: {route:?}"
+                            ),
+                        ));
+                    } else if route.next_hop_iface.is_none()
+                        && !route.is_no_next_hop_type()
                     {
                         return Err(NmstateError::new(
                             ErrorKind::NotImplementedError,
@@ -177,8 +189,29 @@ pub struct RouteEntry {
     pub weight: Option<u16>,
     /// Route type
     /// Serialize and deserialize to/from `route-type`.
+    /// Route type `Local` requires `next-hop-interface` and cannot have
+    /// `next-hop-address`.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub route_type: Option<RouteType>,
+    /// Route scope. IPv4 only.
+    /// When querying, only shown when different from the default scope of
+    /// kernel: `host` for `Local` route, `link` for route without
+    /// `next-hop-address` and `universe` for others.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub scope: Option<RouteScope>,
+    /// Pretend the next hop is directly attached to this link even it does
+    /// not match any interface prefix. Only valid with `next-hop-address`.
+    #[serde(
+        skip_serializing_if = "Option::is_none",
+        default,
+        deserialize_with = "crate::deserializer::option_bool_or_string"
+    )]
+    pub onlink: Option<bool>,
+    /// Preferred source address used when sending packets to the
+    /// destination of this route. Should be an IP address assigned to the
+    /// next hop interface and in the same IP family of destination.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
 }
 
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
@@ -189,6 +222,17 @@ pub enum RouteType {
     Blackhole,
     Unreachable,
     Prohibit,
+    /// Destination is assigned to this host.
+    Local,
+    /// Destination is a broadcast address. IPv4 only.
+    /// Only for querying, not supported for applying yet.
+    Broadcast,
+    /// Destination is an anycast address. IPv6 only.
+    /// Only for querying, not supported for applying yet.
+    Anycast,
+    /// Destination is a multicast address.
+    /// Only for querying, not supported for applying yet.
+    Multicast,
 }
 
 impl std::fmt::Display for RouteType {
@@ -200,12 +244,64 @@ impl std::fmt::Display for RouteType {
                 Self::Blackhole => "blackhole",
                 Self::Unreachable => "unreachable",
                 Self::Prohibit => "prohibit",
+                Self::Local => "local",
+                Self::Broadcast => "broadcast",
+                Self::Anycast => "anycast",
+                Self::Multicast => "multicast",
+            }
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub enum RouteScope {
+    /// Global route.
+    /// Deserialize and serialize from/to `universe`.
+    Universe,
+    /// Interior route within local autonomous system.
+    /// Deserialize and serialize from/to `site`.
+    Site,
+    /// Route to directly attached network.
+    /// Deserialize and serialize from/to `link`.
+    Link,
+    /// Route to local address.
+    /// Deserialize and serialize from/to `host`.
+    Host,
+}
+
+impl std::fmt::Display for RouteScope {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Self::Universe => "universe",
+                Self::Site => "site",
+                Self::Link => "link",
+                Self::Host => "host",
             }
         )
     }
 }
 
+impl From<RouteScope> for u8 {
+    fn from(v: RouteScope) -> u8 {
+        match v {
+            RouteScope::Universe => 0,
+            RouteScope::Site => 200,
+            RouteScope::Link => 253,
+            RouteScope::Host => 254,
+        }
+    }
+}
+
 const RTN_UNICAST: u8 = 1;
+const RTN_LOCAL: u8 = 2;
+const RTN_BROADCAST: u8 = 3;
+const RTN_ANYCAST: u8 = 4;
+const RTN_MULTICAST: u8 = 5;
 const RTN_BLACKHOLE: u8 = 6;
 const RTN_UNREACHABLE: u8 = 7;
 const RTN_PROHIBIT: u8 = 8;
@@ -216,6 +312,10 @@ impl From<RouteType> for u8 {
             RouteType::Blackhole => RTN_BLACKHOLE,
             RouteType::Unreachable => RTN_UNREACHABLE,
             RouteType::Prohibit => RTN_PROHIBIT,
+            RouteType::Local => RTN_LOCAL,
+            RouteType::Broadcast => RTN_BROADCAST,
+            RouteType::Anycast => RTN_ANYCAST,
+            RouteType::Multicast => RTN_MULTICAST,
         }
     }
 }
@@ -262,9 +362,37 @@ impl RouteEntry {
         if self.route_type.is_some() && self.route_type != other.route_type {
             return false;
         }
+        if self.source.is_some() && self.source != other.source {
+            return false;
+        }
+        if self.scope.is_some() && self.scope != Some(other.get_scope()) {
+            return false;
+        }
         true
     }
 
+    // The scope kernel uses when not defined.
+    pub(crate) fn default_scope(&self) -> RouteScope {
+        if self.route_type == Some(RouteType::Local) {
+            RouteScope::Host
+        } else if self.is_unicast()
+            && self
+                .next_hop_addr
+                .as_deref()
+                .and_then(|a| a.parse::<std::net::IpAddr>().ok())
+                .map(|a| a.is_unspecified())
+                .unwrap_or(true)
+        {
+            RouteScope::Link
+        } else {
+            RouteScope::Universe
+        }
+    }
+
+    fn get_scope(&self) -> RouteScope {
+        self.scope.unwrap_or_else(|| self.default_scope())
+    }
+
     // Return tuple of (no_absent, is_ipv4, table_id, next_hop_iface,
     // destination, next_hop_addr, weight)
     fn sort_key(&self) -> (bool, bool, u32, &str, &str, &str, u16) {
@@ -312,6 +440,59 @@ impl RouteEntry {
                 self.next_hop_addr = Some(new_via);
             }
         }
+        if let Some(src) = self.source.as_ref() {
+            let src_ip = match src.parse::<std::net::IpAddr>() {
+                Ok(i) => i,
+                Err(_) => {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Invalid route source address {src}, \
+                            should be an IP address"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            };
+            if let Some(dst) = self.destination.as_deref() {
+                if is_ipv6_addr(dst) != src_ip.is_ipv6() {
+                    let e = NmstateError::new(
+                        ErrorKind::InvalidArgument,
+                        format!(
+                            "Route {self} has source address in different \
+                            IP family of destination"
+                        ),
+                    );
+                    log::error!("{}", e);
+                    return Err(e);
+                }
+            }
+            let new_src = src_ip.to_string();
+            if src != &new_src {
+                log::warn!("Route source {} sanitized to {}", src, new_src);
+                self.source = Some(new_src);
+            }
+        }
+        if self.onlink == Some(true)
+            && !self.is_absent()
+            && self
+                .next_hop_addr
+                .as_deref()
+                .and_then(|a| a.parse::<std::net::IpAddr>().ok())
+                .map(|a| a.is_unspecified())
+                .unwrap_or(true)
+        {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Route {self} has onlink enabled but without \
+                    next-hop-address defined"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
         if let Some(weight) = self.weight {
             if !(1..=256).contains(&weight) {
                 return Err(NmstateError::new(
@@ -344,6 +525,16 @@ impl RouteEntry {
         self.route_type.is_none()
             || u8::from(self.route_type.unwrap()) == RTN_UNICAST
     }
+
+    // Route type which does not forward packet to any next hop.
+    pub(crate) fn is_no_next_hop_type(&self) -> bool {
+        matches!(
+            self.route_type,
+            Some(RouteType::Blackhole)
+                | Some(RouteType::Unreachable)
+                | Some(RouteType::Prohibit)
+        )
+    }
 }
 
 // For Vec::dedup()
@@ -394,6 +585,15 @@ impl std::fmt::Display for RouteEntry {
         if let Some(v) = self.weight {
             props.push(format!("weight: {v}"));
         }
+        if let Some(v) = self.onlink {
+            props.push(format!("onlink: {v}"));
+        }
+        if let Some(v) = self.source.as_ref() {
+            props.push(format!("source: {v}"));
+        }
+        if let Some(v) = self.scope.as_ref() {
+            props.push(format!("scope: {v}"));
+        }
 
         write!(f, "{}", props.join(" "))
     }
@@ -484,6 +684,7 @@ impl MergedRoutes {
                         ),
                     ));
                 }
+                validate_route_src(rt, via, merged_ifaces)?;
                 changed_ifaces.insert(via.as_str());
             } else if rt.route_type.is_some() {
                 changed_ifaces.insert(LOOPBACK_IFACE_NAME);
@@ -507,7 +708,12 @@ impl MergedRoutes {
             }
         }
 
-        let mut flattend_routes: Vec<RouteEntry> = Vec::new();
+        let mut flattend_routes: Vec<RouteEntry> = desired_routes
+            .as_slice()
+            .iter()
+            .filter(|rt| !rt.is_absent())
+            .cloned()
+            .collect();
 
         if let Some(cur_rts) = current.config.as_ref() {
             for rt in cur_rts {
@@ -535,16 +741,9 @@ impl MergedRoutes {
             }
         }
 
-        // Append desired routes
-        for rt in desired_routes
-            .as_slice()
-            .iter()
-            .filter(|rt| !rt.is_absent())
-        {
-            flattend_routes.push(rt.clone());
-        }
-
-        flattend_routes.sort_unstable();
+        // Stable sort is used, so dedup() will keep the desired route over
+        // the current one.
+        flattend_routes.sort();
         flattend_routes.dedup();
 
         let mut indexed: HashMap<String, Vec<RouteEntry>> = HashMap::new();
@@ -655,3 +854,104 @@ fn validate_route_dst(route: &RouteEntry) -> Result<(), NmstateError> {
     }
     Ok(())
 }
+
+// The route source address should be an address of next hop interface.
+// Skip the check when IP address of interface is dynamically assigned.
+fn validate_route_src(
+    route: &RouteEntry,
+    iface_name: &str,
+    merged_ifaces: &MergedInterfaces,
+) -> Result<(), NmstateError> {
+    let src_ip = match route
+        .source
+        .as_deref()
+        .and_then(|s| IpAddr::from_str(s).ok())
+    {
+        Some(i) => i,
+        None => return Ok(()),
+    };
+    let iface = match merged_ifaces.kernel_ifaces.get(iface_name) {
+        Some(i) => i,
+        None => return Ok(()),
+    };
+    let base_iface = iface.merged.base_iface();
+    let (is_auto, addrs) = if src_ip.is_ipv6() {
+        match base_iface.ipv6.as_ref() {
+            Some(ip_conf) => (ip_conf.is_auto(), ip_conf.addresses.as_ref()),
+            None => return Ok(()),
+        }
+    } else {
+        match base_iface.ipv4.as_ref() {
+            Some(ip_conf) => (ip_conf.is_auto(), ip_conf.addresses.as_ref()),
+            None => return Ok(()),
+        }
+    };
+    if is_auto
+        || addrs
+            .map(|addrs| addrs.iter().any(|a| a.ip == src_ip))
+            .unwrap_or_default()
+    {
+        Ok(())
+    } else {
+        let e = NmstateError::new(
+            ErrorKind::InvalidArgument,
+            format!(
+                "The source address of desired route '{route}' is not \
+                an IP address of next hop interface {iface_name}"
+            ),
+        );
+        log::error!("{}", e);
+        Err(e)
+    }
+}
+
+fn validate_route_type(route: &RouteEntry) -> Result<(), NmstateError> {
+    if let Some(scope) = route.scope {
+        if route.is_ipv6() {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!(
+                    "Route scope {scope} is only supported by IPv4 route: \
+                    {route}"
+                ),
+            );
+            log::error!("{}", e);
+            return Err(e);
+        }
+    }
+    match route.route_type {
+        Some(
+            t @ (RouteType::Broadcast
+            | RouteType::Anycast
+            | RouteType::Multicast),
+        ) => {
+            let e = NmstateError::new(
+                ErrorKind::NotSupportedError,
+                format!("Route type {t} is not supported yet: {route}"),
+            );
+            log::error!("{}", e);
+            Err(e)
+        }
+        Some(RouteType::Local) => {
+            let err_msg = if route.next_hop_iface.is_none() {
+                Some(format!(
+                    "Route type local requires next-hop-interface: {route}"
+                ))
+            } else if route.next_hop_addr.is_some() {
+                Some(format!(
+                    "Route type local cannot have next-hop-address: {route}"
+                ))
+            } else {
+                None
+            };
+            if let Some(err_msg) = err_msg {
+                let e = NmstateError::new(ErrorKind::InvalidArgument, err_msg);
+                log::error!("{}", e);
+                Err(e)
+            } else {
+                Ok(())
+            }
+        }
+        _ => Ok(()),
+    }
+}
@@ -0,0 +1,101 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde::Serialize;
+
+use crate::{Interface, InterfaceType, NetworkState};
+
+#[derive(Debug, Clone, PartialEq, Eq, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+/// Controller and port relationship of interfaces in a [NetworkState],
+/// generated by [NetworkState::topology()].
+pub struct NetworkTopology {
+    /// Controller interfaces with their ports, sorted by controller name.
+    pub controllers: Vec<ControllerTopology>,
+    /// Interfaces referring to a controller which does not exist in the
+    /// state, sorted by name.
+    pub orphan_ports: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+#[non_exhaustive]
+pub struct ControllerTopology {
+    /// Controller interface name.
+    pub name: String,
+    /// Controller interface type.
+    #[serde(rename = "type")]
+    pub iface_type: InterfaceType,
+    /// Sorted port names of this controller.
+    pub ports: Vec<String>,
+}
+
+impl NetworkState {
+    /// Generate the controller and port relationship of interfaces in this
+    /// state. Ports are resolved from the port list of controller and
+    /// the `controller` and `controller-type` properties of port. Absent
+    /// interfaces are ignored.
+    pub fn topology(&self) -> NetworkTopology {
+        let mut ret = NetworkTopology::default();
+        let ifaces: Vec<&Interface> =
+            self.interfaces.iter().filter(|i| !i.is_absent()).collect();
+
+        for ctrl_iface in ifaces.iter().filter(|i| i.is_controller()) {
+            let mut ports: Vec<String> = ctrl_iface
+                .ports()
+                .unwrap_or_default()
+                .into_iter()
+                .map(|p| p.to_string())
+                .collect();
+            for port_iface in
+                ifaces.iter().filter(|i| is_port_of(i, ctrl_iface))
+            {
+                if !ports.iter().any(|p| p == port_iface.name()) {
+                    ports.push(port_iface.name().to_string());
+                }
+            }
+            ports.sort_unstable();
+            ret.controllers.push(ControllerTopology {
+                name: ctrl_iface.name().to_string(),
+                iface_type: ctrl_iface.iface_type(),
+                ports,
+            });
+        }
+        ret.controllers.sort_unstable_by(|a, b| {
+            (a.name.as_str(), a.iface_type.to_string())
+                .cmp(&(b.name.as_str(), b.iface_type.to_string()))
+        });
+
+        for iface in ifaces.iter() {
+            if let Some(ctrl_name) = iface
+                .base_iface()
+                .controller
+                .as_deref()
+                .filter(|c| !c.is_empty())
+            {
+                if !ifaces
+                    .iter()
+                    .any(|c| c.is_controller() && is_port_of(iface, c))
+                {
+                    log::debug!(
+                        "Interface {} has no controller {ctrl_name} in state",
+                        iface.name()
+                    );
+                    ret.orphan_ports.push(iface.name().to_string());
+                }
+            }
+        }
+        ret.orphan_ports.sort_unstable();
+        ret
+    }
+}
+
+fn is_port_of(iface: &Interface, ctrl_iface: &Interface) -> bool {
+    iface.base_iface().controller.as_deref() == Some(ctrl_iface.name())
+        && iface
+            .base_iface()
+            .controller_type
+            .as_ref()
+            .map(|t| t == &ctrl_iface.iface_type())
+            .unwrap_or(true)
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::BaseInterface;
+use crate::{BaseInterface, ErrorKind};
 
 #[test]
 fn test_base_iface_stringlized_attributes() {
@@ -47,3 +47,80 @@ fn test_base_iface_serialize_copy_mac_from() {
 
     assert_eq!(desired, new);
 }
+
+#[test]
+fn test_base_iface_invalid_sysctl_key() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"
+name: "eth1"
+sysctl:
+  net.ipv4.conf.eth1.arp_ignore: 1
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_base_iface_bind_by_glob_without_pattern() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"
+name: "uplink"
+bind-by: match-glob
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_base_iface_invalid_static_neighbor_mac() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"
+name: "eth1"
+static-neighbors:
+- ip: 192.0.2.1
+  mac-address: 00:23:45:67:89
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_base_iface_nm_flags_external_with_volatile() {
+    let mut iface: BaseInterface = serde_yaml::from_str(
+        r#"
+name: "eth1"
+nm-flags:
+- volatile
+- external
+"#,
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
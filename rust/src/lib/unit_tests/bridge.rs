@@ -719,3 +719,66 @@ fn test_bridge_sanitize_group_forward_mask_and_group_fwd_mask() {
     assert_eq!(desired_old, expected);
     assert_eq!(desired_new, expected);
 }
+
+#[test]
+fn test_linux_bridge_invalid_group_addr() {
+    let mut desired: LinuxBridgeInterface = serde_yaml::from_str(
+        r"
+        name: br0
+        type: linux-bridge
+        state: up
+        bridge:
+          options:
+            group-addr: 01:80:C2:00:00:02
+        ",
+    )
+    .unwrap();
+
+    let result = desired.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_linux_bridge_verify_autoconnect_ports() {
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: br0
+  type: linux-bridge
+  state: up
+  bridge:
+    port: []
+",
+    )
+    .unwrap();
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: br0
+  type: linux-bridge
+  state: up
+  autoconnect-ports: true
+",
+    )
+    .unwrap();
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, cur_ifaces.clone(), false, false)
+            .unwrap();
+    merged_ifaces.verify(&cur_ifaces).unwrap();
+
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: br0
+  type: linux-bridge
+  state: up
+  autoconnect-ports: false
+",
+    )
+    .unwrap();
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, cur_ifaces.clone(), false, false)
+            .unwrap();
+    assert!(merged_ifaces.verify(&cur_ifaces).is_err());
+}
@@ -198,3 +198,51 @@ fn test_new_veth_without_peer_config() {
         assert!(e.msg().contains("Veth interface veth1 does not exist"));
     }
 }
+
+#[test]
+fn test_s390_qeth_with_two_subchannels() {
+    let mut iface: EthernetInterface = serde_yaml::from_str(
+        r"---
+name: enc600
+type: ethernet
+state: up
+ethernet:
+  s390:
+    subchannels:
+    - 0.0.0600
+    - 0.0.0601
+    nettype: qeth
+",
+    )
+    .unwrap();
+
+    let result = iface.sanitize();
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_s390_invalid_subchannel() {
+    let mut iface: EthernetInterface = serde_yaml::from_str(
+        r"---
+name: enc600
+type: ethernet
+state: up
+ethernet:
+  s390:
+    subchannels:
+    - 0.0.600
+    - 0.0.0601
+    - 0.0.0602
+",
+    )
+    .unwrap();
+
+    let result = iface.sanitize();
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{EthernetInterface, EthtoolFeatureConfig};
+use crate::{ErrorKind, EthernetInterface, EthtoolFeatureConfig};
 
 #[test]
 fn test_ethtool_stringlized_attributes() {
@@ -127,3 +127,24 @@ fn test_ethtool_sort_features_when_serialize() {
     let yml_out = serde_yaml::to_string(&features).unwrap();
     assert_eq!(yml_out, "a: true\nb: true\nc: true\n");
 }
+
+#[test]
+fn test_ethtool_zero_gso_max_size() {
+    let mut iface: EthernetInterface = serde_yaml::from_str(
+        r"---
+name: eth1
+type: ethernet
+state: up
+ethtool:
+  gso-max-size: 0
+",
+    )
+    .unwrap();
+
+    let result = iface.base.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::NetworkState;
+use crate::{DnsClientState, Interface, NetworkState};
 
 const TEST_DATA_FOLDER_PATH: &str = "unit_tests/gen_revert_test_files";
 const DESIRED_FILE_NAME: &str = "desired.yml";
@@ -45,3 +45,43 @@ fn load_state(file_path: &std::path::Path) -> NetworkState {
         }
     }
 }
+
+#[test]
+fn test_gen_revert_iface_static_dns_to_dhcp() {
+    let mut current: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv4:
+          enabled: true
+          dhcp: false
+          address:
+          - ip: 192.0.2.251
+            prefix-length: 24",
+    )
+    .unwrap();
+    let mut dns = DnsClientState::new();
+    dns.server = Some(vec!["192.0.2.53".to_string()]);
+    dns.search = Some(vec!["example.org".to_string()]);
+    if let Some(ipv4) = current.base_iface_mut().ipv4.as_mut() {
+        ipv4.dns = Some(dns.clone());
+    }
+    let desired: Interface = serde_yaml::from_str(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ipv4:
+          enabled: true
+          dhcp: true
+          auto-dns: true",
+    )
+    .unwrap();
+
+    let revert = desired.generate_revert(&current).unwrap();
+
+    let revert_ipv4 = revert.base_iface().ipv4.as_ref().unwrap();
+    assert_eq!(revert_ipv4.dhcp, Some(false));
+    assert_eq!(revert_ipv4.dns, Some(dns));
+}
@@ -368,3 +368,35 @@ fn test_unknown_iface_type() {
         "foo_type\n"
     );
 }
+
+#[test]
+fn test_dummy_anycast_address_collide_with_other_iface() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: dummy0
+          type: dummy
+          state: up
+          ipv4:
+            enabled: true
+            address:
+            - ip: 192.0.2.1
+              prefix-length: 32
+        - name: eth1
+          type: ethernet
+          state: up
+          ipv4:
+            enabled: true
+            address:
+            - ip: 192.0.2.1
+              prefix-length: 24",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(desired, Interfaces::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), crate::ErrorKind::InvalidArgument);
+    }
+}
@@ -942,3 +942,31 @@ fn test_gen_topoligies_ovs_bridge() {
         .join(" -> ")]
     );
 }
+
+#[test]
+fn test_ifaces_controller_loop_two_nodes() {
+    let ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: bond0
+          type: bond
+          state: up
+          controller: bond1
+          link-aggregation:
+            mode: active-backup
+        - name: bond1
+          type: bond
+          state: up
+          controller: bond0
+          link-aggregation:
+            mode: active-backup",
+    )
+    .unwrap();
+
+    let result = MergedInterfaces::new(ifaces, Interfaces::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("bond0 -> bond1 -> bond0"));
+    }
+}
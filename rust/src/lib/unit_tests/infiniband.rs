@@ -2,7 +2,7 @@
 
 use crate::{
     BondMode, ErrorKind, InfiniBandInterface, Interface, InterfaceType,
-    Interfaces, MergedInterfaces,
+    Interfaces, MergedInterfaces, NetworkState,
 };
 
 #[test]
@@ -374,3 +374,177 @@ fn test_ib_port_of_active_backup_bond_mode_in_desire() {
         Some(&InterfaceType::Bond)
     );
 }
+
+#[test]
+fn test_ib_sriov_accept_queried_vf_defaults() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: mlx5_ib0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+    sr-iov:
+      total-vfs: 1
+      vfs:
+      - id: 0
+        mac-address: 00:00:00:00:00:00
+        spoof-check: false
+        trust: true
+        vlan-id: 0
+        qos: 0
+        vlan-proto: 802.1q
+"#,
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, Interfaces::new(), false, false)
+            .unwrap();
+
+    let iface = merged_ifaces
+        .get_iface("mlx5_ib0", InterfaceType::InfiniBand)
+        .unwrap()
+        .for_apply
+        .as_ref()
+        .unwrap();
+    if let Interface::InfiniBand(iface) = iface {
+        let vfs = iface
+            .ib
+            .as_ref()
+            .and_then(|i| i.sr_iov.as_ref())
+            .and_then(|s| s.vfs.as_ref())
+            .unwrap();
+        assert_eq!(vfs[0].mac_address, None);
+        assert_eq!(vfs[0].vlan_proto, None);
+        assert_eq!(vfs[0].trust, Some(true));
+    } else {
+        panic!("Expecting InfiniBand interface, got {iface:?}");
+    }
+}
+
+#[test]
+fn test_ib_sriov_auto_fill_vf_and_verify() {
+    let current: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: mlx5_ib0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+    sr-iov:
+      total-vfs: 2
+      vfs:
+      - id: 0
+        iface-name: mlx5_ib0v0
+        trust: false
+      - id: 1
+        iface-name: mlx5_ib0v1
+        trust: true
+- name: mlx5_ib0v0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+- name: mlx5_ib0v1
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+"#,
+    )
+    .unwrap();
+    let desired: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: mlx5_ib0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+    sr-iov:
+      vfs:
+      - id: 1
+        trust: true
+"#,
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, current.clone(), false, false).unwrap();
+
+    merged_ifaces.verify(&current).unwrap();
+
+    let iface = merged_ifaces
+        .get_iface("mlx5_ib0", InterfaceType::InfiniBand)
+        .unwrap()
+        .for_apply
+        .as_ref()
+        .unwrap();
+    if let Interface::InfiniBand(iface) = iface {
+        let sriov_conf =
+            iface.ib.as_ref().and_then(|i| i.sr_iov.as_ref()).unwrap();
+        assert_eq!(sriov_conf.total_vfs, Some(2));
+        assert_eq!(sriov_conf.vfs.as_ref().map(Vec::len), Some(2));
+    } else {
+        panic!("Expecting InfiniBand interface, got {iface:?}");
+    }
+}
+
+#[test]
+fn test_ib_sriov_enable_and_use_in_single_yaml() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r#"---
+interfaces:
+- name: mlx5_ib0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+    sr-iov:
+      total-vfs: 2
+- name: mlx5_ib0v0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+"#,
+    )
+    .unwrap();
+    let current: NetworkState = serde_yaml::from_str(
+        r#"---
+interfaces:
+- name: mlx5_ib0
+  type: infiniband
+  state: up
+  infiniband:
+    pkey: "0xffff"
+    mode: "datagram"
+"#,
+    )
+    .unwrap();
+
+    assert!(desired.has_sriov_and_missing_eth(&current));
+
+    let pf_state = desired.get_sriov_pf_conf_state().unwrap();
+    if let Some(Interface::InfiniBand(pf_iface)) =
+        pf_state.interfaces.kernel_ifaces.get("mlx5_ib0")
+    {
+        assert_eq!(
+            pf_iface
+                .ib
+                .as_ref()
+                .and_then(|i| i.sr_iov.as_ref())
+                .and_then(|s| s.total_vfs),
+            Some(2)
+        );
+    } else {
+        panic!("Expecting InfiniBand interface, got {pf_state:?}");
+    }
+}
@@ -377,6 +377,69 @@ fn test_ipv6_verify_valid_prefix() {
     assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
 }
 
+#[test]
+fn test_ipv4_invalid_dad_timeout() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+            - name: eth1
+              type: ethernet
+              state: up
+              ipv4:
+                enabled: true
+                dad-timeout: -2
+                address:
+                - ip: 192.0.2.251
+                  prefix-length: 24",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_ipv6_negative_dad_transmits() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+            - name: eth1
+              type: ethernet
+              state: up
+              ipv6:
+                enabled: true
+                dad-transmits: -1",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_ipv6_invalid_accept_ra() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+            - name: eth1
+              type: ethernet
+              state: up
+              ipv6:
+                enabled: true
+                accept-ra: 3",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, gen_test_eth_ifaces(), false, false);
+
+    assert!(result.is_err());
+    assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidArgument);
+}
+
 #[test]
 fn test_sanitize_ip_network_empty_str() {
     let result = sanitize_ip_network("");
@@ -566,3 +629,71 @@ fn test_ip_serlize_allow_extra_address() {
 
     assert_eq!(desired, new);
 }
+
+#[test]
+fn test_ipv6_addr_gen_mode_default_verify_with_fallback() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    addr-gen-mode: default
+"#,
+    )
+    .unwrap();
+    let current: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    addr-gen-mode: stable-privacy
+"#,
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, gen_test_eth_ifaces(), false, false)
+            .unwrap();
+
+    merged_ifaces.verify(&current).unwrap();
+}
+
+#[test]
+fn test_ipv6_addr_gen_mode_default_or_eui64_verify_failure() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    addr-gen-mode: default-or-eui64
+"#,
+    )
+    .unwrap();
+    let current: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: eth1
+  type: ethernet
+  state: up
+  ipv6:
+    enabled: true
+    addr-gen-mode: stable-privacy
+"#,
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(desired, gen_test_eth_ifaces(), false, false)
+            .unwrap();
+
+    let result = merged_ifaces.verify(&current);
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::VerificationError);
+    }
+}
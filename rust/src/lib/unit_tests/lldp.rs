@@ -1,6 +1,10 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::LldpConfig;
+use crate::{
+    ErrorKind, LldpChassisId, LldpChassisIdType, LldpConfig, LldpNeighborTlv,
+    LldpPortId, LldpPortIdType, LldpSystemName, MergedNetworkState,
+    NetworkState,
+};
 
 #[test]
 fn test_lldp_stringlized_attributes() {
@@ -33,3 +37,57 @@ fn test_lldp_stringlized_attributes() {
         assert!(!conf.enabled);
     }
 }
+
+#[test]
+fn test_lldp_get_neighbors() {
+    let conf = LldpConfig {
+        enabled: true,
+        neighbors: vec![vec![
+            LldpNeighborTlv::ChassisId(LldpChassisId::new(
+                "00:23:ac:00:00:01".to_string(),
+                LldpChassisIdType::MacAddress,
+            )),
+            LldpNeighborTlv::PortId(LldpPortId::new(
+                "Ethernet1".to_string(),
+                LldpPortIdType::InterfaceName,
+            )),
+            LldpNeighborTlv::SystemName(LldpSystemName::new(
+                "sw01".to_string(),
+            )),
+        ]],
+    };
+
+    let neighbors = conf.get_neighbors();
+
+    assert_eq!(neighbors.len(), 1);
+    assert_eq!(
+        neighbors[0].chassis_id.as_deref(),
+        Some("00:23:ac:00:00:01")
+    );
+    assert_eq!(neighbors[0].port_id.as_deref(), Some("Ethernet1"));
+    assert_eq!(neighbors[0].system_name.as_deref(), Some("sw01"));
+    assert_eq!(neighbors[0].system_description, None);
+}
+
+#[test]
+fn test_gather_lldp_neighbors_with_lldp_disabled() {
+    let mut desired: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+          lldp:
+            enabled: false",
+    )
+    .unwrap();
+    desired.set_gather_lldp_neighbors(true);
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
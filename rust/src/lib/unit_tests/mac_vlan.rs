@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::MacVlanInterface;
+use crate::{ErrorKind, MacVlanInterface, MergedNetworkState, NetworkState};
 
 #[test]
 fn test_mac_vlan_stringlized_attributes() {
@@ -20,3 +20,36 @@ mac-vlan:
     let mac_conf = iface.mac_vlan.unwrap();
     assert_eq!(mac_conf.accept_all_mac, Some(true));
 }
+
+#[test]
+fn test_mac_vlan_over_ovs_port_is_rejected() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: eth1
+  type: ethernet
+  state: up
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth1
+- name: mac0
+  type: mac-vlan
+  state: up
+  mac-vlan:
+    base-iface: eth1
+    mode: bridge
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
@@ -53,6 +53,8 @@ mod statistic;
 #[cfg(test)]
 mod testlib;
 #[cfg(test)]
+mod topology;
+#[cfg(test)]
 mod vlan;
 #[cfg(test)]
 mod vrf;
@@ -1,6 +1,6 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::NetworkState;
+use crate::{ErrorKind, NetworkState};
 
 #[test]
 fn test_invalid_top_key() {
@@ -23,3 +23,124 @@ fn test_invalid_top_type() {
 
     assert!(result.is_err());
 }
+
+#[test]
+fn test_versioned_json_round_trip() {
+    let net_state: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: bond0
+  type: bond
+  state: up
+  link-aggregation:
+    mode: balance-rr
+    port:
+    - eth1
+    - eth2
+",
+    )
+    .unwrap();
+
+    let json_str = net_state.to_versioned_json().unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json_str).unwrap();
+
+    assert_eq!(
+        value.get("schema-version").and_then(|v| v.as_u64()),
+        Some(NetworkState::SCHEMA_VERSION.into())
+    );
+    assert_eq!(
+        NetworkState::from_versioned_json(&json_str).unwrap(),
+        net_state
+    );
+}
+
+#[test]
+fn test_versioned_json_migrate_from_v1() {
+    let expected: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: bond0
+  type: bond
+  state: up
+  link-aggregation:
+    mode: balance-rr
+    port:
+    - eth1
+    - eth2
+",
+    )
+    .unwrap();
+
+    let net_state = NetworkState::from_versioned_json(
+        r#"{
+            "schema-version": 1,
+            "interfaces": [{
+                "name": "bond0",
+                "type": "bond",
+                "state": "up",
+                "link-aggregation": {
+                    "mode": "balance-rr",
+                    "slaves": ["eth1", "eth2"]
+                }
+            }]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(net_state, expected);
+}
+
+#[test]
+fn test_versioned_json_unsupported_version() {
+    let result = NetworkState::from_versioned_json(&format!(
+        r#"{{"schema-version": {}}}"#,
+        NetworkState::SCHEMA_VERSION + 1
+    ));
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::NotSupportedError);
+    }
+}
+
+#[test]
+fn test_prune_empty_interfaces() {
+    let mut net_state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: eth1
+          type: ethernet
+          state: up
+        - name: eth2
+          type: ethernet
+          state: up
+          ipv4:
+            enabled: false
+        - name: eth3
+          type: ethernet
+          state: down
+        - name: eth4
+          type: ethernet
+          state: up
+          controller: br0
+        - name: br0
+          type: linux-bridge
+          state: up
+          bridge:
+            port:
+            - name: eth4",
+    )
+    .unwrap();
+
+    net_state.prune_empty();
+
+    let iface_names: Vec<&str> =
+        net_state.interfaces.iter().map(|i| i.name()).collect();
+    assert_eq!(net_state.interfaces.to_vec().len(), 4);
+    assert!(!iface_names.contains(&"eth1"));
+    assert!(net_state
+        .interfaces
+        .insert_order
+        .iter()
+        .all(|(n, _)| n != "eth1"));
+}
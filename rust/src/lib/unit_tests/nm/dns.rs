@@ -386,3 +386,96 @@ fn test_copy_ip_stack_if_marked_for_dns() {
     assert!(iface.base_iface().ipv6.is_some());
     assert!(iface.base_iface().ipv4.is_some());
 }
+
+#[test]
+fn test_dns_interleaved_ipv4_ipv6_servers_order() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+        dns-resolver:
+          config:
+            search:
+            - example.com
+            server:
+            - 192.0.2.1
+            - 2001:db8:1::1
+            - 192.0.2.2
+        interfaces:
+          - name: dummy0
+            type: dummy
+            state: up
+            ipv4:
+              address:
+              - ip: 192.0.2.251
+                prefix-length: 24
+              dhcp: false
+              enabled: true
+            ipv6:
+              address:
+              - ip: 2001:db8:1::251
+                prefix-length: 64
+              dhcp: false
+              autoconf: false
+              enabled: true
+          - name: dummy1
+            type: dummy
+            state: up
+            ipv4:
+              address:
+              - ip: 198.51.100.251
+                prefix-length: 24
+              dhcp: false
+              enabled: true",
+    )
+    .unwrap();
+
+    let mut merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), false, false)
+            .unwrap();
+
+    store_dns_config_to_iface(&mut merged_state, &[], &[]).unwrap();
+
+    let get_dns_conf = |iface_name: &str, is_ipv6: bool| {
+        let base_iface = merged_state
+            .interfaces
+            .kernel_ifaces
+            .get(iface_name)
+            .unwrap()
+            .for_apply
+            .as_ref()
+            .unwrap()
+            .base_iface();
+        if is_ipv6 {
+            base_iface.ipv6.as_ref().unwrap().dns.clone().unwrap()
+        } else {
+            base_iface.ipv4.as_ref().unwrap().dns.clone().unwrap()
+        }
+    };
+    let mut dns_confs = vec![
+        get_dns_conf("dummy0", false),
+        get_dns_conf("dummy0", true),
+        get_dns_conf("dummy1", false),
+    ];
+
+    assert_eq!(dns_confs[0].server, Some(vec!["192.0.2.1".to_string()]));
+    assert_eq!(dns_confs[0].search, Some(vec!["example.com".to_string()]));
+    assert_eq!(dns_confs[0].priority, Some(40));
+    assert_eq!(dns_confs[1].server, Some(vec!["2001:db8:1::1".to_string()]));
+    assert_eq!(dns_confs[1].search, Some(Vec::new()));
+    assert_eq!(dns_confs[1].priority, Some(50));
+    assert_eq!(dns_confs[2].server, Some(vec!["192.0.2.2".to_string()]));
+    assert_eq!(dns_confs[2].priority, Some(60));
+
+    dns_confs.sort_unstable_by_key(|d| d.priority.unwrap_or_default());
+    let srvs: Vec<String> = dns_confs
+        .into_iter()
+        .flat_map(|d| d.server.unwrap_or_default())
+        .collect();
+    assert_eq!(
+        srvs,
+        vec![
+            "192.0.2.1".to_string(),
+            "2001:db8:1::1".to_string(),
+            "192.0.2.2".to_string()
+        ]
+    );
+}
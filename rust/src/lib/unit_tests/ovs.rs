@@ -554,6 +554,50 @@ fn test_validate_dpdk_n_rxq_desc() {
     }
 }
 
+#[test]
+fn test_validate_ovs_iface_zero_mtu_request() {
+    let desired: OvsInterface = serde_yaml::from_str(
+        r"
+        name: ovs0
+        type: ovs-interface
+        state: up
+        mtu-request: 0
+        ",
+    )
+    .unwrap();
+
+    let result = desired.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_validate_dpdk_pmd_rxq_affinity() {
+    let desired: OvsInterface = serde_yaml::from_str(
+        r"
+        name: ovs0
+        type: ovs-interface
+        state: up
+        dpdk:
+          devargs: 0000:af:00.1
+          rx-queue: 2
+          pmd-rxq-affinity:
+            2: 3
+        ",
+    )
+    .unwrap();
+
+    let result = desired.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
 #[test]
 fn test_validate_dpdk_n_txq_desc() {
     let desired: OvsInterface = serde_yaml::from_str(
@@ -924,3 +968,293 @@ fn test_ovs_iface_serialize_allow_extra_patch_ports() {
 
     assert_eq!(desired, new);
 }
+
+#[test]
+fn test_ovs_bridge_invalid_controller() {
+    let mut iface: OvsBridgeInterface = serde_yaml::from_str(
+        r"---
+        name: br0
+        type: ovs-bridge
+        state: up
+        bridge:
+          options:
+            controller: 192.0.2.1:6653",
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ovs_bridge_controller() {
+    let mut iface: OvsBridgeInterface = serde_yaml::from_str(
+        r"---
+        name: br0
+        type: ovs-bridge
+        state: up
+        bridge:
+          options:
+            fail-mode: secure
+            controller: tcp:192.0.2.1:6653",
+    )
+    .unwrap();
+
+    iface.sanitize(true).unwrap();
+
+    let opts = iface.bridge.as_ref().unwrap().options.as_ref().unwrap();
+    assert_eq!(opts.controller.as_deref(), Some("tcp:192.0.2.1:6653"));
+    assert_eq!(opts.fail_mode.as_deref(), Some("secure"));
+}
+
+#[test]
+fn test_ovs_bridge_datapath_id_and_protocols() {
+    let mut iface: OvsBridgeInterface = serde_yaml::from_str(
+        r"---
+        name: br0
+        type: ovs-bridge
+        state: up
+        bridge:
+          options:
+            datapath-id: 00000000000000a1
+            protocols:
+            - OpenFlow13",
+    )
+    .unwrap();
+
+    iface.sanitize(true).unwrap();
+
+    let opts = iface.bridge.as_ref().unwrap().options.as_ref().unwrap();
+    assert_eq!(opts.datapath_id.as_deref(), Some("00000000000000a1"));
+    assert_eq!(opts.protocols, Some(vec!["OpenFlow13".to_string()]));
+}
+
+#[test]
+fn test_ovs_bridge_invalid_datapath_id() {
+    let mut iface: OvsBridgeInterface = serde_yaml::from_str(
+        r"---
+        name: br0
+        type: ovs-bridge
+        state: up
+        bridge:
+          options:
+            datapath-id: '00a1'",
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_ovs_bridge_invalid_protocols() {
+    let mut iface: OvsBridgeInterface = serde_yaml::from_str(
+        r"---
+        name: br0
+        type: ovs-bridge
+        state: up
+        bridge:
+          options:
+            protocols:
+            - OpenFlow13
+            - OpenFlow16",
+    )
+    .unwrap();
+
+    let result = iface.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_orphan_ovs_iface() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: ovs0
+  type: ovs-interface
+  state: up
+  mtu: 1400
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: ovs0
+  type: ovs-interface
+  state: up
+  controller: br9
+  mtu: 1500
+",
+    )
+    .unwrap();
+
+    let result = MergedInterfaces::new(des_ifaces, cur_ifaces, false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("br9"));
+    }
+}
+
+#[test]
+fn test_ovs_iface_without_controller() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: ovs0
+  type: ovs-interface
+  state: up
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, Interfaces::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_vlan_iface_as_ovs_bridge_port() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth1.101
+- name: eth1.101
+  type: vlan
+  state: up
+  vlan:
+    base-iface: eth1
+    id: 101
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+
+    let result = MergedInterfaces::new(des_ifaces, cur_ifaces, false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("tag"));
+    }
+}
+
+#[test]
+fn test_existing_vlan_iface_as_ovs_bridge_port() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth1.101
+    - name: eth2
+- name: eth1.101
+  type: vlan
+  state: up
+  mtu: 1400
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+- name: eth2
+  type: ethernet
+  state: up
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    port:
+    - name: eth1.101
+- name: eth1.101
+  type: vlan
+  state: up
+  vlan:
+    base-iface: eth1
+    id: 101
+",
+    )
+    .unwrap();
+
+    MergedInterfaces::new(des_ifaces, cur_ifaces, false, false).unwrap();
+}
+
+#[test]
+fn test_ovs_bridge_verify_removed_openflow_options() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r#"---
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    options:
+      controller: ""
+      datapath-id: ""
+      protocols: []
+    port:
+    - name: eth1
+"#,
+    )
+    .unwrap();
+    let pre_apply_cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+",
+    )
+    .unwrap();
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+- name: eth1
+  type: ethernet
+  state: up
+- name: br0
+  type: ovs-bridge
+  state: up
+  bridge:
+    options:
+      stp: false
+    port:
+    - name: eth1
+",
+    )
+    .unwrap();
+
+    let merged_ifaces =
+        MergedInterfaces::new(des_ifaces, pre_apply_cur_ifaces, false, false)
+            .unwrap();
+
+    merged_ifaces.verify(&cur_ifaces).unwrap();
+}
@@ -1,6 +1,9 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{MergedOvsDbGlobalConfig, NetworkState, OvsDbGlobalConfig};
+use crate::{
+    ovsdb::{gen_bridge_controller_ops, gen_bridge_protocols_op},
+    MergedOvsDbGlobalConfig, NetworkState, OvsDbGlobalConfig,
+};
 
 fn get_current_ovsdb_config() -> OvsDbGlobalConfig {
     serde_yaml::from_str(
@@ -218,3 +221,58 @@ fn test_ovsdb_purge_by_empty_hash() {
 
     assert!(desired.ovsdb.unwrap().is_purge());
 }
+
+#[test]
+fn test_ovsdb_set_bridge_controller() {
+    let ops = gen_bridge_controller_ops("br0", "tcp:192.0.2.1:6653");
+
+    assert_eq!(
+        serde_json::Value::Array(ops),
+        serde_json::json!([
+            {
+                "op": "insert",
+                "table": "Controller",
+                "row": {"target": "tcp:192.0.2.1:6653"},
+                "uuid-name": "nmstate_controller",
+            },
+            {
+                "op": "update",
+                "table": "Bridge",
+                "where": [["name", "==", "br0"]],
+                "row": {"controller": ["named-uuid", "nmstate_controller"]},
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_ovsdb_remove_bridge_controller() {
+    let ops = gen_bridge_controller_ops("br0", "");
+
+    assert_eq!(
+        serde_json::Value::Array(ops),
+        serde_json::json!([
+            {
+                "op": "update",
+                "table": "Bridge",
+                "where": [["name", "==", "br0"]],
+                "row": {"controller": ["set", []]},
+            },
+        ])
+    );
+}
+
+#[test]
+fn test_ovsdb_set_bridge_protocols() {
+    let op = gen_bridge_protocols_op("br0", &["OpenFlow13".to_string()]);
+
+    assert_eq!(
+        op,
+        serde_json::json!({
+            "op": "update",
+            "table": "Bridge",
+            "where": [["name", "==", "br0"]],
+            "row": {"protocols": ["set", ["OpenFlow13"]]},
+        })
+    );
+}
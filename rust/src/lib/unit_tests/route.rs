@@ -296,6 +296,77 @@ fn test_route_sanitize_ipv6_ecmp() {
     assert_eq!(result.err().unwrap().kind(), ErrorKind::NotSupportedError);
 }
 
+#[test]
+fn test_route_sanitize_onlink_without_gateway() {
+    let mut route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 198.51.100.0/24
+        next-hop-interface: eth1
+        onlink: true
+        ",
+    )
+    .unwrap();
+    let result = route.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_source_on_iface() {
+    let des_routes: Routes = serde_yaml::from_str(
+        r"
+        config:
+        - destination: 198.51.100.0/24
+          next-hop-address: 192.0.2.1
+          next-hop-interface: eth1
+          source: 192.0.2.251
+        ",
+    )
+    .unwrap();
+    let merged_ifaces = gen_merged_ifaces_for_route_test();
+
+    let merged_routes =
+        MergedRoutes::new(des_routes, Routes::new(), &merged_ifaces).unwrap();
+
+    let config_routes = merged_routes.indexed.get("eth1").unwrap();
+    assert_eq!(config_routes.len(), 1);
+    assert_eq!(config_routes[0].source.as_deref(), Some("192.0.2.251"));
+}
+
+#[test]
+fn test_route_source_not_on_iface() {
+    let des_routes: Routes = serde_yaml::from_str(
+        r"
+        config:
+        - destination: 198.51.100.0/24
+          next-hop-address: 192.0.2.1
+          next-hop-interface: eth1
+          source: 192.0.2.253
+        ",
+    )
+    .unwrap();
+    let merged_ifaces = gen_merged_ifaces_for_route_test();
+
+    let result = MergedRoutes::new(des_routes, Routes::new(), &merged_ifaces);
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_sanitize_source_family_mismatch() {
+    let mut route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 198.51.100.0/24
+        next-hop-interface: eth1
+        source: 2001:db8:1::1
+        ",
+    )
+    .unwrap();
+    let result = route.sanitize();
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().kind(), ErrorKind::InvalidArgument);
+}
+
 #[test]
 fn test_route_ipv4_ecmp_is_match() {
     let absent_route: RouteEntry = serde_yaml::from_str(
@@ -406,6 +477,33 @@ config:
     assert_eq!(result.err().unwrap().kind(), ErrorKind::InvalidArgument);
 }
 
+#[test]
+fn test_route_without_next_hop() {
+    let routes: Routes = serde_yaml::from_str(
+        r"
+config:
+- destination: 198.51.100.0/24
+",
+    )
+    .unwrap();
+    let result = routes.validate();
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_with_next_hop_iface_only() {
+    let routes: Routes = serde_yaml::from_str(
+        r"
+config:
+- destination: 198.51.100.0/24
+  next-hop-interface: eth1
+",
+    )
+    .unwrap();
+    routes.validate().unwrap();
+}
+
 #[test]
 fn test_route_matching_empty_via_with_none() {
     let absent_route: RouteEntry = serde_yaml::from_str(
@@ -432,3 +530,100 @@ fn test_route_matching_empty_via_with_none() {
     assert!(!absent_route.is_match(&not_match_route));
     assert!(!absent_route.is_match(&match_route));
 }
+
+#[test]
+fn test_route_multicast_not_supported() {
+    let routes: Routes = serde_yaml::from_str(
+        r"
+config:
+- destination: 224.0.0.0/24
+  next-hop-interface: eth1
+  route-type: multicast
+  scope: link
+",
+    )
+    .unwrap();
+    let result = routes.validate();
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().kind(), ErrorKind::NotSupportedError);
+}
+
+#[test]
+fn test_route_local_with_host_scope() {
+    let routes: Routes = serde_yaml::from_str(
+        r"
+config:
+- destination: 198.51.100.1/32
+  next-hop-interface: eth1
+  route-type: local
+  scope: host
+",
+    )
+    .unwrap();
+    routes.validate().unwrap();
+}
+
+#[test]
+fn test_route_ipv6_with_scope() {
+    let routes: Routes = serde_yaml::from_str(
+        r"
+config:
+- destination: 2001:db8:1::/64
+  next-hop-interface: eth1
+  scope: link
+",
+    )
+    .unwrap();
+    let result = routes.validate();
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().kind(), ErrorKind::InvalidArgument);
+}
+
+#[test]
+fn test_route_scope_matching_default_scope() {
+    let desired: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 198.51.100.1/32
+        next-hop-interface: eth1
+        route-type: local
+        scope: host
+        ",
+    )
+    .unwrap();
+    let current: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 198.51.100.1/32
+        next-hop-interface: eth1
+        route-type: local
+        ",
+    )
+    .unwrap();
+    let link_scope_route: RouteEntry = serde_yaml::from_str(
+        r"
+        destination: 198.51.100.1/32
+        next-hop-interface: eth1
+        route-type: local
+        scope: link
+        ",
+    )
+    .unwrap();
+    assert!(desired.is_match(&current));
+    assert!(!desired.is_match(&link_scope_route));
+}
+
+#[test]
+fn test_route_local_with_next_hop_addr() {
+    let routes: Routes = serde_yaml::from_str(
+        r"
+config:
+- destination: 198.51.100.1/32
+  next-hop-interface: eth1
+  next-hop-address: 192.0.2.1
+  route-type: local
+",
+    )
+    .unwrap();
+    let result = routes.validate();
+    assert!(result.is_err());
+    assert_eq!(result.err().unwrap().kind(), ErrorKind::InvalidArgument);
+}
@@ -868,3 +868,29 @@ fn test_sriov_not_allow_802_1ad_vlan_protocol_for_vlan_0_and_qos_0() {
         assert_eq!(e.kind(), ErrorKind::InvalidArgument);
     }
 }
+
+#[test]
+fn test_sriov_vf_min_tx_rate_bigger_than_max() {
+    let mut desired = serde_yaml::from_str::<Interface>(
+        r"---
+        name: eth1
+        type: ethernet
+        state: up
+        ethernet:
+          sr-iov:
+            total-vfs: 1
+            vfs:
+            - id: 0
+              min-tx-rate: 200
+              max-tx-rate: 100
+        ",
+    )
+    .unwrap();
+
+    let result = desired.sanitize(true);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
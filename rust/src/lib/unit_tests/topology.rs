@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use crate::{ControllerTopology, InterfaceType, NetworkState};
+
+#[test]
+fn test_topology_bond_in_bridge() {
+    let mut state: NetworkState = serde_yaml::from_str(
+        r"---
+        interfaces:
+        - name: br0
+          type: linux-bridge
+          state: up
+          bridge:
+            port:
+            - name: bond0
+        - name: bond0
+          type: bond
+          state: up
+          link-aggregation:
+            mode: active-backup
+            port:
+            - eth1
+        - name: eth1
+          type: ethernet
+          state: up
+        - name: eth2
+          type: ethernet
+          state: up
+          controller: bond0
+        - name: eth3
+          type: ethernet
+          state: up
+          controller: br1
+        - name: eth4
+          type: ethernet
+          state: up
+          controller: bond0",
+    )
+    .unwrap();
+    // The eth4 is pointing to a bond0 of wrong type
+    if let Some(iface) = state
+        .interfaces
+        .get_iface_mut("eth4", InterfaceType::Ethernet)
+    {
+        iface.base_iface_mut().controller_type =
+            Some(InterfaceType::LinuxBridge);
+    }
+
+    let topology = state.topology();
+
+    assert_eq!(
+        topology.controllers,
+        vec![
+            ControllerTopology {
+                name: "bond0".to_string(),
+                iface_type: InterfaceType::Bond,
+                ports: vec!["eth1".to_string(), "eth2".to_string()],
+            },
+            ControllerTopology {
+                name: "br0".to_string(),
+                iface_type: InterfaceType::LinuxBridge,
+                ports: vec!["bond0".to_string()],
+            },
+        ]
+    );
+    assert_eq!(
+        topology.orphan_ports,
+        vec!["eth3".to_string(), "eth4".to_string()]
+    );
+}
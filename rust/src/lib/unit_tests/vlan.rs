@@ -183,6 +183,29 @@ fn test_vlan_orphan_has_now_parent() {
     MergedInterfaces::new(desired, current, false, false).unwrap();
 }
 
+#[test]
+fn test_vlan_over_missing_parent() {
+    let desired: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: eth1.100
+          state: up
+          type: vlan
+          vlan:
+            base-iface: eth1
+            id: 100",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(desired, Interfaces::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("eth1"));
+    }
+}
+
 #[test]
 fn test_vlan_update() {
     let mut iface1: VlanInterface = serde_yaml::from_str(
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::{InterfaceType, Interfaces, MergedInterfaces, VrfInterface};
+use crate::{
+    ErrorKind, InterfaceType, Interfaces, MergedInterfaces, VrfInterface,
+};
 
 #[test]
 fn test_vrf_stringlized_attributes() {
@@ -85,3 +87,98 @@ fn test_vrf_on_bond_vlan_got_auto_remove() {
         .unwrap();
     assert!(iface.is_absent());
 }
+
+#[test]
+fn test_vrf_share_table_id() {
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: vrf0
+          type: vrf
+          state: up
+          vrf:
+            route-table-id: 100
+        - name: vrf1
+          type: vrf
+          state: up
+          vrf:
+            route-table-id: 100
+        ",
+    )
+    .unwrap();
+
+    let result =
+        MergedInterfaces::new(des_ifaces, Interfaces::new(), false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("vrf0 and vrf1"));
+    }
+}
+
+#[test]
+fn test_vrf_share_table_id_with_current() {
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: vrf0
+          type: vrf
+          state: up
+          vrf:
+            route-table-id: 100
+        ",
+    )
+    .unwrap();
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: vrf1
+          type: vrf
+          state: up
+          vrf:
+            route-table-id: 100
+        ",
+    )
+    .unwrap();
+
+    let result = MergedInterfaces::new(des_ifaces, cur_ifaces, false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_vrf_share_table_id_with_current_of_changed_iface() {
+    let cur_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: vrf0
+          type: vrf
+          state: up
+          vrf:
+            route-table-id: 100
+        ",
+    )
+    .unwrap();
+    let des_ifaces: Interfaces = serde_yaml::from_str(
+        r"---
+        - name: vrf0
+          type: vrf
+          state: up
+          mtu: 9000
+        - name: vrf1
+          type: vrf
+          state: up
+          vrf:
+            route-table-id: 100
+        ",
+    )
+    .unwrap();
+
+    let result = MergedInterfaces::new(des_ifaces, cur_ifaces, false, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+        assert!(e.msg().contains("vrf0 and vrf1"));
+    }
+}
@@ -1,6 +1,8 @@
 // SPDX-License-Identifier: Apache-2.0
 
-use crate::VxlanInterface;
+use crate::{
+    ErrorKind, Interface, MergedNetworkState, NetworkState, VxlanInterface,
+};
 
 #[test]
 fn test_vxlan_stringlized_attributes() {
@@ -47,3 +49,86 @@ vxlan:
         Some(std::net::IpAddr::V4("1.2.3.4".parse().unwrap()))
     );
 }
+
+#[test]
+fn test_vxlan_gbp() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: vxlan1
+  type: vxlan
+  state: up
+  vxlan:
+    base-iface: eth1
+    id: 101
+    gbp: true
+",
+    )
+    .unwrap();
+
+    let merged_state =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false)
+            .unwrap();
+    let iface = merged_state
+        .interfaces
+        .kernel_ifaces
+        .get("vxlan1")
+        .and_then(|i| i.for_apply.as_ref());
+    if let Some(Interface::Vxlan(iface)) = iface {
+        assert_eq!(iface.vxlan.as_ref().and_then(|c| c.gbp), Some(true));
+    } else {
+        panic!("Expecting VXLAN interface, but got {iface:?}");
+    }
+}
+
+#[test]
+fn test_vxlan_gpe_with_gbp() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: vxlan1
+  type: vxlan
+  state: up
+  vxlan:
+    base-iface: eth1
+    id: 101
+    gbp: true
+    gpe: true
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
+
+#[test]
+fn test_vxlan_local_remote_family_mismatch() {
+    let desired: NetworkState = serde_yaml::from_str(
+        r"---
+interfaces:
+- name: vxlan1
+  type: vxlan
+  state: up
+  vxlan:
+    base-iface: eth1
+    id: 101
+    local: 192.0.2.1
+    remote: 2001:db8::1
+",
+    )
+    .unwrap();
+
+    let result =
+        MergedNetworkState::new(desired, NetworkState::new(), true, false);
+
+    assert!(result.is_err());
+    if let Err(e) = result {
+        assert_eq!(e.kind(), ErrorKind::InvalidArgument);
+    }
+}
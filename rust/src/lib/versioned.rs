@@ -0,0 +1,134 @@
+// SPDX-License-Identifier: Apache-2.0
+
+use serde_json::{Map, Value};
+
+use crate::{ErrorKind, NetworkState, NmstateError};
+
+const SCHEMA_VERSION_KEY: &str = "schema-version";
+
+// Each entry migrates the payload from schema version `index + 1` to
+// `index + 2`. Append new migration here when changing the schema in
+// backwards incompatible way.
+const MIGRATIONS: [fn(&mut Map<String, Value>); 1] = [migrate_v1_to_v2];
+
+impl NetworkState {
+    /// The schema version of JSON generated by
+    /// [NetworkState::to_versioned_json()].
+    pub const SCHEMA_VERSION: u32 = MIGRATIONS.len() as u32 + 1;
+
+    /// Serialize to JSON string with top level `schema-version` property
+    /// for storing the desired state. The payload could be loaded by
+    /// [NetworkState::from_versioned_json()] of newer nmstate.
+    pub fn to_versioned_json(&self) -> Result<String, NmstateError> {
+        let mut value = match serde_json::to_value(self) {
+            Ok(Value::Object(v)) => v,
+            Ok(v) => {
+                return Err(NmstateError::new(
+                    ErrorKind::Bug,
+                    format!("NetworkState serialized to non-object: {v}"),
+                ));
+            }
+            Err(e) => {
+                return Err(NmstateError::new(
+                    ErrorKind::Bug,
+                    format!("Failed to serialize NetworkState: {e}"),
+                ));
+            }
+        };
+        value.insert(
+            SCHEMA_VERSION_KEY.to_string(),
+            Value::from(Self::SCHEMA_VERSION),
+        );
+        serde_json::to_string(&Value::Object(value)).map_err(|e| {
+            NmstateError::new(
+                ErrorKind::Bug,
+                format!("Failed to serialize NetworkState: {e}"),
+            )
+        })
+    }
+
+    /// Load JSON string generated by [NetworkState::to_versioned_json()],
+    /// payload of older schema version will be migrated to current schema.
+    pub fn from_versioned_json(json_str: &str) -> Result<Self, NmstateError> {
+        let mut value = match serde_json::from_str::<Value>(json_str) {
+            Ok(Value::Object(v)) => v,
+            Ok(_) => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    "Versioned network state should be a JSON object"
+                        .to_string(),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            Err(e) => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!("Invalid JSON string: {e}"),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        };
+        let version = match value
+            .remove(SCHEMA_VERSION_KEY)
+            .as_ref()
+            .and_then(|v| v.as_u64())
+        {
+            Some(v) if v >= 1 && v <= Self::SCHEMA_VERSION.into() => v,
+            Some(v) => {
+                let e = NmstateError::new(
+                    ErrorKind::NotSupportedError,
+                    format!(
+                        "Unsupported {SCHEMA_VERSION_KEY} {v}, \
+                        only support 1 to {}",
+                        Self::SCHEMA_VERSION
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+            None => {
+                let e = NmstateError::new(
+                    ErrorKind::InvalidArgument,
+                    format!(
+                        "Versioned network state should have unsigned \
+                        integer {SCHEMA_VERSION_KEY} property"
+                    ),
+                );
+                log::error!("{}", e);
+                return Err(e);
+            }
+        };
+        for migration in MIGRATIONS.iter().skip(version as usize - 1) {
+            migration(&mut value);
+        }
+        serde_json::from_value(Value::Object(value)).map_err(|e| {
+            let e = NmstateError::new(
+                ErrorKind::InvalidArgument,
+                format!("Invalid versioned network state: {e}"),
+            );
+            log::error!("{}", e);
+            e
+        })
+    }
+}
+
+// Schema version 1 used `slaves` for the port list of bond
+fn migrate_v1_to_v2(value: &mut Map<String, Value>) {
+    for iface in value
+        .get_mut("interfaces")
+        .and_then(|v| v.as_array_mut())
+        .map(|v| v.as_mut_slice())
+        .unwrap_or_default()
+    {
+        if let Some(bond_conf) = iface
+            .get_mut("link-aggregation")
+            .and_then(|v| v.as_object_mut())
+        {
+            if let Some(ports) = bond_conf.remove("slaves") {
+                bond_conf.insert("port".to_string(), ports);
+            }
+        }
+    }
+}